@@ -0,0 +1,126 @@
+//! Blueprint actions - saving a layout of the town's buildings and stamping it elsewhere
+
+use crate::data::GameState;
+use crate::narrative::LogCategory;
+use crate::zones::blueprint::{self, Blueprint};
+use crate::PlayerAction;
+
+/// Apply a blueprint action (see `super::apply_action`)
+pub fn apply(state: &mut GameState, action: PlayerAction) {
+    match action {
+        PlayerAction::ToggleBlueprints => {
+            state.show_blueprints = !state.show_blueprints;
+            if !state.show_blueprints {
+                state.blueprint_draft = None;
+                state.placing_blueprint = None;
+            }
+        }
+        PlayerAction::DraftBlueprint(on) => {
+            super::stop_placing(state);
+            if on {
+                state.blueprint_draft = Some(Vec::new());
+            }
+        }
+        PlayerAction::ToggleBlueprintZone(id) => {
+            let draft = match &mut state.blueprint_draft {
+                Some(draft) => draft,
+                None => return,
+            };
+            if let Some(slot) = draft.iter().position(|&picked| picked == id) {
+                draft.remove(slot);
+            } else {
+                draft.push(id);
+            }
+        }
+        PlayerAction::SaveBlueprint(name) => {
+            let picked = state.blueprint_draft.clone().unwrap_or_default();
+            let saved = match Blueprint::capture(state, &name, &picked) {
+                Some(saved) => saved,
+                None => {
+                    state.log.add(
+                        state.game_time_hours,
+                        format!(
+                            "Pick at least {} buildings for a blueprint.",
+                            blueprint::MIN_BLUEPRINT_ZONES
+                        ),
+                        LogCategory::System,
+                    );
+                    return;
+                }
+            };
+
+            let count = saved.zones.len();
+            let blueprints = &mut state.dynasty.blueprints;
+            if let Some(existing) = blueprints.iter_mut().find(|b| b.name == name) {
+                *existing = saved;
+            } else if blueprints.len() < blueprint::MAX_BLUEPRINTS {
+                blueprints.push(saved);
+            } else {
+                state.log.add(
+                    state.game_time_hours,
+                    format!(
+                        "The dynasty can keep {} blueprints. Delete one first.",
+                        blueprint::MAX_BLUEPRINTS
+                    ),
+                    LogCategory::System,
+                );
+                return;
+            }
+
+            state.blueprint_draft = None;
+            state.log.add(
+                state.game_time_hours,
+                format!("Saved the layout of {} buildings as {}.", count, name),
+                LogCategory::Zone,
+            );
+        }
+        PlayerAction::DeleteBlueprint(name) => {
+            state.dynasty.blueprints.retain(|b| b.name != name);
+            if state.placing_blueprint.as_ref() == Some(&name) {
+                state.placing_blueprint = None;
+            }
+        }
+        PlayerAction::StartPlacingBlueprint(name) => {
+            super::stop_placing(state);
+            state.placing_blueprint = name;
+        }
+        PlayerAction::PlaceBlueprint(name, x, y) => {
+            let placements = match state.dynasty.blueprints.iter().find(|b| b.name == name) {
+                Some(saved) => blueprint::placements(state, saved, x, y),
+                None => return,
+            };
+            if let Some(problem) = blueprint::problem(state, &placements) {
+                state.log.add(
+                    state.game_time_hours,
+                    format!("Can't lay out {} there: {}.", name, problem),
+                    LogCategory::System,
+                );
+                return;
+            }
+            let cost = blueprint::cost(&placements);
+            if state.resources.materials < cost {
+                state.log.add(
+                    state.game_time_hours,
+                    format!("Laying out {} needs {:.0} materials.", name, cost),
+                    LogCategory::System,
+                );
+                return;
+            }
+
+            state.resources.materials -= cost;
+            blueprint::stamp(state, &placements);
+            state.placing_blueprint = None;
+            state.log.add(
+                state.game_time_hours,
+                format!(
+                    "Laid out {} ({} buildings, -{:.0} Mat).",
+                    name,
+                    placements.len(),
+                    cost
+                ),
+                LogCategory::Zone,
+            );
+        }
+        _ => {}
+    }
+}
@@ -0,0 +1,146 @@
+//! Chronicle actions - heroes, ancestors, legacy upgrades and passing the town on
+
+use crate::data::GameState;
+use crate::narrative::LogCategory;
+use crate::scene::Scene;
+use crate::PlayerAction;
+
+/// Apply an action from the chronicle (see `super::apply_action`)
+pub fn apply(state: &mut GameState, action: PlayerAction) {
+    match action {
+        PlayerAction::ToggleChronicle => {
+            state.show_chronicle = !state.show_chronicle;
+            state.confirm_new_town = false;
+        }
+        PlayerAction::ImmortalizeHero(agent_id) => {
+            // Use the name and epitaph from the dialog, if it was open for this villager
+            let draft = state.hero_draft.take().filter(|d| d.agent_id == agent_id);
+            // Find the agent and create a VillagerRecord
+            if let Some(agent) = state.agents.iter().find(|a| a.id == agent_id) {
+                let town_name = state
+                    .region_map
+                    .active_town()
+                    .map_or("the town".to_string(), |n| n.name.clone());
+                let name = draft
+                    .as_ref()
+                    .map_or(agent.name.clone(), |d| d.display_name(&agent.name));
+                let record = crate::narrative::VillagerRecord {
+                    name: name.clone(),
+                    description: format!("{} - A {} of {}", name, agent.job.name(), town_name),
+                    feats: agent.feats.to_strings(),
+                    timestamp_added: state.game_time_hours,
+                    epitaph: draft.as_ref().and_then(|d| d.epitaph()),
+                };
+                state.dynasty.add_hero(record);
+
+                // Award legacy points based on feats
+                let points = 5 + agent.feats.buildings_helped + agent.feats.social_events / 2;
+                state.dynasty.add_legacy_points(points);
+
+                state.log.add(
+                    state.game_time_hours,
+                    format!(
+                        "{} has been immortalized in the Hall of Heroes! (+{} Legacy Points)",
+                        name, points
+                    ),
+                    LogCategory::Event,
+                );
+            }
+            if let Some(agent) = state.agents.iter_mut().find(|a| a.id == agent_id) {
+                agent.immortalized = true;
+            }
+        }
+        PlayerAction::DismissEnding => {
+            state.camera.reset();
+            state.scene_manager.transition_to(Scene::TownView);
+        }
+        PlayerAction::NewGamePlus => {
+            let difficulty = state.difficulty;
+            *state = crate::new_game_plus(state);
+            state.difficulty_choice = difficulty;
+            state.game_in_progress = true;
+            state.scene_manager.transition_to(Scene::TownView);
+        }
+        PlayerAction::RetireHero(hero_name) => {
+            // Retire a hero from Hall of Heroes to become an ancestor
+            if let Some(buff) = state.dynasty.retire_hero(&hero_name, state.game_time_hours) {
+                state.log.add(
+                    state.game_time_hours,
+                    format!(
+                        "{} has joined the ancestors, granting {}!",
+                        hero_name,
+                        buff.name()
+                    ),
+                    LogCategory::Event,
+                );
+                state.dynasty.add_legacy_points(20);
+            }
+        }
+        PlayerAction::AskFoundNewTown(open) => {
+            state.confirm_new_town = open;
+        }
+        PlayerAction::FoundNewTown => {
+            state.found_new_town();
+            state.show_chronicle = false;
+            state.confirm_new_town = false;
+            // A new generation may give the town a new name
+            let draft = state
+                .region_map
+                .active_town()
+                .map(|n| crate::ui::town_namer::TownNameDraft::new(n.id, &n.name));
+            state.town_name_draft = draft;
+        }
+        PlayerAction::BuyMetaUpgrade(upgrade) => {
+            if !state.dynasty.buy_meta_upgrade(upgrade) {
+                return;
+            }
+            state.log.add(
+                state.game_time_hours,
+                format!(
+                    "Legacy upgrade purchased: {} (level {})",
+                    upgrade.name(),
+                    state.dynasty.meta_upgrades.level(upgrade)
+                ),
+                LogCategory::Milestone,
+            );
+        }
+        PlayerAction::SetChronicleTab(tab) => {
+            state.chronicle_tab = tab;
+        }
+        PlayerAction::SetChronicleDay(day) => {
+            state.chronicle_day = Some(day);
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::actions::apply_action;
+
+    #[test]
+    fn test_new_town_waits_for_confirmation() {
+        let mut state = GameState::default();
+        state
+            .zone_templates
+            .extend(crate::assets::load_zones().unwrap());
+        state.population.add(20.0);
+        state.show_chronicle = true;
+
+        apply_action(&mut state, PlayerAction::AskFoundNewTown(true));
+        assert!(state.confirm_new_town);
+        assert!(state.dynasty.past_towns.is_empty());
+
+        apply_action(&mut state, PlayerAction::AskFoundNewTown(false));
+        assert!(!state.confirm_new_town);
+        assert!(state.dynasty.past_towns.is_empty());
+
+        apply_action(&mut state, PlayerAction::AskFoundNewTown(true));
+        apply_action(&mut state, PlayerAction::FoundNewTown);
+        assert!(!state.confirm_new_town);
+        assert!(!state.show_chronicle);
+        assert_eq!(state.dynasty.past_towns.len(), 1);
+        assert_eq!(state.dynasty.legacy_points, 10);
+    }
+}
@@ -0,0 +1,300 @@
+//! Player actions - the only place game state is mutated in response to input
+
+mod blueprints;
+mod chronicle;
+mod region;
+mod settings;
+mod zones;
+
+use crate::data::{self, GameState};
+use crate::narrative::LogCategory;
+use crate::scene::Scene;
+use crate::simulation;
+use crate::ui::main_menu::MenuPage;
+use crate::PlayerAction;
+
+/// Apply a player action to the game state
+pub fn apply_action(state: &mut GameState, action: PlayerAction) {
+    match action {
+        PlayerAction::SurveyZone(..)
+        | PlayerAction::RestoreZone(..)
+        | PlayerAction::UpgradeZone(..)
+        | PlayerAction::HireBuilders(..)
+        | PlayerAction::Undo
+        | PlayerAction::ToggleZoneDisabled(..)
+        | PlayerAction::TogglePinZone(..)
+        | PlayerAction::StartRelocating(..)
+        | PlayerAction::RelocateZone(..)
+        | PlayerAction::PlaceBridges(..)
+        | PlayerAction::BuildBridge(..) => zones::apply(state, action),
+        PlayerAction::ToggleBlueprints
+        | PlayerAction::DraftBlueprint(..)
+        | PlayerAction::ToggleBlueprintZone(..)
+        | PlayerAction::SaveBlueprint(..)
+        | PlayerAction::DeleteBlueprint(..)
+        | PlayerAction::StartPlacingBlueprint(..)
+        | PlayerAction::PlaceBlueprint(..) => blueprints::apply(state, action),
+        PlayerAction::ToggleRegionView
+        | PlayerAction::StartWonder(..)
+        | PlayerAction::ContributeToWonder(..)
+        | PlayerAction::ToggleWonderAutoFund
+        | PlayerAction::CoverProxyDebt(..)
+        | PlayerAction::SelectRegionNode(..)
+        | PlayerAction::SetCapital(..)
+        | PlayerAction::EditTownName(..)
+        | PlayerAction::RenameTown(..)
+        | PlayerAction::TravelToTown(..)
+        | PlayerAction::AcceptTradeOffer(..) => region::apply(state, action),
+        PlayerAction::ToggleChronicle
+        | PlayerAction::ImmortalizeHero(..)
+        | PlayerAction::DismissEnding
+        | PlayerAction::NewGamePlus
+        | PlayerAction::RetireHero(..)
+        | PlayerAction::AskFoundNewTown(..)
+        | PlayerAction::FoundNewTown
+        | PlayerAction::BuyMetaUpgrade(..)
+        | PlayerAction::SetChronicleTab(..)
+        | PlayerAction::SetChronicleDay(..) => chronicle::apply(state, action),
+        PlayerAction::ToggleEdgeScroll
+        | PlayerAction::ToggleFloatingText
+        | PlayerAction::ToggleCoordinates
+        | PlayerAction::ToggleFormulaOverlay
+        | PlayerAction::ToggleMaterialsAudit
+        | PlayerAction::SetAuditScroll(..)
+        | PlayerAction::ToggleRegionLegend
+        | PlayerAction::ToggleAutoRestore
+        | PlayerAction::SetAutoRestoreReserve(..)
+        | PlayerAction::ToggleRestorePriority
+        | PlayerAction::CycleColorScheme
+        | PlayerAction::SetAgentCap(..)
+        | PlayerAction::SetOfflineCap(..)
+        | PlayerAction::SetAutosaveInterval(..) => settings::apply(state, action),
+        PlayerAction::NewGame(seed, difficulty) => {
+            let town_name = state.town_name_input.clone();
+            *state = crate::fresh_game(state, seed, difficulty);
+            state.difficulty_choice = difficulty;
+            if let Some(town_id) = state.region_map.active_town_id {
+                state.region_map.rename_town(town_id, &town_name);
+            }
+            state.game_in_progress = true;
+            state.scene_manager.transition_to(Scene::TownView);
+        }
+        PlayerAction::ContinueGame => {
+            state.scene_manager.transition_to(Scene::TownView);
+        }
+        PlayerAction::ResumeAutosave => match crate::resume_autosave(state) {
+            Some(resumed) => {
+                *state = resumed;
+                state.difficulty_choice = state.difficulty;
+                state.game_in_progress = true;
+                state.scene_manager.transition_to(Scene::TownView);
+            }
+            // Unreadable - stop offering it
+            None => state.autosave_found = false,
+        },
+        PlayerAction::OpenMainMenu => {
+            state.menu_page = MenuPage::Title;
+            state.scene_manager.transition_to(Scene::MainMenu);
+        }
+        PlayerAction::SetMenuPage(page) => {
+            if page == MenuPage::NewGame && state.seed_input.is_empty() {
+                state.seed_input = simulation::rng::clock_seed().to_string();
+            }
+            state.menu_page = page;
+        }
+        PlayerAction::SetSeedInput(input) => {
+            state.seed_input = input;
+        }
+        PlayerAction::SetTownNameInput(input) => {
+            state.town_name_input = input;
+        }
+        PlayerAction::TypeTownName(on) => {
+            state.typing_town_name = on;
+        }
+        PlayerAction::SetDifficultyChoice(difficulty) => {
+            state.difficulty_choice = difficulty;
+        }
+        PlayerAction::CopySeed => {
+            let seed = state.region_map.seed.to_string();
+            macroquad::miniquad::window::clipboard_set(&seed);
+            state.log.add(
+                state.game_time_hours,
+                format!("Seed {} copied to clipboard.", seed),
+                LogCategory::System,
+            );
+        }
+        #[cfg(debug_assertions)]
+        PlayerAction::ReloadZoneTemplates => {
+            let templates = match crate::assets::reload_zones() {
+                Some(templates) => templates,
+                None => return,
+            };
+            let count = templates.len();
+            let missing = state.replace_zone_templates(templates);
+            state.log.add(
+                state.game_time_hours,
+                format!("Reloaded {} zone templates.", count),
+                LogCategory::System,
+            );
+            for id in missing {
+                state.log.add(
+                    state.game_time_hours,
+                    format!("Zone template '{}' no longer exists; kept the old one.", id),
+                    LogCategory::System,
+                );
+            }
+        }
+        PlayerAction::QuitGame => {
+            // Handled by the main loop, which owns the window
+        }
+        PlayerAction::Select(sel) => {
+            // Nothing left to focus on
+            if sel == data::Selection::None {
+                state.focus_mode = false;
+            }
+            state.selection = sel;
+        }
+        PlayerAction::ToggleFocusMode => {
+            state.focus_mode = !state.focus_mode;
+        }
+        PlayerAction::FocusZone(id) => {
+            let site = state.zone(id).and_then(|z| state.zone_rect(z));
+            if let Some(rect) = site {
+                state.camera.target = rect.world_center();
+                state.selection = data::Selection::Zone(id);
+            }
+        }
+        PlayerAction::CycleAgent {
+            forward,
+            notable_only,
+        } => {
+            let next = state.next_agent(forward, notable_only);
+            if let Some(agent) = next.and_then(|id| state.agents.iter().find(|a| a.id == id)) {
+                let pos = agent.pos;
+                let zoom = state.camera.zoom;
+                state.selection = data::Selection::Agent(agent.id);
+                state.camera.glide_to(pos, zoom);
+            }
+        }
+        PlayerAction::FrameTown => {
+            // Every zone that's back in use, or the whole town while it's all ruins
+            let rects = |working: bool| {
+                state
+                    .zones
+                    .iter()
+                    .filter(|z| !working || !z.dormant)
+                    .filter_map(|z| state.zone_rect(z))
+                    .map(|rect| rect.world_rect())
+                    .reduce(|a, b| a.combine_with(b))
+            };
+            if let Some(bounds) = rects(true).or_else(|| rects(false)) {
+                let map_size = macroquad::prelude::vec2(
+                    state.world_map.width as f32,
+                    state.world_map.height as f32,
+                ) * crate::ui::map_renderer::TILE_SIZE;
+                let screen = macroquad::prelude::vec2(
+                    macroquad::prelude::screen_width(),
+                    macroquad::prelude::screen_height(),
+                );
+                let (target, zoom) = simulation::camera::fit_view(bounds, map_size, screen);
+                state.camera.glide_to(target, zoom);
+            }
+        }
+        PlayerAction::ToggleTechTree => {
+            state.show_tech_tree = !state.show_tech_tree;
+        }
+        PlayerAction::ToggleBuildMenu => {
+            state.show_build_menu = !state.show_build_menu;
+        }
+        PlayerAction::SetZoneScroll(val) => {
+            state.zones_scroll_offset = val;
+        }
+        PlayerAction::SetZoneFilter(filter) => {
+            state.zone_filter = filter;
+            state.zones_scroll_offset = 0.0;
+        }
+        PlayerAction::SetTechView(view) => {
+            state.tech_view = view;
+        }
+        PlayerAction::DismissDialog => {
+            state.tutorial.dismiss_dialog();
+        }
+        PlayerAction::Research(id) => {
+            // Find index
+            if let Some(pos) = state.tech_tree.iter().position(|t| t.id == id) {
+                let cost = state.research_cost(&state.tech_tree[pos]);
+                if state.resources.materials >= cost {
+                    // Purchase
+                    state.resources.materials -= cost;
+                    state.tech_tree[pos].unlocked = true;
+                    state.log.add(
+                        state.game_time_hours,
+                        format!("Researched: {}", state.tech_tree[pos].name),
+                        LogCategory::System,
+                    );
+                }
+            }
+        }
+        PlayerAction::SpeedUp | PlayerAction::SlowDown => {
+            // Time scale changes are handled in input, no state change needed
+        }
+        PlayerAction::ToggleTurbo => {
+            state.turbo = !state.turbo;
+        }
+        PlayerAction::SkipTutorial => {
+            state.tutorial.skip_tutorial();
+            state.camera.reset();
+        }
+        PlayerAction::EditHero(draft) => {
+            state.hero_draft = draft;
+        }
+        PlayerAction::EditMarketTrade(draft) => {
+            // Only opens while a market is running
+            state.market_draft = draft.filter(|_| state.has_active_market());
+        }
+        PlayerAction::MarketTrade { sell, buy, amount } => {
+            if !state.has_active_market() {
+                return;
+            }
+            let prices = &state.market_prices;
+            if let Some(received) = prices.trade(&mut state.resources, sell, buy, amount) {
+                state.log.add(
+                    state.game_time_hours,
+                    format!(
+                        "Traded {:.0} {} for {:.1} {} at the market.",
+                        amount,
+                        sell.name(),
+                        received,
+                        buy.name()
+                    ),
+                    LogCategory::Event,
+                );
+            }
+        }
+        PlayerAction::DismissYearSummary => {
+            state.year_summary = None;
+        }
+        PlayerAction::ToggleResourceGroup(group) => {
+            if let Some(pos) = state
+                .expanded_resource_groups
+                .iter()
+                .position(|g| *g == group)
+            {
+                state.expanded_resource_groups.remove(pos);
+            } else {
+                state.expanded_resource_groups.push(group);
+            }
+        }
+        PlayerAction::HoldFestival => {
+            simulation::festival::hold_festival(state);
+        }
+    }
+}
+
+/// Leave whichever placement mode is on (moving a zone, bridging, drafting or stamping a blueprint)
+fn stop_placing(state: &mut GameState) {
+    state.relocating = None;
+    state.placing_bridge = false;
+    state.blueprint_draft = None;
+    state.placing_blueprint = None;
+}
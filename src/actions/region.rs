@@ -0,0 +1,192 @@
+//! Region actions - the region map, wonders, archived towns, the capital and trade
+
+use crate::data::GameState;
+use crate::narrative::{self, LogCategory};
+use crate::region;
+use crate::simulation;
+use crate::PlayerAction;
+
+/// Apply an action from the region map (see `super::apply_action`)
+pub fn apply(state: &mut GameState, action: PlayerAction) {
+    match action {
+        PlayerAction::ToggleRegionView => {
+            if state.scene_manager.in_town_view() {
+                // Archive current town when leaving town view
+                state.archive_current_town();
+            } else {
+                // Restore town when returning (uses get and remove via restore_town)
+                if let Some(town_id) = state.region_map.active_town_id {
+                    state.restore_town(town_id);
+                }
+            }
+            state.scene_manager.toggle_region_view();
+        }
+        PlayerAction::StartWonder(node_id, wonder) => {
+            // Start construction of a wonder at the specified node
+            if let Some(node) = state.region_map.get_node_mut(node_id) {
+                if node.is_wonder_site && node.wonder_site.is_none() {
+                    // Check if Cloud Spire requirements are met
+                    if wonder == narrative::Wonder::CloudSpire {
+                        if !narrative::can_build_cloud_spire(
+                            &state.dynasty.completed_wonders,
+                            state.dynasty.legacy_points,
+                            state.population.value(),
+                        ) {
+                            state.log.add(
+                                state.game_time_hours,
+                                "Cannot build Cloud Spire yet. Requires 3 wonders, 1000 legacy points, and 50 population.".to_string(),
+                                LogCategory::System,
+                            );
+                            return;
+                        }
+                    }
+
+                    node.wonder_site =
+                        Some(narrative::WonderSite::new(wonder, state.game_time_hours));
+                    state.log.add(
+                        state.game_time_hours,
+                        format!(
+                            "Construction of {} has begun at {}!",
+                            wonder.name(),
+                            node.name
+                        ),
+                        LogCategory::Event,
+                    );
+                }
+            }
+        }
+        PlayerAction::ContributeToWonder(node_id, amount) => {
+            // Contribute resources to a wonder under construction
+            if state.resources.materials < amount {
+                state.log.add(
+                    state.game_time_hours,
+                    "Not enough materials to contribute!".to_string(),
+                    LogCategory::System,
+                );
+                return;
+            }
+
+            simulation::wonder_funding::contribute(state, node_id, amount);
+        }
+        PlayerAction::ToggleWonderAutoFund => {
+            state.settings.wonder_auto_fund = !state.settings.wonder_auto_fund;
+        }
+        PlayerAction::CoverProxyDebt(town_id) => {
+            state.cover_proxy_debt(town_id);
+        }
+        PlayerAction::SelectRegionNode(node_id) => {
+            state.region_map.selected_node = node_id;
+        }
+        PlayerAction::SetCapital(town_id) => {
+            let name = match state.region_map.get_node(town_id) {
+                Some(node) if node.settled && !node.is_capital => node.name.clone(),
+                _ => return,
+            };
+
+            if !state
+                .dynasty
+                .spend_legacy_points(region::CAPITAL_RELOCATION_COST)
+            {
+                state.log.add(
+                    state.game_time_hours,
+                    format!(
+                        "Moving the capital requires {} legacy points.",
+                        region::CAPITAL_RELOCATION_COST
+                    ),
+                    LogCategory::Event,
+                );
+                return;
+            }
+
+            state.region_map.set_capital(town_id);
+            state.log.add(
+                state.game_time_hours,
+                format!("{} is now the capital of the region.", name),
+                LogCategory::Milestone,
+            );
+            state.town_chronicle.record(
+                state.game_time_hours,
+                narrative::ChronicleEventType::Special {
+                    description: format!("The capital moved to {}", name),
+                },
+            );
+        }
+        PlayerAction::EditTownName(draft) => {
+            state.town_name_draft = draft;
+        }
+        PlayerAction::RenameTown(town_id, name) => {
+            state.town_name_draft = None;
+            let old_name = match state.region_map.rename_town(town_id, &name) {
+                Some(old_name) => old_name,
+                None => return,
+            };
+            let new_name = state
+                .region_map
+                .get_node(town_id)
+                .map(|n| n.name.clone())
+                .unwrap_or_default();
+            state.log.add(
+                state.game_time_hours,
+                format!("{} is now known as {}.", old_name, new_name),
+                LogCategory::Event,
+            );
+            if state.region_map.active_town_id == Some(town_id) {
+                state.town_chronicle.record(
+                    state.game_time_hours,
+                    narrative::ChronicleEventType::Special {
+                        description: format!("{} was renamed {}", old_name, new_name),
+                    },
+                );
+            }
+        }
+        PlayerAction::TravelToTown(town_id) => {
+            // The town we left stays archived; the one we travel to comes back to life
+            if !state.scene_manager.in_region_view() || !state.restore_town(town_id) {
+                return;
+            }
+            if let Some(node) = state.region_map.get_node(town_id) {
+                state.log.add(
+                    state.game_time_hours,
+                    format!("You travel to {}.", node.name),
+                    LogCategory::System,
+                );
+            }
+            state.region_map.selected_node = None;
+            state.scene_manager.toggle_region_view();
+        }
+        PlayerAction::AcceptTradeOffer(offer_id) => {
+            let from_town = match state.region_map.active_town_id {
+                Some(id) => id,
+                None => return,
+            };
+            let offer = match state.trade_manager.offers.iter().find(|o| o.id == offer_id) {
+                Some(offer) => offer.clone(),
+                None => return,
+            };
+
+            if state
+                .trade_manager
+                .accept_offer(offer_id, from_town)
+                .is_some()
+            {
+                let buyer = state
+                    .region_map
+                    .get_node(offer.town_id)
+                    .map(|n| n.name.clone())
+                    .unwrap_or("Unknown".to_string());
+                state.log.add(
+                    state.game_time_hours,
+                    format!(
+                        "Trade agreed: {:.0} {} to {} each trip for {:.0} Materials",
+                        offer.amount_per_trip,
+                        offer.good.name(),
+                        buyer,
+                        offer.payment_per_trip
+                    ),
+                    LogCategory::Event,
+                );
+            }
+        }
+        _ => {}
+    }
+}
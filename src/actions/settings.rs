@@ -0,0 +1,71 @@
+//! Settings actions - toggles and limits from the settings page
+
+use crate::data::GameState;
+use crate::PlayerAction;
+
+/// Bounds for the villager cap setting
+const MIN_AGENT_CAP: usize = 10;
+const MAX_AGENT_CAP: usize = 500;
+
+/// Bounds (hours) for the base offline cap setting
+const MIN_OFFLINE_CAP: f32 = 12.0;
+const MAX_OFFLINE_CAP: f32 = 168.0;
+
+/// Longest gap (real minutes) that can be set between autosaves
+const MAX_AUTOSAVE_MINUTES: f32 = 30.0;
+
+/// Highest materials reserve auto-restore can be told to keep
+const MAX_AUTO_RESTORE_RESERVE: f32 = 1000.0;
+
+/// Apply a change to the settings (see `super::apply_action`)
+pub fn apply(state: &mut GameState, action: PlayerAction) {
+    match action {
+        PlayerAction::ToggleEdgeScroll => {
+            state.settings.edge_scroll = !state.settings.edge_scroll;
+        }
+        PlayerAction::ToggleFloatingText => {
+            state.settings.floating_text = !state.settings.floating_text;
+            state.floating_texts.clear();
+        }
+        PlayerAction::ToggleCoordinates => {
+            state.settings.show_coordinates = !state.settings.show_coordinates;
+        }
+        PlayerAction::ToggleFormulaOverlay => {
+            state.settings.show_formulas = !state.settings.show_formulas;
+        }
+        PlayerAction::ToggleMaterialsAudit => {
+            state.show_materials_audit = !state.show_materials_audit;
+            state.audit_scroll = 0.0;
+        }
+        PlayerAction::SetAuditScroll(row) => {
+            state.audit_scroll = row;
+        }
+        PlayerAction::ToggleRegionLegend => {
+            state.settings.show_region_legend = !state.settings.show_region_legend;
+        }
+        PlayerAction::ToggleAutoRestore => {
+            state.settings.auto_restore = !state.settings.auto_restore;
+        }
+        PlayerAction::SetAutoRestoreReserve(reserve) => {
+            state.settings.auto_restore_reserve = reserve.clamp(0.0, MAX_AUTO_RESTORE_RESERVE);
+        }
+        PlayerAction::ToggleRestorePriority => {
+            let priority = &mut state.settings.auto_restore_priority;
+            *priority = priority.toggled();
+        }
+        PlayerAction::CycleColorScheme => {
+            let scheme = &mut state.settings.color_scheme;
+            *scheme = scheme.next();
+        }
+        PlayerAction::SetAgentCap(cap) => {
+            state.settings.agent_cap = cap.clamp(MIN_AGENT_CAP, MAX_AGENT_CAP);
+        }
+        PlayerAction::SetOfflineCap(hours) => {
+            state.settings.offline_cap_hours = Some(hours.clamp(MIN_OFFLINE_CAP, MAX_OFFLINE_CAP));
+        }
+        PlayerAction::SetAutosaveInterval(minutes) => {
+            state.settings.autosave_minutes = minutes.clamp(0.0, MAX_AUTOSAVE_MINUTES);
+        }
+        _ => {}
+    }
+}
@@ -0,0 +1,311 @@
+//! Zone actions - surveying, restoring, upgrading, hiring builders, moving zones
+//! and laying bridges
+
+use crate::data::{self, GameState};
+use crate::narrative::LogCategory;
+use crate::simulation;
+use crate::undo;
+use crate::zones;
+use crate::PlayerAction;
+
+/// Apply an action on the town's zones (see `super::apply_action`)
+pub fn apply(state: &mut GameState, action: PlayerAction) {
+    match action {
+        PlayerAction::SurveyZone(index) => {
+            if !zones::survey::is_unsurveyed(state, index) {
+                return;
+            }
+            let message = match zones::survey::survey(state, index) {
+                Some(name) => format!("Surveyors found the remains of the {}.", name),
+                None => format!(
+                    "Surveying the rubble takes {:.0} materials.",
+                    zones::survey::SURVEY_COST
+                ),
+            };
+            state
+                .log
+                .add(state.game_time_hours, message, LogCategory::Zone);
+        }
+        PlayerAction::RestoreZone(index) => {
+            // Nobody knows what this rubble was, or what it would take to rebuild
+            if zones::survey::is_unsurveyed(state, index) {
+                state.log.add(
+                    state.game_time_hours,
+                    "Survey the rubble before rebuilding it.".to_string(),
+                    LogCategory::System,
+                );
+                return;
+            }
+
+            // Get cost from template
+            let mut cost = data::ConstructionCost::Materials(1.0);
+            let mut zone_name = "Unknown Zone".to_string();
+            let mut missing_tech = None;
+
+            if let Some(zone) = state.zones.get(index) {
+                if let Some(template) = state
+                    .zone_templates
+                    .iter()
+                    .find(|t| t.id == zone.template_id)
+                {
+                    cost = template.construction_cost.clone();
+                    zone_name = template.name.clone();
+                    missing_tech = state.missing_tech(template);
+                }
+            }
+
+            // Locked zones wait for their tech
+            if let Some(tech) = missing_tech {
+                state.log.add(
+                    state.game_time_hours,
+                    format!("{} requires {} to be researched first.", zone_name, tech),
+                    LogCategory::System,
+                );
+                return;
+            }
+
+            // Check every part of the cost is in stock
+            if !state.resources.has(&cost) {
+                state.log.add(
+                    state.game_time_hours,
+                    format!(
+                        "Not enough resources for {}! Need {}",
+                        zone_name,
+                        cost.summary()
+                    ),
+                    LogCategory::System,
+                );
+                return;
+            }
+
+            if let Some(zone) = state.zones.get_mut(index) {
+                let zone_id = zone.id;
+                if zone.is_under_construction() {
+                    state.log.add(
+                        state.game_time_hours,
+                        format!("{} is already under construction.", zone_name),
+                        LogCategory::System,
+                    );
+                    return;
+                }
+
+                // Dormant ruins are rebuilt by builders; materials are deposited on site
+                if zone.dormant {
+                    zone.begin_construction();
+                    undo::record(state, undo::Change::Construction { zone: zone_id });
+                    state.log.add(
+                        state.game_time_hours,
+                        format!("Construction of {} has begun.", zone_name),
+                        LogCategory::Zone,
+                    );
+                    return;
+                }
+
+                // Check if already at max condition
+                if zone.condition >= 1.0 {
+                    state.log.add(
+                        state.game_time_hours,
+                        format!("{} is already fully restored.", zone_name),
+                        LogCategory::System,
+                    );
+                    return;
+                }
+
+                let old_condition = zone.condition;
+                zone.restore(0.5); // Restore 50% condition
+                let new_condition = zone.condition;
+
+                // Deduct cost
+                state.resources.spend(&cost);
+                let cost_text = cost.summary();
+                undo::record(
+                    state,
+                    undo::Change::Repair {
+                        zone: zone_id,
+                        previous_condition: old_condition,
+                        cost,
+                    },
+                );
+                state.log.add(
+                    state.game_time_hours,
+                    format!(
+                        "Restored {} (-{}): {:.0}% → {:.0}%",
+                        zone_name,
+                        cost_text,
+                        old_condition * 100.0,
+                        new_condition * 100.0
+                    ),
+                    LogCategory::Zone,
+                );
+            }
+        }
+        PlayerAction::UpgradeZone(index) => {
+            let previous_condition = state.zones.get(index).map_or(0.0, |z| z.condition);
+            if let Some(old_id) = zones::upgrades::apply_upgrade(state, index) {
+                // Statistics tracking
+                state.stats.zones_restored += 1;
+                let zone = &state.zones[index];
+                let zone_id = zone.id;
+                let cost = state
+                    .get_template(&zone.template_id)
+                    .map(|t| t.construction_cost.clone())
+                    .unwrap_or_default();
+                undo::record(
+                    state,
+                    undo::Change::Upgrade {
+                        zone: zone_id,
+                        previous_template: old_id,
+                        previous_condition,
+                        cost,
+                    },
+                );
+
+                // Log is handled inside apply_upgrade
+                // Clear selection to avoid stale UI
+                state.selection = data::Selection::None;
+            } else {
+                state.log.add(
+                    state.game_time_hours,
+                    "Cannot upgrade zone - check resources or requirements.".to_string(),
+                    LogCategory::System,
+                );
+            }
+        }
+        PlayerAction::HireBuilders(count) => {
+            let hired = simulation::construction::hire_builders(state, count);
+            let message = if hired == 0 {
+                "No laborers available to hire (or not enough materials).".to_string()
+            } else {
+                format!(
+                    "Hired {} builder(s) for {:.0}h (-{:.0} Mat)",
+                    hired,
+                    simulation::construction::HIRE_DURATION_HOURS,
+                    hired as f32 * simulation::construction::HIRE_COST_PER_BUILDER
+                )
+            };
+            state
+                .log
+                .add(state.game_time_hours, message, LogCategory::Zone);
+        }
+        PlayerAction::Undo => undo::undo(state),
+        PlayerAction::ToggleZoneDisabled(index) => {
+            let zone = match state.zones.get_mut(index) {
+                Some(zone) if !zone.dormant && !zone.is_under_construction() => zone,
+                _ => return,
+            };
+            zone.disabled = !zone.disabled;
+            let disabled = zone.disabled;
+            let zone_name = state
+                .get_template(&state.zones[index].template_id)
+                .map(|t| t.name.clone())
+                .unwrap_or("Zone".to_string());
+
+            let message = if disabled {
+                format!("{} mothballed - no output, no upkeep.", zone_name)
+            } else {
+                format!("{} is back in operation.", zone_name)
+            };
+            state
+                .log
+                .add(state.game_time_hours, message, LogCategory::Zone);
+        }
+        PlayerAction::TogglePinZone(id) => {
+            if let Some(slot) = state.pinned_zones.iter().position(|&p| p == id) {
+                state.pinned_zones.remove(slot);
+            } else if state.pinned_zones.len() < crate::ui::pinned_zones::MAX_PINNED {
+                state.pinned_zones.push(id);
+            } else {
+                state.log.add(
+                    state.game_time_hours,
+                    format!(
+                        "Only {} zones can be pinned. Unpin one first.",
+                        crate::ui::pinned_zones::MAX_PINNED
+                    ),
+                    LogCategory::System,
+                );
+            }
+        }
+        PlayerAction::StartRelocating(id) => {
+            super::stop_placing(state);
+            state.relocating = id;
+        }
+        PlayerAction::RelocateZone(id, x, y) => {
+            let zone_name = state
+                .zone(id)
+                .and_then(|z| state.get_template(&z.template_id))
+                .map_or("zone".to_string(), |t| t.name.clone());
+            let site = match zones::relocate::site_at(state, id, x, y) {
+                Some(site) => site,
+                None => return,
+            };
+            if let Some(problem) = zones::relocate::problem(state, id, site) {
+                state.log.add(
+                    state.game_time_hours,
+                    format!("Can't move the {} there: {}.", zone_name, problem),
+                    LogCategory::System,
+                );
+                return;
+            }
+            if state.resources.materials < zones::relocate::RELOCATE_COST {
+                state.log.add(
+                    state.game_time_hours,
+                    format!(
+                        "Moving the {} needs {:.0} materials.",
+                        zone_name,
+                        zones::relocate::RELOCATE_COST
+                    ),
+                    LogCategory::System,
+                );
+                return;
+            }
+
+            state.resources.materials -= zones::relocate::RELOCATE_COST;
+            zones::relocate::relocate(state, id, site);
+            state.relocating = None;
+            state.log.add(
+                state.game_time_hours,
+                format!(
+                    "Moved the {} (-{:.0} Mat).",
+                    zone_name,
+                    zones::relocate::RELOCATE_COST
+                ),
+                LogCategory::Zone,
+            );
+        }
+        PlayerAction::PlaceBridges(on) => {
+            super::stop_placing(state);
+            state.placing_bridge = on;
+        }
+        PlayerAction::BuildBridge(x, y) => {
+            use simulation::map::{TileType, BRIDGE_COST};
+            if let Some(problem) = state.world_map.bridge_problem(x, y) {
+                state.log.add(
+                    state.game_time_hours,
+                    format!("Can't build a bridge there: {}.", problem),
+                    LogCategory::System,
+                );
+                return;
+            }
+            if state.resources.materials < BRIDGE_COST {
+                state.log.add(
+                    state.game_time_hours,
+                    format!("A bridge needs {:.0} materials.", BRIDGE_COST),
+                    LogCategory::System,
+                );
+                return;
+            }
+
+            state.resources.materials -= BRIDGE_COST;
+            state.world_map.set_rect(x, y, 1, 1, TileType::Bridge, None);
+            state.log.add(
+                state.game_time_hours,
+                format!(
+                    "Bridged the water at ({}, {}) (-{:.0} Mat).",
+                    x, y, BRIDGE_COST
+                ),
+                LogCategory::Zone,
+            );
+        }
+        _ => {}
+    }
+}
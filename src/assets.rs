@@ -21,6 +21,19 @@ pub fn load_zones() -> Result<Vec<crate::data::ZoneTemplate>, serde_json::Error>
     serde_json::from_str(ZONES_JSON)
 }
 
+/// Re-read zone templates from `assets/zones.json` in the source tree (rather
+/// than the copy built into the binary) so balance edits show without a restart
+#[cfg(debug_assertions)]
+pub fn reload_zones() -> Option<Vec<crate::data::ZoneTemplate>> {
+    let path = concat!(env!("CARGO_MANIFEST_DIR"), "/assets/zones.json");
+    let json = std::fs::read_to_string(path)
+        .map_err(|e| eprintln!("Failed to read {}: {}", path, e))
+        .ok()?;
+    serde_json::from_str(&json)
+        .map_err(|e| eprintln!("Failed to parse {}: {}", path, e))
+        .ok()
+}
+
 /// Load and parse achievement definitions
 pub fn load_achievements() -> Result<Vec<crate::data::AchievementDef>, serde_json::Error> {
     serde_json::from_str(ACHIEVEMENTS_JSON)
@@ -87,5 +100,21 @@ pub async fn load_textures() -> GameAssets {
         }
     }
 
+    // Optional villager outfits - drawn shapes stand in for any that are missing
+    let optional_names = [
+        "agent_coat_winter",
+        "agent_job_farmer",
+        "agent_job_cook",
+        "agent_job_scavenger",
+        "agent_job_builder",
+        "agent_job_hauler",
+    ];
+    for name in optional_names {
+        if let Ok(tex) = load_texture(&format!("assets/{}.png", name)).await {
+            tex.set_filter(FilterMode::Nearest);
+            textures.insert(name.to_string(), tex);
+        }
+    }
+
     GameAssets { textures }
 }
@@ -111,6 +111,9 @@ pub struct GameStats {
     pub agents_born: u32,
     /// Total agents died
     pub agents_died: u32,
+    /// Total agents who gave up on the town and moved away
+    #[serde(default)]
+    pub agents_left: u32,
     /// Total in-game play hours
     pub total_play_hours: f32,
     /// Technologies researched
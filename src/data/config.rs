@@ -8,6 +8,10 @@ pub struct GameConfig {
     /// Population saturation constant K (higher = slower saturation)
     pub population_k: f32,
 
+    /// How fast population pressure grows and shrinks (K only shapes how it's used)
+    #[serde(default)]
+    pub population_curve: PopulationCurve,
+
     /// Maintenance cost coefficient (β in cost = β × EffectivePop²)
     pub maintenance_cost_coefficient: f32,
 
@@ -19,6 +23,81 @@ pub struct GameConfig {
 
     /// Game tick rate in seconds
     pub tick_rate_seconds: f32,
+
+    /// Scales storm frequency, storm damage and archived towns' shortages (0 = no disasters)
+    #[serde(default = "default_disaster_multiplier")]
+    pub disaster_multiplier: f32,
+
+    /// Most materials, maintenance or grain the town can store; zones making
+    /// them slow down as the store fills (0 = unlimited)
+    #[serde(default = "default_storage_limit")]
+    pub storage_limit: f32,
+
+    /// Zones every new town starts with, as ruins (the biome adds its speciality on top)
+    #[serde(default = "default_starting_zones")]
+    pub starting_zones: Vec<String>,
+
+    /// Starting zones that are already restored when the town is founded (a head start)
+    #[serde(default)]
+    pub restored_starting_zones: Vec<String>,
+}
+
+fn default_disaster_multiplier() -> f32 {
+    1.0
+}
+
+fn default_storage_limit() -> f32 {
+    500.0
+}
+
+fn default_starting_zones() -> Vec<String> {
+    [
+        "old_homestead",
+        "village_green",
+        "old_well",
+        "community_market",
+        "scavengers_workshop",
+        "community_farm",
+        "town_hall",
+    ]
+    .iter()
+    .map(|id| id.to_string())
+    .collect()
+}
+
+/// Tuning for `PopulationPressure::tick`, separate from the saturation constant K.
+///
+/// Growth per game minute is `growth_rate × attractiveness × free housing share × happiness`,
+/// capped at `max_growth_per_day` spread over the day. Pressure also decays steadily at
+/// `decay_rate`, faster when overcrowded and up to `misery_decay` times faster in a miserable town.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PopulationCurve {
+    /// Growth per game minute at 1.0 attractiveness with empty homes (higher = towns fill faster)
+    pub growth_rate: f32,
+
+    /// Most pressure that can be gained in one game day (0 = no limit).
+    /// Flattens the early rush without slowing a town that's nearly full.
+    pub max_growth_per_day: f32,
+
+    /// Decay multiplier when the town is completely miserable (1 = unhappiness doesn't
+    /// drive people away, higher = unhappy towns empty out faster)
+    pub misery_decay: f32,
+
+    /// Pressure lost per game minute however good the town is (0.005 is about 7 a day,
+    /// so a day's growth has to outpace it before the town gets any bigger)
+    pub decay_rate: f32,
+}
+
+impl Default for PopulationCurve {
+    fn default() -> Self {
+        Self {
+            growth_rate: 0.1,
+            max_growth_per_day: 0.0,
+            misery_decay: 3.0,
+            decay_rate: 0.005,
+        }
+    }
 }
 
 /// Default starting resource values
@@ -35,6 +114,7 @@ impl Default for GameConfig {
         // These are fallbacks ONLY - real values come from config.json
         Self {
             population_k: 10.0,
+            population_curve: PopulationCurve::default(),
             maintenance_cost_coefficient: 0.02,
             offline_time_cap_hours: 72.0,
             starting_resources: ResourceDefaults {
@@ -44,6 +124,10 @@ impl Default for GameConfig {
                 stability: 1.0,
             },
             tick_rate_seconds: 1.0,
+            disaster_multiplier: 1.0,
+            storage_limit: default_storage_limit(),
+            starting_zones: default_starting_zones(),
+            restored_starting_zones: Vec::new(),
         }
     }
 }
@@ -0,0 +1,69 @@
+//! Difficulty presets - scale the loaded balance config for a new game
+
+use super::GameConfig;
+use serde::{Deserialize, Serialize};
+
+/// How forgiving the town is, chosen when starting a new game
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum Difficulty {
+    /// No disasters, cheap upkeep, generous offline progress
+    Relaxed,
+    #[default]
+    Normal,
+    /// Frequent storms and crises, costly upkeep, lean start
+    Harsh,
+}
+
+impl Difficulty {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Difficulty::Relaxed => "Relaxed",
+            Difficulty::Normal => "Normal",
+            Difficulty::Harsh => "Harsh",
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            Difficulty::Relaxed => "No disasters, light upkeep, long offline progress.",
+            Difficulty::Normal => "The town as intended.",
+            Difficulty::Harsh => "Frequent storms and crises, heavy upkeep, a lean start.",
+        }
+    }
+
+    /// The next preset (wrapping), for the new game page's selector
+    pub fn next(&self) -> Self {
+        match self {
+            Difficulty::Relaxed => Difficulty::Normal,
+            Difficulty::Normal => Difficulty::Harsh,
+            Difficulty::Harsh => Difficulty::Relaxed,
+        }
+    }
+
+    /// Scale the balance values in a freshly loaded config
+    pub fn apply(&self, config: &mut GameConfig) {
+        let (upkeep, disasters, offline, start) = match self {
+            Difficulty::Relaxed => (0.5, 0.0, 2.0, 2.0),
+            Difficulty::Normal => return,
+            Difficulty::Harsh => (1.5, 2.0, 0.5, 0.5),
+        };
+        config.maintenance_cost_coefficient *= upkeep;
+        config.disaster_multiplier *= disasters;
+        config.offline_time_cap_hours *= offline;
+        config.starting_resources.materials *= start;
+        config.starting_resources.maintenance *= start;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_relaxed_disables_disasters() {
+        let mut config = GameConfig::default();
+        Difficulty::Relaxed.apply(&mut config);
+        assert_eq!(config.disaster_multiplier, 0.0);
+        assert!(config.offline_time_cap_hours > GameConfig::default().offline_time_cap_hours);
+    }
+}
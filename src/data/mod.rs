@@ -4,12 +4,18 @@
 
 mod achievements;
 mod config;
+mod difficulty;
+mod settings;
 mod state;
 mod tech;
+mod town;
 mod zone_template;
 
 pub use achievements::*;
 pub use config::*;
+pub use difficulty::*;
+pub use settings::*;
 pub use state::*;
 pub use tech::*;
+pub use town::Founding;
 pub use zone_template::*;
@@ -0,0 +1,148 @@
+//! Player settings - options the player can change (unlike balance config)
+
+use serde::{Deserialize, Serialize};
+
+/// Player-adjustable settings, saved with the game
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    /// Maximum number of visible villagers simulated as agents
+    pub agent_cap: usize,
+
+    /// Above this many agents, off-screen agents update less often
+    pub agent_lod_threshold: usize,
+
+    /// Off-screen agents update once every N ticks when LOD is active
+    pub agent_lod_interval: u32,
+
+    /// Pan the town camera when the cursor rests at a screen edge
+    pub edge_scroll: bool,
+
+    /// Automatically put part of each tick's material surplus into the wonder under construction
+    pub wonder_auto_fund: bool,
+
+    /// Share (0-1) of the surplus sent to the wonder when auto-funding
+    pub wonder_auto_fund_share: f32,
+
+    /// Base offline progress cap in hours; can lower the difficulty's cap but not raise it
+    pub offline_cap_hours: Option<f32>,
+
+    /// Show resource changes as floating text over the town
+    pub floating_text: bool,
+
+    /// Spend surplus materials restoring and repairing zones automatically
+    pub auto_restore: bool,
+
+    /// Materials auto-restore always leaves in the stockpile
+    pub auto_restore_reserve: f32,
+
+    /// Which zones auto-restore sees to first
+    pub auto_restore_priority: RestorePriority,
+
+    /// Show a compass and the tile/position under the mouse
+    pub show_coordinates: bool,
+
+    /// Show the live values behind the economy formulas (F3)
+    pub show_formulas: bool,
+
+    /// Show the key to the region map's symbols and colours (L). Starts collapsed,
+    /// since it shares the right-hand column with the town panels.
+    pub show_region_legend: bool,
+
+    /// Real minutes between autosaves (0 turns autosave off)
+    pub autosave_minutes: f32,
+
+    /// Colour scheme the interface is drawn in
+    pub color_scheme: ColorScheme,
+}
+
+/// Named palettes for the interface (see `ui::theme::Theme`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum ColorScheme {
+    #[default]
+    Default,
+    /// Stark panels and bright text, for readability
+    HighContrast,
+    /// Dim blues that are easy on the eyes after dark
+    Night,
+    /// Warm browns and parchment
+    Sepia,
+}
+
+impl ColorScheme {
+    pub fn name(&self) -> &'static str {
+        match self {
+            ColorScheme::Default => "Default",
+            ColorScheme::HighContrast => "High Contrast",
+            ColorScheme::Night => "Night",
+            ColorScheme::Sepia => "Sepia",
+        }
+    }
+
+    /// The next scheme along (for the settings button)
+    pub fn next(&self) -> Self {
+        match self {
+            ColorScheme::Default => ColorScheme::HighContrast,
+            ColorScheme::HighContrast => ColorScheme::Night,
+            ColorScheme::Night => ColorScheme::Sepia,
+            ColorScheme::Sepia => ColorScheme::Default,
+        }
+    }
+}
+
+/// Order auto-restore works through the town in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum RestorePriority {
+    /// Homes first, so the town can grow
+    #[default]
+    Housing,
+    /// Buildings that produce materials or food first
+    Production,
+}
+
+impl RestorePriority {
+    pub fn name(&self) -> &'static str {
+        match self {
+            RestorePriority::Housing => "Housing",
+            RestorePriority::Production => "Production",
+        }
+    }
+
+    /// The other priority (for the settings toggle)
+    pub fn toggled(&self) -> Self {
+        match self {
+            RestorePriority::Housing => RestorePriority::Production,
+            RestorePriority::Production => RestorePriority::Housing,
+        }
+    }
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            agent_cap: 50,
+            agent_lod_threshold: 100,
+            agent_lod_interval: 4,
+            edge_scroll: true,
+            wonder_auto_fund: false,
+            wonder_auto_fund_share: 0.25,
+            offline_cap_hours: None,
+            floating_text: true,
+            auto_restore: false,
+            auto_restore_reserve: 50.0,
+            auto_restore_priority: RestorePriority::default(),
+            show_coordinates: false,
+            show_formulas: false,
+            show_region_legend: false,
+            autosave_minutes: 5.0,
+            color_scheme: ColorScheme::default(),
+        }
+    }
+}
+
+impl Settings {
+    /// Whether level-of-detail scheduling applies to this many agents
+    pub fn lod_active(&self, agent_count: usize) -> bool {
+        agent_count > self.agent_lod_threshold && self.agent_lod_interval > 1
+    }
+}
@@ -4,9 +4,12 @@ use crate::data::{GameConfig, ZoneTemplate};
 use crate::economy::Resources;
 use crate::narrative::GameLog;
 use crate::population::PopulationPressure;
-use crate::zones::Zone;
+use crate::zones::{Zone, ZoneId};
 use serde::{Deserialize, Serialize};
 
+/// Number of towns in a generated region
+const REGION_NODE_COUNT: usize = 6;
+
 /// The root game state - everything the game needs to run
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GameState {
@@ -28,12 +31,24 @@ pub struct GameState {
     /// Current resources
     pub resources: Resources,
 
+    /// Average per-tick change over the last simulation batch (for trend display)
+    #[serde(skip)]
+    pub last_tick_delta: Resources,
+
+    /// Resource groups expanded in the top bar
+    #[serde(skip)]
+    pub expanded_resource_groups: Vec<crate::economy::ResourceGroup>,
+
     /// Population pressure
     pub population: PopulationPressure,
 
     /// Active zones
     pub zones: Vec<Zone>,
 
+    /// ID the next added zone receives
+    #[serde(default)]
+    pub next_zone_id: u32,
+
     /// The 2D World Map
     pub world_map: crate::simulation::map::WorldMap,
     /// Viewport camera (stored in state to persist position)
@@ -41,9 +56,29 @@ pub struct GameState {
     pub camera: crate::simulation::camera::Camera2D,
 
     /// Active agents (villagers)
-    #[serde(skip)]
+    #[serde(default)]
     pub agents: Vec<crate::simulation::agents::Agent>,
 
+    /// Agent update counter (drives level-of-detail scheduling)
+    #[serde(skip)]
+    pub agent_tick: u64,
+
+    /// Game hour of the next job rebalancing pass
+    #[serde(skip)]
+    pub next_job_review: f32,
+
+    /// Visible world area, set by the renderer each frame
+    #[serde(skip)]
+    pub view_rect: Option<macroquad::prelude::Rect>,
+
+    /// Player settings
+    #[serde(default)]
+    pub settings: super::Settings,
+
+    /// Difficulty chosen when this game was started
+    #[serde(default)]
+    pub difficulty: super::Difficulty,
+
     /// Game log
     pub log: GameLog,
 
@@ -58,15 +93,148 @@ pub struct GameState {
     #[serde(default)]
     pub season_state: crate::simulation::seasons::SeasonState,
 
+    /// Simulation RNG (saved so a loaded game continues the same sequence)
+    #[serde(default)]
+    pub rng: crate::simulation::rng::SimRng,
+
+    /// Separate RNG for villager wandering, whose draw count depends on the camera (LOD)
+    #[serde(default = "crate::simulation::rng::SimRng::default_agents")]
+    pub agent_rng: crate::simulation::rng::SimRng,
+
+    /// Today's prices at the town market
+    #[serde(default)]
+    pub market_prices: crate::economy::market::MarketPrices,
+
+    /// Migration waves already sent and newcomers on their way
+    #[serde(default)]
+    pub migration: crate::simulation::migration::Migration,
+
+    /// Yearly festival tracking
+    #[serde(default)]
+    pub festival: crate::simulation::festival::FestivalState,
+
+    /// Figures at the start of the current year (for the yearly recap)
+    #[serde(default)]
+    pub year_start: crate::simulation::yearly::YearSnapshot,
+
+    /// Achievements and wonders already earned when this town was founded
+    #[serde(default)]
+    pub founding: super::Founding,
+
+    /// Game hours the town has been empty with no materials (see simulation::collapse)
+    #[serde(default)]
+    pub collapse_hours: f32,
+
+    /// The town has collapsed and is waiting to be refounded
+    #[serde(default)]
+    pub town_failed: bool,
+
+    /// When the game was last saved (unix seconds, 0 = never), for catching up on load
+    #[serde(default)]
+    pub saved_at: f64,
+
+    /// Recap of the year that just ended, shown until dismissed
+    #[serde(skip)]
+    pub year_summary: Option<crate::simulation::yearly::YearSummary>,
+
+    /// Last restore/upgrade that can still be undone
+    #[serde(skip)]
+    pub last_undo: Option<crate::undo::UndoEntry>,
+
+    /// Itemised materials table under the formula overlay (F4)
+    #[serde(skip)]
+    pub show_materials_audit: bool,
+    /// First row shown in the materials audit
+    #[serde(skip)]
+    pub audit_scroll: f32,
+    /// Dim everything but the selection (F)
+    #[serde(skip)]
+    pub focus_mode: bool,
+
+    /// Low-materials warning (recomputed every tick)
+    #[serde(skip)]
+    pub materials_warning: crate::simulation::warnings::MaterialsWarning,
+
+    /// Newcomers are waiting for homes and the player has been told
+    #[serde(skip)]
+    pub housing_full: bool,
+
     // UI State
+    /// Page shown by the main menu
+    #[serde(skip)]
+    pub menu_page: crate::ui::main_menu::MenuPage,
+    /// Whether a town has been started this session (enables Continue on the menu)
+    #[serde(skip)]
+    pub game_in_progress: bool,
+    /// An autosave was found at launch (offered on the title page)
+    #[serde(skip)]
+    pub autosave_found: bool,
+    /// Region seed being typed on the new game page
+    #[serde(skip)]
+    pub seed_input: String,
+    /// Difficulty selected on the new game page
+    #[serde(skip)]
+    pub difficulty_choice: super::Difficulty,
+    /// Town name being typed on the new game page (blank keeps the usual name)
+    #[serde(skip)]
+    pub town_name_input: String,
+    /// Typing on the new game page goes to the town name rather than the seed
+    #[serde(skip)]
+    pub typing_town_name: bool,
     #[serde(skip)]
     pub show_tech_tree: bool,
     #[serde(skip)]
+    pub tech_view: crate::ui::tech::TechView,
+    #[serde(skip)]
     pub show_build_menu: bool,
     #[serde(skip)]
     pub show_chronicle: bool,
     #[serde(skip)]
+    pub chronicle_tab: crate::ui::chronicle_ui::ChronicleTab,
+    /// The chronicle is asking whether to really pass the town on
+    #[serde(skip)]
+    pub confirm_new_town: bool,
+    /// Day picked on the chronicle timeline (None = today)
+    #[serde(skip)]
+    pub chronicle_day: Option<u32>,
+    #[serde(skip)]
     pub zones_scroll_offset: f32,
+    /// Category shown in the zone list (None = all)
+    #[serde(skip)]
+    pub zone_filter: Option<super::ZoneCategory>,
+    /// Zone being moved: the next click on the map picks its new site
+    #[serde(skip)]
+    pub relocating: Option<ZoneId>,
+    /// Laying bridges: each click on a water tile bridges it
+    #[serde(skip)]
+    pub placing_bridge: bool,
+    /// Zones picked for a new blueprint: clicks on the map add or drop them (None = not drafting)
+    #[serde(skip)]
+    pub blueprint_draft: Option<Vec<ZoneId>>,
+    /// Blueprint being stamped: the next click on the map lays it out
+    #[serde(skip)]
+    pub placing_blueprint: Option<String>,
+    /// Blueprints panel (P)
+    #[serde(skip)]
+    pub show_blueprints: bool,
+    /// Zones pinned to the quick bar, in slot order
+    #[serde(skip)]
+    pub pinned_zones: Vec<ZoneId>,
+    /// Auto-restore timers and unreported jobs
+    #[serde(skip)]
+    pub auto_restore: crate::simulation::auto_restore::AutoRestore,
+    /// Turbo mode: the simulation runs far past the normal time scale
+    #[serde(skip)]
+    pub turbo: bool,
+    /// Name and epitaph being written before immortalizing a villager
+    #[serde(skip)]
+    pub hero_draft: Option<crate::ui::hero_editor::HeroDraft>,
+    /// Trade being set up at the market exchange (None = closed)
+    #[serde(skip)]
+    pub market_draft: Option<crate::ui::market_ui::MarketDraft>,
+    /// New name being typed for a town (None = closed)
+    #[serde(skip)]
+    pub town_name_draft: Option<crate::ui::town_namer::TownNameDraft>,
 
     /// Milestones that have been achieved (by ID)
     pub achieved_milestones: Vec<String>,
@@ -79,6 +247,10 @@ pub struct GameState {
     #[serde(default)]
     pub town_chronicle: crate::narrative::TownChronicle,
 
+    /// Villager feats already claimed by someone in this town
+    #[serde(default)]
+    pub villager_firsts: std::collections::HashSet<crate::narrative::firsts::VillagerFirst>,
+
     // === Phase 4: Legacy & Dynasty ===
     /// Global dynasty record
     #[serde(default)]
@@ -105,7 +277,26 @@ pub struct GameState {
     #[serde(skip)]
     pub floating_texts: crate::ui::floating_text::FloatingTextManager,
 
+    /// Stockpile watch for the out-of-stock alert
+    #[serde(skip)]
+    pub resource_alerts: crate::ui::resource_alerts::ResourceAlerts,
+
     // === Phase 5: Visuals ===
+    /// Dawn/dusk vignette
+    #[serde(skip)]
+    pub day_transition: crate::ui::day_transition::DayTransition,
+
+    /// Banner and fireworks for a freshly finished wonder
+    #[serde(skip)]
+    pub wonder_celebration: crate::ui::wonder_celebration::WonderCelebration,
+
+    /// Closing sequence once the Cloud Spire is finished
+    #[serde(skip)]
+    pub ending: crate::ui::ending::Ending,
+
+    #[serde(skip)]
+    pub save_toast: crate::ui::save_toast::SaveToast,
+
     /// Particle System (Weather, Smoke, FX)
     #[serde(skip)]
     pub particle_system: crate::ui::particles::ParticleSystem,
@@ -127,7 +318,7 @@ pub struct GameState {
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Selection {
     None,
-    Zone(usize),
+    Zone(ZoneId),
     Agent(u64),
 }
 
@@ -157,29 +348,86 @@ impl GameState {
             assets,
             tech_tree: crate::data::default_tech_tree(),
             resources,
+            last_tick_delta: Resources::default(),
+            expanded_resource_groups: Vec::new(),
             population: PopulationPressure::default(),
             zones: Vec::new(),
+            next_zone_id: 0,
             world_map: crate::simulation::map::WorldMap::default(),
             camera: crate::simulation::camera::Camera2D::new(),
             agents: Vec::new(),
+            agent_tick: 0,
+            next_job_review: 0.0,
+            view_rect: None,
+            settings: super::Settings::default(),
+            difficulty: super::Difficulty::default(),
             log: GameLog::new(100),
             game_time_hours: 0.0,
             game_hour: 8.0, // Start at 8 AM
             season_state: crate::simulation::seasons::SeasonState::default(),
+            rng: crate::simulation::rng::SimRng::default(),
+            agent_rng: crate::simulation::rng::SimRng::default_agents(),
+            market_prices: crate::economy::market::MarketPrices::default(),
+            migration: crate::simulation::migration::Migration::default(),
+            festival: crate::simulation::festival::FestivalState::default(),
+            year_start: crate::simulation::yearly::YearSnapshot::default(),
+            founding: super::Founding::default(),
+            collapse_hours: 0.0,
+            town_failed: false,
+            saved_at: 0.0,
+            year_summary: None,
+            show_materials_audit: false,
+            audit_scroll: 0.0,
+            focus_mode: false,
+            materials_warning: crate::simulation::warnings::MaterialsWarning::default(),
+            housing_full: false,
+            menu_page: crate::ui::main_menu::MenuPage::default(),
+            game_in_progress: false,
+            autosave_found: false,
+            last_undo: None,
+            seed_input: String::new(),
+            difficulty_choice: super::Difficulty::default(),
+            town_name_input: String::new(),
+            typing_town_name: false,
             show_tech_tree: false,
+            tech_view: crate::ui::tech::TechView::default(),
             show_build_menu: false,
             show_chronicle: false,
+            chronicle_tab: crate::ui::chronicle_ui::ChronicleTab::default(),
+            confirm_new_town: false,
+            chronicle_day: None,
             zones_scroll_offset: 0.0,
+            zone_filter: None,
+            relocating: None,
+            placing_bridge: false,
+            blueprint_draft: None,
+            placing_blueprint: None,
+            show_blueprints: false,
+            pinned_zones: Vec::new(),
+            auto_restore: crate::simulation::auto_restore::AutoRestore::default(),
+            turbo: false,
+            hero_draft: None,
+            market_draft: None,
+            town_name_draft: None,
             achieved_milestones: Vec::new(),
             selection: Selection::None,
             town_chronicle: crate::narrative::TownChronicle::new(200),
+            villager_firsts: std::collections::HashSet::new(),
             dynasty: crate::narrative::Dynasty::new(),
             // Phase 3 - Use procedural generation (uses generate_region from generation.rs)
             scene_manager: crate::scene::SceneManager::new(),
-            region_map: crate::region::RegionMap::generate_procedural(12345, 6),
+            region_map: crate::region::RegionMap::generate_procedural(
+                crate::simulation::rng::DEFAULT_SEED,
+                REGION_NODE_COUNT,
+            ),
             town_proxies: crate::region::TownProxyManager::new(),
             trade_manager: crate::region::TradeManager::new(),
             floating_texts: crate::ui::floating_text::FloatingTextManager::new(),
+            resource_alerts: crate::ui::resource_alerts::ResourceAlerts::default(),
+            day_transition: crate::ui::day_transition::DayTransition::default(),
+            wonder_celebration: crate::ui::wonder_celebration::WonderCelebration::default(),
+            ending: crate::ui::ending::Ending::default(),
+            save_toast: crate::ui::save_toast::SaveToast::default(),
             particle_system: crate::ui::particles::ParticleSystem::new(2000),
             tutorial: crate::narrative::tutorial::TutorialManager::new(),
             achievements: super::achievements::AchievementManager::new(),
@@ -192,14 +440,137 @@ impl GameState {
         self.zone_templates.iter().find(|t| t.id == id)
     }
 
-    /// Add a new zone from template (starts DORMANT - player must restore it)
-    pub fn add_zone(&mut self, template_id: &str) -> bool {
-        if self.get_template(template_id).is_some() {
-            self.zones.push(Zone::new(template_id));
-            true
+    /// Swap in a fresh set of zone templates while the game runs. Zones find their
+    /// template again by ID and stay on the tiles they cover; a template that has
+    /// gone missing keeps its old definition so its zones still work.
+    /// Returns the IDs of the missing templates.
+    pub fn replace_zone_templates(&mut self, mut templates: Vec<ZoneTemplate>) -> Vec<String> {
+        let mut missing = Vec::new();
+        for zone in &mut self.zones {
+            let old = self
+                .zone_templates
+                .iter()
+                .find(|t| t.id == zone.template_id);
+            match templates.iter().find(|t| t.id == zone.template_id) {
+                // A template that moved leaves existing zones where they stand
+                Some(new) => {
+                    if zone.map_rect.is_none() && old.is_some_and(|o| o.map_rect != new.map_rect) {
+                        zone.map_rect = old.and_then(|o| o.map_rect);
+                    }
+                }
+                None => {
+                    if let Some(old) = old {
+                        missing.push(old.id.clone());
+                        templates.push(old.clone());
+                    }
+                }
+            }
+        }
+        self.zone_templates = templates;
+        missing
+    }
+
+    /// Add a new zone from template (starts DORMANT - player must restore it).
+    /// Returns its ID, or None if there is no such template.
+    pub fn add_zone(&mut self, template_id: &str) -> Option<ZoneId> {
+        self.get_template(template_id)?;
+        let id = ZoneId(self.next_zone_id);
+        self.next_zone_id += 1;
+        self.zones.push(Zone::new(id, template_id));
+        Some(id)
+    }
+
+    /// Current position of a zone in `zones`
+    pub fn zone_index(&self, id: ZoneId) -> Option<usize> {
+        self.zones.iter().position(|z| z.id == id)
+    }
+
+    /// Look up a zone by ID
+    pub fn zone(&self, id: ZoneId) -> Option<&Zone> {
+        self.zones.iter().find(|z| z.id == id)
+    }
+
+    /// Villager to select after the current one when cycling through them
+    /// (by ID, so a selected villager who has since left still has a place in
+    /// the order). `notable_only` skips anyone without deeds to their name.
+    pub fn next_agent(&self, forward: bool, notable_only: bool) -> Option<u64> {
+        let mut ids: Vec<u64> = self
+            .agents
+            .iter()
+            .filter(|a| !notable_only || a.immortalized || a.feats.is_notable())
+            .map(|a| a.id)
+            .collect();
+        ids.sort_unstable();
+        let current = match self.selection {
+            Selection::Agent(id) => Some(id),
+            _ => None,
+        };
+        let next = if forward {
+            current.and_then(|c| ids.iter().find(|&&id| id > c))
         } else {
-            false
+            current.and_then(|c| ids.iter().rev().find(|&&id| id < c))
+        };
+        // Past either end, wrap around
+        next.or(if forward { ids.first() } else { ids.last() })
+            .copied()
+    }
+
+    /// Tiles a zone covers (None if it has no place on the map)
+    pub fn zone_rect(&self, zone: &Zone) -> Option<super::MapRect> {
+        zone.footprint(self.get_template(&zone.template_id)?)
+    }
+
+    /// Take a zone out of the town (e.g. for demolition). Later zones move down in
+    /// `zones`, but their IDs - and so tile links, selection and pins - stay valid.
+    pub fn remove_zone(&mut self, id: ZoneId) -> Option<Zone> {
+        let index = self.zone_index(id)?;
+        for tile in &mut self.world_map.tiles {
+            if tile.zone_id == Some(id) {
+                tile.zone_id = None;
+            }
         }
+        if self.selection == Selection::Zone(id) {
+            self.selection = Selection::None;
+            self.focus_mode = false;
+        }
+        self.pinned_zones.retain(|&pinned| pinned != id);
+        if self.relocating == Some(id) {
+            self.relocating = None;
+        }
+        if let Some(draft) = &mut self.blueprint_draft {
+            draft.retain(|&picked| picked != id);
+        }
+        if self
+            .last_undo
+            .as_ref()
+            .is_some_and(|entry| entry.change.zone() == id)
+        {
+            self.last_undo = None;
+        }
+        Some(self.zones.remove(index))
+    }
+
+    /// Name of the tech a zone is still waiting on, or None if it is available.
+    /// A requirement naming a tech that isn't in the tree never unlocks, and the
+    /// local biome's signature zones never wait on one.
+    pub fn missing_tech(&self, template: &ZoneTemplate) -> Option<String> {
+        let tech_id = template.locked_by_tech.as_ref()?;
+        if self.signature_zones().contains(&template.id.as_str()) {
+            return None;
+        }
+        match self.tech_tree.iter().find(|t| &t.id == tech_id) {
+            Some(tech) if tech.unlocked => None,
+            Some(tech) => Some(tech.name.clone()),
+            None => Some(tech_id.clone()),
+        }
+    }
+
+    /// Whether a market is open for business (needed for the market exchange)
+    pub fn has_active_market(&self) -> bool {
+        self.zones.iter().filter(|z| z.is_operating()).any(|z| {
+            self.get_template(&z.template_id)
+                .is_some_and(|t| t.category == super::ZoneCategory::Market)
+        })
     }
 
     /// Calculate effective population using config K value
@@ -214,106 +585,84 @@ impl GameState {
             self.config.maintenance_cost_coefficient,
         )
     }
-    /// Calculate total housing capacity from active zones
-    pub fn calculate_housing_capacity(&self) -> f32 {
-        let mut total = 0.0;
+    /// Everything contributing to attractiveness this tick, as (label, amount) pairs.
+    /// The sum (floored at zero) is the town's attractiveness.
+    pub fn attractiveness_sources(&self) -> Vec<(String, f32)> {
+        let bonuses = crate::data::TechBonuses::from_tree(&self.tech_tree);
+        let mut sources = Vec::new();
+
+        if bonuses.attractiveness_flat != 0.0 {
+            sources.push(("Research".to_string(), bonuses.attractiveness_flat));
+        }
+
+        let festival = self.festival.attractiveness_bonus(self.game_time_hours);
+        if festival != 0.0 {
+            sources.push(("Festival".to_string(), festival));
+        }
+
         for zone in &self.zones {
-            if !zone.dormant {
-                if let Some(template) = self
-                    .zone_templates
-                    .iter()
-                    .find(|t| t.id == zone.template_id)
-                {
-                    // Capacity scales with condition? Yes, ruined houses hold fewer people.
-                    total += template.population.capacity * zone.condition;
+            if !zone.is_operating() {
+                continue;
+            }
+            if let Some(template) = self.get_template(&zone.template_id) {
+                // Passive output scales with condition; upkeep scales with efficiency techs
+                let amount = template.output.attractiveness * zone.condition
+                    - template.upkeep.attractiveness * bonuses.maintenance_factor;
+                if amount != 0.0 {
+                    sources.push((template.name.clone(), amount));
                 }
             }
         }
-        total
+
+        sources
     }
 
-    /// Archive the current town as a proxy (uses TownProxy::from_town_state, TownProxyManager::set)
-    pub fn archive_current_town(&mut self) {
-        if let Some(town_id) = self.region_map.active_town_id {
-            // Calculate net output based on current zones
-            let net_materials = self.resources.materials * 0.1; // Simplified
-            let net_food = self.resources.attractiveness * 0.05;
-
-            let proxy = crate::region::TownProxy::from_town_state(
-                town_id,
-                self.agents.len() as u32,
-                net_materials,
-                net_food,
-                0.0, // wood
-                0.0, // stone
-            );
-
-            self.town_proxies.set(proxy);
-
-            // Record in Dynasty
-            let town_name = self
-                .region_map
-                .get_node(town_id)
-                .map(|n| n.name.clone())
-                .unwrap_or("Unknown".to_string());
-            let record = crate::narrative::TownRecord {
-                name: town_name,
-                timestamp: self.game_time_hours,
-                population: self.agents.len() as u32,
-                outcome: "Archived".to_string(),
-                chronicle: self.town_chronicle.clone(),
-            };
-            self.dynasty.add_town_record(record);
-
-            // Award Legacy Points (simplified for now)
-            self.dynasty
-                .add_legacy_points(10 + (self.agents.len() as u32 / 10));
-
-            // Clear current chronicle for next town
-            self.town_chronicle = crate::narrative::TownChronicle::new(200);
+    /// Net change per game hour for the accumulating resources (materials, maintenance),
+    /// using the same math as the simulation tick, including population maintenance cost
+    pub fn net_production_rate(&self) -> crate::data::ResourceDelta {
+        let bonuses = crate::simulation::production::production_bonuses(self);
+        let rates = crate::simulation::production::production_rates(self, &bonuses);
+        let minutes_per_hour = 60.0;
+
+        crate::data::ResourceDelta {
+            materials: rates.per_minute.materials * minutes_per_hour,
+            maintenance: (rates.per_minute.maintenance - self.calculate_maintenance_cost())
+                * minutes_per_hour,
+            attractiveness: 0.0,
+            stability: 0.0,
         }
     }
 
-    /// Restore a town from proxy (uses TownProxyManager::get, remove)
-    pub fn restore_town(&mut self, town_id: u32) -> bool {
-        // Check if town exists in proxies
-        if let Some(proxy) = self.town_proxies.get(town_id) {
-            // Get accumulated resources before removing
-            let _accumulated_materials = proxy.stockpile_materials;
-            let _accumulated_food = proxy.stockpile_food;
-        }
+    /// Maximum hours of offline progress: the difficulty's cap (or the player's setting,
+    /// if lower) plus tech and legacy bonuses
+    pub fn offline_cap_hours(&self) -> f32 {
+        let difficulty_cap = self.config.offline_time_cap_hours;
+        self.settings
+            .offline_cap_hours
+            .map_or(difficulty_cap, |hours| hours.min(difficulty_cap))
+            + crate::data::TechBonuses::from_tree(&self.tech_tree).offline_cap_hours
+            + self.dynasty.meta_upgrades.offline_cap_hours()
+    }
 
-        // Remove from proxies
-        if self.town_proxies.remove(town_id).is_some() {
-            self.region_map.active_town_id = Some(town_id);
-            return true;
-        }
+    /// Material cost to research a tech (after legacy discounts)
+    pub fn research_cost(&self, tech: &crate::data::TechNode) -> f32 {
+        tech.cost * self.dynasty.meta_upgrades.research_cost_multiplier()
+    }
 
-        false
+    /// Calculate total housing capacity from active zones
+    pub fn calculate_housing_capacity(&self) -> f32 {
+        // The beds villagers are actually housed in, so growth never outruns them
+        crate::simulation::housing::homes(self)
+            .iter()
+            .map(|(_, _, beds)| beds)
+            .sum::<usize>() as f32
     }
 
-    /// Settle a new town (uses RegionMap::get_node_mut)
-    pub fn settle_town(&mut self, town_id: u32) -> bool {
-        if let Some(node) = self.region_map.get_node_mut(town_id) {
-            if !node.settled {
-                node.settled = true;
-                self.log.add(
-                    self.game_time_hours,
-                    format!("Settled new town: {}", node.name),
-                    crate::narrative::LogCategory::Event,
-                );
-
-                // Record in chronicle
-                self.town_chronicle.record(
-                    self.game_time_hours,
-                    crate::narrative::ChronicleEventType::MilestoneAchieved {
-                        milestone_name: format!("Settled {}", node.name),
-                    },
-                );
-                return true;
-            }
-        }
-        false
+    /// Regenerate the region and reseed the simulation from one seed
+    pub fn reseed(&mut self, seed: u64) {
+        self.region_map = crate::region::RegionMap::generate_procedural(seed, REGION_NODE_COUNT);
+        self.rng = crate::simulation::rng::SimRng::new(seed);
+        self.agent_rng = crate::simulation::rng::SimRng::for_agents(seed);
     }
 
     /// Switch to static starter map instead of procedural (uses generate_starter)
@@ -331,3 +680,107 @@ impl Default for GameState {
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_removing_a_zone_keeps_later_ids_valid() {
+        let mut state = GameState::default();
+        state
+            .zone_templates
+            .extend(crate::assets::load_zones().unwrap());
+        state.setup_starting_town();
+        let first = state.zones[0].id;
+        let last = state.zones.last().unwrap().id;
+        state.selection = Selection::Zone(last);
+
+        assert!(state.remove_zone(first).is_some());
+        assert!(state.zone(first).is_none());
+        assert!(state
+            .world_map
+            .tiles
+            .iter()
+            .all(|t| t.zone_id != Some(first)));
+        assert_eq!(state.selection, Selection::Zone(last));
+        assert_eq!(state.zone_index(last), Some(state.zones.len() - 1));
+    }
+
+    #[test]
+    fn test_zone_waits_for_its_tech() {
+        let mut state = GameState::default();
+        state
+            .zone_templates
+            .extend(crate::assets::load_zones().unwrap());
+        // Away from the mountains, where the quarry isn't the local speciality
+        state.region_map.active_town_id = None;
+        let quarry = state.get_template("stone_quarry").unwrap().clone();
+        assert_eq!(
+            state.missing_tech(&quarry).as_deref(),
+            Some("Basic Masonry")
+        );
+
+        for tech in state.tech_tree.iter_mut().filter(|t| t.id == "masonry") {
+            tech.unlocked = true;
+        }
+        assert_eq!(state.missing_tech(&quarry), None);
+    }
+
+    #[test]
+    fn test_reloading_templates_keeps_zones_in_place() {
+        let mut state = GameState::default();
+        state
+            .zone_templates
+            .extend(crate::assets::load_zones().unwrap());
+        state.setup_starting_town();
+        let placed = state
+            .zones
+            .iter()
+            .position(|z| state.zone_rect(z).is_some())
+            .unwrap();
+        let site = state.zone_rect(&state.zones[placed]);
+        let gone = state.zones[(placed + 1) % state.zones.len()]
+            .template_id
+            .clone();
+
+        let mut templates = crate::assets::load_zones().unwrap();
+        for template in &mut templates {
+            if template.id == state.zones[placed].template_id {
+                template.map_rect = None;
+            }
+        }
+        templates.retain(|t| t.id != gone);
+
+        let missing = state.replace_zone_templates(templates);
+        assert_eq!(missing.first(), Some(&gone));
+        assert!(state.get_template(&gone).is_some());
+        assert_eq!(state.zone_rect(&state.zones[placed]), site);
+    }
+
+    #[test]
+    fn test_cycling_agents_wraps_and_survives_departures() {
+        use crate::simulation::agents::Agent;
+        let mut state = GameState::default();
+        let mut rng = crate::simulation::rng::SimRng::new(1);
+        for id in [1, 2, 3] {
+            state
+                .agents
+                .push(Agent::new(id, macroquad::prelude::Vec2::ZERO, &mut rng));
+        }
+        state.agents[2].immortalized = true;
+
+        assert_eq!(state.next_agent(true, false), Some(1));
+        assert_eq!(state.next_agent(false, false), Some(3));
+        state.selection = Selection::Agent(3);
+        assert_eq!(state.next_agent(true, false), Some(1));
+
+        // The selected villager left: carry on from where they were
+        state.selection = Selection::Agent(2);
+        state.agents.remove(1);
+        assert_eq!(state.next_agent(true, false), Some(3));
+        assert_eq!(state.next_agent(false, false), Some(1));
+
+        assert_eq!(state.next_agent(true, true), Some(3));
+    }
+}
@@ -48,6 +48,74 @@ pub enum TechEffect {
     EfficiencyMulti(f32), // Reduces upkeep e.g. 0.9 for -10% cost
     AttractivenessFlat(f32),
     HousingGlobal(f32),
+    MovementSpeed(f32),   // Agent movement multiplier e.g. 1.2 for +20%
+    OfflineCapHours(f32), // Extra hours added to the offline progress cap
+    TradeBonus(f32),      // Caravan cargo multiplier
+    StabilityFlat(f32),
+}
+
+impl TechEffect {
+    /// Short human-readable summary for tooltips
+    pub fn summary(&self) -> String {
+        match self {
+            TechEffect::ProductionMulti(m) => format!("Production {:+.0}%", (m - 1.0) * 100.0),
+            TechEffect::EfficiencyMulti(m) => format!("Upkeep {:+.0}%", (m - 1.0) * 100.0),
+            TechEffect::AttractivenessFlat(v) => format!("Attractiveness {:+.1}", v),
+            TechEffect::HousingGlobal(v) => format!("Housing {:+.0}", v),
+            TechEffect::MovementSpeed(m) => format!("Movement {:+.0}%", (m - 1.0) * 100.0),
+            TechEffect::OfflineCapHours(v) => format!("Offline cap {:+.0}h", v),
+            TechEffect::TradeBonus(m) => format!("Caravan cargo {:+.0}%", (m - 1.0) * 100.0),
+            TechEffect::StabilityFlat(v) => format!("Stability {:+.1}", v),
+        }
+    }
+}
+
+/// Aggregated effects of all researched techs
+#[derive(Debug, Clone, Copy)]
+pub struct TechBonuses {
+    pub production_multi: f32,
+    pub maintenance_factor: f32,
+    pub attractiveness_flat: f32,
+    pub housing_flat: f32,
+    pub movement_multi: f32,
+    pub offline_cap_hours: f32,
+    pub trade_multi: f32,
+    pub stability_flat: f32,
+}
+
+impl Default for TechBonuses {
+    fn default() -> Self {
+        Self {
+            production_multi: 1.0,
+            maintenance_factor: 1.0,
+            attractiveness_flat: 0.0,
+            housing_flat: 0.0,
+            movement_multi: 1.0,
+            offline_cap_hours: 0.0,
+            trade_multi: 1.0,
+            stability_flat: 0.0,
+        }
+    }
+}
+
+impl TechBonuses {
+    /// Sum up the effects of every unlocked node in the tree
+    pub fn from_tree(tech_tree: &[TechNode]) -> Self {
+        let mut bonuses = Self::default();
+        for tech in tech_tree.iter().filter(|t| t.unlocked) {
+            match tech.effect {
+                TechEffect::ProductionMulti(m) => bonuses.production_multi *= m,
+                TechEffect::EfficiencyMulti(m) => bonuses.maintenance_factor *= m,
+                TechEffect::AttractivenessFlat(v) => bonuses.attractiveness_flat += v,
+                TechEffect::HousingGlobal(v) => bonuses.housing_flat += v,
+                TechEffect::MovementSpeed(m) => bonuses.movement_multi *= m,
+                TechEffect::OfflineCapHours(v) => bonuses.offline_cap_hours += v,
+                TechEffect::TradeBonus(m) => bonuses.trade_multi *= m,
+                TechEffect::StabilityFlat(v) => bonuses.stability_flat += v,
+            }
+        }
+        bonuses
+    }
 }
 
 impl TechNode {
@@ -93,7 +161,7 @@ pub fn default_tech_tree() -> Vec<TechNode> {
         TechNode::new(
             "logistics",
             "Logistics",
-            "Optimized paths reduce maintenance costs by 15%.",
+            "Optimized paths reduce maintenance costs and building wear by 15%.",
             15.0,
             Some("masonry"),
             TechEffect::EfficiencyMulti(0.85),
@@ -131,5 +199,45 @@ pub fn default_tech_tree() -> Vec<TechNode> {
             -100.0,
             200.0,
         ),
+        TechNode::new(
+            "paved_roads",
+            "Paved Roads",
+            "Cobbled streets let villagers get around 20% faster.",
+            30.0,
+            Some("logistics"),
+            TechEffect::MovementSpeed(1.2),
+            -300.0,
+            200.0,
+        ),
+        TechNode::new(
+            "trade_charters",
+            "Trade Charters",
+            "Formal agreements let caravans carry 50% more cargo.",
+            60.0,
+            Some("paved_roads"),
+            TechEffect::TradeBonus(1.5),
+            -300.0,
+            300.0,
+        ),
+        TechNode::new(
+            "town_watch",
+            "Town Watch",
+            "Neighbours keeping an eye out steadies the town (+0.3 Stability).",
+            40.0,
+            Some("urban_planning"),
+            TechEffect::StabilityFlat(0.3),
+            300.0,
+            200.0,
+        ),
+        TechNode::new(
+            "caretakers",
+            "Caretakers",
+            "Trusted stewards keep things running while you're away (+24h offline cap).",
+            60.0,
+            Some("insulation"),
+            TechEffect::OfflineCapHours(24.0),
+            100.0,
+            300.0,
+        ),
     ]
 }
@@ -0,0 +1,568 @@
+//! Town lifecycle - founding, archiving, restoring and settling towns
+
+use super::{GameState, Selection};
+use crate::economy::Resources;
+use crate::narrative::{GameLog, LogCategory, TownChronicle, TownRecord};
+use crate::population::PopulationPressure;
+use crate::region::Biome;
+use crate::simulation::map::{TileType, WorldMap};
+use serde::{Deserialize, Serialize};
+
+/// Share of the usual legacy points a collapsed town still earns
+const COLLAPSED_LEGACY_SHARE: f32 = 0.25;
+
+/// Villagers a town needs before its population earns legacy points
+const PRESTIGE_MIN_POPULATION: u32 = 10;
+
+/// What the dynasty had already earned when the current town was founded,
+/// so passing a town on pays only for the progress made there
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Founding {
+    pub achievements: usize,
+    pub wonders: usize,
+}
+
+impl Founding {
+    pub fn take(state: &GameState) -> Self {
+        Self {
+            achievements: state.achievements.count(),
+            wonders: state.dynasty.completed_wonders.len(),
+        }
+    }
+}
+
+/// Extra starting zones for towns founded on a biome that favours them
+fn biome_starting_zones(biome: Biome) -> &'static [&'static str] {
+    match biome {
+        Biome::Forest => &["woodcutters_block"],
+        Biome::Mountains => &["stone_quarry"],
+        Biome::Coast => &["fishing_dock"],
+        Biome::Plains | Biome::Desert | Biome::Tundra | Biome::Swamp => &[],
+    }
+}
+
+impl GameState {
+    /// Speciality zones of the active town's biome. The locals already know them,
+    /// so they start identified and need no research to restore.
+    pub fn signature_zones(&self) -> &'static [&'static str] {
+        let biome = self
+            .region_map
+            .active_town()
+            .map(|node| node.biome)
+            .unwrap_or_default();
+        biome_starting_zones(biome)
+    }
+
+    /// Lay out the starting ruins and their dormant zones on a fresh map
+    pub fn setup_starting_town(&mut self) {
+        self.world_map = WorldMap::default();
+        self.zones.clear();
+
+        // Every template with map coordinates starts out as ruins
+        for template in &self.zone_templates {
+            if let Some(rect) = template.map_rect {
+                self.world_map
+                    .set_rect(rect.x, rect.y, rect.w, rect.h, TileType::Ruins, None);
+            }
+        }
+
+        // Starting zones come from the config and are linked to their tiles. They're
+        // dormant (the player must restore them) unless the config restores them up front.
+        // The local biome adds its own speciality on top of the usual set.
+        let template_ids: Vec<String> = self
+            .config
+            .starting_zones
+            .iter()
+            .cloned()
+            .chain(self.signature_zones().iter().map(|id| id.to_string()))
+            .collect();
+        for template_id in &template_ids {
+            let zone_id = match self.add_zone(template_id) {
+                Some(id) => id,
+                None => {
+                    eprintln!("Unknown starting zone '{}' in config", template_id);
+                    continue;
+                }
+            };
+            if self.config.restored_starting_zones.contains(template_id) {
+                if let Some(zone) = self.zones.iter_mut().find(|z| z.id == zone_id) {
+                    zone.restore(1.0);
+                    zone.construction_state = crate::zones::ConstructionState::Complete;
+                }
+            }
+            if let Some(rect) = self.get_template(template_id).and_then(|t| t.map_rect) {
+                self.world_map.set_rect(
+                    rect.x,
+                    rect.y,
+                    rect.w,
+                    rect.h,
+                    TileType::Ruins,
+                    Some(zone_id),
+                );
+            }
+        }
+    }
+
+    /// Apply the dynasty's permanent upgrades to a freshly founded town
+    pub fn apply_meta_upgrades(&mut self) {
+        let upgrades = &self.dynasty.meta_upgrades;
+        self.resources.materials += upgrades.starting_materials();
+
+        for zone in self
+            .zones
+            .iter_mut()
+            .take(upgrades.starting_zones_restored())
+        {
+            zone.restore(1.0);
+            zone.construction_state = crate::zones::ConstructionState::Complete;
+        }
+    }
+
+    /// Legacy points earned by passing the current town on to a new generation:
+    /// its population once past a floor, plus achievements and wonders won since
+    /// its founding (a collapsed town earns only a share)
+    pub fn prestige_legacy_points(&self) -> u32 {
+        let population = self.population.value().max(0.0) as u32;
+        let population_points = if population >= PRESTIGE_MIN_POPULATION {
+            population / 2
+        } else {
+            0
+        };
+        let achievements = self
+            .achievements
+            .count()
+            .saturating_sub(self.founding.achievements) as u32;
+        let wonders = self
+            .dynasty
+            .completed_wonders
+            .len()
+            .saturating_sub(self.founding.wonders) as u32;
+        let points = population_points + achievements * 5 + wonders * 50;
+        if self.town_failed {
+            (points as f32 * COLLAPSED_LEGACY_SHARE) as u32
+        } else {
+            points
+        }
+    }
+
+    /// Record the current town in the dynasty's history and bank its legacy points.
+    /// Returns the town's name and the points it earned.
+    pub fn archive_town(&mut self) -> (String, u32) {
+        let points = self.prestige_legacy_points();
+        let town_name = self
+            .region_map
+            .active_town()
+            .map(|n| n.name.clone())
+            .unwrap_or("Unknown".to_string());
+
+        let record = TownRecord {
+            name: town_name.clone(),
+            timestamp: self.game_time_hours,
+            population: self.population.value().round() as u32,
+            outcome: if self.town_failed {
+                format!("Collapsed (+{} legacy)", points)
+            } else {
+                format!("Passed on (+{} legacy)", points)
+            },
+            chronicle: std::mem::replace(&mut self.town_chronicle, TownChronicle::new(200)),
+        };
+        self.dynasty.add_town_record(record);
+        self.dynasty.add_legacy_points(points);
+        (town_name, points)
+    }
+
+    /// Start over with a fresh town while banking legacy points.
+    /// The dynasty, achievements, stats and region are kept; everything in the town is reset.
+    pub fn found_new_town(&mut self) -> u32 {
+        let collapsed = self.town_failed;
+        let (town_name, points) = self.archive_town();
+
+        // Back to the starting state
+        let start = &self.config.starting_resources;
+        self.resources = Resources::new(
+            start.materials,
+            start.maintenance,
+            start.attractiveness,
+            start.stability,
+        );
+        self.population = PopulationPressure::default();
+        self.tech_tree = crate::data::default_tech_tree();
+        self.agents.clear();
+        self.villager_firsts.clear();
+        self.selection = Selection::None;
+        self.focus_mode = false;
+        self.pinned_zones.clear();
+        self.relocating = None;
+        self.placing_bridge = false;
+        self.blueprint_draft = None;
+        self.placing_blueprint = None;
+        self.market_draft = None;
+        self.auto_restore = Default::default();
+        self.migration = Default::default();
+        self.zones_scroll_offset = 0.0;
+        self.floating_texts = crate::ui::floating_text::FloatingTextManager::new();
+        self.resource_alerts.reset();
+        self.collapse_hours = 0.0;
+        self.town_failed = false;
+        self.setup_starting_town();
+        self.apply_meta_upgrades();
+        crate::zones::survey::cover_ruins(self);
+        // The new town's first yearly recap and its legacy count from its founding
+        self.year_start = crate::simulation::yearly::YearSnapshot::take(self);
+        self.founding = Founding::take(self);
+
+        self.log = GameLog::new(100);
+        let message = if collapsed {
+            format!(
+                "From the ruins of {}, the dynasty begins again. +{} legacy points.",
+                town_name, points
+            )
+        } else {
+            format!(
+                "{} passes to a new generation. +{} legacy points.",
+                town_name, points
+            )
+        };
+        self.log
+            .add(self.game_time_hours, message, LogCategory::Milestone);
+
+        points
+    }
+
+    /// Archive the current town as a proxy (uses TownProxy::from_town_state, TownProxyManager::set)
+    pub fn archive_current_town(&mut self) {
+        if let Some(town_id) = self.region_map.active_town_id {
+            // Calculate net output based on current zones
+            let net_materials = self.resources.materials * 0.1; // Simplified
+            let net_food = self.resources.attractiveness * 0.05;
+
+            let proxy = crate::region::TownProxy::from_town_state(
+                town_id,
+                self.agents.len() as u32,
+                net_materials,
+                net_food,
+                0.0, // wood
+                0.0, // stone
+            );
+
+            self.town_proxies.set(proxy);
+
+            // Record in Dynasty
+            let town_name = self
+                .region_map
+                .get_node(town_id)
+                .map(|n| n.name.clone())
+                .unwrap_or("Unknown".to_string());
+            let record = crate::narrative::TownRecord {
+                name: town_name,
+                timestamp: self.game_time_hours,
+                population: self.agents.len() as u32,
+                outcome: "Archived".to_string(),
+                chronicle: self.town_chronicle.clone(),
+            };
+            self.dynasty.add_town_record(record);
+
+            // Award Legacy Points (simplified for now)
+            self.dynasty
+                .add_legacy_points(10 + (self.agents.len() as u32 / 10));
+
+            // Clear current chronicle for next town
+            self.town_chronicle = crate::narrative::TownChronicle::new(200);
+            self.villager_firsts.clear();
+        }
+    }
+
+    /// Restore a town from proxy (uses TownProxyManager::get, remove)
+    ///
+    /// The town's own stockpile comes home in full. Other archived towns that are
+    /// connected by a discovered route ship their surplus too, scaled by road quality.
+    pub fn restore_town(&mut self, town_id: u32) -> bool {
+        let mut proxy = match self.town_proxies.remove(town_id) {
+            Some(proxy) => proxy,
+            None => return false,
+        };
+        self.region_map.active_town_id = Some(town_id);
+
+        let shipment = proxy.withdraw_surplus(1.0);
+        self.receive_shipment(&shipment, "stockpiles kept while away");
+
+        let connected: Vec<(u32, f32)> = self
+            .town_proxies
+            .all()
+            .iter()
+            .filter_map(|p| {
+                self.region_map
+                    .route_between(p.town_id, town_id)
+                    .filter(|route| route.discovered)
+                    .map(|route| (p.town_id, 0.5 + route.quality * 0.5))
+            })
+            .collect();
+
+        for (other_id, efficiency) in connected {
+            let shipment = match self.town_proxies.get_mut(other_id) {
+                Some(other) => other.withdraw_surplus(efficiency),
+                None => continue,
+            };
+            let source = self
+                .region_map
+                .get_node(other_id)
+                .map(|n| format!("surplus from {}", n.name))
+                .unwrap_or_else(|| "surplus from a neighbour".to_string());
+            self.receive_shipment(&shipment, &source);
+        }
+
+        true
+    }
+
+    /// Credit a proxy shipment to the active town's resources and log it
+    fn receive_shipment(&mut self, shipment: &crate::region::ProxyShipment, source: &str) {
+        if shipment.is_empty() {
+            return;
+        }
+
+        self.resources.materials += shipment.materials;
+        self.resources.grain += shipment.food;
+        self.resources.logs += shipment.wood;
+        self.resources.stone_chunks += shipment.stone;
+
+        self.log.add(
+            self.game_time_hours,
+            format!(
+                "Received {}: {:.0} Mat, {:.0} Food, {:.0} Wood, {:.0} Stone",
+                source, shipment.materials, shipment.food, shipment.wood, shipment.stone
+            ),
+            crate::narrative::LogCategory::Event,
+        );
+    }
+
+    /// Pay off an archived town's negative stockpiles using the active town's materials
+    pub fn cover_proxy_debt(&mut self, town_id: u32) -> bool {
+        let debt = match self.town_proxies.get(town_id) {
+            Some(proxy) if proxy.debt() > 0.0 => proxy.debt(),
+            _ => return false,
+        };
+
+        if self.resources.materials < debt {
+            self.log.add(
+                self.game_time_hours,
+                format!("Not enough materials to cover debt! Need {:.1}", debt),
+                crate::narrative::LogCategory::System,
+            );
+            return false;
+        }
+
+        self.resources.materials -= debt;
+        if let Some(proxy) = self.town_proxies.get_mut(town_id) {
+            proxy.cover_debt();
+        }
+
+        let name = self
+            .region_map
+            .get_node(town_id)
+            .map(|n| n.name.clone())
+            .unwrap_or("Unknown".to_string());
+        self.log.add(
+            self.game_time_hours,
+            format!("Covered {:.1} debt for {} (-{:.1} Mat)", debt, name, debt),
+            crate::narrative::LogCategory::Event,
+        );
+        true
+    }
+
+    /// Settle a new town (uses RegionMap::get_node_mut)
+    pub fn settle_town(&mut self, town_id: u32) -> bool {
+        if let Some(node) = self.region_map.get_node_mut(town_id) {
+            if !node.settled {
+                node.settled = true;
+                self.log.add(
+                    self.game_time_hours,
+                    format!("Settled new town: {}", node.name),
+                    crate::narrative::LogCategory::Event,
+                );
+
+                // Record in chronicle
+                self.town_chronicle.record(
+                    self.game_time_hours,
+                    crate::narrative::ChronicleEventType::MilestoneAchieved {
+                        milestone_name: format!("Settled {}", node.name),
+                    },
+                );
+                return true;
+            }
+        }
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_starting_zones_come_from_the_config() {
+        let mut state = GameState::default();
+        state
+            .zone_templates
+            .extend(crate::assets::load_zones().unwrap());
+
+        // A blank slate: nothing but the biome's speciality (none on the plains)
+        state.region_map.active_town_id = None;
+        state.config.starting_zones.clear();
+        state.setup_starting_town();
+        assert!(state.zones.is_empty());
+
+        // A head start: the homestead begins restored, the well doesn't
+        state.config.starting_zones = vec!["old_homestead".into(), "old_well".into()];
+        state.config.restored_starting_zones = vec!["old_homestead".into()];
+        state.setup_starting_town();
+        assert_eq!(state.zones.len(), 2);
+        for zone in &state.zones {
+            assert_eq!(zone.dormant, zone.template_id == "old_well");
+        }
+        let homestead = &state.zones[0];
+        assert_eq!(
+            homestead.construction_state,
+            crate::zones::ConstructionState::Complete
+        );
+        assert!(homestead.is_operating());
+    }
+
+    #[test]
+    fn test_archiving_a_town_banks_its_legacy() {
+        let mut state = GameState::default();
+        state.population.add(10.0);
+        let expected = state.prestige_legacy_points();
+
+        let (_, points) = state.archive_town();
+        assert_eq!(points, expected);
+        assert_eq!(state.dynasty.legacy_points, expected);
+        assert_eq!(state.dynasty.past_towns.len(), 1);
+        assert_eq!(state.dynasty.past_towns[0].population, 10);
+    }
+
+    #[test]
+    fn test_refounding_pays_only_for_new_progress() {
+        let mut state = GameState::default();
+        state
+            .zone_templates
+            .extend(crate::assets::load_zones().unwrap());
+        state.achievements.unlock("first_restore");
+        state
+            .dynasty
+            .add_wonder(crate::narrative::Wonder::GreatLibrary);
+        state.population.add(20.0);
+        assert_eq!(state.found_new_town(), 10 + 5 + 50);
+
+        // Founding again straight away brings nothing: the carried-over
+        // achievement and wonder were already paid for
+        assert_eq!(state.found_new_town(), 0);
+        assert_eq!(state.found_new_town(), 0);
+
+        // A handful of villagers is below the floor; a new achievement still counts
+        state.population.add(5.0);
+        state.achievements.unlock("first_wonder");
+        assert_eq!(state.prestige_legacy_points(), 5);
+    }
+
+    #[test]
+    fn test_mountain_quarry_starts_known_and_unlocked() {
+        let mut state = GameState::default();
+        state
+            .zone_templates
+            .extend(crate::assets::load_zones().unwrap());
+        let town_id = state.region_map.active_town_id.unwrap();
+        state.region_map.get_node_mut(town_id).unwrap().biome = Biome::Mountains;
+        state.setup_starting_town();
+        crate::zones::survey::cover_ruins(&mut state);
+
+        let quarry = state
+            .zones
+            .iter()
+            .find(|z| z.template_id == "stone_quarry")
+            .unwrap();
+        assert!(quarry.surveyed);
+        let template = state.get_template("stone_quarry").unwrap();
+        assert_eq!(state.missing_tech(template), None);
+        // Masonry still gates everything else
+        let mine = state.get_template("mine").unwrap();
+        assert!(state.missing_tech(mine).is_some());
+    }
+
+    /// An archived town with the given stockpiles (materials, food, wood, stone)
+    fn proxy_with(town_id: u32, stock: [f32; 4]) -> crate::region::TownProxy {
+        let mut proxy = crate::region::TownProxy::from_town_state(town_id, 10, 0.0, 0.0, 0.0, 0.0);
+        proxy.stockpile_materials = stock[0];
+        proxy.stockpile_food = stock[1];
+        proxy.stockpile_wood = stock[2];
+        proxy.stockpile_stone = stock[3];
+        proxy
+    }
+
+    #[test]
+    fn test_restoring_a_town_brings_home_connected_surplus() {
+        let mut state = GameState {
+            region_map: crate::region::RegionMap::generate_starter(1),
+            ..Default::default()
+        };
+        state.resources.materials = 0.0;
+        state.resources.grain = 0.0;
+        // Pine Ridge (1) is the town coming back; Quiteville (0) is a half-paved road
+        // away, Marshwood (9) has no road to it
+        state.region_map.routes[0].quality = 0.5;
+        assert!(state.region_map.route_between(1, 9).is_none());
+        state
+            .town_proxies
+            .set(proxy_with(1, [40.0, 10.0, 0.0, 0.0]));
+        state
+            .town_proxies
+            .set(proxy_with(0, [20.0, 0.0, 8.0, -4.0]));
+        state
+            .town_proxies
+            .set(proxy_with(9, [100.0, 0.0, 0.0, 0.0]));
+
+        assert!(state.restore_town(1));
+        assert_eq!(state.region_map.active_town_id, Some(1));
+        assert!(state.town_proxies.get(1).is_none());
+        // Its own stockpile in full, plus three quarters of Quiteville's surplus
+        assert_eq!(state.resources.materials, 40.0 + 15.0);
+        assert_eq!(state.resources.grain, 10.0);
+        assert_eq!(state.resources.logs, 6.0);
+
+        // Shipped stock leaves the neighbour; its debt and the cut-off town stay put
+        let neighbour = state.town_proxies.get(0).unwrap();
+        assert_eq!(neighbour.stockpile_materials, 0.0);
+        assert_eq!(neighbour.stockpile_stone, -4.0);
+        assert_eq!(
+            state.town_proxies.get(9).unwrap().stockpile_materials,
+            100.0
+        );
+
+        assert!(!state.restore_town(1));
+    }
+
+    #[test]
+    fn test_covering_proxy_debt_costs_its_sum_in_materials() {
+        let mut state = GameState::default();
+        state
+            .town_proxies
+            .set(proxy_with(2, [-10.0, -5.0, 3.0, 0.0]));
+        assert_eq!(state.town_proxies.get(2).unwrap().debt(), 15.0);
+
+        // Not enough on hand: nothing changes
+        state.resources.materials = 10.0;
+        assert!(!state.cover_proxy_debt(2));
+        assert_eq!(state.resources.materials, 10.0);
+        assert_eq!(state.town_proxies.get(2).unwrap().debt(), 15.0);
+
+        state.resources.materials = 20.0;
+        assert!(state.cover_proxy_debt(2));
+        assert_eq!(state.resources.materials, 5.0);
+        let proxy = state.town_proxies.get(2).unwrap();
+        assert_eq!(proxy.debt(), 0.0);
+        assert_eq!(proxy.stockpile_wood, 3.0);
+
+        // Nothing left owing
+        assert!(!state.cover_proxy_debt(2));
+        assert_eq!(state.resources.materials, 5.0);
+    }
+}
@@ -1,6 +1,8 @@
 //! Zone template - Data-driven zone definitions
 
+use crate::economy::ResourceType;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 
 /// A zone template loaded from JSON
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -12,9 +14,9 @@ pub struct ZoneTemplate {
     /// Base throughput before condition/activity multipliers
     pub base_throughput: f32,
 
-    /// Cost to build/restore (material units)
+    /// Cost to build/restore (a plain number is material units)
     #[serde(default)]
-    pub construction_cost: f32,
+    pub construction_cost: ConstructionCost,
 
     /// Work units required to complete construction
     #[serde(default = "default_construction_work")]
@@ -27,12 +29,20 @@ pub struct ZoneTemplate {
     /// How fast diminishing returns kick in (higher = faster plateau)
     pub saturation_bias: f32,
 
+    /// Shape of the activity -> output curve
+    #[serde(default)]
+    pub saturation_curve: SaturationCurve,
+
     /// Resource effects when zone is active
     pub output: ResourceDelta,
 
     /// Resource costs to maintain the zone
     pub upkeep: ResourceDelta,
 
+    /// Grain harvested per game hour while operating (rations for hungry villagers)
+    #[serde(default)]
+    pub food_output: f32,
+
     /// Population effects
     pub population: PopulationEffect,
 
@@ -43,8 +53,8 @@ pub struct ZoneTemplate {
     #[serde(default)]
     pub map_rect: Option<MapRect>,
 
-    /// Tech ID required to unlock this zone (if any)
-    #[serde(default)]
+    /// Tech ID that must be researched before this zone can be restored or built (if any)
+    #[serde(default, alias = "required_tech")]
     pub locked_by_tech: Option<String>,
 
     /// Template ID this zone can upgrade to (if any)
@@ -56,7 +66,53 @@ fn default_construction_work() -> f32 {
     10.0 // Default 10 work units
 }
 
-#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+/// What a zone costs to build or restore.
+/// JSON accepts a plain number (materials only) or a map such as
+/// `{ "materials": 4.0, "lumber": 3.0 }`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ConstructionCost {
+    Materials(f32),
+    Mixed(BTreeMap<ResourceType, f32>),
+}
+
+impl Default for ConstructionCost {
+    fn default() -> Self {
+        Self::Materials(0.0)
+    }
+}
+
+impl ConstructionCost {
+    /// Each resource and amount the cost asks for
+    pub fn components(&self) -> Vec<(ResourceType, f32)> {
+        match self {
+            Self::Materials(amount) => vec![(ResourceType::Materials, *amount)],
+            Self::Mixed(parts) => parts
+                .iter()
+                .map(|(kind, amount)| (*kind, *amount))
+                .collect(),
+        }
+    }
+
+    /// Short label such as "8.0 Mat" or "4.0 Mat + 3.0 Lumber"
+    pub fn summary(&self) -> String {
+        self.components()
+            .iter()
+            .map(|(kind, amount)| format!("{:.1} {}", amount, cost_label(*kind)))
+            .collect::<Vec<_>>()
+            .join(" + ")
+    }
+}
+
+/// Resource name used in cost labels (materials keep their short "Mat")
+pub fn cost_label(kind: ResourceType) -> &'static str {
+    match kind {
+        ResourceType::Materials => "Mat",
+        other => other.name(),
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub struct MapRect {
     pub x: usize,
     pub y: usize,
@@ -64,6 +120,63 @@ pub struct MapRect {
     pub h: usize,
 }
 
+impl MapRect {
+    /// Centre of the rect in world pixels
+    pub fn world_center(&self) -> macroquad::prelude::Vec2 {
+        let tile = crate::ui::map_renderer::TILE_SIZE;
+        macroquad::prelude::vec2(
+            (self.x as f32 + self.w as f32 / 2.0) * tile,
+            (self.y as f32 + self.h as f32 / 2.0) * tile,
+        )
+    }
+
+    /// The rect in world pixels
+    pub fn world_rect(&self) -> macroquad::prelude::Rect {
+        let tile = crate::ui::map_renderer::TILE_SIZE;
+        macroquad::prelude::Rect::new(
+            self.x as f32 * tile,
+            self.y as f32 * tile,
+            self.w as f32 * tile,
+            self.h as f32 * tile,
+        )
+    }
+}
+
+/// How a zone's output responds to activity (0-1)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum SaturationCurve {
+    /// activity / (activity + bias) - the original diminishing-returns curve
+    #[default]
+    Hyperbolic,
+    /// Output grows in step with activity
+    Linear,
+    /// Ramps up quickly with a little activity, then flattens
+    Sqrt,
+    /// Slow start, fast middle, plateau (half output at activity = bias)
+    Logistic,
+}
+
+impl SaturationCurve {
+    /// Output fraction (0-1) for the given activity
+    pub fn apply(&self, activity: f32, bias: f32) -> f32 {
+        let activity = activity.max(0.0);
+        match self {
+            SaturationCurve::Hyperbolic => activity / (activity + bias),
+            SaturationCurve::Linear => activity.min(1.0),
+            SaturationCurve::Sqrt => activity.sqrt().min(1.0),
+            SaturationCurve::Logistic => {
+                let squared = activity * activity;
+                if squared <= 0.0 {
+                    0.0
+                } else {
+                    squared / (squared + bias * bias)
+                }
+            }
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum ZoneCategory {
@@ -73,6 +186,35 @@ pub enum ZoneCategory {
     Cultural,
     Transit,
     Utility,
+    /// Town halls and the like - hold the town together (stability)
+    Civic,
+}
+
+impl ZoneCategory {
+    pub fn all() -> [ZoneCategory; 7] {
+        [
+            ZoneCategory::Residential,
+            ZoneCategory::Market,
+            ZoneCategory::Infrastructure,
+            ZoneCategory::Cultural,
+            ZoneCategory::Transit,
+            ZoneCategory::Utility,
+            ZoneCategory::Civic,
+        ]
+    }
+
+    /// Short label for the zone list's filter tabs
+    pub fn label(&self) -> &'static str {
+        match self {
+            ZoneCategory::Residential => "Homes",
+            ZoneCategory::Market => "Market",
+            ZoneCategory::Infrastructure => "Infra",
+            ZoneCategory::Cultural => "Culture",
+            ZoneCategory::Transit => "Transit",
+            ZoneCategory::Utility => "Utility",
+            ZoneCategory::Civic => "Civic",
+        }
+    }
 }
 
 /// Resource delta (can be positive or negative)
@@ -105,7 +247,7 @@ pub struct PopulationEffect {
 /// Zone decay behavior
 #[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 pub struct DecayModel {
-    /// Natural condition decay per tick
+    /// Condition lost per game hour when the zone is fully understaffed
     pub natural_rate: f32,
     /// Condition threshold below which zone becomes dormant
     pub neglect_threshold: f32,
@@ -119,3 +261,21 @@ impl Default for DecayModel {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_construction_cost_formats() {
+        let plain: ConstructionCost = serde_json::from_str("8.0").unwrap();
+        assert_eq!(plain.components(), vec![(ResourceType::Materials, 8.0)]);
+
+        let mixed: ConstructionCost =
+            serde_json::from_str(r#"{ "materials": 4.0, "lumber": 3.0 }"#).unwrap();
+        assert_eq!(
+            mixed.components(),
+            vec![(ResourceType::Materials, 4.0), (ResourceType::Lumber, 3.0)]
+        );
+    }
+}
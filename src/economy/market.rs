@@ -0,0 +1,181 @@
+//! Market exchange - swapping one stockpiled good for another at a town market
+
+use super::{ResourceType, Resources};
+use crate::region::{ResourcePotentials, TradeGood};
+use crate::simulation::rng::SimRng;
+use serde::{Deserialize, Serialize};
+
+/// Processed goods the market deals in, with their base worth in materials
+/// (raw goods are valued as trade goods, see `TradeGood::base_value`)
+const PROCESSED_VALUES: [(ResourceType, f32); 3] = [
+    (ResourceType::Lumber, 2.0),
+    (ResourceType::CutStone, 2.4),
+    (ResourceType::Flour, 2.5),
+];
+
+/// Share of a trade's value the market keeps
+pub const SPREAD: f32 = 0.2;
+
+/// Most a price moves in a day
+const DAILY_DRIFT: f32 = 0.05;
+
+/// Prices never stray further than this from their base value
+const MAX_DRIFT: f32 = 0.25;
+
+/// Goods the market will buy and sell, in display order
+pub fn tradeable() -> impl Iterator<Item = ResourceType> {
+    TradeGood::all()
+        .into_iter()
+        .map(|good| good.resource())
+        .chain(PROCESSED_VALUES.iter().map(|(kind, _)| *kind))
+}
+
+/// Worth of one unit in materials before local prices and drift
+fn base_value(kind: ResourceType) -> Option<f32> {
+    TradeGood::all()
+        .iter()
+        .find(|good| good.resource() == kind)
+        .map(|good| good.base_value())
+        .or_else(|| {
+            PROCESSED_VALUES
+                .iter()
+                .find(|(k, _)| *k == kind)
+                .map(|(_, value)| *value)
+        })
+}
+
+/// Today's prices: each good's base value nudged up or down a little every day
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MarketPrices {
+    /// Current offset from the base value per good (-0.25 to +0.25), missing = 0
+    drift: Vec<(ResourceType, f32)>,
+    /// Game day the prices last moved
+    last_day: u32,
+    /// How the town's own plenty or scarcity moves each raw good's price, missing = 1
+    #[serde(default)]
+    local: Vec<(ResourceType, f32)>,
+}
+
+impl MarketPrices {
+    /// Worth of one unit in materials, or None if the market doesn't deal in it
+    pub fn price(&self, kind: ResourceType) -> Option<f32> {
+        let base = base_value(kind)?;
+        let drift = self
+            .drift
+            .iter()
+            .find(|(k, _)| *k == kind)
+            .map_or(0.0, |(_, d)| *d);
+        let local = self
+            .local
+            .iter()
+            .find(|(k, _)| *k == kind)
+            .map_or(1.0, |(_, f)| *f);
+        Some(base * local * (1.0 + drift))
+    }
+
+    /// What selling `amount` of one good brings in of another, after the spread
+    pub fn quote(&self, sell: ResourceType, buy: ResourceType, amount: f32) -> Option<f32> {
+        if sell == buy || amount <= 0.0 {
+            return None;
+        }
+        let value = amount * self.price(sell)? * (1.0 - SPREAD);
+        Some(value / self.price(buy)?)
+    }
+
+    /// Swap goods in the stockpile; returns the amount received, or None if it can't be done
+    pub fn trade(
+        &self,
+        resources: &mut Resources,
+        sell: ResourceType,
+        buy: ResourceType,
+        amount: f32,
+    ) -> Option<f32> {
+        let received = self.quote(sell, buy, amount)?;
+        if resources.get(sell) < amount {
+            return None;
+        }
+        *resources.get_mut(sell) -= amount;
+        *resources.get_mut(buy) += received;
+        Some(received)
+    }
+
+    /// Move prices once per game day, pricing raw goods by the town's resource
+    /// potentials (if it has a place on the region map)
+    pub fn update(&mut self, day: u32, local: Option<&ResourcePotentials>, rng: &mut SimRng) {
+        if day <= self.last_day {
+            return;
+        }
+        self.last_day = day;
+        self.local = local.map_or(Vec::new(), |potentials| {
+            TradeGood::all()
+                .iter()
+                .map(|good| {
+                    let factor = good.local_value(potentials) / good.base_value();
+                    (good.resource(), factor)
+                })
+                .collect()
+        });
+        self.drift = tradeable()
+            .filter(|kind| *kind != ResourceType::Materials)
+            .map(|kind| {
+                let old = self
+                    .drift
+                    .iter()
+                    .find(|(k, _)| *k == kind)
+                    .map_or(0.0, |(_, d)| *d);
+                let new = old + rng.range(-DAILY_DRIFT, DAILY_DRIFT);
+                (kind, new.clamp(-MAX_DRIFT, MAX_DRIFT))
+            })
+            .collect();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip_loses_the_spread() {
+        let prices = MarketPrices::default();
+        let mut resources = Resources {
+            logs: 10.0,
+            ..Default::default()
+        };
+
+        let materials = prices
+            .trade(
+                &mut resources,
+                ResourceType::Logs,
+                ResourceType::Materials,
+                10.0,
+            )
+            .unwrap();
+        assert_eq!(resources.logs, 0.0);
+        assert!((materials - 10.0 * 1.2 * (1.0 - SPREAD)).abs() < 0.001);
+
+        // Can't sell what isn't in stock, or trade in abstract resources
+        assert!(prices
+            .trade(
+                &mut resources,
+                ResourceType::Logs,
+                ResourceType::Materials,
+                1.0
+            )
+            .is_none());
+        assert!(prices
+            .quote(ResourceType::Stability, ResourceType::Materials, 1.0)
+            .is_none());
+    }
+
+    #[test]
+    fn test_local_plenty_makes_goods_cheap() {
+        let mut prices = MarketPrices::default();
+        let forest = ResourcePotentials::new(2.0, 0.5, 0.8, 0.5);
+        prices.update(1, Some(&forest), &mut SimRng::new(1));
+        let logs = prices.price(ResourceType::Logs).unwrap();
+        let stone = prices.price(ResourceType::StoneChunks).unwrap();
+        // Drift never outweighs the local factor here
+        assert!(logs < TradeGood::Wood.base_value());
+        assert!(stone > TradeGood::Stone.base_value());
+    }
+}
@@ -2,6 +2,9 @@
 //!
 //! Implements the "unbreakable" formulas from formulas_and_statistics.md
 
+pub mod market;
+mod resource_type;
 mod resources;
 
+pub use resource_type::*;
 pub use resources::*;
@@ -0,0 +1,275 @@
+//! Resource identifiers and display groups
+
+use super::Resources;
+use crate::data::ConstructionCost;
+use serde::{Deserialize, Serialize};
+
+/// How resources are grouped for display
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResourceGroup {
+    /// The four town-wide abstract resources
+    Abstract,
+    /// Gathered materials (logs, stone, grain)
+    Raw,
+    /// Refined goods made from raw materials
+    Processed,
+}
+
+impl ResourceGroup {
+    pub fn name(&self) -> &'static str {
+        match self {
+            ResourceGroup::Abstract => "Abstract",
+            ResourceGroup::Raw => "Raw",
+            ResourceGroup::Processed => "Processed",
+        }
+    }
+
+    /// Resources in this group, in display order
+    pub fn members(&self) -> Vec<ResourceType> {
+        ResourceType::all()
+            .into_iter()
+            .filter(|r| r.group() == *self)
+            .collect()
+    }
+}
+
+/// One-line explanation of a resource for new players
+#[derive(Debug, Clone, Copy)]
+pub struct ResourceHelp {
+    pub role: &'static str,
+    pub raised_by: &'static str,
+    pub lowered_by: &'static str,
+}
+
+/// Every stockpiled resource
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ResourceType {
+    Materials,
+    Maintenance,
+    Attractiveness,
+    Stability,
+    Logs,
+    StoneChunks,
+    Grain,
+    Lumber,
+    CutStone,
+    Flour,
+}
+
+impl ResourceType {
+    /// All resources, in display order
+    pub fn all() -> [ResourceType; 10] {
+        [
+            ResourceType::Materials,
+            ResourceType::Maintenance,
+            ResourceType::Attractiveness,
+            ResourceType::Stability,
+            ResourceType::Logs,
+            ResourceType::StoneChunks,
+            ResourceType::Grain,
+            ResourceType::Lumber,
+            ResourceType::CutStone,
+            ResourceType::Flour,
+        ]
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            ResourceType::Materials => "Materials",
+            ResourceType::Maintenance => "Maint.",
+            ResourceType::Attractiveness => "Attr.",
+            ResourceType::Stability => "Stab.",
+            ResourceType::Logs => "Logs",
+            ResourceType::StoneChunks => "Stone",
+            ResourceType::Grain => "Grain",
+            ResourceType::Lumber => "Lumber",
+            ResourceType::CutStone => "Cut Stone",
+            ResourceType::Flour => "Flour",
+        }
+    }
+
+    /// Name without abbreviation, for tooltips
+    pub fn full_name(&self) -> &'static str {
+        match self {
+            ResourceType::Maintenance => "Maintenance",
+            ResourceType::Attractiveness => "Attractiveness",
+            ResourceType::Stability => "Stability",
+            other => other.name(),
+        }
+    }
+
+    /// What the resource is for and what moves it, shown when hovering it in the top bar
+    pub fn help(&self) -> ResourceHelp {
+        let (role, raised_by, lowered_by) = match self {
+            ResourceType::Materials => (
+                "The town's building stock. Pays for restoring, repairing and researching.",
+                "Workshops, scavenging and trade",
+                "Construction, repairs and upkeep",
+            ),
+            ResourceType::Maintenance => (
+                "Keeps buildings in working order. Low maintenance cuts every zone's output.",
+                "Wells and service zones",
+                "Each villager's upkeep, which grows with the square of the population",
+            ),
+            ResourceType::Attractiveness => (
+                "Drives how fast new villagers arrive. Jumps in it bring whole waves of settlers.",
+                "Restored homes, greens, markets and wonders",
+                "Zone upkeep and neglected buildings",
+            ),
+            ResourceType::Stability => (
+                "Holds the town together. Low stability slows output and, in a big town, sparks riots.",
+                "The town hall, order zones and research",
+                "Crowding as the population grows, and zone upkeep",
+            ),
+            ResourceType::Logs => (
+                "Raw wood, worth trading at the market.",
+                "Shipments from archived towns and market trades",
+                "Market trades",
+            ),
+            ResourceType::StoneChunks => (
+                "Rough stone, worth trading at the market.",
+                "Shipments from archived towns and market trades",
+                "Market trades",
+            ),
+            ResourceType::Grain => (
+                "Food. Villagers eat it when the flour runs out; without food they leave.",
+                "Farm harvests and shipments from archived towns",
+                "Villagers' meals and market trades",
+            ),
+            ResourceType::Lumber => (
+                "Sawn boards some sturdier buildings need.",
+                "Market trades",
+                "Building costs",
+            ),
+            ResourceType::CutStone => (
+                "Dressed stone some sturdier buildings need.",
+                "Market trades",
+                "Building costs",
+            ),
+            ResourceType::Flour => (
+                "Food. Villagers eat it before grain.",
+                "Market trades",
+                "Villagers' meals",
+            ),
+        };
+        ResourceHelp {
+            role,
+            raised_by,
+            lowered_by,
+        }
+    }
+
+    pub fn group(&self) -> ResourceGroup {
+        match self {
+            ResourceType::Materials
+            | ResourceType::Maintenance
+            | ResourceType::Attractiveness
+            | ResourceType::Stability => ResourceGroup::Abstract,
+            ResourceType::Logs | ResourceType::StoneChunks | ResourceType::Grain => {
+                ResourceGroup::Raw
+            }
+            ResourceType::Lumber | ResourceType::CutStone | ResourceType::Flour => {
+                ResourceGroup::Processed
+            }
+        }
+    }
+
+    /// Display colour (RGBA)
+    pub fn color(&self) -> [f32; 4] {
+        match self {
+            ResourceType::Materials => [0.0, 0.89, 0.19, 1.0],
+            ResourceType::Maintenance => [1.0, 0.63, 0.0, 1.0],
+            ResourceType::Attractiveness => [1.0, 0.43, 0.76, 1.0],
+            ResourceType::Stability => [0.4, 0.75, 1.0, 1.0],
+            ResourceType::Logs => [0.6, 0.4, 0.2, 1.0],
+            ResourceType::StoneChunks => [0.6, 0.6, 0.6, 1.0],
+            ResourceType::Grain => [0.9, 0.8, 0.3, 1.0],
+            ResourceType::Lumber => [0.8, 0.55, 0.3, 1.0],
+            ResourceType::CutStone => [0.8, 0.8, 0.85, 1.0],
+            ResourceType::Flour => [1.0, 0.95, 0.85, 1.0],
+        }
+    }
+
+    /// Single-letter badge drawn as the resource's icon
+    pub fn icon(&self) -> &'static str {
+        match self {
+            ResourceType::Materials => "M",
+            ResourceType::Maintenance => "W",
+            ResourceType::Attractiveness => "A",
+            ResourceType::Stability => "S",
+            ResourceType::Logs => "L",
+            ResourceType::StoneChunks => "R",
+            ResourceType::Grain => "G",
+            ResourceType::Lumber => "P",
+            ResourceType::CutStone => "C",
+            ResourceType::Flour => "F",
+        }
+    }
+}
+
+impl Resources {
+    /// Current amount of a resource
+    pub fn get(&self, kind: ResourceType) -> f32 {
+        match kind {
+            ResourceType::Materials => self.materials,
+            ResourceType::Maintenance => self.maintenance,
+            ResourceType::Attractiveness => self.attractiveness,
+            ResourceType::Stability => self.stability,
+            ResourceType::Logs => self.logs,
+            ResourceType::StoneChunks => self.stone_chunks,
+            ResourceType::Grain => self.grain,
+            ResourceType::Lumber => self.lumber,
+            ResourceType::CutStone => self.cut_stone,
+            ResourceType::Flour => self.flour,
+        }
+    }
+
+    /// Mutable access to a resource
+    pub fn get_mut(&mut self, kind: ResourceType) -> &mut f32 {
+        match kind {
+            ResourceType::Materials => &mut self.materials,
+            ResourceType::Maintenance => &mut self.maintenance,
+            ResourceType::Attractiveness => &mut self.attractiveness,
+            ResourceType::Stability => &mut self.stability,
+            ResourceType::Logs => &mut self.logs,
+            ResourceType::StoneChunks => &mut self.stone_chunks,
+            ResourceType::Grain => &mut self.grain,
+            ResourceType::Lumber => &mut self.lumber,
+            ResourceType::CutStone => &mut self.cut_stone,
+            ResourceType::Flour => &mut self.flour,
+        }
+    }
+
+    /// Whether every part of a cost is in stock
+    pub fn has(&self, cost: &ConstructionCost) -> bool {
+        cost.components()
+            .iter()
+            .all(|(kind, amount)| self.get(*kind) >= *amount)
+    }
+
+    /// Deduct a cost (check `has` first)
+    pub fn spend(&mut self, cost: &ConstructionCost) {
+        for (kind, amount) in cost.components() {
+            let stock = self.get_mut(kind);
+            *stock = (*stock - amount).max(0.0);
+        }
+    }
+
+    /// Give back a cost that was spent
+    pub fn refund(&mut self, cost: &ConstructionCost) {
+        for (kind, amount) in cost.components() {
+            *self.get_mut(kind) += amount;
+        }
+    }
+
+    /// Average change per tick since an earlier snapshot
+    pub fn change_since(&self, earlier: &Resources, ticks: u32) -> Resources {
+        let mut change = Resources::default();
+        let ticks = ticks.max(1) as f32;
+        for kind in ResourceType::all() {
+            *change.get_mut(kind) = (self.get(kind) - earlier.get(kind)) / ticks;
+        }
+        change
+    }
+}
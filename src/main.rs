@@ -3,6 +3,7 @@
 //!
 //! A relaxing idle town builder about reviving a small town that grows when you're not watching.
 
+mod actions;
 mod assets;
 mod city;
 mod data;
@@ -14,6 +15,7 @@ mod save;
 mod scene;
 mod simulation;
 mod ui;
+mod undo;
 mod zones;
 
 use data::GameState;
@@ -22,7 +24,7 @@ use narrative::LogCategory;
 
 fn window_conf() -> Conf {
     Conf {
-        window_title: "Quiteville".to_owned(),
+        window_title: ui::window_title(),
         window_width: 1280,
         window_height: 720,
         high_dpi: false,
@@ -58,69 +60,112 @@ async fn initialize_game() -> GameState {
 
     // Initialize achievements with loaded definitions
     state.achievements.set_definitions(achievement_defs);
+    setup_new_town(&mut state);
+    state.autosave_found = save::exists(save::AUTOSAVE_PATH);
 
-    // Set initial camera target so map (0,0) is at top-left of screen
-    state.camera.target = vec2(screen_width() / 2.0, screen_height() / 2.0);
-
-    // Initialize Map with Zones
-    // Can't iterate state.zone_templates directly while borrowing state mutably?
-    // Actually we can iterate state.zone_templates since we only need read access to templates,
-    // and write access to map.
-    for (_i, template) in state.zone_templates.iter().enumerate() {
-        if let Some(rect) = template.map_rect {
-            // Found a zone with map coords!
-            // Set it to Ruins by default
-            state.world_map.set_rect(
-                rect.x,
-                rect.y,
-                rect.w,
-                rect.h,
-                simulation::map::TileType::Ruins,
-                // We need the ID of the zone INSTANCE, not the template index.
-                // But wait, the instances are created below.
-                // The zone instance ID should match the index in state.zones.
-                // But zones might not be created yet.
-                // For MVP fixed map, let's assume 1 instance per template for unique ones.
-                None, // Will link zone_id when instance is added
-            );
-        }
+    state
+}
+
+/// A fresh game from `seed`, reusing already-loaded data (zone templates, textures) and settings.
+/// The config is reloaded so difficulty scaling never stacks across games.
+fn fresh_game(loaded: &GameState, seed: u64, difficulty: data::Difficulty) -> GameState {
+    start_game(loaded, seed, difficulty, narrative::Dynasty::new())
+}
+
+/// Start over in a new region after the ending, carrying the dynasty forward
+/// with every legacy upgrade unlocked. The finished town is archived first, and
+/// the dynasty's stats carry on.
+fn new_game_plus(finished: &mut GameState) -> GameState {
+    finished.archive_town();
+    let mut dynasty = finished.dynasty.clone();
+    dynasty.meta_upgrades.unlock_all();
+    let mut state = start_game(
+        finished,
+        simulation::rng::clock_seed(),
+        finished.difficulty,
+        dynasty,
+    );
+    state.stats = finished.stats.clone();
+    state
+}
+
+fn start_game(
+    loaded: &GameState,
+    seed: u64,
+    difficulty: data::Difficulty,
+    dynasty: narrative::Dynasty,
+) -> GameState {
+    let mut config = assets::load_config().unwrap_or_else(|_| loaded.config.clone());
+    difficulty.apply(&mut config);
+    let mut state = GameState::new(config, loaded.zone_templates.clone(), loaded.assets.clone());
+    state.difficulty = difficulty;
+    state
+        .achievements
+        .set_definitions(loaded.achievements.definitions.clone());
+    state.settings = loaded.settings.clone();
+    state.dynasty = dynasty;
+    state.reseed(seed);
+    setup_new_town(&mut state);
+    state
+}
+
+/// The autosaved game, with what isn't saved (data, textures, achievements) taken
+/// from the already-loaded state. None if there's no readable autosave.
+fn resume_autosave(loaded: &GameState) -> Option<GameState> {
+    let mut state = save::load(save::AUTOSAVE_PATH)?;
+    let mut config = assets::load_config().unwrap_or_else(|_| loaded.config.clone());
+    state.difficulty.apply(&mut config);
+    state.config = config;
+    state.zone_templates = loaded.zone_templates.clone();
+    state.assets = loaded.assets.clone();
+    state
+        .achievements
+        .set_definitions(loaded.achievements.definitions.clone());
+    state.particle_system = ui::particles::ParticleSystem::new(2000);
+    state.camera.reset();
+
+    // Saved from the region map, the town was archived - bring it back
+    if let Some(town_id) = state.region_map.active_town_id {
+        state.restore_town(town_id);
     }
 
-    // Add all starting zones (all start DORMANT - player must restore them)
-    // AND link them to the map
-    let zones_to_add = [
-        "old_homestead",
-        "village_green",
-        "old_well",
-        "community_market",
-        "scavengers_workshop",
-        "community_farm",
-    ];
-
-    for template_id in zones_to_add {
-        // Find index of added zone
-        let zone_idx = state.zones.len();
-        state.add_zone(template_id);
-
-        // Link map tiles to this new zone instance
-        if let Some(template) = state.zone_templates.iter().find(|t| t.id == template_id) {
-            if let Some(rect) = template.map_rect {
-                state.world_map.set_rect(
-                    rect.x,
-                    rect.y,
-                    rect.w,
-                    rect.h,
-                    simulation::map::TileType::Ruins,
-                    Some(zone_idx),
-                );
-            }
-        }
+    // Catch up on the time since the save and welcome the player back
+    let hours_away =
+        simulation::offline::hours_since_save(state.saved_at, macroquad::miniquad::date::now());
+    if hours_away > 0.0 {
+        simulation::offline::process_offline_time(&mut state, hours_away);
     }
+    Some(state)
+}
+
+/// Save to the autosave slot, with a toast if it worked
+fn autosave(state: &mut GameState) {
+    state.saved_at = macroquad::miniquad::date::now();
+    if save::save(state, save::AUTOSAVE_PATH) {
+        state.save_toast.show();
+    }
+}
+
+/// Lay out the starting town and region for a new game
+fn setup_new_town(state: &mut GameState) {
+    // Set initial camera target so map (0,0) is at top-left of screen
+    state.camera.reset();
+
+    // Lay out the starting ruins (all zones start DORMANT - player must restore them)
+    state.setup_starting_town();
+    state.apply_meta_upgrades();
+    zones::survey::cover_ruins(state);
+    // The first yearly recap and the town's legacy count from here
+    state.year_start = simulation::yearly::YearSnapshot::take(state);
+    state.founding = data::Founding::take(state);
 
     // Add welcome log entry
     state.log.add(
         0.0,
-        "Six abandoned sites await restoration. Press [1-6] to begin repairs.".to_string(),
+        format!(
+            "{} abandoned sites await restoration. Press [1-6] to begin repairs.",
+            state.zones.len()
+        ),
         LogCategory::System,
     );
 
@@ -137,38 +182,114 @@ async fn initialize_game() -> GameState {
     // Settle the neighboring town (uses get_node_mut via settle_town)
     state.settle_town(1); // Settle Pine Ridge
 
-    // Demonstrate use_static_map is available (uses generate_starter)
-    // Use an always-false condition that compiler can't verify easily at compile time to keep it alive
     // Demonstrate use_static_map is available (uses generate_starter)
     // Use an always-false condition that compiler can't verify easily at compile time to keep it alive
     if get_time() < 0.0 {
         state.use_static_map(12345);
     }
-
-    state
 }
 
 /// Actions the player can take
 #[derive(Debug, Clone)]
 pub enum PlayerAction {
-    RestoreZone(usize), // Index into zones vec
-    UpgradeZone(usize), // Upgrade zone at index
+    RestoreZone(usize),        // Index into zones vec
+    SurveyZone(usize),         // Identify the unsurveyed ruin at index
+    UpgradeZone(usize),        // Upgrade zone at index
+    ToggleZoneDisabled(usize), // Mothball or reopen the zone at index
+    Undo,                      // Reverse the last restore/upgrade
+    HireBuilders(usize),       // Pay laborers to join the building crew for a while
     Select(data::Selection),
+    TogglePinZone(zones::ZoneId), // Pin/unpin a zone on the quick bar
+    FocusZone(zones::ZoneId),     // Select a zone and centre the camera on it
+    FrameTown,                    // Glide the camera to take in every working zone
+    // Select and centre on the next (or previous) villager
+    CycleAgent {
+        forward: bool,
+        notable_only: bool,
+    },
+    StartRelocating(Option<zones::ZoneId>), // Pick a zone to move (None = cancel)
+    RelocateZone(zones::ZoneId, usize, usize), // Move a zone so its top-left tile is (x, y)
+    PlaceBridges(bool),                     // Start or stop laying bridges over water
+    BuildBridge(usize, usize),              // Bridge the water tile at (x, y)
+    ToggleBlueprints,                       // Show/hide the blueprints panel
+    DraftBlueprint(bool),                   // Start or stop picking zones for a blueprint
+    ToggleBlueprintZone(zones::ZoneId),     // Add/drop a zone from the blueprint being drafted
+    SaveBlueprint(String),                  // Save the picked zones' layout under a name
+    DeleteBlueprint(String),
+    StartPlacingBlueprint(Option<String>), // Pick a blueprint to stamp (None = cancel)
+    PlaceBlueprint(String, usize, usize),  // Stamp a blueprint with its top-left tile at (x, y)
     ToggleTechTree,
     ToggleBuildMenu,
-    ToggleRegionView,   // Switch between town and region view
-    SetZoneScroll(f32), // Absolute offset
-    Research(String),   // Tech ID
-    SpeedUp,            // Temporary speed boost for testing
+    ToggleRegionView,                          // Switch between town and region view
+    SetZoneScroll(f32),                        // Absolute offset
+    SetZoneFilter(Option<data::ZoneCategory>), // Zone list category (None = all)
+    Research(String),                          // Tech ID
+    SetTechView(ui::tech::TechView),           // Pan/zoom the tech tree
+    SpeedUp,                                   // Temporary speed boost for testing
     SlowDown,
+    ToggleTurbo, // Run the simulation at turbo speed for fast catch-up
     ToggleChronicle,
     DismissDialog,
     SkipTutorial,
-    ImmortalizeHero(u64), // Agent ID to immortalize
+    EditHero(Option<ui::hero_editor::HeroDraft>), // Open, edit or cancel the immortalize dialog
+    ImmortalizeHero(u64),                         // Agent ID to immortalize
     // Phase 4: Wonders & Ancestors
     StartWonder(u32, narrative::Wonder), // Node ID and Wonder type
     ContributeToWonder(u32, f32),        // Node ID and amount
+    ToggleWonderAutoFund,                // Fund wonders from surplus materials
     RetireHero(String),                  // Hero name to retire as ancestor
+    CoverProxyDebt(u32),                 // Pay off an archived town's debts
+    SelectRegionNode(Option<u32>),       // Region map selection
+    SetCapital(u32),                     // Move the capital to a settled town
+    EditTownName(Option<ui::town_namer::TownNameDraft>), // Open, edit or cancel the rename dialog
+    RenameTown(u32, String),             // Node ID and its new name
+    TravelToTown(u32),                   // Leave the region map for an archived town
+    AskFoundNewTown(bool),               // Open or cancel the New Town confirmation
+    FoundNewTown,                        // Prestige: start fresh, keep the dynasty
+    BuyMetaUpgrade(narrative::MetaUpgrade), // Spend legacy points on a permanent upgrade
+    SetChronicleTab(ui::chronicle_ui::ChronicleTab),
+    SetChronicleDay(u32), // Day picked on the chronicle timeline
+    HoldFestival,         // Celebrate this year's festival
+    ToggleResourceGroup(economy::ResourceGroup), // Expand/collapse a top bar group
+    AcceptTradeOffer(u32), // Turn a town's offer into a trade route
+    DismissYearSummary,   // Close the yearly recap
+    DismissEnding,        // Carry on playing after the Cloud Spire ending
+    NewGamePlus,          // Start a new region with the dynasty and every legacy upgrade
+    EditMarketTrade(Option<ui::market_ui::MarketDraft>), // Open/update/close the market exchange
+    MarketTrade {
+        sell: economy::ResourceType,
+        buy: economy::ResourceType,
+        amount: f32,
+    },
+    // Main menu
+    NewGame(u64, data::Difficulty), // Start a fresh town with this region seed
+    SetDifficultyChoice(data::Difficulty),
+    ContinueGame,
+    ResumeAutosave, // Pick up the autosaved game from the title page
+    OpenMainMenu,
+    SetMenuPage(ui::main_menu::MenuPage),
+    SetSeedInput(String),
+    SetTownNameInput(String),
+    TypeTownName(bool), // Send new game page typing to the town name (true) or the seed
+    CopySeed,           // Copy the region seed to the clipboard
+    ToggleEdgeScroll,
+    ToggleFloatingText,
+    ToggleAutoRestore,
+    SetAutoRestoreReserve(f32),
+    ToggleRestorePriority,
+    ToggleCoordinates,
+    ToggleFormulaOverlay,
+    ToggleMaterialsAudit,
+    SetAuditScroll(f32), // First row shown in the materials audit
+    ToggleRegionLegend,
+    ToggleFocusMode, // Dim everything but the selection
+    #[cfg(debug_assertions)]
+    ReloadZoneTemplates, // Re-read zones.json from disk (debug builds only)
+    CycleColorScheme,
+    SetAgentCap(usize),
+    SetOfflineCap(f32),       // Base offline progress cap in hours
+    SetAutosaveInterval(f32), // Real minutes between autosaves (0 = off)
+    QuitGame,
 }
 
 #[macroquad::main(window_conf)]
@@ -177,19 +298,50 @@ async fn main() {
     let mut tick_timer = simulation::TickTimer::new(state.config.tick_rate_seconds);
     let mut time_scale: f32 = 1.0;
     let mut paused = false;
+    let mut autosave_timer = 0.0;
 
     loop {
         let delta = get_frame_time();
 
         // Update scene transitions
         state.scene_manager.update(delta);
+        state.wonder_celebration.update(delta);
+        state.save_toast.update(delta);
+
+        // Draw this frame in the player's colour scheme
+        ui::theme::apply(state.settings.color_scheme);
+
+        // Autosave every few real minutes while playing in the town
+        let autosave_seconds = state.settings.autosave_minutes * 60.0;
+        if state.game_in_progress && autosave_seconds > 0.0 && state.scene_manager.in_town_view() {
+            autosave_timer += delta;
+            if autosave_timer >= autosave_seconds {
+                autosave_timer = 0.0;
+                autosave(&mut state);
+            }
+        }
 
-        // Handle input (Keyboard)
-        let mut action = handle_input(&state, &mut time_scale, &mut paused);
+        // Handle input (Keyboard) - the main menu and ending handle their own
+        let mut action = if state.scene_manager.in_main_menu() || state.scene_manager.in_ending() {
+            None
+        } else {
+            handle_input(&state, &mut time_scale, &mut paused)
+        };
 
-        // Process game ticks (if not paused and in town view)
-        if !paused && state.scene_manager.in_town_view() {
-            let scaled_delta = delta * time_scale;
+        // Process game ticks (if not paused and in town view).
+        // A long gap between frames (machine asleep, window suspended) is time away instead,
+        // unless the game was paused.
+        let running = !paused && state.scene_manager.in_town_view();
+        if running && delta > simulation::offline::AWAY_GAP_SECONDS {
+            // 1 real second = 1 game minute
+            simulation::offline::process_offline_time(&mut state, delta / 60.0);
+        } else if running {
+            let scale = if state.turbo {
+                simulation::TURBO_TIME_SCALE
+            } else {
+                time_scale
+            };
+            let scaled_delta = delta * scale;
             let ticks = tick_timer.update(scaled_delta);
 
             if ticks > 0 {
@@ -203,14 +355,32 @@ async fn main() {
         // Render based on current scene
         clear_background(Color::from_rgba(30, 30, 40, 255));
 
-        if state.scene_manager.in_region_view() {
+        if state.scene_manager.in_main_menu() {
+            let menu_action = ui::main_menu::draw_main_menu(&state);
+            if action.is_none() {
+                action = menu_action;
+            }
+        } else if state.scene_manager.in_ending() {
+            // The camera pulls back over the town while the story is told
+            state.ending.update(delta);
+            let map_size = vec2(state.world_map.width as f32, state.world_map.height as f32)
+                * ui::map_renderer::TILE_SIZE;
+            let progress = state.ending.camera_progress();
+            state.camera.follow_ending(progress, map_size);
+            state.camera.update(delta, true, true, false);
+            ui::map_renderer::draw_map(&state);
+            ui::particles::update_ambient(&mut state, delta);
+
+            if let Some(ending_action) = ui::ending::draw_ending(&state) {
+                action = Some(ending_action);
+            }
+        } else if state.scene_manager.in_region_view() {
             // Region map view
-            ui::region_ui::draw_region_map(
-                &state.region_map,
-                &state.trade_manager,
-                screen_width(),
-                screen_height(),
-            );
+            let region_action =
+                ui::region_ui::draw_region_map(&state, screen_width(), screen_height());
+            if action.is_none() {
+                action = region_action;
+            }
 
             // Check for node hover and draw tooltip (uses draw_node_tooltip)
             let mouse_pos: Vec2 = mouse_position().into();
@@ -227,11 +397,47 @@ async fn main() {
                     break;
                 }
             }
+
+            // A wonder finished from here is announced over the map
+            state.wonder_celebration.draw_banner(20.0);
         } else {
             // Town view (default)
             // Update Camera
-            let input_captured = is_mouse_over_ui(&state);
-            state.camera.update(delta, input_captured);
+            // The tutorial intro sweeps the camera across the ruins (player input waits)
+            let intro = state.tutorial.in_camera_intro();
+            if intro {
+                let map_size = vec2(state.world_map.width as f32, state.world_map.height as f32)
+                    * ui::map_renderer::TILE_SIZE;
+                state
+                    .camera
+                    .follow_intro(state.tutorial.camera_intro_progress, map_size);
+            }
+            // Modals take the keyboard too; panels only take the mouse
+            let keys_captured = intro || is_modal_open(&state);
+            let mouse_captured = keys_captured || is_mouse_over_ui(&state);
+            state.camera.update(
+                delta,
+                keys_captured,
+                mouse_captured,
+                state.settings.edge_scroll,
+            );
+            let view_min = state.camera.screen_to_world(vec2(0.0, 0.0));
+            let view_max = state
+                .camera
+                .screen_to_world(vec2(screen_width(), screen_height()));
+            state.view_rect = Some(Rect::new(
+                view_min.x,
+                view_min.y,
+                view_max.x - view_min.x,
+                view_max.y - view_min.y,
+            ));
+
+            // Weather and smoke run on frame time, whatever the simulation speed
+            if !paused {
+                ui::particles::update_ambient(&mut state, delta);
+                let hour = state.game_hour;
+                state.day_transition.update(hour, delta);
+            }
 
             // Draw World (Behind UI)
             ui::map_renderer::draw_map(&state);
@@ -251,7 +457,7 @@ async fn main() {
 
             // Check for zone/agent hover and draw tooltip
             if let Some((_, zone, template)) = ui::tooltip::get_hovered_zone(&state, mouse_world) {
-                ui::tooltip::draw_zone_tooltip(zone, template, mouse_screen.into());
+                ui::tooltip::draw_zone_tooltip(&state, zone, template, mouse_screen.into());
             } else if let Some(agent) = ui::tooltip::get_hovered_agent(&state, mouse_world) {
                 ui::tooltip::draw_agent_tooltip(agent, mouse_screen.into());
             }
@@ -259,6 +465,13 @@ async fn main() {
             // Update and draw floating texts
             state.floating_texts.update(delta);
             state.floating_texts.draw(&state.camera);
+
+            // Note the moment a stockpile runs dry (the top bar flashes it too)
+            for alert in state.resource_alerts.update(&state.resources, delta) {
+                state
+                    .log
+                    .add(state.game_time_hours, alert, LogCategory::System);
+            }
         }
 
         // Draw scene transition fade
@@ -273,13 +486,19 @@ async fn main() {
             );
         }
 
-        // Apply action if any
-        if let Some(act) = action {
-            apply_action(&mut state, act);
-        }
+        state.save_toast.draw();
 
-        if is_key_pressed(KeyCode::Escape) {
-            break;
+        // Apply action if any
+        match action {
+            Some(PlayerAction::QuitGame) => {
+                // A clean exit always saves
+                if state.game_in_progress {
+                    autosave(&mut state);
+                }
+                break;
+            }
+            Some(act) => actions::apply_action(&mut state, act),
+            None => {}
         }
 
         next_frame().await;
@@ -292,20 +511,58 @@ fn handle_input(
     time_scale: &mut f32,
     paused: &mut bool,
 ) -> Option<PlayerAction> {
+    // The immortalize, market and rename dialogs take the keyboard while they are open
+    if state.hero_draft.is_some() || state.market_draft.is_some() || state.town_name_draft.is_some()
+    {
+        return None;
+    }
+
+    // While placing something, Escape or a right click cancels instead
+    if ui::placement::is_active(state)
+        && (is_key_pressed(KeyCode::Escape) || is_mouse_button_pressed(MouseButton::Right))
+    {
+        return ui::placement::cancel(state);
+    }
+
+    // Escape leaves the town for the main menu
+    if is_key_pressed(KeyCode::Escape) {
+        return Some(PlayerAction::OpenMainMenu);
+    }
+
     // Pause toggle
     if is_key_pressed(KeyCode::Space) {
         *paused = !*paused;
     }
 
-    // Time scale controls
-    if is_key_pressed(KeyCode::Up) || is_key_pressed(KeyCode::Equal) {
+    // Time scale controls (arrow keys belong to the tech tree cursor while it is open)
+    let arrows = !state.show_tech_tree;
+    if (arrows && is_key_pressed(KeyCode::Up)) || is_key_pressed(KeyCode::Equal) {
         *time_scale = (*time_scale * 2.0).min(64.0);
         return Some(PlayerAction::SpeedUp);
     }
-    if is_key_pressed(KeyCode::Down) || is_key_pressed(KeyCode::Minus) {
+    if (arrows && is_key_pressed(KeyCode::Down)) || is_key_pressed(KeyCode::Minus) {
         *time_scale = (*time_scale / 2.0).max(0.25);
         return Some(PlayerAction::SlowDown);
     }
+    if is_key_pressed(KeyCode::T) {
+        return Some(PlayerAction::ToggleTurbo);
+    }
+    if is_key_pressed(KeyCode::F3) {
+        return Some(PlayerAction::ToggleFormulaOverlay);
+    }
+    if state.settings.show_formulas && is_key_pressed(KeyCode::F4) {
+        return Some(PlayerAction::ToggleMaterialsAudit);
+    }
+    #[cfg(debug_assertions)]
+    if is_key_pressed(KeyCode::F5) {
+        return Some(PlayerAction::ReloadZoneTemplates);
+    }
+
+    // Ctrl+Z reverses the last restore/upgrade
+    let ctrl = is_key_down(KeyCode::LeftControl) || is_key_down(KeyCode::RightControl);
+    if ctrl && is_key_pressed(KeyCode::Z) {
+        return Some(PlayerAction::Undo);
+    }
 
     // Check UI overlap (Click blocking)
     if is_mouse_over_ui(state) {
@@ -314,6 +571,10 @@ fn handle_input(
 
     // Shortcuts
     if is_key_pressed(KeyCode::B) {
+        // Shift+B lays bridges instead
+        if is_key_down(KeyCode::LeftShift) || is_key_down(KeyCode::RightShift) {
+            return Some(PlayerAction::PlaceBridges(!state.placing_bridge));
+        }
         return Some(PlayerAction::ToggleBuildMenu);
     }
     if is_key_pressed(KeyCode::R) {
@@ -323,11 +584,32 @@ fn handle_input(
     if is_key_pressed(KeyCode::M) {
         return Some(PlayerAction::ToggleRegionView);
     }
+    if state.scene_manager.in_region_view() && is_key_pressed(KeyCode::L) {
+        return Some(PlayerAction::ToggleRegionLegend);
+    }
     if is_key_pressed(KeyCode::C) {
         return Some(PlayerAction::ToggleChronicle);
     }
+    if is_key_pressed(KeyCode::P) {
+        return Some(PlayerAction::ToggleBlueprints);
+    }
+    if is_key_pressed(KeyCode::F) && state.selection != data::Selection::None {
+        return Some(PlayerAction::ToggleFocusMode);
+    }
+    if is_key_pressed(KeyCode::Home) {
+        return Some(PlayerAction::FrameTown);
+    }
+    // Tab / Shift+Tab step through villagers (with Ctrl, only those of note)
+    if is_key_pressed(KeyCode::Tab) {
+        let shift = is_key_down(KeyCode::LeftShift) || is_key_down(KeyCode::RightShift);
+        let ctrl = is_key_down(KeyCode::LeftControl) || is_key_down(KeyCode::RightControl);
+        return Some(PlayerAction::CycleAgent {
+            forward: !shift,
+            notable_only: ctrl,
+        });
+    }
 
-    // Number keys to restore specific zones
+    // Number keys jump to pinned zones, or restore the first zones when nothing is pinned
     for (i, key) in [
         KeyCode::Key1,
         KeyCode::Key2,
@@ -339,7 +621,19 @@ fn handle_input(
     .iter()
     .enumerate()
     {
-        if is_key_pressed(*key) && i < state.zones.len() {
+        if !is_key_pressed(*key) {
+            continue;
+        }
+        if !state.pinned_zones.is_empty() {
+            return state
+                .pinned_zones
+                .get(i)
+                .map(|&zone| PlayerAction::FocusZone(zone));
+        }
+        if zones::survey::is_unsurveyed(state, i) {
+            return Some(PlayerAction::SurveyZone(i));
+        }
+        if i < state.zones.len() {
             return Some(PlayerAction::RestoreZone(i));
         }
     }
@@ -356,6 +650,11 @@ fn handle_input(
         if was_click {
             let world_pos = state.camera.screen_to_world(mouse_pos);
 
+            // Placing a moved zone, a bridge or a blueprint
+            if ui::placement::is_active(state) {
+                return ui::placement::map_click(state, world_pos);
+            }
+
             // 1. Check Agents (Top layer)
             if let Some(agent) = state
                 .agents
@@ -384,11 +683,8 @@ fn handle_input(
     None
 }
 
-fn is_mouse_over_ui(state: &GameState) -> bool {
-    let mouse_pos = macroquad::input::mouse_position();
-    let screen_w = macroquad::window::screen_width();
-    let screen_h = macroquad::window::screen_height();
-
+/// Whether a modal (tech tree, chronicle, dialog) has the screen, keyboard included
+fn is_modal_open(state: &GameState) -> bool {
     // 1. Tech Tree Modal
     if state.show_tech_tree {
         return true;
@@ -399,8 +695,32 @@ fn is_mouse_over_ui(state: &GameState) -> bool {
         return true;
     }
 
+    // 3. Yearly recap and immortalize dialog
+    if state.year_summary.is_some()
+        || state.hero_draft.is_some()
+        || state.market_draft.is_some()
+        || state.town_name_draft.is_some()
+        || state.town_failed
+    {
+        return true;
+    }
+
     // 3. Tutorial Dialog (blocks all input when active)
-    if state.tutorial.has_active_dialog() {
+    state.tutorial.has_active_dialog()
+}
+
+fn is_mouse_over_ui(state: &GameState) -> bool {
+    let mouse_pos = macroquad::input::mouse_position();
+    let screen_w = macroquad::window::screen_width();
+    let screen_h = macroquad::window::screen_height();
+
+    // 1-3. Modals cover the whole screen
+    if is_modal_open(state) {
+        return true;
+    }
+
+    // 4. Top Bar (and any open resource dropdowns)
+    if ui::resources::is_mouse_over_top_bar(state, mouse_pos.into()) {
         return true;
     }
 
@@ -437,275 +757,34 @@ fn is_mouse_over_ui(state: &GameState) -> bool {
         return true;
     }
 
-    false
-}
-
-/// Apply a player action to the game state
-fn apply_action(state: &mut GameState, action: PlayerAction) {
-    match action {
-        PlayerAction::RestoreZone(index) => {
-            // Get cost from template
-            let mut cost = 1.0;
-            let mut zone_name = "Unknown Zone".to_string();
-
-            if let Some(zone) = state.zones.get(index) {
-                if let Some(template) = state
-                    .zone_templates
-                    .iter()
-                    .find(|t| t.id == zone.template_id)
-                {
-                    cost = template.construction_cost;
-                    zone_name = template.name.clone();
-                }
-            }
-
-            // Check if we have enough materials
-            if state.resources.materials < cost {
-                state.log.add(
-                    state.game_time_hours,
-                    format!("Not enough materials for {}! Need {:.1}", zone_name, cost),
-                    LogCategory::System,
-                );
-                return;
-            }
-
-            if let Some(zone) = state.zones.get_mut(index) {
-                // Check if already at max condition
-                if zone.condition >= 1.0 {
-                    state.log.add(
-                        state.game_time_hours,
-                        format!("{} is already fully restored.", zone_name),
-                        LogCategory::System,
-                    );
-                    return;
-                }
-
-                // Deduct cost
-                state.resources.materials -= cost;
-
-                let old_condition = zone.condition;
-                zone.restore(0.5); // Restore 50% condition
-
-                state.log.add(
-                    state.game_time_hours,
-                    format!(
-                        "Restored {} (-{:.1} Mat): {:.0}% → {:.0}%",
-                        zone_name,
-                        cost,
-                        old_condition * 100.0,
-                        zone.condition * 100.0
-                    ),
-                    LogCategory::Zone,
-                );
-            }
-        }
-        PlayerAction::UpgradeZone(index) => {
-            if let Some(_old_id) = zones::upgrades::apply_upgrade(state, index) {
-                // Statistics tracking
-                state.stats.zones_restored += 1;
-
-                // Log is handled inside apply_upgrade
-                // Clear selection to avoid stale UI
-                state.selection = data::Selection::None;
-            } else {
-                state.log.add(
-                    state.game_time_hours,
-                    "Cannot upgrade zone - check materials or requirements.".to_string(),
-                    LogCategory::System,
-                );
-            }
-        }
-        PlayerAction::Select(sel) => {
-            state.selection = sel;
-        }
-        PlayerAction::ToggleTechTree => {
-            state.show_tech_tree = !state.show_tech_tree;
-        }
-        PlayerAction::ToggleBuildMenu => {
-            state.show_build_menu = !state.show_build_menu;
-        }
-        PlayerAction::SetZoneScroll(val) => {
-            state.zones_scroll_offset = val;
-        }
-        PlayerAction::ToggleChronicle => {
-            state.show_chronicle = !state.show_chronicle;
-        }
-        PlayerAction::DismissDialog => {
-            state.tutorial.dismiss_dialog();
-        }
-        PlayerAction::Research(id) => {
-            // Find index
-            if let Some(pos) = state.tech_tree.iter().position(|t| t.id == id) {
-                let cost = state.tech_tree[pos].cost;
-                if state.resources.materials >= cost {
-                    // Purchase
-                    state.resources.materials -= cost;
-                    state.tech_tree[pos].unlocked = true;
-                    state.log.add(
-                        state.game_time_hours,
-                        format!("Researched: {}", state.tech_tree[pos].name),
-                        LogCategory::System,
-                    );
-                }
-            }
-        }
-        PlayerAction::SpeedUp | PlayerAction::SlowDown => {
-            // Time scale changes are handled in input, no state change needed
-        }
-        PlayerAction::SkipTutorial => {
-            state.tutorial.skip_tutorial();
-        }
-        PlayerAction::ImmortalizeHero(agent_id) => {
-            // Find the agent and create a VillagerRecord
-            if let Some(agent) = state.agents.iter().find(|a| a.id == agent_id) {
-                let record = crate::narrative::VillagerRecord {
-                    name: agent.name.clone(),
-                    description: format!("{} - A {} of Quiteville", agent.name, agent.job.name()),
-                    feats: agent.feats.to_strings(),
-                    timestamp_added: state.game_time_hours,
-                };
-                state.dynasty.add_hero(record);
-
-                // Award legacy points based on feats
-                let points = 5 + agent.feats.buildings_helped + agent.feats.social_events / 2;
-                state.dynasty.add_legacy_points(points);
-
-                state.log.add(
-                    state.game_time_hours,
-                    format!(
-                        "{} has been immortalized in the Hall of Heroes! (+{} Legacy Points)",
-                        agent.name, points
-                    ),
-                    LogCategory::Event,
-                );
-            }
-        }
-        PlayerAction::ToggleRegionView => {
-            if state.scene_manager.in_town_view() {
-                // Archive current town when leaving town view
-                state.archive_current_town();
-            } else {
-                // Restore town when returning (uses get and remove via restore_town)
-                if let Some(town_id) = state.region_map.active_town_id {
-                    state.restore_town(town_id);
-                }
-            }
-            state.scene_manager.toggle_region_view();
+    // Pinned-zone quick bar (just above the buttons)
+    if let Some((px, py, pw, ph)) = ui::pinned_zones::bar_rect(state, screen_w, btn_y) {
+        if mouse_pos.0 >= px
+            && mouse_pos.0 <= px + pw
+            && mouse_pos.1 >= py
+            && mouse_pos.1 <= py + ph
+        {
+            return true;
         }
-        PlayerAction::StartWonder(node_id, wonder) => {
-            // Start construction of a wonder at the specified node
-            if let Some(node) = state.region_map.get_node_mut(node_id) {
-                if node.is_wonder_site && node.wonder_site.is_none() {
-                    // Check if Cloud Spire requirements are met
-                    if wonder == narrative::Wonder::CloudSpire {
-                        if !narrative::can_build_cloud_spire(
-                            &state.dynasty.completed_wonders,
-                            state.dynasty.legacy_points,
-                            state.population.value(),
-                        ) {
-                            state.log.add(
-                                state.game_time_hours,
-                                "Cannot build Cloud Spire yet. Requires 3 wonders, 1000 legacy points, and 50 population.".to_string(),
-                                LogCategory::System,
-                            );
-                            return;
-                        }
-                    }
+    }
 
-                    node.wonder_site =
-                        Some(narrative::WonderSite::new(wonder, state.game_time_hours));
-                    state.log.add(
-                        state.game_time_hours,
-                        format!(
-                            "Construction of {} has begun at {}!",
-                            wonder.name(),
-                            node.name
-                        ),
-                        LogCategory::Event,
-                    );
-                }
-            }
-        }
-        PlayerAction::ContributeToWonder(node_id, amount) => {
-            // Contribute resources to a wonder under construction
-            if state.resources.materials < amount {
-                state.log.add(
-                    state.game_time_hours,
-                    "Not enough materials to contribute!".to_string(),
-                    LogCategory::System,
-                );
-                return;
-            }
+    // Blueprints panel
+    if ui::blueprints::panel_rect(state).is_some_and(|r| r.contains(mouse_pos.into())) {
+        return true;
+    }
 
-            if let Some(node) = state.region_map.get_node_mut(node_id) {
-                if let Some(ref mut wonder_site) = node.wonder_site {
-                    let (used, stage_done, wonder_done) =
-                        wonder_site.contribute(amount, state.game_time_hours);
-
-                    if used > 0.0 {
-                        state.resources.materials -= used;
-
-                        if stage_done {
-                            let stage_name = if wonder_site.current_stage > 0 {
-                                wonder_site
-                                    .wonder
-                                    .stages()
-                                    .get(wonder_site.current_stage - 1)
-                                    .map(|s| s.name.clone())
-                                    .unwrap_or("Stage".to_string())
-                            } else {
-                                "Stage".to_string()
-                            };
-                            state.log.add(
-                                state.game_time_hours,
-                                format!("{}: {} completed!", wonder_site.wonder.name(), stage_name),
-                                LogCategory::Event,
-                            );
-                        }
-
-                        if wonder_done {
-                            let wonder = wonder_site.wonder;
-                            state.dynasty.add_wonder(wonder);
-                            state.dynasty.add_legacy_points(100);
-
-                            state.log.add(
-                                state.game_time_hours,
-                                format!(
-                                    "🏛️ {} has been completed! (+100 Legacy Points)",
-                                    wonder.name()
-                                ),
-                                LogCategory::Milestone,
-                            );
-
-                            // Check if this triggers ending
-                            if wonder.is_endgame() {
-                                state.log.add(
-                                    state.game_time_hours,
-                                    "The Cloud Spire reaches into the heavens. Your legacy is complete.".to_string(),
-                                    LogCategory::Milestone,
-                                );
-                            }
-                        }
-                    }
-                }
-            }
-        }
-        PlayerAction::RetireHero(hero_name) => {
-            // Retire a hero from Hall of Heroes to become an ancestor
-            if let Some(buff) = state.dynasty.retire_hero(&hero_name, state.game_time_hours) {
-                state.log.add(
-                    state.game_time_hours,
-                    format!(
-                        "{} has joined the ancestors, granting {}!",
-                        hero_name,
-                        buff.name()
-                    ),
-                    LogCategory::Event,
-                );
-                state.dynasty.add_legacy_points(20);
-            }
-        }
+    // Materials audit (scrolls with the wheel)
+    if ui::materials_audit::panel_rect(state).is_some_and(|r| r.contains(mouse_pos.into())) {
+        return true;
     }
+
+    // 6. Festival Button
+    let (fx, fy, fw, fh) = ui::layout::festival_button_rect(screen_w);
+    if mouse_pos.0 >= fx && mouse_pos.0 <= fx + fw && mouse_pos.1 >= fy && mouse_pos.1 <= fy + fh {
+        return true;
+    }
+
+    false
 }
 
 // Debug UI removed - replaced by ui module.
@@ -24,6 +24,34 @@ pub enum ChronicleEventType {
     Disaster { description: String },
     /// Special event
     Special { description: String },
+    /// The town was lost with nobody left and nothing in store
+    TownCollapsed { town_name: String },
+}
+
+impl ChronicleEventType {
+    /// Single-character marker drawn on the chronicle timeline
+    pub fn icon(&self) -> &'static str {
+        match self {
+            ChronicleEventType::VillagerArrived { .. } => "+",
+            ChronicleEventType::VillagerLost { .. } => "-",
+            ChronicleEventType::BuildingConstructed { .. } => "B",
+            ChronicleEventType::BuildingUpgraded { .. } => "U",
+            ChronicleEventType::TechResearched { .. } => "T",
+            ChronicleEventType::MilestoneAchieved { .. } => "*",
+            ChronicleEventType::SeasonChanged { .. } => "S",
+            ChronicleEventType::Disaster { .. } => "!",
+            ChronicleEventType::Special { .. } => "o",
+            ChronicleEventType::TownCollapsed { .. } => "X",
+        }
+    }
+
+    /// Whether this is a villager joining or leaving the town
+    pub fn is_population_change(&self) -> bool {
+        matches!(
+            self,
+            ChronicleEventType::VillagerArrived { .. } | ChronicleEventType::VillagerLost { .. }
+        )
+    }
 }
 
 /// A single event in the town's history
@@ -74,6 +102,9 @@ impl ChronicleEvent {
                 format!("⚠ {}", description)
             }
             ChronicleEventType::Special { description } => description.clone(),
+            ChronicleEventType::TownCollapsed { town_name } => {
+                format!("☠ {} collapsed", town_name)
+            }
         }
     }
 }
@@ -121,6 +152,16 @@ impl TownChronicle {
         self.events.iter().rev().take(count).collect()
     }
 
+    /// Get the most recent N arrivals and departures, newest first
+    pub fn recent_population_changes(&self, count: usize) -> Vec<&ChronicleEvent> {
+        self.events
+            .iter()
+            .rev()
+            .filter(|e| e.event_type.is_population_change())
+            .take(count)
+            .collect()
+    }
+
     /// Get total number of events
     pub fn len(&self) -> usize {
         self.events.len()
@@ -154,6 +195,9 @@ pub struct VillagerRecord {
     pub description: String,
     pub feats: Vec<String>,
     pub timestamp_added: f32,
+    /// Short line written by the player when immortalizing them
+    #[serde(default)]
+    pub epitaph: Option<String>,
 }
 
 /// Buff types granted by ancestral spirits
@@ -223,6 +267,12 @@ pub struct Dynasty {
     pub ancestors: Vec<AncestorSpirit>,
     /// Completed wonders
     pub completed_wonders: Vec<super::wonders::Wonder>,
+    /// Permanent upgrades bought with legacy points
+    #[serde(default)]
+    pub meta_upgrades: super::meta_upgrades::MetaUpgrades,
+    /// Saved building layouts, for stamping into any town
+    #[serde(default)]
+    pub blueprints: Vec<crate::zones::blueprint::Blueprint>,
 }
 
 impl Dynasty {
@@ -234,6 +284,28 @@ impl Dynasty {
         self.legacy_points += amount;
     }
 
+    /// Spend legacy points if enough are available
+    pub fn spend_legacy_points(&mut self, amount: u32) -> bool {
+        if self.legacy_points < amount {
+            return false;
+        }
+        self.legacy_points -= amount;
+        true
+    }
+
+    /// Buy the next level of a meta-upgrade if it is affordable and not maxed
+    pub fn buy_meta_upgrade(&mut self, upgrade: super::meta_upgrades::MetaUpgrade) -> bool {
+        let cost = match self.meta_upgrades.next_cost(upgrade) {
+            Some(cost) => cost,
+            None => return false,
+        };
+        if !self.spend_legacy_points(cost) {
+            return false;
+        }
+        self.meta_upgrades.raise(upgrade);
+        true
+    }
+
     pub fn add_town_record(&mut self, record: TownRecord) {
         self.past_towns.push(record);
     }
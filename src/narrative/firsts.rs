@@ -0,0 +1,70 @@
+//! Villager firsts - story beats when an ordinary villager reaches a notable feat
+
+use serde::{Deserialize, Serialize};
+
+use super::{ChronicleEventType, LogCategory};
+use crate::data::GameState;
+use crate::simulation::agents::AgentFeats;
+
+/// A feat that only the first villager in a town to reach it is remembered for
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum VillagerFirst {
+    /// Helped build 10 structures
+    MasterBuilder,
+    /// Attended 20 social gatherings
+    SocialButterfly,
+}
+
+impl VillagerFirst {
+    pub fn all() -> [VillagerFirst; 2] {
+        [VillagerFirst::MasterBuilder, VillagerFirst::SocialButterfly]
+    }
+
+    /// Whether these feats meet the threshold
+    pub fn reached(&self, feats: &AgentFeats) -> bool {
+        match self {
+            VillagerFirst::MasterBuilder => feats.buildings_helped >= 10,
+            VillagerFirst::SocialButterfly => feats.social_events >= 20,
+        }
+    }
+
+    /// Chronicle line naming the villager
+    pub fn description(&self, villager: &str) -> String {
+        match self {
+            VillagerFirst::MasterBuilder => format!(
+                "{} became the town's first Master Builder, having helped raise 10 structures",
+                villager
+            ),
+            VillagerFirst::SocialButterfly => format!(
+                "{} became the town's first Social Butterfly, a face at 20 gatherings",
+                villager
+            ),
+        }
+    }
+}
+
+/// Record a chronicle entry for any first that a villager has just reached
+pub fn check_villager_firsts(state: &mut GameState) {
+    for first in VillagerFirst::all() {
+        if state.villager_firsts.contains(&first) {
+            continue;
+        }
+
+        let villager = match state.agents.iter().find(|a| first.reached(&a.feats)) {
+            Some(agent) => agent.name.clone(),
+            None => continue,
+        };
+
+        state.villager_firsts.insert(first);
+        let description = first.description(&villager);
+        state.log.add(
+            state.game_time_hours,
+            description.clone(),
+            LogCategory::Population,
+        );
+        state.town_chronicle.record(
+            state.game_time_hours,
+            ChronicleEventType::Special { description },
+        );
+    }
+}
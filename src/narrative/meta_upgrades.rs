@@ -0,0 +1,123 @@
+//! Legacy meta-upgrades - permanent bonuses bought with legacy points
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// A permanent upgrade that carries over to every new town
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum MetaUpgrade {
+    /// Extra starting materials
+    StartingMaterials,
+    /// Starting zones that begin already restored
+    HeadStart,
+    /// Cheaper research
+    ScholarlyTradition,
+    /// Longer offline progress cap
+    LongMemory,
+}
+
+impl MetaUpgrade {
+    /// All upgrades, in display order
+    pub fn all() -> [MetaUpgrade; 4] {
+        [
+            MetaUpgrade::StartingMaterials,
+            MetaUpgrade::HeadStart,
+            MetaUpgrade::ScholarlyTradition,
+            MetaUpgrade::LongMemory,
+        ]
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            MetaUpgrade::StartingMaterials => "Inheritance",
+            MetaUpgrade::HeadStart => "Head Start",
+            MetaUpgrade::ScholarlyTradition => "Scholarly Tradition",
+            MetaUpgrade::LongMemory => "Long Memory",
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            MetaUpgrade::StartingMaterials => "+5 starting materials per level",
+            MetaUpgrade::HeadStart => "One more starting zone begins restored",
+            MetaUpgrade::ScholarlyTradition => "Research costs 5% less per level",
+            MetaUpgrade::LongMemory => "+12h offline progress cap per level",
+        }
+    }
+
+    /// Highest level that can be bought
+    pub fn max_level(&self) -> u32 {
+        match self {
+            MetaUpgrade::StartingMaterials => 5,
+            MetaUpgrade::HeadStart => 3,
+            MetaUpgrade::ScholarlyTradition => 5,
+            MetaUpgrade::LongMemory => 4,
+        }
+    }
+
+    /// Legacy point cost to go from `level` to `level + 1`
+    pub fn cost(&self, level: u32) -> u32 {
+        let base = match self {
+            MetaUpgrade::StartingMaterials => 20,
+            MetaUpgrade::HeadStart => 60,
+            MetaUpgrade::ScholarlyTradition => 30,
+            MetaUpgrade::LongMemory => 25,
+        };
+        base * (level + 1)
+    }
+}
+
+/// Purchased upgrade levels (persisted on the dynasty)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MetaUpgrades {
+    levels: HashMap<MetaUpgrade, u32>,
+}
+
+impl MetaUpgrades {
+    /// Current level of an upgrade
+    pub fn level(&self, upgrade: MetaUpgrade) -> u32 {
+        self.levels.get(&upgrade).copied().unwrap_or(0)
+    }
+
+    /// Cost of the next level, or None if already maxed
+    pub fn next_cost(&self, upgrade: MetaUpgrade) -> Option<u32> {
+        let level = self.level(upgrade);
+        if level >= upgrade.max_level() {
+            None
+        } else {
+            Some(upgrade.cost(level))
+        }
+    }
+
+    /// Raise an upgrade by one level (cost is handled by the caller)
+    pub fn raise(&mut self, upgrade: MetaUpgrade) {
+        *self.levels.entry(upgrade).or_insert(0) += 1;
+    }
+
+    /// Raise every upgrade to its highest level (New Game+)
+    pub fn unlock_all(&mut self) {
+        for upgrade in MetaUpgrade::all() {
+            self.levels.insert(upgrade, upgrade.max_level());
+        }
+    }
+
+    /// Extra materials given to each new town
+    pub fn starting_materials(&self) -> f32 {
+        self.level(MetaUpgrade::StartingMaterials) as f32 * 5.0
+    }
+
+    /// Number of starting zones that begin restored
+    pub fn starting_zones_restored(&self) -> usize {
+        self.level(MetaUpgrade::HeadStart) as usize
+    }
+
+    /// Multiplier applied to research costs
+    pub fn research_cost_multiplier(&self) -> f32 {
+        1.0 - self.level(MetaUpgrade::ScholarlyTradition) as f32 * 0.05
+    }
+
+    /// Extra hours added to the offline progress cap
+    pub fn offline_cap_hours(&self) -> f32 {
+        self.level(MetaUpgrade::LongMemory) as f32 * 12.0
+    }
+}
@@ -1,10 +1,13 @@
 //! Narrative module - Event logs and milestones
 
 pub mod chronicle;
+pub mod firsts;
 pub mod log;
+pub mod meta_upgrades;
 pub mod tutorial;
 pub mod wonders;
 
 pub use chronicle::*;
 pub use log::*;
+pub use meta_upgrades::*;
 pub use wonders::*;
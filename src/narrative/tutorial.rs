@@ -40,7 +40,9 @@ pub struct DialogData {
 }
 
 /// Tutorial context passed from game state
-pub struct TutorialContext {
+pub struct TutorialContext<'a> {
+    /// The town's name, as the player chose it
+    pub town_name: &'a str,
     pub zones_active: usize,
     pub agent_count: usize,
     pub materials: f32,
@@ -81,6 +83,11 @@ impl TutorialManager {
         }
     }
 
+    /// Whether the opening camera sweep is still playing
+    pub fn in_camera_intro(&self) -> bool {
+        !self.skipped && self.state == TutorialState::Intro && self.camera_intro_progress < 1.0
+    }
+
     /// Skip the tutorial entirely
     pub fn skip_tutorial(&mut self) {
         self.skipped = true;
@@ -99,7 +106,7 @@ impl TutorialManager {
     }
 
     /// Main update - check triggers and show dialogs
-    pub fn update(&mut self, ctx: TutorialContext, delta: f32) {
+    pub fn update(&mut self, ctx: TutorialContext<'_>, delta: f32) {
         if self.is_complete() {
             return;
         }
@@ -117,11 +124,11 @@ impl TutorialManager {
                     if self.camera_intro_progress >= 1.0 {
                         self.camera_intro_progress = 1.0;
                         // Show welcome dialog after camera pans
-                        self.show_dialog(
-                            "Uncle Artie",
-                            "Welcome back to Quiteville, kid. Or what's left of it... Your granddad built this place from nothing. Now it's your turn to bring it back.",
-                            true
+                        let welcome = format!(
+                            "Welcome back to {}, kid. Or what's left of it... Your granddad built this place from nothing. Now it's your turn to bring it back.",
+                            ctx.town_name
                         );
+                        self.show_dialog("Uncle Artie", &welcome, true);
                     }
                 }
             }
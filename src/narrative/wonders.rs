@@ -149,6 +149,45 @@ impl WonderSite {
         completed_cost / total_cost
     }
 
+    /// Materials still needed to finish the current stage
+    pub fn remaining_current_stage(&self) -> f32 {
+        self.current_stage_info()
+            .map_or(0.0, |stage| (stage.cost - self.stage_progress).max(0.0))
+    }
+
+    /// Materials still needed to finish the whole wonder
+    pub fn remaining_total(&self) -> f32 {
+        let later: f32 = self
+            .wonder
+            .stages()
+            .iter()
+            .skip(self.current_stage + 1)
+            .map(|s| s.cost)
+            .sum();
+        self.remaining_current_stage() + later
+    }
+
+    /// Average materials contributed per game day since construction began
+    pub fn average_rate_per_day(&self, game_time: f32) -> f32 {
+        let days = (game_time - self.started_at) / 24.0;
+        if days <= 0.0 {
+            return 0.0;
+        }
+        self.overall_progress() * self.wonder.total_cost() / days
+    }
+
+    /// Game days until completion at the given contribution rate (infinite if not funded)
+    pub fn estimated_completion(&self, rate_per_day: f32) -> f32 {
+        if self.completed {
+            return 0.0;
+        }
+        if rate_per_day <= 0.0 {
+            return f32::INFINITY;
+        }
+        let remaining = self.wonder.total_cost() * (1.0 - self.overall_progress());
+        remaining / rate_per_day
+    }
+
     /// Contribute resources to the wonder
     /// Returns (resources used, stage completed, wonder completed)
     pub fn contribute(&mut self, materials: f32, game_time: f32) -> (f32, bool, bool) {
@@ -233,3 +272,30 @@ impl WonderBuffs {
         buffs
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_remaining_counts_down_across_stages() {
+        let mut site = WonderSite::new(Wonder::GreatLibrary, 0.0);
+        assert_eq!(site.remaining_current_stage(), 100.0);
+        assert_eq!(site.remaining_total(), 800.0);
+
+        site.contribute(120.0, 1.0);
+        // Only what the foundation needed was used
+        assert_eq!(site.current_stage, 1);
+        assert_eq!(site.remaining_current_stage(), 200.0);
+        assert_eq!(site.remaining_total(), 700.0);
+
+        site.contribute(20.0, 2.0);
+        assert_eq!(site.remaining_current_stage(), 180.0);
+        assert_eq!(site.remaining_total(), 680.0);
+
+        while !site.completed {
+            site.contribute(1000.0, 3.0);
+        }
+        assert_eq!(site.remaining_total(), 0.0);
+    }
+}
@@ -1,33 +1,27 @@
 //! Population pressure - The central driver of the game
 
+use crate::data::PopulationCurve;
 use serde::{Deserialize, Serialize};
 
+/// Town happiness at or above which growth is unaffected
+pub const CONTENT_HAPPINESS: f32 = 0.5;
+
+/// Town happiness at or below which nobody new arrives (and people start leaving)
+pub const MISERABLE_HAPPINESS: f32 = 0.25;
+
+/// Game minutes in a day, for spreading `max_growth_per_day` over ticks
+const MINUTES_PER_DAY: f32 = 24.0 * 60.0;
+
 /// Population pressure state
 ///
 /// Population is NOT a count of people. It's a pressure value that:
 /// - Generates needs
 /// - Creates momentum
 /// - Drives zone activity
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct PopulationPressure {
     /// Current pressure value (0 to infinity, but saturates via formulas)
     pressure: f32,
-
-    /// Growth rate per tick (affected by attractiveness)
-    growth_rate: f32,
-
-    /// Decay rate per tick (base decay when no attractiveness)
-    decay_rate: f32,
-}
-
-impl Default for PopulationPressure {
-    fn default() -> Self {
-        Self {
-            pressure: 0.0,
-            growth_rate: 0.1,
-            decay_rate: 0.005,
-        }
-    }
 }
 
 impl PopulationPressure {
@@ -36,8 +30,26 @@ impl PopulationPressure {
         self.pressure
     }
 
-    /// Update pressure based on attractiveness and housing capacity
-    pub fn tick(&mut self, attractiveness: f32, capacity: f32, delta_time: f32) {
+    /// Bring people into the town directly (e.g. a migration wave)
+    pub fn add(&mut self, amount: f32) {
+        self.pressure += amount.max(0.0);
+    }
+
+    /// Take people out of the town directly (e.g. villagers lost to starvation)
+    pub fn remove(&mut self, amount: f32) {
+        self.pressure = (self.pressure - amount).max(0.0);
+    }
+
+    /// Update pressure based on attractiveness, housing capacity and town happiness
+    /// (average villager spirit, 0.0 - 1.0), shaped by the configured growth curve
+    pub fn tick(
+        &mut self,
+        curve: &PopulationCurve,
+        attractiveness: f32,
+        capacity: f32,
+        happiness: f32,
+        delta_time: f32,
+    ) {
         // Growth is driven by attractiveness BUT limited by housing capacity
         // Logistic growth-like behavior: slows as it approaches capacity.
         let space_factor = if capacity > 0.0 {
@@ -46,7 +58,14 @@ impl PopulationPressure {
             0.0 // No space = no growth
         };
 
-        let growth = self.growth_rate * attractiveness * space_factor * delta_time;
+        let mut growth = curve.growth_rate
+            * attractiveness
+            * space_factor
+            * happiness_growth_factor(happiness)
+            * delta_time;
+        if curve.max_growth_per_day > 0.0 {
+            growth = growth.min(curve.max_growth_per_day * delta_time / MINUTES_PER_DAY);
+        }
 
         // Decay is always present
         // If over capacity (pressure > capacity), add extra decay to simulate overcrowding
@@ -56,9 +75,80 @@ impl PopulationPressure {
             1.0
         };
 
-        let decay = self.decay_rate * overcrowding_factor * delta_time;
+        let decay = curve.decay_rate
+            * overcrowding_factor
+            * misery_decay_factor(happiness, curve.misery_decay)
+            * delta_time;
 
         self.pressure += growth - decay;
         self.pressure = self.pressure.max(0.0);
     }
 }
+
+/// Growth multiplier from town happiness: none when miserable, full when content
+pub fn happiness_growth_factor(happiness: f32) -> f32 {
+    ((happiness - MISERABLE_HAPPINESS) / (CONTENT_HAPPINESS - MISERABLE_HAPPINESS)).clamp(0.0, 1.0)
+}
+
+/// Decay multiplier from town happiness: miserable towns lose people faster
+fn misery_decay_factor(happiness: f32, misery_decay: f32) -> f32 {
+    let misery = ((MISERABLE_HAPPINESS - happiness) / MISERABLE_HAPPINESS).clamp(0.0, 1.0);
+    1.0 + misery * (misery_decay - 1.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unhappy_town_stops_growing_and_shrinks() {
+        let curve = PopulationCurve::default();
+        let mut content = PopulationPressure::default();
+        let mut miserable = PopulationPressure::default();
+        content.tick(&curve, 1.0, 10.0, 1.0, 10.0);
+        miserable.tick(&curve, 1.0, 10.0, 0.2, 10.0);
+        assert!(content.value() > 0.0);
+        assert_eq!(miserable.value(), 0.0);
+
+        let before = content.value();
+        let mut sad = content.clone();
+        content.tick(&curve, 0.0, 10.0, 1.0, 10.0);
+        sad.tick(&curve, 0.0, 10.0, 0.0, 10.0);
+        assert!(sad.value() < content.value());
+        assert!(content.value() < before);
+    }
+
+    #[test]
+    fn test_daily_growth_cap_limits_each_tick() {
+        // Without decay, a day of growth lands exactly on the cap
+        let uncapped = PopulationCurve {
+            decay_rate: 0.0,
+            ..PopulationCurve::default()
+        };
+        let capped = PopulationCurve {
+            max_growth_per_day: 1.0,
+            ..uncapped.clone()
+        };
+        let mut free = PopulationPressure::default();
+        let mut population = PopulationPressure::default();
+        free.tick(&uncapped, 5.0, 100.0, 1.0, MINUTES_PER_DAY);
+        population.tick(&capped, 5.0, 100.0, 1.0, MINUTES_PER_DAY);
+        assert!(free.value() > 1.0);
+        assert!((population.value() - 1.0).abs() < 0.001);
+
+        // The cap is spread over the day: an hour earns an hour's share
+        let mut hour = PopulationPressure::default();
+        hour.tick(&capped, 5.0, 100.0, 1.0, 60.0);
+        assert!((hour.value() - 1.0 / 24.0).abs() < 0.001);
+
+        // With the default decay, a capped day of growth can't keep up
+        let decaying = PopulationCurve {
+            max_growth_per_day: 1.0,
+            ..PopulationCurve::default()
+        };
+        let mut shrinking = PopulationPressure::default();
+        shrinking.add(10.0);
+        shrinking.tick(&decaying, 5.0, 100.0, 1.0, MINUTES_PER_DAY);
+        assert!(shrinking.value() < 10.0);
+    }
+}
@@ -28,6 +28,18 @@ impl Default for Biome {
 }
 
 impl Biome {
+    pub fn all() -> [Biome; 7] {
+        [
+            Biome::Plains,
+            Biome::Forest,
+            Biome::Mountains,
+            Biome::Desert,
+            Biome::Coast,
+            Biome::Tundra,
+            Biome::Swamp,
+        ]
+    }
+
     /// Display name for UI
     pub fn name(&self) -> &'static str {
         match self {
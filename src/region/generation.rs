@@ -1,6 +1,7 @@
 //! Procedural generation for region maps
 
 use super::{Biome, RegionMap, ResourcePotentials, Route, TownNode};
+use crate::simulation::rng::SimRng;
 
 /// Configuration for map generation
 #[derive(Debug, Clone)]
@@ -26,36 +27,9 @@ impl Default for GenerationConfig {
     }
 }
 
-/// Simple pseudo-random number generator using seed
-struct SeededRng {
-    state: u64,
-}
-
-impl SeededRng {
-    fn new(seed: u64) -> Self {
-        Self {
-            state: seed.wrapping_add(1),
-        }
-    }
-
-    fn next(&mut self) -> f32 {
-        // Simple LCG
-        self.state = self.state.wrapping_mul(1103515245).wrapping_add(12345);
-        ((self.state >> 16) & 0x7FFF) as f32 / 32767.0
-    }
-
-    fn range(&mut self, min: f32, max: f32) -> f32 {
-        min + self.next() * (max - min)
-    }
-
-    fn range_int(&mut self, min: usize, max: usize) -> usize {
-        min + (self.next() * (max - min) as f32) as usize
-    }
-}
-
 /// Generate a procedural region map
 pub fn generate_region(config: &GenerationConfig) -> RegionMap {
-    let mut rng = SeededRng::new(config.seed);
+    let mut rng = SimRng::new(config.seed);
     let mut map = RegionMap::new(config.seed);
 
     // Generate node positions using Poisson disk-like sampling
@@ -126,14 +100,6 @@ pub fn generate_region(config: &GenerationConfig) -> RegionMap {
 
         let mut node = TownNode::new(i as u32, name, pos[0], pos[1], biome);
 
-        // Set resource potentials based on biome
-        node.resource_potentials = ResourcePotentials::new(
-            biome.wood_multiplier() * rng.range(0.8, 1.2),
-            biome.stone_multiplier() * rng.range(0.8, 1.2),
-            biome.food_multiplier() * rng.range(0.8, 1.2),
-            biome.trade_multiplier() * rng.range(0.8, 1.2),
-        );
-
         // Starting town is settled and capital
         if i == 0 {
             node.settled = true;
@@ -180,41 +146,183 @@ pub fn generate_region(config: &GenerationConfig) -> RegionMap {
         }
     }
 
+    // Fix up samey or resource-starved biome layouts, then derive potentials from the result
+    ensure_biome_variety(&mut map);
+    for node in &mut map.nodes {
+        let biome = node.biome;
+        node.resource_potentials = ResourcePotentials::new(
+            biome.wood_multiplier() * rng.range(0.8, 1.2),
+            biome.stone_multiplier() * rng.range(0.8, 1.2),
+            biome.food_multiplier() * rng.range(0.8, 1.2),
+            biome.trade_multiplier() * rng.range(0.8, 1.2),
+        );
+    }
+
     map.active_town_id = Some(0);
     map
 }
 
+/// Fewest distinct biomes a generated region should have
+pub const MIN_DISTINCT_BIOMES: usize = 3;
+
+/// Forest (wood) and Mountains (stone) must be this many route hops or fewer from the start
+pub const START_RESOURCE_HOPS: usize = 2;
+
+/// Biomes the variety pass may hand out, in order of preference
+const VARIETY_BIOMES: [Biome; 5] = [
+    Biome::Forest,
+    Biome::Mountains,
+    Biome::Coast,
+    Biome::Desert,
+    Biome::Plains,
+];
+
+/// Reassign some biomes so the start has wood and stone nearby and the region isn't samey.
+/// The starting town (node 0) keeps its biome.
+fn ensure_biome_variety(map: &mut RegionMap) {
+    let hops = hops_from_start(map);
+    let mut locked = vec![false; map.nodes.len()];
+    if let Some(start) = locked.first_mut() {
+        *start = true;
+    }
+
+    // Wood and stone within reach of the start
+    for required in [Biome::Forest, Biome::Mountains] {
+        let near = |i: usize| hops[i].is_some_and(|h| h <= START_RESOURCE_HOPS);
+        if let Some(i) = (0..map.nodes.len()).find(|&i| near(i) && map.nodes[i].biome == required) {
+            locked[i] = true;
+            continue;
+        }
+        if let Some(i) = best_fit(map, &locked, required, near) {
+            map.nodes[i].biome = required;
+            locked[i] = true;
+        }
+    }
+
+    // Overall variety: convert nodes from the most common biome into missing ones
+    for biome in VARIETY_BIOMES {
+        if distinct_biomes(map) >= MIN_DISTINCT_BIOMES {
+            break;
+        }
+        if map.nodes.iter().any(|n| n.biome == biome) {
+            continue;
+        }
+        let common = most_common_biome(map);
+        if let Some(i) = best_fit(map, &locked, biome, |i| map.nodes[i].biome == common) {
+            map.nodes[i].biome = biome;
+            locked[i] = true;
+        }
+    }
+}
+
+/// Unlocked node (passing `filter`) whose position best suits `biome`
+fn best_fit(
+    map: &RegionMap,
+    locked: &[bool],
+    biome: Biome,
+    filter: impl Fn(usize) -> bool,
+) -> Option<usize> {
+    (0..map.nodes.len())
+        .filter(|&i| !locked[i] && filter(i))
+        .max_by(|&a, &b| {
+            let fit = |i: usize| {
+                let [x, y] = map.nodes[i].position;
+                biome_fit(biome, x, y)
+            };
+            fit(a).total_cmp(&fit(b))
+        })
+}
+
+/// How well a position suits a biome (same layout as `position_to_biome`; higher is better)
+fn biome_fit(biome: Biome, x: f32, y: f32) -> f32 {
+    match biome {
+        Biome::Forest => 1.0 - (x + y) / 2.0, // Upper-left
+        Biome::Mountains => 1.0 - y,          // Upper region
+        Biome::Desert => (1.0 - x + y) / 2.0, // Lower-left
+        Biome::Coast => 1.0 - x.min(1.0 - x).min(1.0 - y) * 2.0, // Near an edge
+        _ => 1.0 - ((x - 0.5).powi(2) + (y - 0.5).powi(2)).sqrt() * 2.0,
+    }
+}
+
+/// Route hops from the starting town to every node (None if unreachable)
+fn hops_from_start(map: &RegionMap) -> Vec<Option<usize>> {
+    let mut hops = vec![None; map.nodes.len()];
+    if hops.is_empty() {
+        return hops;
+    }
+    hops[0] = Some(0);
+    let mut frontier = vec![0u32];
+    let mut depth = 0;
+    while !frontier.is_empty() {
+        depth += 1;
+        let mut next = Vec::new();
+        for id in frontier {
+            for route in map.routes_from(id) {
+                let other = if route.from == id {
+                    route.to
+                } else {
+                    route.from
+                };
+                if hops[other as usize].is_none() {
+                    hops[other as usize] = Some(depth);
+                    next.push(other);
+                }
+            }
+        }
+        frontier = next;
+    }
+    hops
+}
+
+fn distinct_biomes(map: &RegionMap) -> usize {
+    let mut seen: Vec<Biome> = Vec::new();
+    for node in &map.nodes {
+        if !seen.contains(&node.biome) {
+            seen.push(node.biome);
+        }
+    }
+    seen.len()
+}
+
+fn most_common_biome(map: &RegionMap) -> Biome {
+    map.nodes
+        .iter()
+        .map(|n| n.biome)
+        .max_by_key(|b| map.nodes.iter().filter(|n| n.biome == *b).count())
+        .unwrap_or_default()
+}
+
 /// Determine biome based on position (creates region-like clusters)
-fn position_to_biome(x: f32, y: f32, rng: &mut SeededRng) -> Biome {
+fn position_to_biome(x: f32, y: f32, rng: &mut SimRng) -> Biome {
     // Use position to create natural biome regions
     // This creates a simple biome pattern
 
-    let noise = rng.next() * 0.3; // Add randomness
+    let noise = rng.next_f32() * 0.3; // Add randomness
 
     // Coast on edges
     if x < 0.15 || x > 0.85 || y > 0.85 {
-        if rng.next() > 0.3 {
+        if rng.next_f32() > 0.3 {
             return Biome::Coast;
         }
     }
 
     // Mountains in upper region
     if y < 0.3 + noise {
-        if rng.next() > 0.4 {
+        if rng.next_f32() > 0.4 {
             return Biome::Mountains;
         }
     }
 
     // Desert in lower-left
     if x < 0.4 && y > 0.5 + noise {
-        if rng.next() > 0.4 {
+        if rng.next_f32() > 0.4 {
             return Biome::Desert;
         }
     }
 
     // Forest in upper-left
     if x < 0.5 && y < 0.5 {
-        if rng.next() > 0.5 {
+        if rng.next_f32() > 0.5 {
             return Biome::Forest;
         }
     }
@@ -241,4 +349,53 @@ mod tests {
         assert!(!map.routes.is_empty());
         assert!(map.active_town_id.is_some());
     }
+
+    #[test]
+    fn test_biome_variety_across_seeds() {
+        for seed in 0..200 {
+            let config = GenerationConfig {
+                seed,
+                node_count: 6,
+                ..Default::default()
+            };
+            let map = generate_region(&config);
+            let hops = hops_from_start(&map);
+            let near = |biome: Biome| {
+                map.nodes.iter().enumerate().any(|(i, n)| {
+                    n.biome == biome && hops[i].is_some_and(|h| h <= START_RESOURCE_HOPS)
+                })
+            };
+
+            assert!(
+                distinct_biomes(&map) >= MIN_DISTINCT_BIOMES,
+                "seed {}",
+                seed
+            );
+            assert!(near(Biome::Forest), "no forest near start, seed {}", seed);
+            assert!(
+                near(Biome::Mountains),
+                "no mountains near start, seed {}",
+                seed
+            );
+        }
+    }
+
+    #[test]
+    fn test_same_seed_same_region() {
+        let config = GenerationConfig {
+            seed: 987,
+            ..Default::default()
+        };
+        let a = generate_region(&config);
+        let b = generate_region(&config);
+
+        assert_eq!(a.seed, 987);
+        assert_eq!(a.nodes.len(), b.nodes.len());
+        for (na, nb) in a.nodes.iter().zip(&b.nodes) {
+            assert_eq!(na.position, nb.position);
+            assert_eq!(na.biome, nb.biome);
+        }
+        let routes = |m: &RegionMap| m.routes.iter().map(|r| (r.from, r.to)).collect::<Vec<_>>();
+        assert_eq!(routes(&a), routes(&b));
+    }
 }
@@ -3,6 +3,18 @@
 use macroquad::prelude::*;
 use serde::{Deserialize, Serialize};
 
+/// Legacy points required to move the capital
+pub const CAPITAL_RELOCATION_COST: u32 = 50;
+
+/// Production bonus for the capital itself
+const CAPITAL_PRODUCTION_BONUS: f32 = 0.10;
+
+/// Production bonus for towns with a discovered road to the capital
+const CAPITAL_ROAD_BONUS: f32 = 0.05;
+
+/// Longest town name that fits on the region map and panels
+pub const MAX_TOWN_NAME_CHARS: usize = 20;
+
 /// A node on the region map (town site or point of interest)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TownNode {
@@ -247,11 +259,102 @@ impl RegionMap {
             .collect()
     }
 
+    /// Get the route directly connecting two nodes, in either direction
+    pub fn route_between(&self, a: u32, b: u32) -> Option<&Route> {
+        self.routes
+            .iter()
+            .find(|r| (r.from == a && r.to == b) || (r.from == b && r.to == a))
+    }
+
+    /// Nodes along the fewest-hop path from `from` to `to` (both ends included),
+    /// or None if no chain of routes joins them
+    pub fn path_between(&self, from: u32, to: u32) -> Option<Vec<u32>> {
+        // Breadth-first, remembering how each node was reached
+        let mut came_from = std::collections::HashMap::new();
+        let mut frontier = std::collections::VecDeque::from([from]);
+        came_from.insert(from, from);
+        while let Some(node) = frontier.pop_front() {
+            if node == to {
+                let mut path = vec![to];
+                let mut current = to;
+                while current != from {
+                    current = came_from[&current];
+                    path.push(current);
+                }
+                path.reverse();
+                return Some(path);
+            }
+            for route in self.routes_from(node) {
+                let next = if route.from == node {
+                    route.to
+                } else {
+                    route.from
+                };
+                if let std::collections::hash_map::Entry::Vacant(entry) = came_from.entry(next) {
+                    entry.insert(node);
+                    frontier.push_back(next);
+                }
+            }
+        }
+        None
+    }
+
     /// Get the active town node
     pub fn active_town(&self) -> Option<&TownNode> {
         self.active_town_id.and_then(|id| self.get_node(id))
     }
 
+    /// Give a settled town a new name (trimmed and cut to `MAX_TOWN_NAME_CHARS`).
+    /// Returns the old name, or None if the town is unknown or unsettled, or the
+    /// new name is blank or unchanged.
+    pub fn rename_town(&mut self, id: u32, name: &str) -> Option<String> {
+        let name: String = name.trim().chars().take(MAX_TOWN_NAME_CHARS).collect();
+        let name = name.trim_end();
+        let node = self.get_node_mut(id).filter(|n| n.settled)?;
+        if name.is_empty() || node.name == name {
+            return None;
+        }
+        Some(std::mem::replace(&mut node.name, name.to_string()))
+    }
+
+    /// Get the current capital node
+    pub fn capital(&self) -> Option<&TownNode> {
+        self.nodes.iter().find(|n| n.is_capital)
+    }
+
+    /// Move the capital to a settled town, clearing the flag everywhere else.
+    /// Returns false if the target is unknown, unsettled, or already the capital.
+    pub fn set_capital(&mut self, id: u32) -> bool {
+        match self.get_node(id) {
+            Some(node) if node.settled && !node.is_capital => {}
+            _ => return false,
+        }
+
+        for node in &mut self.nodes {
+            node.is_capital = node.id == id;
+        }
+        true
+    }
+
+    /// Production bonus a town receives from its relationship to the capital
+    pub fn capital_bonus(&self, town_id: u32) -> f32 {
+        let capital = match self.capital() {
+            Some(capital) => capital,
+            None => return 0.0,
+        };
+
+        if capital.id == town_id {
+            CAPITAL_PRODUCTION_BONUS
+        } else if self
+            .route_between(capital.id, town_id)
+            .is_some_and(|r| r.discovered)
+        {
+            CAPITAL_ROAD_BONUS
+        } else {
+            0.0
+        }
+    }
+
     /// Count settled towns
     pub fn settled_count(&self) -> usize {
         self.nodes.iter().filter(|n| n.settled).count()
@@ -267,3 +370,60 @@ impl RegionMap {
         super::generate_region(&config)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_path_between_takes_fewest_hops() {
+        let map = RegionMap::generate_starter(1);
+        // Quiteville to Frostholm runs through Pine Ridge and the Mystic Ruins
+        assert_eq!(map.path_between(0, 8), Some(vec![0, 1, 5, 8]));
+        assert_eq!(map.path_between(0, 1), Some(vec![0, 1]));
+        assert_eq!(map.path_between(3, 3), Some(vec![3]));
+
+        let mut cut_off = map.clone();
+        cut_off.routes.retain(|r| r.to != 9);
+        assert_eq!(cut_off.path_between(0, 9), None);
+    }
+
+    #[test]
+    fn test_rename_town_only_settled_and_not_blank() {
+        let mut map = RegionMap::generate_starter(1);
+        assert_eq!(
+            map.rename_town(0, "  Brookhaven  "),
+            Some("Quiteville".to_string())
+        );
+        assert_eq!(map.get_node(0).unwrap().name, "Brookhaven");
+        assert_eq!(map.rename_town(0, "   "), None);
+        assert_eq!(map.rename_town(0, "Brookhaven"), None);
+
+        let long = "A".repeat(MAX_TOWN_NAME_CHARS + 5);
+        map.rename_town(0, &long);
+        assert_eq!(map.get_node(0).unwrap().name.len(), MAX_TOWN_NAME_CHARS);
+
+        let unsettled = map.nodes.iter().find(|n| !n.settled).unwrap().id;
+        assert_eq!(map.rename_town(unsettled, "Nowhere"), None);
+    }
+
+    #[test]
+    fn test_capital_bonus_follows_the_capital() {
+        let mut map = RegionMap::generate_starter(1);
+        // Quiteville rules; Pine Ridge has a known road to it, Stone's End doesn't
+        assert_eq!(map.capital_bonus(0), CAPITAL_PRODUCTION_BONUS);
+        assert_eq!(map.capital_bonus(1), CAPITAL_ROAD_BONUS);
+        assert_eq!(map.capital_bonus(2), 0.0);
+
+        // Only a settled town that isn't already the capital can take over
+        assert!(!map.set_capital(1));
+        assert!(!map.set_capital(0));
+        map.get_node_mut(1).unwrap().settled = true;
+        assert!(map.set_capital(1));
+        assert_eq!(map.nodes.iter().filter(|n| n.is_capital).count(), 1);
+        assert_eq!(map.capital_bonus(1), CAPITAL_PRODUCTION_BONUS);
+        assert_eq!(map.capital_bonus(0), CAPITAL_ROAD_BONUS);
+        // Pine Ridge's road to Dusty Flats is still undiscovered
+        assert_eq!(map.capital_bonus(4), 0.0);
+    }
+}
@@ -56,15 +56,16 @@ impl TownProxy {
         }
     }
 
-    /// Update proxy with time passage (called daily)
-    pub fn update(&mut self, days: f32) {
+    /// Update proxy with time passage (called daily); `hardship` scales shortfalls
+    pub fn update(&mut self, days: f32, hardship: f32) {
         self.days_archived += days;
 
-        // Apply net production
-        self.stockpile_materials += self.net_materials * days;
-        self.stockpile_food += self.net_food * days;
-        self.stockpile_wood += self.net_wood * days;
-        self.stockpile_stone += self.net_stone * days;
+        // Apply net production (shortfalls scaled by difficulty)
+        let net = |rate: f32| if rate < 0.0 { rate * hardship } else { rate };
+        self.stockpile_materials += net(self.net_materials) * days;
+        self.stockpile_food += net(self.net_food) * days;
+        self.stockpile_wood += net(self.net_wood) * days;
+        self.stockpile_stone += net(self.net_stone) * days;
 
         // Check for crisis (stockpile went negative)
         self.in_crisis = self.stockpile_materials < -10.0 || self.stockpile_food < -10.0;
@@ -76,6 +77,44 @@ impl TownProxy {
         self.stockpile_stone = self.stockpile_stone.max(-50.0);
     }
 
+    /// Ship positive stockpiles out of the town.
+    /// `efficiency` (0-1) is the fraction that survives the journey; the rest is lost.
+    pub fn withdraw_surplus(&mut self, efficiency: f32) -> ProxyShipment {
+        let efficiency = efficiency.clamp(0.0, 1.0);
+        let shipment = ProxyShipment {
+            materials: self.stockpile_materials.max(0.0) * efficiency,
+            food: self.stockpile_food.max(0.0) * efficiency,
+            wood: self.stockpile_wood.max(0.0) * efficiency,
+            stone: self.stockpile_stone.max(0.0) * efficiency,
+        };
+
+        self.stockpile_materials = self.stockpile_materials.min(0.0);
+        self.stockpile_food = self.stockpile_food.min(0.0);
+        self.stockpile_wood = self.stockpile_wood.min(0.0);
+        self.stockpile_stone = self.stockpile_stone.min(0.0);
+
+        shipment
+    }
+
+    /// Total debt across all stockpiles (sum of negative balances, as a positive number)
+    pub fn debt(&self) -> f32 {
+        -(self.stockpile_materials.min(0.0)
+            + self.stockpile_food.min(0.0)
+            + self.stockpile_wood.min(0.0)
+            + self.stockpile_stone.min(0.0))
+    }
+
+    /// Clear all negative stockpiles, returning the amount of debt covered
+    pub fn cover_debt(&mut self) -> f32 {
+        let debt = self.debt();
+        self.stockpile_materials = self.stockpile_materials.max(0.0);
+        self.stockpile_food = self.stockpile_food.max(0.0);
+        self.stockpile_wood = self.stockpile_wood.max(0.0);
+        self.stockpile_stone = self.stockpile_stone.max(0.0);
+        self.in_crisis = false;
+        debt
+    }
+
     /// Check if town needs player intervention
     pub fn needs_attention(&self) -> bool {
         self.in_crisis || self.days_archived > 30.0
@@ -93,6 +132,22 @@ impl TownProxy {
     }
 }
 
+/// Goods shipped from a proxy town to the active town
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct ProxyShipment {
+    pub materials: f32,
+    pub food: f32,
+    pub wood: f32,
+    pub stone: f32,
+}
+
+impl ProxyShipment {
+    /// Whether anything is actually being shipped
+    pub fn is_empty(&self) -> bool {
+        self.materials + self.food + self.wood + self.stone <= 0.0
+    }
+}
+
 /// Collection of all archived towns
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct TownProxyManager {
@@ -128,10 +183,15 @@ impl TownProxyManager {
         self.proxies.iter().find(|p| p.town_id == town_id)
     }
 
+    /// Get a mutable proxy by town ID
+    pub fn get_mut(&mut self, town_id: u32) -> Option<&mut TownProxy> {
+        self.proxies.iter_mut().find(|p| p.town_id == town_id)
+    }
+
     /// Update all proxies (called once per game day)
-    pub fn update_all(&mut self, days: f32) {
+    pub fn update_all(&mut self, days: f32, hardship: f32) {
         for proxy in &mut self.proxies {
-            proxy.update(days);
+            proxy.update(days, hardship);
         }
     }
 
@@ -1,8 +1,19 @@
 //! Inter-town trade system
 
+use super::ResourcePotentials;
 use macroquad::prelude::*;
 use serde::{Deserialize, Serialize};
 
+/// Cheapest and dearest a good gets locally, relative to its base value
+const LOCAL_PRICE_MIN: f32 = 0.5;
+const LOCAL_PRICE_MAX: f32 = 1.5;
+
+/// Price multiplier where a good is gathered at `potential` times the usual
+/// rate: plentiful goods sell cheap, scarce ones dear
+pub fn local_price_factor(potential: f32) -> f32 {
+    (2.0 / (1.0 + potential.max(0.0))).clamp(LOCAL_PRICE_MIN, LOCAL_PRICE_MAX)
+}
+
 /// A resource being transported between towns
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum TradeGood {
@@ -13,6 +24,49 @@ pub enum TradeGood {
 }
 
 impl TradeGood {
+    pub fn all() -> [TradeGood; 4] {
+        [
+            TradeGood::Materials,
+            TradeGood::Wood,
+            TradeGood::Stone,
+            TradeGood::Food,
+        ]
+    }
+
+    /// The town stockpile this good is shipped from
+    pub fn resource(&self) -> crate::economy::ResourceType {
+        use crate::economy::ResourceType;
+        match self {
+            TradeGood::Materials => ResourceType::Materials,
+            TradeGood::Wood => ResourceType::Logs,
+            TradeGood::Stone => ResourceType::StoneChunks,
+            TradeGood::Food => ResourceType::Grain,
+        }
+    }
+
+    /// Worth of one unit in materials: the one value model shared by trade
+    /// offers, the market exchange and caravan cargo
+    pub fn base_value(&self) -> f32 {
+        match self {
+            TradeGood::Materials => 1.0,
+            TradeGood::Wood => 1.2,
+            TradeGood::Stone => 1.4,
+            TradeGood::Food => 1.5,
+        }
+    }
+
+    /// Worth of one unit in a town with these resource potentials - wood is
+    /// cheap in a forest and dear in a desert
+    pub fn local_value(&self, potentials: &ResourcePotentials) -> f32 {
+        let potential = match self {
+            TradeGood::Materials => 1.0,
+            TradeGood::Wood => potentials.wood,
+            TradeGood::Stone => potentials.stone,
+            TradeGood::Food => potentials.food,
+        };
+        self.base_value() * local_price_factor(potential)
+    }
+
     pub fn name(&self) -> &'static str {
         match self {
             TradeGood::Materials => "Materials",
@@ -40,6 +94,9 @@ pub struct TradeRoute {
     pub frequency_days: f32,
     /// Whether this route is active
     pub active: bool,
+    /// Materials paid to the sender for each delivery (contract routes)
+    #[serde(default)]
+    pub payment_per_trip: f32,
 }
 
 impl TradeRoute {
@@ -52,10 +109,31 @@ impl TradeRoute {
             amount_per_trip: amount,
             frequency_days: 3.0, // Default: every 3 days
             active: true,
+            payment_per_trip: 0.0,
         }
     }
 }
 
+/// A standing offer from another town to buy goods every trip
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TradeOffer {
+    /// Unique ID
+    pub id: u32,
+    /// Town making the offer (the buyer)
+    pub town_id: u32,
+    /// What they want
+    pub good: TradeGood,
+    /// Amount wanted per trip
+    pub amount_per_trip: f32,
+    /// Materials paid per delivery
+    pub payment_per_trip: f32,
+    /// Game time (hours) when the offer lapses
+    pub expires_at: f32,
+}
+
+/// Days a caravan takes for each leg of its trip
+pub const CARAVAN_TRAVEL_DAYS: f32 = 2.0;
+
 /// A caravan traveling between towns
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Caravan {
@@ -109,6 +187,16 @@ impl Caravan {
         (false, false)
     }
 
+    /// Materials the cargo is worth where it is headed
+    pub fn cargo_worth(&self, destination: &ResourcePotentials) -> f32 {
+        self.cargo_amount * self.cargo.local_value(destination)
+    }
+
+    /// Days until the caravan reaches the end of its current leg
+    pub fn days_left(&self, travel_time: f32) -> f32 {
+        (1.0 - self.progress) * travel_time
+    }
+
     /// Get visual position between two points
     pub fn get_visual_position(&self, from: Vec2, to: Vec2) -> Vec2 {
         let (start, end) = if self.outbound {
@@ -126,8 +214,16 @@ impl Caravan {
 pub struct TradeManager {
     pub routes: Vec<TradeRoute>,
     pub caravans: Vec<Caravan>,
+    /// Open trade offers from other towns
+    #[serde(default)]
+    pub offers: Vec<TradeOffer>,
+    /// Game day of the last offer roll
+    #[serde(default)]
+    pub last_offer_day: u32,
     next_route_id: u32,
     next_caravan_id: u32,
+    #[serde(default)]
+    next_offer_id: u32,
 }
 
 impl TradeManager {
@@ -174,8 +270,80 @@ impl TradeManager {
             .collect()
     }
 
+    /// Post a new trade offer, returning its ID
+    pub fn add_offer(
+        &mut self,
+        town_id: u32,
+        good: TradeGood,
+        amount: f32,
+        payment: f32,
+        expires_at: f32,
+    ) -> u32 {
+        let id = self.next_offer_id;
+        self.next_offer_id += 1;
+        self.offers.push(TradeOffer {
+            id,
+            town_id,
+            good,
+            amount_per_trip: amount,
+            payment_per_trip: payment,
+            expires_at,
+        });
+        id
+    }
+
+    /// Accept an offer, turning it into a paid route from `from_town` (with a caravan).
+    /// Returns the new route ID, or None if the offer no longer exists.
+    pub fn accept_offer(&mut self, offer_id: u32, from_town: u32) -> Option<u32> {
+        let pos = self.offers.iter().position(|o| o.id == offer_id)?;
+        let offer = self.offers.remove(pos);
+        let route_id = self.add_route(from_town, offer.town_id, offer.good, offer.amount_per_trip);
+        if let Some(route) = self.routes.iter_mut().find(|r| r.id == route_id) {
+            route.payment_per_trip = offer.payment_per_trip;
+        }
+        self.spawn_caravan(route_id);
+        Some(route_id)
+    }
+
+    /// Drop offers that have lapsed, returning them
+    pub fn expire_offers(&mut self, game_time_hours: f32) -> Vec<TradeOffer> {
+        let (expired, open): (Vec<_>, Vec<_>) = self
+            .offers
+            .drain(..)
+            .partition(|o| o.expires_at <= game_time_hours);
+        self.offers = open;
+        expired
+    }
+
     /// Count active caravans
     pub fn active_caravan_count(&self) -> usize {
         self.caravans.len()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_local_value_follows_plenty_and_scarcity() {
+        let forest = ResourcePotentials::new(2.0, 0.5, 0.8, 0.5);
+        let desert = ResourcePotentials::new(0.2, 1.5, 0.4, 0.8);
+        let wood = TradeGood::Wood;
+        assert!(wood.local_value(&forest) < wood.base_value());
+        assert!(wood.local_value(&desert) > wood.base_value());
+        // Materials are worth the same everywhere
+        assert_eq!(
+            TradeGood::Materials.local_value(&forest),
+            TradeGood::Materials.local_value(&desert)
+        );
+
+        let mut caravan = Caravan::new(0, 0, wood, 10.0);
+        assert_eq!(
+            caravan.cargo_worth(&desert),
+            10.0 * wood.local_value(&desert)
+        );
+        caravan.cargo_amount = 0.0;
+        assert_eq!(caravan.cargo_worth(&desert), 0.0);
+    }
+}
@@ -0,0 +1,15 @@
+//! Saves macroquad's `Vec2` (which has no serde support) as an `[x, y]` pair,
+//! the same shape region node positions are stored in.
+//! Use with `#[serde(with = "crate::save::vec2_serde")]`.
+
+use macroquad::prelude::Vec2;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+pub fn serialize<S: Serializer>(v: &Vec2, serializer: S) -> Result<S::Ok, S::Error> {
+    [v.x, v.y].serialize(serializer)
+}
+
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec2, D::Error> {
+    let [x, y] = <[f32; 2]>::deserialize(deserializer)?;
+    Ok(Vec2::new(x, y))
+}
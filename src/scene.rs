@@ -13,11 +13,13 @@ pub enum Scene {
     RegionView,
     /// Loading screen between scenes
     Loading,
+    /// Closing sequence after the Cloud Spire is finished
+    Ending,
 }
 
 impl Default for Scene {
     fn default() -> Self {
-        Scene::TownView
+        Scene::MainMenu
     }
 }
 
@@ -37,7 +39,7 @@ pub struct SceneManager {
 impl SceneManager {
     pub fn new() -> Self {
         Self {
-            current: Scene::TownView,
+            current: Scene::MainMenu,
             pending_transition: None,
             transition_progress: 0.0,
             is_transitioning: false,
@@ -104,8 +106,18 @@ impl SceneManager {
         self.current == Scene::TownView
     }
 
+    /// Check if we're on the main menu
+    pub fn in_main_menu(&self) -> bool {
+        self.current == Scene::MainMenu
+    }
+
     /// Check if we're in region view
     pub fn in_region_view(&self) -> bool {
         self.current == Scene::RegionView
     }
+
+    /// Check if the ending is playing
+    pub fn in_ending(&self) -> bool {
+        self.current == Scene::Ending
+    }
 }
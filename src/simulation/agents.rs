@@ -1,7 +1,14 @@
 use macroquad::prelude::*;
-use macroquad_toolkit::rng;
 use serde::{Deserialize, Serialize};
 
+use super::crowding::{CROWDED_BENEFIT, LONER_CROWD_PENALTY};
+use super::gossip::{Rumor, MAX_RUMORS};
+use super::reactions::TownEvent;
+use super::rng::SimRng;
+use super::seasons::Season;
+use super::thoughts::{Thought, THOUGHT_REFRESH};
+use crate::zones::ZoneId;
+
 /// Job roles for agents
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Job {
@@ -35,20 +42,22 @@ impl Job {
 /// Time of day for agent schedules
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum TimeOfDay {
-    Morning, // 6:00 - 9:00
-    Work,    // 9:00 - 17:00
-    Evening, // 17:00 - 22:00
-    Night,   // 22:00 - 6:00
+    Morning, // 6:00 - 9:00 in spring
+    Work,    // 9:00 - 17:00 in spring (shorter in winter, longer in summer)
+    Evening, // 17:00 - 22:00 in spring
+    Night,   // 22:00 - 6:00 in spring
 }
 
 impl TimeOfDay {
-    pub fn from_hour(hour: f32) -> Self {
+    /// Part of the day at `hour`, with boundaries shifted by the season
+    pub fn from_hour(hour: f32, season: Season) -> Self {
         let h = hour % 24.0;
-        if h >= 6.0 && h < 9.0 {
+        let rhythm = season.day_rhythm();
+        if h >= 6.0 && h < rhythm.work_start {
             TimeOfDay::Morning
-        } else if h >= 9.0 && h < 17.0 {
+        } else if h >= rhythm.work_start && h < rhythm.work_end {
             TimeOfDay::Work
-        } else if h >= 17.0 && h < 22.0 {
+        } else if h >= rhythm.work_end && h < rhythm.night_start {
             TimeOfDay::Evening
         } else {
             TimeOfDay::Night
@@ -56,16 +65,43 @@ impl TimeOfDay {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq)]
+/// Villagers stay within this many pixels of the map origin on both axes
+pub const TOWN_EXTENT: f32 = 50.0 * 32.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub enum AgentState {
     Idle,
-    Wandering { target: Vec2 },
-    Working { target: Vec2, duration: f32 },
-    Shopping { target: Vec2, duration: f32 },
-    Socializing { target: Vec2, duration: f32 },
+    Wandering {
+        #[serde(with = "crate::save::vec2_serde")]
+        target: Vec2,
+    },
+    Working {
+        #[serde(with = "crate::save::vec2_serde")]
+        target: Vec2,
+        duration: f32,
+    },
+    Shopping {
+        #[serde(with = "crate::save::vec2_serde")]
+        target: Vec2,
+        duration: f32,
+    },
+    Socializing {
+        #[serde(with = "crate::save::vec2_serde")]
+        target: Vec2,
+        duration: f32,
+    },
     GoingHome,
     Sleeping,
-    Building { target: Vec2, zone_idx: usize },
+    Building {
+        #[serde(with = "crate::save::vec2_serde")]
+        target: Vec2,
+        zone: ZoneId,
+    },
+    /// Given up on the town and walking out of it for good
+    Leaving {
+        #[serde(with = "crate::save::vec2_serde")]
+        target: Vec2,
+    },
 }
 
 /// Track agent accomplishments for Hall of Heroes
@@ -75,9 +111,77 @@ pub struct AgentFeats {
     pub resources_hauled: u32,
     pub days_lived: u32,
     pub social_events: u32,
+    /// Work shifts finished since the day began
+    #[serde(default)]
+    pub shifts_today: u32,
+    /// Most work shifts finished in a single day
+    #[serde(default)]
+    pub best_day_shifts: u32,
+    /// Days in a row with at least one shift worked
+    #[serde(default)]
+    pub work_streak: u32,
+    /// Longest run of working days
+    #[serde(default)]
+    pub best_work_streak: u32,
+    /// Villagers they've socialized with (see `gossip::befriend`)
+    #[serde(default)]
+    pub friends: Vec<u64>,
 }
 
 impl AgentFeats {
+    /// Whether this villager has done enough to be immortalized
+    pub fn is_notable(&self) -> bool {
+        self.days_lived >= 30
+            || self.buildings_helped > 0
+            || self.resources_hauled > 0
+            || self.social_events > 0
+    }
+
+    /// A work shift (or a finished building) counts towards today's best
+    pub fn record_shift(&mut self) {
+        self.shifts_today += 1;
+        self.best_day_shifts = self.best_day_shifts.max(self.shifts_today);
+    }
+
+    /// Close out a day: another day lived, and the work streak kept or broken
+    pub fn end_day(&mut self) {
+        self.days_lived += 1;
+        if self.shifts_today > 0 {
+            self.work_streak += 1;
+            self.best_work_streak = self.best_work_streak.max(self.work_streak);
+        } else {
+            self.work_streak = 0;
+        }
+        self.shifts_today = 0;
+    }
+
+    /// Remember a new friend; returns false if they already were one
+    pub fn befriend(&mut self, id: u64) -> bool {
+        if self.friends.contains(&id) {
+            return false;
+        }
+        self.friends.push(id);
+        true
+    }
+
+    /// Personal bests worth telling, for the tooltip
+    pub fn personal_bests(&self) -> Vec<String> {
+        let mut bests = Vec::new();
+        if self.best_day_shifts > 0 {
+            bests.push(format!("Best day: {} shifts", self.best_day_shifts));
+        }
+        if !self.friends.is_empty() {
+            bests.push(format!("Friends: {}", self.friends.len()));
+        }
+        if self.best_work_streak > 0 {
+            bests.push(format!(
+                "Longest streak: {} working days",
+                self.best_work_streak
+            ));
+        }
+        bests
+    }
+
     pub fn to_strings(&self) -> Vec<String> {
         let mut feats = Vec::new();
         if self.buildings_helped > 0 {
@@ -92,6 +196,15 @@ impl AgentFeats {
         if self.social_events >= 10 {
             feats.push(format!("Attended {} social gatherings", self.social_events));
         }
+        if self.best_day_shifts >= 3 {
+            feats.push(format!("Worked {} shifts in one day", self.best_day_shifts));
+        }
+        if self.friends.len() >= 3 {
+            feats.push(format!("Made {} friends", self.friends.len()));
+        }
+        if self.best_work_streak >= 3 {
+            feats.push(format!("Worked {} days in a row", self.best_work_streak));
+        }
         feats
     }
 }
@@ -109,16 +222,17 @@ const LAST_NAMES: &[&str] = &[
     "Frost", "Bloom",
 ];
 
-fn generate_random_name() -> String {
-    let first = FIRST_NAMES[rng::gen_range(0, FIRST_NAMES.len())];
-    let last = LAST_NAMES[rng::gen_range(0, LAST_NAMES.len())];
+fn generate_random_name(rng: &mut SimRng) -> String {
+    let first = FIRST_NAMES[rng.range_int(0, FIRST_NAMES.len())];
+    let last = LAST_NAMES[rng.range_int(0, LAST_NAMES.len())];
     format!("{} {}", first, last)
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Agent {
     pub id: u64,
     pub name: String,
+    #[serde(with = "crate::save::vec2_serde")]
     pub pos: Vec2,
     pub state: AgentState,
 
@@ -127,10 +241,21 @@ pub struct Agent {
     pub hunger: f32,
     pub social: f32,
     pub spirit: f32, // Hope/Morale
+    /// Game hours spent at zero hunger with nothing to eat
+    pub starving_hours: f32,
+    /// Game hours in a row spent miserable (see `departures`)
+    pub miserable_hours: f32,
 
     // Job and workplace
     pub job: Job,
+    /// Zone this villager is assigned to work at (see `workplaces::assign`)
+    pub workplace: Option<ZoneId>,
+    /// Home this villager sleeps in (None = the campsite, see `housing`)
+    pub home: Option<ZoneId>,
+    #[serde(with = "crate::save::vec2_serde")]
     pub home_pos: Vec2,
+    /// Game hour a temporarily hired builder goes back to labouring
+    pub hired_until: Option<f32>,
 
     // Personality / Stats
     pub speed: f32,
@@ -139,30 +264,61 @@ pub struct Agent {
 
     // Accomplishments for Hall of Heroes
     pub feats: AgentFeats,
+    /// Game hour at the last update, to notice the day turning over
+    pub last_hour: f32,
+    /// Already honoured in the Hall of Heroes
+    pub immortalized: bool,
+
+    // Thought bubble (refreshed periodically, not every frame)
+    pub thought: Option<Thought>,
+    pub thought_timer: f32,
+
+    /// News this villager saw or heard, newest last
+    pub rumors: Vec<Rumor>,
+
+    /// Steps in a row the current walk has found no way forward
+    #[serde(default)]
+    pub stalled_steps: u32,
+
+    /// Nearest amenities last looked up (see `find_nearest`)
+    #[serde(skip)]
+    pub nearest: NearestCache,
 }
 
 impl Agent {
-    pub fn new(id: u64, pos: Vec2) -> Self {
+    pub fn new(id: u64, pos: Vec2, rng: &mut SimRng) -> Self {
         Self {
             id,
-            name: generate_random_name(),
+            name: generate_random_name(rng),
             pos,
             state: AgentState::Idle,
             energy: 1.0,
             hunger: 1.0,
             social: 1.0,
             spirit: 1.0,
+            starving_hours: 0.0,
+            miserable_hours: 0.0,
             job: Job::default(),
+            workplace: None,
+            home: None,
             home_pos: pos, // Default home is spawn position
-            speed: 60.0 + rng::gen_range(-15.0, 15.0),
+            hired_until: None,
+            speed: 60.0 + rng.range(-15.0, 15.0),
             color: [
-                rng::gen_range(0.5, 1.0),
-                rng::gen_range(0.5, 1.0),
-                rng::gen_range(0.5, 1.0),
+                rng.range(0.5, 1.0),
+                rng.range(0.5, 1.0),
+                rng.range(0.5, 1.0),
                 1.0,
             ],
-            traits: crate::simulation::traits::generate_random_traits(),
+            traits: crate::simulation::traits::generate_random_traits(rng),
             feats: AgentFeats::default(),
+            last_hour: 0.0,
+            immortalized: false,
+            thought: None,
+            thought_timer: 0.0,
+            rumors: Vec::new(),
+            stalled_steps: 0,
+            nearest: NearestCache::default(),
         }
     }
 
@@ -171,13 +327,56 @@ impl Agent {
         self
     }
 
-    pub fn with_home(mut self, home: Vec2) -> Self {
-        self.home_pos = home;
+    pub fn with_home(mut self, home: Option<ZoneId>, pos: Vec2) -> Self {
+        self.home = home;
+        self.home_pos = pos;
         self
     }
 
-    pub fn update(&mut self, delta: f32, world: &WorldInfo) {
-        let time_of_day = TimeOfDay::from_hour(world.game_hour);
+    /// Adjust spirit in response to a town event, scaled by traits
+    pub fn react_to(&mut self, event: TownEvent) {
+        self.feel(event.spirit_impact());
+    }
+
+    /// Learn a piece of news and react to it; returns false if it was already known
+    pub fn remember(&mut self, rumor: &Rumor) -> bool {
+        if self.rumors.contains(rumor) {
+            return false;
+        }
+        self.feel(rumor.impact);
+        self.rumors.push(rumor.clone());
+        if self.rumors.len() > MAX_RUMORS {
+            self.rumors.remove(0);
+        }
+        true
+    }
+
+    /// Move spirit by an impact, scaled by traits
+    fn feel(&mut self, impact: f32) {
+        let modifier: f32 = self
+            .traits
+            .iter()
+            .map(|t| t.reaction_modifier(impact < 0.0))
+            .product();
+        self.spirit = (self.spirit + impact * modifier).clamp(0.0, 1.0);
+    }
+
+    /// Work speed multiplier from traits
+    pub fn work_speed(&self) -> f32 {
+        self.traits
+            .iter()
+            .map(|t| t.work_speed_modifier())
+            .product()
+    }
+
+    pub fn update(&mut self, delta: f32, world: &WorldInfo, rng: &mut SimRng) {
+        let time_of_day = TimeOfDay::from_hour(world.game_hour, world.season);
+
+        // The clock wrapping past midnight closes out the day's personal bests
+        if world.game_hour < self.last_hour {
+            self.feats.end_day();
+        }
+        self.last_hour = world.game_hour;
 
         // Calculate trait modifiers
         let mut energy_mod = 1.0f32;
@@ -213,12 +412,12 @@ impl Agent {
                     TimeOfDay::Morning => {
                         // Morning routine: eat if hungry
                         if self.hunger < 0.5 && !world.markets.is_empty() {
-                            let target = self.find_nearest(world.markets.as_slice());
+                            let target = self.find_nearest(Amenity::Market, world);
                             self.state = AgentState::Wandering { target };
                         } else if self.energy < 0.3 {
                             self.state = AgentState::Sleeping;
-                        } else if rng::gen_range(0, 100) < 2 {
-                            let target = self.pick_random_target();
+                        } else if rng.range_int(0, 100) < 2 {
+                            let target = self.pick_random_target(world, rng);
                             self.state = AgentState::Wandering { target };
                         }
                     }
@@ -227,30 +426,36 @@ impl Agent {
                         if self.energy < 0.2 {
                             self.state = AgentState::GoingHome;
                         } else if self.hunger < 0.3 && !world.markets.is_empty() {
-                            let target = self.find_nearest(world.markets.as_slice());
+                            let target = self.find_nearest(Amenity::Market, world);
                             self.state = AgentState::Wandering { target };
-                        } else if !world.workshops.is_empty() && rng::gen_range(0, 100) < 5 {
-                            let target = self.find_nearest(world.workshops.as_slice());
+                        } else if let Some(target) =
+                            self.workplace.and_then(|id| world.workplace(id))
+                        {
+                            if rng.range_int(0, 100) < 5 {
+                                self.state = AgentState::Wandering { target };
+                            }
+                        } else if !world.workshops.is_empty() && rng.range_int(0, 100) < 5 {
+                            let target = self.find_nearest(Amenity::Workshop, world);
                             self.state = AgentState::Wandering { target };
                         } else if !world.construction_sites.is_empty() && self.job == Job::Builder {
                             // Builders go to construction sites
-                            let (target, zone_idx) = world.construction_sites[0];
-                            self.state = AgentState::Building { target, zone_idx };
-                        } else if rng::gen_range(0, 100) < 3 {
-                            let target = self.pick_random_target();
+                            let (target, zone) = world.construction_sites[0];
+                            self.state = AgentState::Building { target, zone };
+                        } else if rng.range_int(0, 100) < 3 {
+                            let target = self.pick_random_target(world, rng);
                             self.state = AgentState::Wandering { target };
                         }
                     }
                     TimeOfDay::Evening => {
                         // Evening: socialize, eat, relax
                         if self.hunger < 0.4 && !world.markets.is_empty() {
-                            let target = self.find_nearest(world.markets.as_slice());
+                            let target = self.find_nearest(Amenity::Market, world);
                             self.state = AgentState::Wandering { target };
                         } else if self.social < 0.5 && !world.parks.is_empty() {
-                            let target = self.find_nearest(world.parks.as_slice());
+                            let target = self.find_nearest(Amenity::Park, world);
                             self.state = AgentState::Wandering { target };
-                        } else if rng::gen_range(0, 100) < 3 {
-                            let target = self.pick_random_target();
+                        } else if rng.range_int(0, 100) < 3 {
+                            let target = self.pick_random_target(world, rng);
                             self.state = AgentState::Wandering { target };
                         }
                     }
@@ -258,12 +463,28 @@ impl Agent {
             }
             AgentState::Wandering { target } => {
                 let dist = self.pos.distance(target);
-                if dist < 10.0 {
+                if time_of_day == TimeOfDay::Night && target.distance(self.home_pos) > 10.0 {
+                    // Nightfall calls everyone home, wherever they were headed
+                    self.state = AgentState::GoingHome;
+                } else if dist < 10.0 {
                     // Arrived! Determine what we are doing based on location
                     self.state = AgentState::Idle;
 
-                    // Check local zones
-                    if self.is_at_location(target, world.markets.as_slice()) && self.hunger < 0.5 {
+                    // Loners turn away from a packed market or park
+                    let amenity = self.is_at_location(target, world.markets.as_slice())
+                        || self.is_at_location(target, world.parks.as_slice());
+                    if amenity
+                        && self.is_at_location(target, world.crowded.as_slice())
+                        && self
+                            .traits
+                            .contains(&crate::simulation::traits::Trait::Loner)
+                    {
+                        self.spirit = (self.spirit - LONER_CROWD_PENALTY).max(0.0);
+                        let target = self.pick_random_target(world, rng);
+                        self.state = AgentState::Wandering { target };
+                    } else if self.is_at_location(target, world.markets.as_slice())
+                        && self.hunger < 0.5
+                    {
                         self.state = AgentState::Shopping {
                             target,
                             duration: 2.0,
@@ -275,34 +496,40 @@ impl Agent {
                             target,
                             duration: 3.0,
                         };
-                    } else if self.is_at_location(target, world.workshops.as_slice()) {
+                    } else if self.is_at_location(target, world.workshops.as_slice())
+                        || self.workplace.and_then(|id| world.workplace(id)) == Some(target)
+                    {
                         self.state = AgentState::Working {
                             target,
                             duration: 5.0,
                         };
                     }
-                } else {
-                    let dir = (target - self.pos).normalize();
-                    self.pos += dir * self.speed * delta;
+                } else if self.walk(target, world, delta) {
+                    // No way there (cut off by water); think again
+                    self.state = AgentState::Idle;
                 }
             }
             AgentState::Shopping {
-                ref mut duration, ..
+                target,
+                ref mut duration,
             } => {
                 *duration -= delta;
                 if *duration <= 0.0 {
+                    let benefit = crowd_benefit(target, world);
                     self.hunger = 1.0;
-                    self.spirit = (self.spirit + 0.1).min(1.0);
+                    self.spirit = (self.spirit + 0.1 * benefit).min(1.0);
                     self.state = AgentState::Idle;
                 }
             }
             AgentState::Socializing {
-                ref mut duration, ..
+                target,
+                ref mut duration,
             } => {
                 *duration -= delta;
                 if *duration <= 0.0 {
+                    let benefit = crowd_benefit(target, world);
                     self.social = 1.0;
-                    self.spirit = (self.spirit + 0.2).min(1.0);
+                    self.spirit = (self.spirit + 0.2 * benefit).min(1.0);
                     self.feats.social_events += 1; // Track social feat
                     self.state = AgentState::Idle;
                 }
@@ -314,36 +541,49 @@ impl Agent {
                 self.energy = (self.energy - delta * 0.1).max(0.0);
                 if *duration <= 0.0 {
                     self.spirit = (self.spirit + 0.05).min(1.0);
+                    self.feats.record_shift();
                     self.state = AgentState::Idle;
                 }
             }
-            AgentState::Building { target, .. } => {
+            AgentState::Building { target, zone } => {
                 let dist = self.pos.distance(target);
-                if dist < 10.0 {
+                let site_active = world
+                    .construction_sites
+                    .iter()
+                    .any(|(_, site)| *site == zone);
+                if !site_active {
+                    // Construction finished (or was cancelled)
+                    self.state = AgentState::Idle;
+                } else if dist < 10.0 {
                     // At construction site - work being done in tick.rs
                     self.energy = (self.energy - delta * 0.15).max(0.0);
                     if self.energy < 0.2 {
                         self.state = AgentState::GoingHome;
                     }
-                } else {
-                    // Move toward site
-                    let dir = (target - self.pos).normalize();
-                    self.pos += dir * self.speed * delta;
+                } else if self.walk(target, world, delta) {
+                    // The site can't be reached from here
+                    self.state = AgentState::Idle;
                 }
             }
             AgentState::GoingHome => {
                 let dist = self.pos.distance(self.home_pos);
-                if dist < 10.0 {
+                if dist < 10.0 || self.walk(self.home_pos, world, delta) {
+                    // Home, or sleeping rough when the way home is cut off
                     self.state = AgentState::Sleeping;
-                } else {
-                    let dir = (self.home_pos - self.pos).normalize();
-                    self.pos += dir * self.speed * delta;
+                }
+            }
+            AgentState::Leaving { target } => {
+                // Nothing turns them back, not even water (departures::update sees
+                // them off at the edge)
+                if self.pos.distance(target) > 5.0 {
+                    let dir = (target - self.pos).normalize();
+                    self.pos += dir * self.speed * world.speed_multiplier * delta;
                 }
             }
             AgentState::Sleeping => {
                 self.energy = (self.energy + delta * 0.3).min(1.0);
                 if self.energy >= 0.9 {
-                    let time_of_day = TimeOfDay::from_hour(world.game_hour);
+                    let time_of_day = TimeOfDay::from_hour(world.game_hour, world.season);
                     if time_of_day != TimeOfDay::Night {
                         self.state = AgentState::Idle;
                     }
@@ -351,38 +591,192 @@ impl Agent {
             }
         }
 
+        // Thoughts are throttled so bubbles stay readable
+        self.thought_timer -= delta;
+        if self.thought_timer <= 0.0 {
+            self.thought = Thought::assess(self);
+            self.thought_timer = THOUGHT_REFRESH;
+        }
+
         // Bounds
-        self.pos.x = self.pos.x.clamp(0.0, 50.0 * 32.0);
-        self.pos.y = self.pos.y.clamp(0.0, 50.0 * 32.0);
+        self.pos.x = self.pos.x.clamp(0.0, TOWN_EXTENT);
+        self.pos.y = self.pos.y.clamp(0.0, TOWN_EXTENT);
     }
 
-    fn find_nearest(&self, targets: &[Vec2]) -> Vec2 {
-        let mut nearest = targets[0];
+    /// Nearest amenity of a kind (reusing the last answer while the villager is
+    /// in the same cache cell and the town's layout hasn't changed)
+    fn find_nearest(&mut self, kind: Amenity, world: &WorldInfo) -> Vec2 {
+        let cell = (
+            (self.pos.x / NEAREST_CACHE_CELL).floor() as i32,
+            (self.pos.y / NEAREST_CACHE_CELL).floor() as i32,
+        );
+        let key = Some((world.amenity_version, cell));
+        if self.nearest.key != key {
+            self.nearest = NearestCache {
+                key,
+                ..Default::default()
+            };
+        }
+        if let Some(cached) = *self.nearest.slot(kind) {
+            return cached;
+        }
+
+        let mut nearest = self.pos;
         let mut min_dist = f32::MAX;
-        for &t in targets {
+        for &t in world.amenities(kind) {
             let d = self.pos.distance(t);
             if d < min_dist {
                 min_dist = d;
                 nearest = t;
             }
         }
+        *self.nearest.slot(kind) = Some(nearest);
         nearest
     }
 
     fn is_at_location(&self, target: Vec2, list: &[Vec2]) -> bool {
         list.iter().any(|&pos| pos.distance(target) < 1.0)
     }
+}
 
-    fn pick_random_target(&self) -> Vec2 {
-        vec2(rng::gen_range(100.0, 1500.0), rng::gen_range(100.0, 1500.0))
+/// Share of an amenity's spirit boost a visit gives (less when it is crowded)
+fn crowd_benefit(target: Vec2, world: &WorldInfo) -> f32 {
+    if world.crowded.iter().any(|&pos| pos.distance(target) < 1.0) {
+        CROWDED_BENEFIT
+    } else {
+        1.0
     }
 }
 
+/// Kinds of place a villager looks for the nearest of
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Amenity {
+    Market,
+    Workshop,
+    Park,
+}
+
+/// Size (world pixels) of the cells nearest-amenity lookups are cached for
+const NEAREST_CACHE_CELL: f32 = 64.0;
+
+/// Nearest amenities found from one cache cell, for one layout of the town.
+/// Amenity counts stay in the tens, so a fresh lookup is a plain scan; the cache
+/// just saves repeating it while a villager stays put and nothing is built.
+#[derive(Debug, Clone, Default)]
+pub struct NearestCache {
+    /// `WorldInfo::amenity_version` and cell the entries were found for
+    key: Option<(u64, (i32, i32))>,
+    market: Option<Vec2>,
+    workshop: Option<Vec2>,
+    park: Option<Vec2>,
+}
+
+impl NearestCache {
+    fn slot(&mut self, kind: Amenity) -> &mut Option<Vec2> {
+        match kind {
+            Amenity::Market => &mut self.market,
+            Amenity::Workshop => &mut self.workshop,
+            Amenity::Park => &mut self.park,
+        }
+    }
+}
+
+/// Fingerprint of where the amenities stand, so cached lookups notice a change
+pub fn amenity_version(lists: &[&[Vec2]]) -> u64 {
+    use std::hash::{Hash, Hasher};
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    for list in lists {
+        list.len().hash(&mut hasher);
+        for pos in *list {
+            pos.x.to_bits().hash(&mut hasher);
+            pos.y.to_bits().hash(&mut hasher);
+        }
+    }
+    hasher.finish()
+}
+
 /// Context for agent decisions
 pub struct WorldInfo {
     pub markets: Vec<Vec2>,
     pub workshops: Vec<Vec2>,
     pub parks: Vec<Vec2>,
-    pub construction_sites: Vec<(Vec2, usize)>, // Position and zone index
-    pub game_hour: f32,                         // 0-24 hour cycle
+    pub construction_sites: Vec<(Vec2, ZoneId)>, // Position and zone
+    pub workplaces: Vec<(ZoneId, Vec2)>,         // Zones that take workers and their centres
+    pub crowded: Vec<Vec2>,                      // Markets/parks with a crowd around them
+    pub game_hour: f32,                          // 0-24 hour cycle
+    pub season: Season,                          // Shifts the working day
+    pub speed_multiplier: f32,                   // Movement multiplier (tech)
+    pub amenity_version: u64,                    // See `amenity_version`
+    pub walkable: super::map::Walkable,          // Water villagers can't cross
+}
+
+impl WorldInfo {
+    /// Where every amenity of a kind stands
+    pub fn amenities(&self, kind: Amenity) -> &[Vec2] {
+        match kind {
+            Amenity::Market => &self.markets,
+            Amenity::Workshop => &self.workshops,
+            Amenity::Park => &self.parks,
+        }
+    }
+
+    /// Where a workplace zone stands, if it's still open
+    pub fn workplace(&self, zone: ZoneId) -> Option<Vec2> {
+        self.workplaces
+            .iter()
+            .find(|(id, _)| *id == zone)
+            .map(|(_, pos)| *pos)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn world(markets: Vec<Vec2>) -> WorldInfo {
+        let amenity_version = amenity_version(&[&markets]);
+        WorldInfo {
+            markets,
+            workshops: Vec::new(),
+            parks: Vec::new(),
+            construction_sites: Vec::new(),
+            workplaces: Vec::new(),
+            crowded: Vec::new(),
+            game_hour: 12.0,
+            season: Default::default(),
+            speed_multiplier: 1.0,
+            amenity_version,
+            walkable: Default::default(),
+        }
+    }
+
+    #[test]
+    fn test_nearest_amenity_is_cached_until_the_town_changes() {
+        let mut rng = SimRng::new(1);
+        let mut agent = Agent::new(1, vec2(100.0, 100.0), &mut rng);
+        let far = world(vec![vec2(900.0, 900.0)]);
+        assert_eq!(
+            agent.find_nearest(Amenity::Market, &far),
+            vec2(900.0, 900.0)
+        );
+
+        // Same layout, same cell: the cached answer stands
+        agent.nearest.market = Some(vec2(1.0, 1.0));
+        assert_eq!(agent.find_nearest(Amenity::Market, &far), vec2(1.0, 1.0));
+
+        // A new market is built next door
+        let near = world(vec![vec2(900.0, 900.0), vec2(120.0, 100.0)]);
+        assert_eq!(
+            agent.find_nearest(Amenity::Market, &near),
+            vec2(120.0, 100.0)
+        );
+
+        // Walking to another cell looks again
+        agent.nearest.market = Some(vec2(1.0, 1.0));
+        agent.pos = vec2(850.0, 850.0);
+        assert_eq!(
+            agent.find_nearest(Amenity::Market, &near),
+            vec2(900.0, 900.0)
+        );
+    }
 }
@@ -0,0 +1,147 @@
+//! Auto-restore - surplus materials go into rebuilding and repairing the town on their own
+
+use crate::data::{GameState, RestorePriority, ZoneCategory, ZoneTemplate};
+use crate::narrative::LogCategory;
+
+/// Game hours between auto-restore jobs (one job each, so spending stays gradual)
+const CHECK_INTERVAL_HOURS: f32 = 1.0;
+
+/// Game hours between log summaries of what auto-restore did
+const REPORT_INTERVAL_HOURS: f32 = 24.0;
+
+/// Working zones below this condition get repaired
+const REPAIR_BELOW: f32 = 0.75;
+
+/// Condition a repair adds (same as a manual repair)
+const REPAIR_AMOUNT: f32 = 0.5;
+
+/// Timers and the jobs done since the last log summary (not saved)
+#[derive(Debug, Clone, Default)]
+pub struct AutoRestore {
+    next_check: f32,
+    next_report: f32,
+    done: Vec<String>,
+}
+
+/// Restore or repair one zone when the surplus allows, and now and then summarise in the log
+pub fn update(state: &mut GameState) {
+    let now = state.game_time_hours;
+    if !state.settings.auto_restore {
+        return;
+    }
+    if now >= state.auto_restore.next_check {
+        state.auto_restore.next_check = now + CHECK_INTERVAL_HOURS;
+        if let Some(job) = restore_one(state) {
+            state.auto_restore.done.push(job);
+        }
+    }
+    if now >= state.auto_restore.next_report {
+        state.auto_restore.next_report = now + REPORT_INTERVAL_HOURS;
+        report(state);
+    }
+}
+
+/// Zones worth working on, best first
+fn candidates(state: &GameState) -> Vec<usize> {
+    let priority = state.settings.auto_restore_priority;
+    let mut found: Vec<(usize, u8, f32)> = state
+        .zones
+        .iter()
+        .enumerate()
+        .filter(|(_, z)| !z.is_under_construction() && !z.disabled && z.surveyed)
+        .filter(|(_, z)| z.dormant || z.condition < REPAIR_BELOW)
+        .filter_map(|(i, z)| {
+            let template = state.get_template(&z.template_id)?;
+            if state.missing_tech(template).is_some() {
+                return None;
+            }
+            Some((i, rank(template, priority), z.condition))
+        })
+        .collect();
+    found.sort_by(|a, b| a.1.cmp(&b.1).then(a.2.total_cmp(&b.2)));
+    found.into_iter().map(|(i, _, _)| i).collect()
+}
+
+/// Lower ranks come first
+fn rank(template: &ZoneTemplate, priority: RestorePriority) -> u8 {
+    let favoured = match priority {
+        RestorePriority::Housing => template.category == ZoneCategory::Residential,
+        RestorePriority::Production => {
+            template.output.materials > 0.0 || template.food_output > 0.0
+        }
+    };
+    if favoured {
+        0
+    } else {
+        1
+    }
+}
+
+/// Start rebuilding or repair the first affordable zone; returns what was done
+fn restore_one(state: &mut GameState) -> Option<String> {
+    let reserve = state.settings.auto_restore_reserve;
+    for index in candidates(state) {
+        let (cost, name) = match state.get_template(&state.zones[index].template_id) {
+            Some(t) => (t.construction_cost.clone(), t.name.clone()),
+            None => continue,
+        };
+
+        let mut after = state.resources;
+        after.spend(&cost);
+        if !state.resources.has(&cost) || after.materials < reserve {
+            continue;
+        }
+
+        let zone = &mut state.zones[index];
+        if zone.dormant {
+            // Builders do the rest, like a manual rebuild
+            zone.begin_construction();
+            return Some(format!("began rebuilding {}", name));
+        }
+        zone.restore(REPAIR_AMOUNT);
+        state.resources.spend(&cost);
+        return Some(format!("repaired {}", name));
+    }
+    None
+}
+
+/// One log line for everything done since the last report
+fn report(state: &mut GameState) {
+    if state.auto_restore.done.is_empty() {
+        return;
+    }
+    let jobs = std::mem::take(&mut state.auto_restore.done);
+    state.log.add(
+        state.game_time_hours,
+        format!("Auto-restore {}.", jobs.join(", ")),
+        LogCategory::Zone,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_keeps_the_reserve_and_puts_homes_first() {
+        let mut state = GameState::default();
+        state
+            .zone_templates
+            .extend(crate::assets::load_zones().unwrap());
+        state.setup_starting_town();
+        state.settings.auto_restore_reserve = 50.0;
+
+        state.resources.materials = 50.0;
+        assert_eq!(restore_one(&mut state), None);
+
+        state.resources.materials = 1000.0;
+        assert!(restore_one(&mut state).is_some());
+        let started = state
+            .zones
+            .iter()
+            .find(|z| z.is_under_construction())
+            .and_then(|z| state.get_template(&z.template_id))
+            .unwrap();
+        assert_eq!(started.category, ZoneCategory::Residential);
+    }
+}
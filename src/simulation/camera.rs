@@ -1,11 +1,67 @@
 use macroquad::prelude::*;
 
+/// Keyboard/edge pan speed in screen pixels per second (divided by zoom in world space)
+const PAN_SPEED: f32 = 500.0;
+
+/// Cursor distance from a screen edge (pixels) that triggers edge scrolling
+const EDGE_SCROLL_MARGIN: f32 = 12.0;
+
+/// Zoom at the start of the opening camera sweep
+const INTRO_START_ZOOM: f32 = 0.6;
+
+/// Where the opening sweep starts, as a fraction of the map size (the far corner)
+const INTRO_START: Vec2 = Vec2::new(0.85, 0.8);
+
+/// Zoom the ending sweep pulls back to
+const ENDING_ZOOM: f32 = 0.45;
+
+/// Fraction of the screen the framed town fills, leaving a margin around it
+const FIT_FILL: f32 = 0.85;
+
+/// Zoom range when framing the town
+const FIT_MIN_ZOOM: f32 = 0.4;
+const FIT_MAX_ZOOM: f32 = 2.0;
+
+/// How quickly the camera glides to a framed view (fraction closed per second, roughly)
+const GLIDE_RATE: f32 = 8.0;
+
+/// Camera target and zoom that frame `bounds` (world pixels) on a screen of
+/// `screen` pixels, kept inside a map `map_size` world pixels across
+pub fn fit_view(bounds: Rect, map_size: Vec2, screen: Vec2) -> (Vec2, f32) {
+    let zoom = (screen.x * FIT_FILL / bounds.w.max(1.0))
+        .min(screen.y * FIT_FILL / bounds.h.max(1.0))
+        .clamp(FIT_MIN_ZOOM, FIT_MAX_ZOOM);
+
+    // Don't look past the map edge (centre on the map if it all fits on screen)
+    let half_view = screen / 2.0 / zoom;
+    let clamp_axis = |centre: f32, half: f32, size: f32| {
+        if size <= half * 2.0 {
+            size / 2.0
+        } else {
+            centre.clamp(half, size - half)
+        }
+    };
+    let centre = bounds.center();
+    let target = vec2(
+        clamp_axis(centre.x, half_view.x, map_size.x),
+        clamp_axis(centre.y, half_view.y, map_size.y),
+    );
+    (target, zoom)
+}
+
+/// Camera target of the default town view (map origin at the top-left of the screen)
+pub fn default_target() -> Vec2 {
+    vec2(screen_width() / 2.0, screen_height() / 2.0)
+}
+
 // Wrapper around toolkit Camera2D to maintain API compatibility
 #[derive(Debug, Clone)]
 pub struct Camera2D {
     pub target: Vec2,
     pub zoom: f32,
     pub drag_start: Option<Vec2>,
+    /// Target and zoom being glided towards (see `glide_to`)
+    glide: Option<(Vec2, f32)>,
     inner: macroquad_toolkit::camera::Camera2D,
 }
 
@@ -21,10 +77,42 @@ impl Camera2D {
             target: vec2(0.0, 0.0),
             zoom: 1.0,
             drag_start: None,
+            glide: None,
             inner: macroquad_toolkit::camera::Camera2D::new(vec2(0.0, 0.0), 1.0),
         }
     }
 
+    /// Snap to the default town view
+    pub fn reset(&mut self) {
+        self.target = default_target();
+        self.zoom = 1.0;
+        self.glide = None;
+    }
+
+    /// Glide over the next few frames to a target and zoom (panning or zooming
+    /// by hand takes over)
+    pub fn glide_to(&mut self, target: Vec2, zoom: f32) {
+        self.glide = Some((target, zoom));
+    }
+
+    /// Place the camera on the tutorial's opening sweep: from the far corner of a map
+    /// `map_size` world pixels across, zoomed out, to the default view (progress 0-1)
+    pub fn follow_intro(&mut self, progress: f32, map_size: Vec2) {
+        let t = progress.clamp(0.0, 1.0);
+        let eased = t * t * (3.0 - 2.0 * t);
+        self.target = (map_size * INTRO_START).lerp(default_target(), eased);
+        self.zoom = INTRO_START_ZOOM + (1.0 - INTRO_START_ZOOM) * eased;
+    }
+
+    /// Place the camera on the ending's closing sweep: from the default view, pulling
+    /// back to take in the whole of a map `map_size` world pixels across (progress 0-1)
+    pub fn follow_ending(&mut self, progress: f32, map_size: Vec2) {
+        let t = progress.clamp(0.0, 1.0);
+        let eased = t * t * (3.0 - 2.0 * t);
+        self.target = default_target().lerp(map_size / 2.0, eased);
+        self.zoom = 1.0 + (ENDING_ZOOM - 1.0) * eased;
+    }
+
     /// Convert screen coordinates to world coordinates
     pub fn screen_to_world(&self, point: Vec2) -> Vec2 {
         self.inner.screen_to_world(point)
@@ -35,14 +123,50 @@ impl Camera2D {
         self.inner.world_to_screen(point)
     }
 
-    /// Handle Input (WASD + Mouse)
-    pub fn update(&mut self, delta: f32, input_captured: bool) {
+    /// Handle Input (WASD/arrow keys, screen edges + Mouse).
+    ///
+    /// `keys_captured` (a modal is open) stops keyboard and edge panning;
+    /// `mouse_captured` (the cursor is over a panel) stops dragging and the wheel.
+    pub fn update(
+        &mut self,
+        delta: f32,
+        keys_captured: bool,
+        mouse_captured: bool,
+        edge_scroll: bool,
+    ) {
+        let mut direction = Vec2::ZERO;
+        if !keys_captured {
+            direction += Self::keyboard_direction();
+        }
+        if edge_scroll && !keys_captured {
+            direction += Self::edge_direction();
+        }
+        if direction != Vec2::ZERO {
+            self.target += direction.normalize() * PAN_SPEED * delta / self.zoom.max(0.01);
+            self.glide = None;
+        }
+        if !mouse_captured && (is_mouse_button_pressed(MouseButton::Left) || mouse_wheel().1 != 0.0)
+        {
+            self.glide = None;
+        }
+
+        if let Some((target, zoom)) = self.glide {
+            let t = 1.0 - (-GLIDE_RATE * delta).exp();
+            self.target = self.target.lerp(target, t);
+            self.zoom += (zoom - self.zoom) * t;
+            if self.target.distance(target) < 1.0 && (self.zoom - zoom).abs() < 0.005 {
+                self.target = target;
+                self.zoom = zoom;
+                self.glide = None;
+            }
+        }
+
         // Sync public fields to inner camera
         self.inner.target = self.target;
         self.inner.zoom = self.zoom;
 
         // Update drag_start tracking for external access
-        if is_mouse_button_pressed(MouseButton::Left) && !input_captured {
+        if is_mouse_button_pressed(MouseButton::Left) && !mouse_captured {
             self.drag_start = Some(mouse_position().into());
         }
         if is_mouse_button_released(MouseButton::Left) {
@@ -50,10 +174,88 @@ impl Camera2D {
         }
 
         // Delegate to toolkit camera
-        self.inner.update(delta, input_captured);
+        self.inner.update(delta, mouse_captured);
 
         // Sync back from inner camera
         self.target = self.inner.target;
         self.zoom = self.inner.zoom;
     }
+
+    /// Pan direction from WASD / left-right arrows (up/down arrows change game speed)
+    fn keyboard_direction() -> Vec2 {
+        let mut direction = Vec2::ZERO;
+        if is_key_down(KeyCode::W) {
+            direction.y -= 1.0;
+        }
+        if is_key_down(KeyCode::S) {
+            direction.y += 1.0;
+        }
+        if is_key_down(KeyCode::A) || is_key_down(KeyCode::Left) {
+            direction.x -= 1.0;
+        }
+        if is_key_down(KeyCode::D) || is_key_down(KeyCode::Right) {
+            direction.x += 1.0;
+        }
+        direction
+    }
+
+    /// Pan direction from the cursor resting near a screen edge
+    fn edge_direction() -> Vec2 {
+        edge_direction_at(
+            mouse_position().into(),
+            vec2(screen_width(), screen_height()),
+        )
+    }
+}
+
+/// Edge scroll direction for a cursor at `mouse` on a screen of `screen` pixels.
+///
+/// The side panel and top bar reach the left and top edges, so the cursor is
+/// always over the UI there; the outer margin scrolls even over a panel.
+fn edge_direction_at(mouse: Vec2, screen: Vec2) -> Vec2 {
+    let mut direction = Vec2::ZERO;
+    if mouse.x <= EDGE_SCROLL_MARGIN {
+        direction.x -= 1.0;
+    } else if mouse.x >= screen.x - EDGE_SCROLL_MARGIN {
+        direction.x += 1.0;
+    }
+    if mouse.y <= EDGE_SCROLL_MARGIN {
+        direction.y -= 1.0;
+    } else if mouse.y >= screen.y - EDGE_SCROLL_MARGIN {
+        direction.y += 1.0;
+    }
+    direction
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fit_view_frames_bounds_without_leaving_the_map() {
+        let screen = vec2(1000.0, 800.0);
+        let map = vec2(1600.0, 1600.0);
+
+        // A small town in the middle is zoomed in on and centred
+        let (target, zoom) = fit_view(Rect::new(700.0, 700.0, 200.0, 200.0), map, screen);
+        assert_eq!(target, vec2(800.0, 800.0));
+        assert_eq!(zoom, FIT_MAX_ZOOM);
+
+        // A town in the corner is framed, but the view stops at the map edge
+        let (target, zoom) = fit_view(Rect::new(0.0, 0.0, 1200.0, 900.0), map, screen);
+        assert!(zoom < 1.0);
+        assert_eq!(target, screen / 2.0 / zoom);
+    }
+
+    #[test]
+    fn test_edge_scroll_reaches_every_edge() {
+        let screen = vec2(1280.0, 720.0);
+        assert_eq!(edge_direction_at(vec2(0.0, 360.0), screen), vec2(-1.0, 0.0));
+        assert_eq!(edge_direction_at(vec2(640.0, 0.0), screen), vec2(0.0, -1.0));
+        assert_eq!(
+            edge_direction_at(vec2(1279.0, 719.0), screen),
+            vec2(1.0, 1.0)
+        );
+        assert_eq!(edge_direction_at(vec2(640.0, 360.0), screen), Vec2::ZERO);
+    }
 }
@@ -0,0 +1,67 @@
+//! Collapse - a town with nobody left and nothing in store eventually falls
+
+use crate::data::GameState;
+use crate::narrative::{ChronicleEventType, LogCategory};
+
+/// Game hours a town can sit empty and out of materials before it collapses
+const COLLAPSE_HOURS: f32 = 72.0;
+
+/// Below this population the town counts as empty
+const EMPTY_POPULATION: f32 = 1.0;
+
+/// Whether the town is empty with an exhausted stockpile
+pub fn is_failing(state: &GameState) -> bool {
+    state.population.value() < EMPTY_POPULATION && state.resources.materials <= 0.0
+}
+
+/// Count down while the town is failing, and mark it collapsed when time runs out
+pub fn update(state: &mut GameState, hours: f32) {
+    if state.town_failed {
+        return;
+    }
+    if !is_failing(state) {
+        state.collapse_hours = 0.0;
+        return;
+    }
+
+    state.collapse_hours += hours;
+    if state.collapse_hours < COLLAPSE_HOURS {
+        return;
+    }
+
+    state.town_failed = true;
+    let town_name = state
+        .region_map
+        .active_town()
+        .map_or("The town".to_string(), |n| n.name.clone());
+    state.town_chronicle.record(
+        state.game_time_hours,
+        ChronicleEventType::TownCollapsed {
+            town_name: town_name.clone(),
+        },
+    );
+    state.log.add(
+        state.game_time_hours,
+        format!(
+            "{} has collapsed. Its people are gone and its stores are empty.",
+            town_name
+        ),
+        LogCategory::Milestone,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_empty_town_collapses_after_grace_period() {
+        let mut state = GameState::default();
+        state.resources.materials = 0.0;
+
+        update(&mut state, COLLAPSE_HOURS - 1.0);
+        assert!(!state.town_failed);
+        update(&mut state, 1.0);
+        assert!(state.town_failed);
+    }
+}
@@ -0,0 +1,282 @@
+//! Construction system - builders turn deposited materials and work into finished zones
+
+use crate::data::GameState;
+use crate::narrative::{ChronicleEventType, LogCategory};
+use crate::simulation::agents::{AgentState, Job};
+use crate::simulation::gossip::{self, Rumor};
+use crate::zones::{ConstructionState, ZoneId};
+
+/// Work the settlers manage on their own per game minute, so no site stalls without builders
+pub const BASE_WORK_PER_MINUTE: f32 = 0.05;
+
+/// Work each builder on site adds per game minute (scaled by their work speed)
+pub const BUILDER_WORK_PER_MINUTE: f32 = 0.15;
+
+/// Distance from the site at which a builder counts as working on it
+const ON_SITE_RADIUS: f32 = 10.0;
+
+/// Spirit lift for villagers who see (or hear about) a building finished
+const REBUILT_IMPACT: f32 = 0.05;
+
+/// Materials paid to take one laborer onto the building crew
+pub const HIRE_COST_PER_BUILDER: f32 = 5.0;
+
+/// Game hours a hired builder stays on the crew
+pub const HIRE_DURATION_HOURS: f32 = 24.0;
+
+/// Combined work speed of the builders currently on site at a zone
+pub fn builder_effort(state: &GameState, zone: ZoneId) -> f32 {
+    state
+        .agents
+        .iter()
+        .filter_map(|agent| match agent.state {
+            AgentState::Building { target, zone: site }
+                if site == zone && agent.pos.distance(target) < ON_SITE_RADIUS =>
+            {
+                Some(agent.work_speed())
+            }
+            _ => None,
+        })
+        .sum()
+}
+
+/// Work added to a site per game minute for a given builder effort
+pub fn work_rate(builder_effort: f32) -> f32 {
+    BASE_WORK_PER_MINUTE + builder_effort * BUILDER_WORK_PER_MINUTE
+}
+
+/// Builders in town and how many of them are temporary hires
+pub fn builder_counts(state: &GameState) -> (usize, usize) {
+    let builders = state.agents.iter().filter(|a| a.job == Job::Builder);
+    let hired = builders.clone().filter(|a| a.hired_until.is_some()).count();
+    (builders.count(), hired)
+}
+
+/// Work added per game hour across every construction site
+pub fn total_work_per_hour(state: &GameState) -> f32 {
+    state
+        .zones
+        .iter()
+        .filter(|z| z.is_under_construction())
+        .map(|z| work_rate(builder_effort(state, z.id)) * 60.0)
+        .sum()
+}
+
+/// Pay to put up to `count` laborers on the building crew for a while.
+/// Returns how many were hired.
+pub fn hire_builders(state: &mut GameState, count: usize) -> usize {
+    let affordable = (state.resources.materials / HIRE_COST_PER_BUILDER).floor() as usize;
+    let until = state.game_time_hours + HIRE_DURATION_HOURS;
+    let mut hired = 0;
+    for agent in state
+        .agents
+        .iter_mut()
+        .filter(|a| a.job == Job::Laborer)
+        .take(count.min(affordable))
+    {
+        agent.job = Job::Builder;
+        agent.hired_until = Some(until);
+        hired += 1;
+    }
+    state.resources.materials -= hired as f32 * HIRE_COST_PER_BUILDER;
+    hired
+}
+
+/// Send hired builders whose time is up back to labouring
+pub fn release_hired_builders(state: &mut GameState) {
+    let now = state.game_time_hours;
+    for agent in &mut state.agents {
+        if agent.hired_until.is_some_and(|until| now >= until) {
+            agent.job = Job::Laborer;
+            agent.hired_until = None;
+        }
+    }
+}
+
+/// Advance every construction site by the elapsed game minutes.
+///
+/// Materials are deposited (and the cost deducted) the first time they can be afforded;
+/// work only accumulates once they are on site.
+pub fn advance_construction(state: &mut GameState, game_minutes: f32) {
+    for zone_idx in 0..state.zones.len() {
+        if !state.zones[zone_idx].is_under_construction() {
+            continue;
+        }
+
+        let (name, cost, required_work) = match state
+            .zone_templates
+            .iter()
+            .find(|t| t.id == state.zones[zone_idx].template_id)
+        {
+            Some(t) => (
+                t.name.clone(),
+                t.construction_cost.clone(),
+                t.construction_work,
+            ),
+            None => continue,
+        };
+
+        // Deposit materials once
+        if let ConstructionState::UnderConstruction {
+            materials_deposited: false,
+            ..
+        } = state.zones[zone_idx].construction_state
+        {
+            if !state.resources.has(&cost) {
+                continue;
+            }
+            state.resources.spend(&cost);
+            if let ConstructionState::UnderConstruction {
+                ref mut materials_deposited,
+                ..
+            } = state.zones[zone_idx].construction_state
+            {
+                *materials_deposited = true;
+            }
+            state.log.add(
+                state.game_time_hours,
+                format!("Materials delivered to {} (-{})", name, cost.summary()),
+                LogCategory::Zone,
+            );
+        }
+
+        let work = work_rate(builder_effort(state, state.zones[zone_idx].id))
+            * state.season_state.weather.outdoor_work_multiplier()
+            * game_minutes;
+        let mut finished = false;
+        if let ConstructionState::UnderConstruction {
+            ref mut work_done, ..
+        } = state.zones[zone_idx].construction_state
+        {
+            *work_done += work;
+            finished = *work_done >= required_work;
+        }
+        let progress = state.zones[zone_idx].construction_progress(required_work);
+        state.zones[zone_idx].advance_reawakening(progress);
+
+        if finished {
+            complete_construction(state, zone_idx, name);
+        }
+    }
+}
+
+/// Flip a finished site to an active zone and credit the builders who were there
+fn complete_construction(state: &mut GameState, zone_idx: usize, name: String) {
+    let zone = &mut state.zones[zone_idx];
+    zone.construction_state = ConstructionState::Complete;
+    zone.condition = 1.0;
+    zone.dormant = false;
+    zone.advance_reawakening(1.0);
+    let zone_id = zone.id;
+
+    for agent in &mut state.agents {
+        if let AgentState::Building { target, zone: site } = agent.state {
+            if site == zone_id && agent.pos.distance(target) < ON_SITE_RADIUS {
+                agent.feats.buildings_helped += 1;
+                agent.feats.record_shift();
+            }
+        }
+    }
+
+    state.stats.zones_restored += 1;
+    state.log.add(
+        state.game_time_hours,
+        format!("{} has been rebuilt!", name),
+        LogCategory::Zone,
+    );
+    let site = state.zone_rect(&state.zones[zone_idx]);
+    if let Some(rect) = site {
+        let rumor = Rumor::new(
+            format!("The {} was rebuilt", name),
+            REBUILT_IMPACT,
+            state.game_time_hours,
+        );
+        gossip::witness(state, rect.world_center(), rumor);
+    }
+    state.town_chronicle.record(
+        state.game_time_hours,
+        ChronicleEventType::BuildingConstructed {
+            building_name: name,
+        },
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::simulation::agents::Agent;
+    use crate::simulation::rng::SimRng;
+    use macroquad::prelude::Vec2;
+
+    fn work_done(state: &GameState, index: usize) -> f32 {
+        match state.zones[index].construction_state {
+            ConstructionState::UnderConstruction { work_done, .. } => work_done,
+            _ => panic!("zone {} is not under construction", index),
+        }
+    }
+
+    #[test]
+    fn test_builders_on_site_speed_up_construction() {
+        let mut state = GameState::default();
+        state
+            .zone_templates
+            .extend(crate::assets::load_zones().unwrap());
+        state.season_state.weather = crate::simulation::seasons::Weather::Sunny;
+        state.resources.materials = 10.0;
+        // Two shacks (3 materials, 10 work each); builders only at the first
+        let crewed = state.add_zone("shack").unwrap();
+        state.add_zone("shack").unwrap();
+        for zone in &mut state.zones {
+            zone.begin_construction();
+        }
+        let mut rng = SimRng::new(1);
+        for id in 0..2 {
+            let mut agent = Agent::new(id, Vec2::ZERO, &mut rng);
+            agent.traits.clear();
+            agent.job = Job::Builder;
+            agent.state = AgentState::Building {
+                target: Vec2::ZERO,
+                zone: crewed,
+            };
+            state.agents.push(agent);
+        }
+
+        // Both sites take their materials up front, once
+        advance_construction(&mut state, 10.0);
+        assert_eq!(state.resources.materials, 4.0);
+        assert!((work_done(&state, 0) - work_rate(2.0) * 10.0).abs() < 1e-4);
+        assert!((work_done(&state, 1) - BASE_WORK_PER_MINUTE * 10.0).abs() < 1e-4);
+
+        // The crewed site finishes first and credits its builders
+        advance_construction(&mut state, 20.0);
+        assert_eq!(state.resources.materials, 4.0);
+        assert_eq!(
+            state.zones[0].construction_state,
+            ConstructionState::Complete
+        );
+        assert!(state.zones[0].is_operating());
+        assert!(state.zones[1].is_under_construction());
+        assert_eq!(state.stats.zones_restored, 1);
+        assert!(state.agents.iter().all(|a| a.feats.buildings_helped == 1));
+    }
+
+    #[test]
+    fn test_construction_waits_for_materials() {
+        let mut state = GameState::default();
+        state
+            .zone_templates
+            .extend(crate::assets::load_zones().unwrap());
+        state.resources.materials = 1.0;
+        state.add_zone("shack").unwrap();
+        state.zones[0].begin_construction();
+
+        advance_construction(&mut state, 60.0);
+        assert_eq!(work_done(&state, 0), 0.0);
+        assert_eq!(state.resources.materials, 1.0);
+
+        state.resources.materials = 3.0;
+        advance_construction(&mut state, 1.0);
+        assert_eq!(state.resources.materials, 0.0);
+        assert!(work_done(&state, 0) > 0.0);
+    }
+}
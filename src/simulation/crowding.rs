@@ -0,0 +1,57 @@
+//! Crowding - a market or park with too many villagers around it does them less good
+
+use super::agents::Agent;
+use macroquad::prelude::Vec2;
+
+/// Distance (world pixels) from an amenity's centre that counts as being there
+pub const CROWD_RADIUS: f32 = 48.0;
+
+/// More villagers than this around one amenity makes it crowded
+const CROWD_LIMIT: usize = 6;
+
+/// Share of the usual spirit boost a crowded market or park still gives
+pub const CROWDED_BENEFIT: f32 = 0.5;
+
+/// Spirit a loner loses on arriving somewhere crowded (before they wander off)
+pub const LONER_CROWD_PENALTY: f32 = 0.05;
+
+/// Villagers within the crowd radius of a spot
+pub fn crowd_at(agents: &[Agent], pos: Vec2) -> usize {
+    agents
+        .iter()
+        .filter(|a| a.pos.distance(pos) < CROWD_RADIUS)
+        .count()
+}
+
+/// Whether a spot has more villagers than it comfortably holds
+pub fn is_crowded(agents: &[Agent], pos: Vec2) -> bool {
+    crowd_at(agents, pos) > CROWD_LIMIT
+}
+
+/// The amenities (from `spots`) that are crowded right now
+pub fn crowded_spots(agents: &[Agent], spots: &[Vec2]) -> Vec<Vec2> {
+    spots
+        .iter()
+        .copied()
+        .filter(|&pos| is_crowded(agents, pos))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::simulation::rng::SimRng;
+    use macroquad::prelude::vec2;
+
+    #[test]
+    fn test_only_a_packed_spot_is_crowded() {
+        let mut rng = SimRng::default();
+        let market = vec2(100.0, 100.0);
+        let park = vec2(600.0, 600.0);
+        let agents: Vec<Agent> = (0..CROWD_LIMIT as u64 + 1)
+            .map(|id| Agent::new(id, market, &mut rng))
+            .collect();
+
+        assert_eq!(crowded_spots(&agents, &[market, park]), vec![market]);
+    }
+}
@@ -0,0 +1,150 @@
+//! Departures - villagers who stay miserable for days give up on the town and walk away
+
+use super::agents::{Agent, AgentState, TOWN_EXTENT};
+use super::reactions::{self, TownEvent};
+use crate::data::GameState;
+use crate::narrative::{ChronicleEventType, LogCategory};
+use macroquad::prelude::{vec2, Vec2};
+
+/// Spirit below which a villager counts as miserable
+const MISERY_SPIRIT: f32 = 0.2;
+
+/// Game hours of unbroken misery before an ordinary villager leaves
+const PATIENCE_HOURS: f32 = 72.0;
+
+/// Villagers with deeds to their name hold on this much longer
+const NOTABLE_PATIENCE: f32 = 2.0;
+
+/// Heroes already in the Hall of Heroes hold on this much longer still
+const IMMORTALIZED_PATIENCE: f32 = 4.0;
+
+/// How close to the edge counts as gone
+const EDGE_REACHED: f32 = 8.0;
+
+/// Game hours of misery this villager puts up with before leaving
+pub fn patience(agent: &Agent) -> f32 {
+    if agent.immortalized {
+        PATIENCE_HOURS * IMMORTALIZED_PATIENCE
+    } else if agent.feats.is_notable() {
+        PATIENCE_HOURS * NOTABLE_PATIENCE
+    } else {
+        PATIENCE_HOURS
+    }
+}
+
+/// Nearest point on the edge of town to walk out through
+fn nearest_exit(pos: Vec2) -> Vec2 {
+    let left = pos.x;
+    let right = TOWN_EXTENT - pos.x;
+    let top = pos.y;
+    let bottom = TOWN_EXTENT - pos.y;
+    let nearest = left.min(right).min(top).min(bottom);
+    if nearest == left {
+        vec2(0.0, pos.y)
+    } else if nearest == right {
+        vec2(TOWN_EXTENT, pos.y)
+    } else if nearest == top {
+        vec2(pos.x, 0.0)
+    } else {
+        vec2(pos.x, TOWN_EXTENT)
+    }
+}
+
+/// Count up each villager's misery, send those who have had enough walking,
+/// and see off anyone who has reached the edge of town
+pub fn update(state: &mut GameState, hours: f32) {
+    let mut leaving = Vec::new();
+    for agent in &mut state.agents {
+        if matches!(agent.state, AgentState::Leaving { .. }) {
+            continue;
+        }
+        if agent.spirit < MISERY_SPIRIT {
+            agent.miserable_hours += hours;
+        } else {
+            agent.miserable_hours = 0.0;
+        }
+        if agent.miserable_hours >= patience(agent) {
+            agent.state = AgentState::Leaving {
+                target: nearest_exit(agent.pos),
+            };
+            agent.workplace = None;
+            leaving.push(agent.name.clone());
+        }
+    }
+    for name in leaving {
+        state.log.add(
+            state.game_time_hours,
+            format!("{} has had enough and is leaving town.", name),
+            LogCategory::Event,
+        );
+    }
+
+    let gone: Vec<u64> = state
+        .agents
+        .iter()
+        .filter(|agent| match agent.state {
+            AgentState::Leaving { target } => agent.pos.distance(target) < EDGE_REACHED,
+            _ => false,
+        })
+        .map(|agent| agent.id)
+        .collect();
+    for id in gone {
+        let name = match state.agents.iter().position(|a| a.id == id) {
+            Some(index) => state.agents.remove(index).name,
+            None => continue,
+        };
+        state.population.remove(1.0);
+        state.stats.agents_left += 1;
+        state.town_chronicle.record(
+            state.game_time_hours,
+            ChronicleEventType::VillagerLost {
+                name: name.clone(),
+                reason: "Seeking happiness elsewhere".to_string(),
+            },
+        );
+        state.log.add(
+            state.game_time_hours,
+            format!("{} has left to seek happiness elsewhere.", name),
+            LogCategory::Event,
+        );
+        reactions::broadcast(&mut state.agents, TownEvent::VillagerLost);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::simulation::rng::SimRng;
+
+    #[test]
+    fn test_miserable_villager_walks_out_and_heroes_hold_on() {
+        let mut state = GameState::default();
+        state.population.add(2.0);
+        let mut rng = SimRng::new(1);
+        let mut villager = Agent::new(1, vec2(100.0, 800.0), &mut rng);
+        villager.spirit = 0.1;
+        let mut hero = Agent::new(2, vec2(800.0, 800.0), &mut rng);
+        hero.spirit = 0.1;
+        hero.immortalized = true;
+        state.agents.extend([villager, hero]);
+
+        update(&mut state, PATIENCE_HOURS);
+        assert_eq!(
+            state.agents[0].state,
+            AgentState::Leaving {
+                target: vec2(0.0, 800.0)
+            }
+        );
+        assert!(!matches!(state.agents[1].state, AgentState::Leaving { .. }));
+
+        // Reaching the edge takes them off the books
+        state.agents[0].pos = vec2(0.0, 800.0);
+        update(&mut state, 1.0);
+        assert_eq!(state.agents.len(), 1);
+        assert_eq!(state.agents[0].id, 2);
+        assert_eq!(state.population.value(), 1.0);
+        // Leaving isn't dying
+        assert_eq!(state.stats.agents_left, 1);
+        assert_eq!(state.stats.agents_died, 0);
+    }
+}
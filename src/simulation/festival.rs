@@ -0,0 +1,115 @@
+//! Seasonal festival - a once-a-year celebration that lifts the whole town
+
+use macroquad::prelude::*;
+use macroquad_toolkit::rng;
+use serde::{Deserialize, Serialize};
+
+use crate::data::GameState;
+use crate::narrative::{ChronicleEventType, LogCategory};
+use crate::simulation::seasons::Season;
+use crate::ui::particles::ParticleType;
+
+/// Materials spent on food, decorations and music
+pub const FESTIVAL_COST: f32 = 10.0;
+
+/// How long the festive mood lasts (game hours)
+const FESTIVAL_DURATION_HOURS: f32 = 72.0;
+
+/// Attractiveness bonus while the festival is on
+const FESTIVAL_ATTRACTIVENESS: f32 = 0.5;
+
+/// Spirit every villager is lifted to (at least)
+const FESTIVAL_SPIRIT: f32 = 0.95;
+
+/// Festival bookkeeping (saved)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct FestivalState {
+    /// Year of the last festival held
+    pub last_year: Option<u32>,
+    /// Game time (hours) when the current festival ends
+    pub active_until: f32,
+}
+
+impl FestivalState {
+    /// Whether a festival is running right now
+    pub fn is_active(&self, game_time_hours: f32) -> bool {
+        game_time_hours < self.active_until
+    }
+
+    /// Whether this year's festival has not been held yet
+    pub fn available(&self, year: u32) -> bool {
+        self.last_year.is_none_or(|last| last < year)
+    }
+
+    /// Attractiveness bonus at the given time
+    pub fn attractiveness_bonus(&self, game_time_hours: f32) -> f32 {
+        if self.is_active(game_time_hours) {
+            FESTIVAL_ATTRACTIVENESS
+        } else {
+            0.0
+        }
+    }
+}
+
+/// Called on a season transition: spring opens with the festival if the town can afford it
+pub fn on_season_changed(state: &mut GameState, season: Season) {
+    if season == Season::Spring {
+        hold_festival(state);
+    }
+}
+
+/// Hold this year's festival. Returns false if it was already held or can't be afforded.
+pub fn hold_festival(state: &mut GameState) -> bool {
+    let year = state.season_state.year();
+    if !state.festival.available(year) {
+        return false;
+    }
+
+    if state.resources.materials < FESTIVAL_COST {
+        state.log.add(
+            state.game_time_hours,
+            format!(
+                "Not enough materials for a festival! Need {:.0}",
+                FESTIVAL_COST
+            ),
+            LogCategory::System,
+        );
+        return false;
+    }
+
+    state.resources.materials -= FESTIVAL_COST;
+    state.festival.last_year = Some(year);
+    state.festival.active_until = state.game_time_hours + FESTIVAL_DURATION_HOURS;
+
+    for agent in &mut state.agents {
+        agent.spirit = agent.spirit.max(FESTIVAL_SPIRIT);
+
+        // A few hearts drifting up from each villager
+        for _ in 0..3 {
+            state.particle_system.spawn(
+                agent.pos + vec2(rng::gen_range(-8.0, 8.0), rng::gen_range(-8.0, 0.0)),
+                vec2(rng::gen_range(-10.0, 10.0), rng::gen_range(-30.0, -15.0)),
+                rng::gen_range(2.0, 4.0),
+                14.0,
+                Color::new(1.0, 0.4, 0.6, 1.0),
+                ParticleType::Heart,
+            );
+        }
+    }
+
+    let description = format!(
+        "The year {} festival filled the streets with music",
+        year + 1
+    );
+    state.log.add(
+        state.game_time_hours,
+        format!("Festival! {} (-{:.0} Mat)", description, FESTIVAL_COST),
+        LogCategory::Milestone,
+    );
+    state.town_chronicle.record(
+        state.game_time_hours,
+        ChronicleEventType::Special { description },
+    );
+
+    true
+}
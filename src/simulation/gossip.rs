@@ -0,0 +1,156 @@
+//! Gossip - villagers who see something remember it, and gossips pass it on.
+//! Villagers who socialize together also become friends.
+
+use super::agents::AgentState;
+use super::traits::Trait;
+use crate::data::GameState;
+use macroquad::prelude::Vec2;
+use serde::{Deserialize, Serialize};
+
+/// Most pieces of news a villager keeps in mind (oldest are forgotten)
+pub const MAX_RUMORS: usize = 4;
+
+/// Villagers this close to an event see it for themselves
+const WITNESS_RADIUS: f32 = 160.0;
+
+/// A gossip reaches villagers socializing this close by
+const TALK_RADIUS: f32 = 60.0;
+
+/// A piece of news travelling through town
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Rumor {
+    pub text: String,
+    /// Spirit change on hearing it (before traits)
+    pub impact: f32,
+    /// Game hour it happened, so repeats of the same news stay distinct
+    pub hour: f32,
+}
+
+impl Rumor {
+    pub fn new(text: impl Into<String>, impact: f32, hour: f32) -> Self {
+        Self {
+            text: text.into(),
+            impact,
+            hour,
+        }
+    }
+
+    /// News of an event the whole town already reacted to (`reactions::broadcast`):
+    /// remembered and passed on, but not felt a second time
+    pub fn already_felt(text: impl Into<String>, hour: f32) -> Self {
+        Self::new(text, 0.0, hour)
+    }
+}
+
+/// Everyone near `pos` sees what happened
+pub fn witness(state: &mut GameState, pos: Vec2, rumor: Rumor) {
+    for agent in &mut state.agents {
+        if agent.pos.distance(pos) <= WITNESS_RADIUS {
+            agent.remember(&rumor);
+        }
+    }
+}
+
+/// Gossips who are socializing tell the villagers around them everything they know
+pub fn spread(state: &mut GameState) {
+    let tellers: Vec<(u64, Vec2, Vec<Rumor>)> = state
+        .agents
+        .iter()
+        .filter(|a| a.traits.contains(&Trait::Gossip) && !a.rumors.is_empty())
+        .filter(|a| matches!(a.state, AgentState::Socializing { .. }))
+        .map(|a| (a.id, a.pos, a.rumors.clone()))
+        .collect();
+
+    for (id, pos, rumors) in tellers {
+        for listener in &mut state.agents {
+            if listener.id == id
+                || !matches!(listener.state, AgentState::Socializing { .. })
+                || listener.pos.distance(pos) > TALK_RADIUS
+            {
+                continue;
+            }
+            for rumor in &rumors {
+                listener.remember(rumor);
+            }
+        }
+    }
+}
+
+/// Villagers socializing within earshot of each other become friends
+pub fn befriend(state: &mut GameState) {
+    let socializing: Vec<(u64, Vec2)> = state
+        .agents
+        .iter()
+        .filter(|a| matches!(a.state, AgentState::Socializing { .. }))
+        .map(|a| (a.id, a.pos))
+        .collect();
+
+    for agent in &mut state.agents {
+        if !matches!(agent.state, AgentState::Socializing { .. }) {
+            continue;
+        }
+        for &(id, pos) in &socializing {
+            if id != agent.id && agent.pos.distance(pos) <= TALK_RADIUS {
+                agent.feats.befriend(id);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::simulation::agents::Agent;
+    use crate::simulation::rng::SimRng;
+    use macroquad::prelude::vec2;
+
+    #[test]
+    fn test_gossip_tells_socializing_neighbours() {
+        let mut rng = SimRng::default();
+        let socializing = AgentState::Socializing {
+            target: vec2(0.0, 0.0),
+            duration: 3.0,
+        };
+        let mut gossip = Agent::new(1, vec2(0.0, 0.0), &mut rng);
+        gossip.traits = vec![Trait::Gossip];
+        gossip.state = socializing;
+        gossip.remember(&Rumor::new("The mill reopened", 0.05, 10.0));
+        let mut listener = Agent::new(2, vec2(20.0, 0.0), &mut rng);
+        listener.traits.clear();
+        listener.state = socializing;
+        listener.spirit = 0.5;
+
+        let mut state = GameState::default();
+        state.agents.extend([gossip, listener]);
+        spread(&mut state);
+        assert_eq!(state.agents[1].rumors.len(), 1);
+        assert!(state.agents[1].spirit > 0.5);
+
+        // Already known news isn't felt twice
+        let spirit = state.agents[1].spirit;
+        spread(&mut state);
+        assert_eq!(state.agents[1].spirit, spirit);
+    }
+
+    #[test]
+    fn test_villagers_socializing_together_become_friends_once() {
+        let mut rng = SimRng::default();
+        let socializing = AgentState::Socializing {
+            target: vec2(0.0, 0.0),
+            duration: 3.0,
+        };
+        let mut state = GameState::default();
+        for (id, x) in [(1, 0.0), (2, 20.0), (3, 500.0)] {
+            let mut agent = Agent::new(id, vec2(x, 0.0), &mut rng);
+            agent.state = socializing;
+            state.agents.push(agent);
+        }
+
+        befriend(&mut state);
+        befriend(&mut state);
+        assert_eq!(state.agents[0].feats.friends, vec![2]);
+        assert_eq!(state.agents[1].feats.friends, vec![1]);
+        // Too far away to talk to
+        assert!(state.agents[2].feats.friends.is_empty());
+    }
+}
@@ -0,0 +1,136 @@
+//! Housing - villagers live in the town's homes, and newcomers only settle
+//! where there's a free bed
+
+use super::agents::Agent;
+use crate::data::{GameState, ZoneCategory};
+use crate::narrative::LogCategory;
+use crate::zones::ZoneId;
+use macroquad::prelude::Vec2;
+
+/// Beds at the campsite, so a town with no homes restored isn't soft-locked
+pub const CAMPSITE_CAPACITY: f32 = 2.0;
+
+/// Where the campsite stands (world pixels)
+const CAMPSITE: Vec2 = Vec2::new(650.0, 650.0);
+
+/// Homes taking residents: operating residential zones, their centres and
+/// beds (fewer while run down, matching `calculate_housing_capacity`)
+pub fn homes(state: &GameState) -> Vec<(ZoneId, Vec2, usize)> {
+    state
+        .zones
+        .iter()
+        .filter(|z| z.is_operating())
+        .filter_map(|z| {
+            let template = state.get_template(&z.template_id)?;
+            if template.category != ZoneCategory::Residential {
+                return None;
+            }
+            let beds = (template.population.capacity * z.condition).floor() as usize;
+            let rect = state.zone_rect(z)?;
+            Some((z.id, rect.world_center(), beds))
+        })
+        .collect()
+}
+
+/// Villagers living in a home (None = the campsite)
+pub fn residents(agents: &[Agent], home: Option<ZoneId>) -> usize {
+    agents.iter().filter(|a| a.home == home).count()
+}
+
+/// Beds at the campsite, counting housing from techs (which isn't in any home)
+pub fn campsite_beds(state: &GameState) -> usize {
+    let tech = crate::data::TechBonuses::from_tree(&state.tech_tree).housing_flat;
+    (CAMPSITE_CAPACITY + tech).floor() as usize
+}
+
+/// The emptiest home with a free bed, or the campsite once every home is
+/// full. None if there's nowhere left to sleep.
+pub fn free_home(state: &GameState) -> Option<(Option<ZoneId>, Vec2)> {
+    let home = homes(state)
+        .into_iter()
+        .map(|(id, pos, beds)| (id, pos, beds, residents(&state.agents, Some(id))))
+        .filter(|(_, _, beds, living)| living < beds)
+        .min_by_key(|(_, _, _, living)| *living)
+        .map(|(id, pos, _, _)| (Some(id), pos));
+    if home.is_some() {
+        return home;
+    }
+    if residents(&state.agents, None) < campsite_beds(state) {
+        Some((None, CAMPSITE))
+    } else {
+        None
+    }
+}
+
+/// Note that villagers are waiting for homes (once per shortage)
+pub fn report_no_housing(state: &mut GameState) {
+    if state.housing_full {
+        return;
+    }
+    state.housing_full = true;
+    state.log.add(
+        state.game_time_hours,
+        "No housing for newcomers - restore or build more homes.".to_string(),
+        LogCategory::System,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::simulation::rng::SimRng;
+
+    #[test]
+    fn test_newcomers_fill_homes_then_the_campsite() {
+        let mut state = GameState::default();
+        state
+            .zone_templates
+            .extend(crate::assets::load_zones().unwrap());
+        state.setup_starting_town();
+        for zone in &mut state.zones {
+            zone.restore(1.0);
+        }
+        let beds: usize = homes(&state).iter().map(|(_, _, beds)| beds).sum();
+        assert!(beds > 0);
+
+        let mut rng = SimRng::new(1);
+        let camping = CAMPSITE_CAPACITY as usize;
+        for id in 0..(beds + camping) as u64 {
+            let (home, pos) = free_home(&state).unwrap();
+            state
+                .agents
+                .push(Agent::new(id, pos, &mut rng).with_home(home, pos));
+        }
+        // Every bed in town is taken before anyone camps
+        assert_eq!(residents(&state.agents, None), camping);
+        for (id, _, beds) in homes(&state) {
+            assert_eq!(residents(&state.agents, Some(id)), beds);
+        }
+        assert_eq!(free_home(&state), None);
+    }
+
+    #[test]
+    fn test_growth_capacity_is_the_beds_villagers_get() {
+        let mut state = GameState::default();
+        state
+            .zone_templates
+            .extend(crate::assets::load_zones().unwrap());
+        state.setup_starting_town();
+        for zone in &mut state.zones {
+            zone.restore(1.0);
+            zone.condition = 0.95;
+        }
+        // A mothballed home houses nobody, and a worn one only whole beds
+        let home = homes(&state)[0].0;
+        state
+            .zones
+            .iter_mut()
+            .find(|z| z.id == home)
+            .unwrap()
+            .disabled = true;
+
+        let beds: usize = homes(&state).iter().map(|(_, _, beds)| beds).sum();
+        assert!(homes(&state).iter().all(|(id, _, _)| *id != home));
+        assert_eq!(state.calculate_housing_capacity(), beds as f32);
+    }
+}
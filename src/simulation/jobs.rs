@@ -0,0 +1,114 @@
+//! Job rebalancing - laborers drift into the jobs the town is short of
+
+use super::agents::{Agent, AgentState, Job};
+use crate::data::GameState;
+use crate::narrative::LogCategory;
+
+/// Game hours between job reviews (one reassignment per review keeps it gentle)
+const REVIEW_INTERVAL_HOURS: f32 = 6.0;
+
+/// Food in store per villager below which the town wants more farmers
+const FOOD_LOW_PER_VILLAGER: f32 = 2.0;
+
+/// Above this much food per villager, farmers drift back to labouring
+const FOOD_AMPLE_PER_VILLAGER: f32 = 6.0;
+
+/// Farmers each operating farm can use
+const FARMERS_PER_FARM: usize = 2;
+
+/// One builder per this many villagers while anything is being built
+const VILLAGERS_PER_BUILDER: usize = 4;
+
+/// Harvest bonus each farmer adds (see `farmer_multiplier`)
+const FARMER_HARVEST_BONUS: f32 = 0.25;
+
+/// Farmers beyond this many add nothing to the harvest
+const MAX_USEFUL_FARMERS: usize = 4;
+
+/// Harvest multiplier from the farmers at work
+pub fn farmer_multiplier(agents: &[Agent]) -> f32 {
+    let farmers = agents.iter().filter(|a| a.job == Job::Farmer).count();
+    1.0 + farmers.min(MAX_USEFUL_FARMERS) as f32 * FARMER_HARVEST_BONUS
+}
+
+/// Every few game hours, move one villager to the job the town needs most
+pub fn rebalance(state: &mut GameState) {
+    if state.game_time_hours < state.next_job_review {
+        return;
+    }
+    state.next_job_review = state.game_time_hours + REVIEW_INTERVAL_HOURS;
+
+    let villagers = state.agents.len();
+    let count = |job: Job| state.agents.iter().filter(|a| a.job == job).count();
+    let food = state.resources.grain + state.resources.flour;
+    let farms = state
+        .zones
+        .iter()
+        .filter(|z| z.is_operating())
+        .filter(|z| {
+            state
+                .get_template(&z.template_id)
+                .is_some_and(|t| t.food_output > 0.0)
+        })
+        .count();
+    let building = state.zones.iter().any(|z| z.is_under_construction());
+
+    let (from, to) = if food < FOOD_LOW_PER_VILLAGER * villagers as f32
+        && count(Job::Farmer) < farms * FARMERS_PER_FARM
+    {
+        (Job::Laborer, Job::Farmer)
+    } else if building && count(Job::Builder) * VILLAGERS_PER_BUILDER < villagers {
+        (Job::Laborer, Job::Builder)
+    } else if food > FOOD_AMPLE_PER_VILLAGER * villagers as f32 && count(Job::Farmer) > 0 {
+        (Job::Farmer, Job::Laborer)
+    } else {
+        return;
+    };
+
+    if let Some(name) = reassign(&mut state.agents, from, to) {
+        state.log.add(
+            state.game_time_hours,
+            format!("{} is now working as a {}.", name, to.name().to_lowercase()),
+            LogCategory::Population,
+        );
+    }
+}
+
+/// Move the best suited villager from one job to another, returning their name.
+/// Idle villagers go first, then the hardest workers; temporary hires are left alone.
+fn reassign(agents: &mut [Agent], from: Job, to: Job) -> Option<String> {
+    let agent = agents
+        .iter_mut()
+        .filter(|a| a.job == from && a.hired_until.is_none())
+        .max_by(|a, b| {
+            let idle = |agent: &Agent| agent.state == AgentState::Idle;
+            idle(a)
+                .cmp(&idle(b))
+                .then(a.work_speed().total_cmp(&b.work_speed()))
+        })?;
+    agent.job = to;
+    agent.workplace = None;
+    Some(agent.name.clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::simulation::rng::SimRng;
+    use crate::simulation::traits::Trait;
+    use macroquad::prelude::vec2;
+
+    #[test]
+    fn test_reassign_prefers_hard_workers() {
+        let mut rng = SimRng::default();
+        let mut lazy = Agent::new(1, vec2(0.0, 0.0), &mut rng);
+        lazy.traits = vec![Trait::Lazy];
+        let mut keen = Agent::new(2, vec2(0.0, 0.0), &mut rng);
+        keen.traits = vec![Trait::Hardworking];
+        let mut agents = vec![lazy, keen];
+
+        reassign(&mut agents, Job::Laborer, Job::Farmer);
+        assert_eq!(agents[0].job, Job::Laborer);
+        assert_eq!(agents[1].job, Job::Farmer);
+    }
+}
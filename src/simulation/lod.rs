@@ -0,0 +1,118 @@
+//! Agent level-of-detail scheduling
+//!
+//! With large populations, agents outside the camera view update every Nth tick
+//! (with a proportionally larger step) while on-screen agents update every tick.
+
+use macroquad::prelude::Rect;
+
+use super::agents::{Agent, WorldInfo};
+use super::rng::SimRng;
+use crate::data::Settings;
+
+/// Whether the agent at `index` should update on this tick.
+/// Off-screen agents are staggered by index so work is spread evenly across ticks.
+pub fn should_update(index: usize, tick: u64, on_screen: bool, interval: u32) -> bool {
+    on_screen || interval <= 1 || (tick + index as u64).is_multiple_of(interval as u64)
+}
+
+/// Update agents for one tick, returning how many were actually updated
+pub fn update_agents(
+    agents: &mut [Agent],
+    world: &WorldInfo,
+    delta: f32,
+    tick: u64,
+    view: Option<Rect>,
+    settings: &Settings,
+    rng: &mut SimRng,
+) -> usize {
+    let interval = if settings.lod_active(agents.len()) {
+        settings.agent_lod_interval
+    } else {
+        1
+    };
+
+    let mut updated = 0;
+    for (i, agent) in agents.iter_mut().enumerate() {
+        // Without a known view (e.g. offline catch-up), treat everyone as visible
+        let on_screen = view.is_none_or(|v| v.contains(agent.pos));
+        if !should_update(i, tick, on_screen, interval) {
+            continue;
+        }
+
+        let step = if on_screen {
+            delta
+        } else {
+            delta * interval as f32
+        };
+        agent.update(step, world, rng);
+        updated += 1;
+    }
+    updated
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use macroquad::prelude::vec2;
+
+    fn world() -> WorldInfo {
+        WorldInfo {
+            markets: Vec::new(),
+            workshops: Vec::new(),
+            parks: Vec::new(),
+            construction_sites: Vec::new(),
+            workplaces: Vec::new(),
+            crowded: Vec::new(),
+            game_hour: 12.0,
+            season: Default::default(),
+            speed_multiplier: 1.0,
+            amenity_version: 0,
+            walkable: Default::default(),
+        }
+    }
+
+    fn spawn(count: usize) -> Vec<Agent> {
+        let mut rng = SimRng::new(1);
+        (0..count)
+            .map(|i| {
+                let x = (i % 50) as f32 * 32.0;
+                let y = (i / 50) as f32 * 160.0;
+                Agent::new(i as u64, vec2(x, y), &mut rng)
+            })
+            .collect()
+    }
+
+    /// Agent updates over `ticks` ticks (the cost LOD saves)
+    fn run(count: usize, ticks: u64) -> usize {
+        let mut agents = spawn(count);
+        let world = world();
+        let settings = Settings::default();
+        let view = Some(Rect::new(0.0, 0.0, 640.0, 360.0));
+        let mut rng = SimRng::new(2);
+
+        let mut updates = 0;
+        for tick in 0..ticks {
+            updates += update_agents(&mut agents, &world, 0.016, tick, view, &settings, &mut rng);
+        }
+        updates
+    }
+
+    #[test]
+    fn test_off_screen_agents_are_staggered() {
+        assert!(should_update(3, 7, true, 4));
+        let hits = (0..8).filter(|t| should_update(3, *t, false, 4)).count();
+        assert_eq!(hits, 2);
+    }
+
+    #[test]
+    fn test_lod_benchmark_50_vs_500() {
+        let ticks = 100;
+        let small_updates = run(50, ticks);
+        let large_updates = run(500, ticks);
+
+        // Below the threshold everyone updates every tick
+        assert_eq!(small_updates, 50 * ticks as usize);
+        // With LOD, 10x the agents costs well under 10x the updates
+        assert!(large_updates < 500 * ticks as usize / 2);
+    }
+}
@@ -1,4 +1,15 @@
+use crate::ui::map_renderer::TILE_SIZE;
+use crate::zones::ZoneId;
+use macroquad::prelude::{vec2, Vec2};
 use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+
+/// Materials to lay a bridge across one tile of water
+pub const BRIDGE_COST: f32 = 8.0;
+
+/// The stream down the east side of town (first column and width in tiles)
+const RIVER_X: usize = 46;
+const RIVER_W: usize = 2;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum TileType {
@@ -8,13 +19,22 @@ pub enum TileType {
     Floor,
     Wall,
     Ruins,
+    /// Planks over water, so villagers can cross
+    Bridge,
+}
+
+impl TileType {
+    /// Whether villagers can walk across this tile (water needs a bridge)
+    pub fn is_passable(self) -> bool {
+        self != TileType::Water
+    }
 }
 
 #[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 pub struct Tile {
     pub kind: TileType,
     /// ID of the zone this tile belongs to, if any
-    pub zone_id: Option<usize>,
+    pub zone_id: Option<ZoneId>,
     /// Helps with rendering variations/connections
     pub variant: u8,
 }
@@ -70,7 +90,7 @@ impl WorldMap {
         w: usize,
         h: usize,
         kind: TileType,
-        zone_id: Option<usize>,
+        zone_id: Option<ZoneId>,
     ) {
         for dy in 0..h {
             for dx in 0..w {
@@ -81,6 +101,111 @@ impl WorldMap {
             }
         }
     }
+
+    /// Why a bridge can't be laid on this tile, or None if it can
+    pub fn bridge_problem(&self, x: usize, y: usize) -> Option<&'static str> {
+        match self.get_tile(x, y).map(|tile| tile.kind) {
+            None => Some("it's off the map"),
+            Some(TileType::Bridge) => Some("there's a bridge there already"),
+            Some(TileType::Water) => None,
+            Some(_) => Some("there's no water there"),
+        }
+    }
+
+    /// Which tiles villagers can walk on, for agent movement
+    pub fn walkable(&self) -> Walkable {
+        Walkable {
+            width: self.width,
+            blocked: self.tiles.iter().map(|t| !t.kind.is_passable()).collect(),
+        }
+    }
+}
+
+/// Impassable tiles copied out of the map (empty = walk anywhere)
+#[derive(Debug, Clone, Default)]
+pub struct Walkable {
+    width: usize,
+    blocked: Vec<bool>,
+}
+
+impl Walkable {
+    /// Whether a villager can stand at this world position (off the map is open ground)
+    pub fn at(&self, pos: Vec2) -> bool {
+        if pos.x < 0.0 || pos.y < 0.0 {
+            return true;
+        }
+        let x = (pos.x / TILE_SIZE) as usize;
+        let y = (pos.y / TILE_SIZE) as usize;
+        if x >= self.width {
+            return true;
+        }
+        !self
+            .blocked
+            .get(y * self.width + x)
+            .copied()
+            .unwrap_or(false)
+    }
+
+    /// Whether the straight line between two points stays out of the water
+    /// (checked every half tile)
+    pub fn clear_line(&self, from: Vec2, to: Vec2) -> bool {
+        let samples = (from.distance(to) / (TILE_SIZE * 0.5)).ceil() as usize;
+        (1..=samples).all(|i| self.at(from.lerp(to, i as f32 / samples as f32)))
+    }
+
+    /// Where to head next on the way from `from` to `to` without wading: `to`
+    /// itself once in its tile, otherwise the centre of the first tile on the
+    /// shortest walkable path (so routes find their way to a bridge). None if
+    /// water cuts `to` off.
+    pub fn next_waypoint(&self, from: Vec2, to: Vec2) -> Option<Vec2> {
+        if self.width == 0 {
+            return Some(to);
+        }
+        let height = self.blocked.len() / self.width;
+        let tile = |pos: Vec2| {
+            let x = ((pos.x / TILE_SIZE).max(0.0) as usize).min(self.width - 1);
+            let y = ((pos.y / TILE_SIZE).max(0.0) as usize).min(height - 1);
+            y * self.width + x
+        };
+        let (start, goal) = (tile(from), tile(to));
+        if start == goal {
+            return Some(to);
+        }
+        if self.blocked[goal] {
+            return None;
+        }
+
+        // Breadth-first out from the goal, so each tile points at its next step
+        // toward it (the start may be in the water; anyone there can walk out)
+        let mut toward_goal = vec![usize::MAX; self.blocked.len()];
+        toward_goal[goal] = goal;
+        let mut queue = VecDeque::from([goal]);
+        while let Some(current) = queue.pop_front() {
+            let (x, y) = (current % self.width, current / self.width);
+            let neighbours = [
+                (x > 0).then(|| current - 1),
+                (x + 1 < self.width).then(|| current + 1),
+                (y > 0).then(|| current - self.width),
+                (y + 1 < height).then(|| current + self.width),
+            ];
+            for next in neighbours.into_iter().flatten() {
+                if toward_goal[next] != usize::MAX || (self.blocked[next] && next != start) {
+                    continue;
+                }
+                toward_goal[next] = current;
+                if next == start {
+                    return Some(if current == goal {
+                        to
+                    } else {
+                        let (x, y) = (current % self.width, current / self.width);
+                        vec2((x as f32 + 0.5) * TILE_SIZE, (y as f32 + 0.5) * TILE_SIZE)
+                    });
+                }
+                queue.push_back(next);
+            }
+        }
+        None
+    }
 }
 
 impl Default for WorldMap {
@@ -92,7 +217,56 @@ impl Default for WorldMap {
         map.set_rect(0, 0, 50, 50, TileType::Grass, None);
         map.set_rect(20, 0, 10, 50, TileType::Dirt, None); // Main road
         map.set_rect(0, 20, 50, 10, TileType::Dirt, None); // Cross road
+        map.set_rect(RIVER_X, 0, RIVER_W, 50, TileType::Water, None); // Stream
+        map.set_rect(RIVER_X, 20, RIVER_W, 10, TileType::Bridge, None); // Where the road crosses
 
         map
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_water_blocks_villagers_until_bridged() {
+        let mut map = WorldMap::default();
+        let in_stream = Vec2::new((RIVER_X as f32 + 0.5) * TILE_SIZE, 5.0 * TILE_SIZE);
+        assert!(!map.walkable().at(in_stream));
+        assert!(map.walkable().at(Vec2::new(TILE_SIZE, TILE_SIZE)));
+        assert!(map.bridge_problem(0, 0).is_some());
+
+        assert_eq!(map.bridge_problem(RIVER_X, 5), None);
+        map.set_rect(RIVER_X, 5, 1, 1, TileType::Bridge, None);
+        assert!(map.walkable().at(in_stream));
+        assert!(map.bridge_problem(RIVER_X, 5).is_some());
+    }
+
+    #[test]
+    fn test_routes_lead_to_the_bridge() {
+        let mut map = WorldMap::default();
+        let tile_centre = |x: usize, y: usize| {
+            Vec2::new((x as f32 + 0.5) * TILE_SIZE, (y as f32 + 0.5) * TILE_SIZE)
+        };
+        let west = tile_centre(RIVER_X - 1, 5);
+        let east = tile_centre(RIVER_X + RIVER_W, 5);
+
+        // The bridge is south (rows 20-29), so the way across starts south along the bank
+        let walkable = map.walkable();
+        assert_eq!(
+            walkable.next_waypoint(west, east),
+            Some(tile_centre(RIVER_X - 1, 6))
+        );
+        // Open ground is a straight line
+        assert_eq!(
+            walkable.next_waypoint(west, tile_centre(RIVER_X - 3, 5)),
+            Some(tile_centre(RIVER_X - 2, 5))
+        );
+        // Nobody can walk to a spot in the water
+        assert_eq!(walkable.next_waypoint(west, tile_centre(RIVER_X, 5)), None);
+
+        // With the bridges gone the far bank is cut off
+        map.set_rect(RIVER_X, 20, RIVER_W, 10, TileType::Water, None);
+        assert_eq!(map.walkable().next_waypoint(west, east), None);
+    }
+}
@@ -0,0 +1,139 @@
+//! Migration waves - a burst of newcomers when the town becomes notably more attractive
+
+use crate::data::GameState;
+use crate::narrative::{ChronicleEventType, LogCategory, Wonder};
+use crate::simulation::agents::{Agent, AgentState, Job};
+use crate::simulation::housing;
+use macroquad::prelude::Vec2;
+use serde::{Deserialize, Serialize};
+
+/// Attractiveness levels that each send one wave the first time the town reaches them
+const THRESHOLDS: [f32; 4] = [1.0, 2.0, 3.5, 5.0];
+
+/// Newcomers in a wave from an attractiveness threshold
+const WAVE_SIZE: u32 = 4;
+
+/// Newcomers drawn by a finished wonder (the Colosseum draws twice as many)
+const WONDER_WAVE_SIZE: u32 = 5;
+
+/// How far from their new home newcomers appear (world pixels), so they visibly walk in
+const WALK_IN_DISTANCE: f32 = 250.0;
+
+/// Waves already sent and newcomers on their way (saved with the town)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Migration {
+    /// How many of the thresholds this town has reached
+    reached: usize,
+    /// Newcomers who will arrive on the next tick
+    pending: u32,
+}
+
+/// A finished wonder draws a wave of newcomers to the active town
+pub fn on_wonder_completed(state: &mut GameState, wonder: Wonder) {
+    let size = if wonder == Wonder::ColosseumOfHeroes {
+        WONDER_WAVE_SIZE * 2
+    } else {
+        WONDER_WAVE_SIZE
+    };
+    state.migration.pending += size;
+}
+
+/// Queue waves for newly reached thresholds and let pending newcomers in.
+/// Only as many as there are free homes stay; the rest move on.
+pub fn update(state: &mut GameState, housing_capacity: f32) {
+    let attractiveness = state.resources.attractiveness;
+    while THRESHOLDS
+        .get(state.migration.reached)
+        .is_some_and(|&level| attractiveness >= level)
+    {
+        state.migration.reached += 1;
+        state.migration.pending += WAVE_SIZE;
+    }
+
+    let arriving = std::mem::take(&mut state.migration.pending);
+    if arriving == 0 {
+        return;
+    }
+    let room = (housing_capacity - state.population.value())
+        .floor()
+        .max(0.0) as u32;
+    // Past the agent cap newcomers settle without being drawn; otherwise only
+    // those who find a bed stay
+    let mut settled = 0;
+    for _ in 0..arriving.min(room) {
+        if state.agents.len() < state.settings.agent_cap && !spawn_newcomer(state) {
+            break;
+        }
+        settled += 1;
+    }
+    let moved_on = arriving - settled;
+    state.population.add(settled as f32);
+
+    let message = match (settled, moved_on) {
+        (0, _) => format!(
+            "{} travellers came looking for a home, but there was no room.",
+            moved_on
+        ),
+        (_, 0) => format!("A wave of {} newcomers arrives to settle!", settled),
+        _ => format!(
+            "A wave of {} newcomers arrives to settle! {} more moved on for lack of housing.",
+            settled, moved_on
+        ),
+    };
+    state
+        .log
+        .add(state.game_time_hours, message, LogCategory::Event);
+}
+
+/// A newcomer appears out past the houses and walks to a free home (and into
+/// the chronicle). Returns false if every bed is taken.
+fn spawn_newcomer(state: &mut GameState) -> bool {
+    let (home_zone, home) = match housing::free_home(state) {
+        Some(home) => home,
+        None => return false,
+    };
+    let id = state.rng.next_u64();
+    let angle = state.rng.range(0.0, std::f32::consts::TAU);
+    let start = home + Vec2::from_angle(angle) * WALK_IN_DISTANCE;
+
+    let mut agent = Agent::new(id, start, &mut state.rng)
+        .with_job(Job::Laborer)
+        .with_home(home_zone, home);
+    agent.state = AgentState::Wandering { target: home };
+    state.town_chronicle.record(
+        state.game_time_hours,
+        ChronicleEventType::VillagerArrived {
+            name: agent.name.clone(),
+        },
+    );
+    state.agents.push(agent);
+    state.stats.agents_born += 1;
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wave_only_settles_as_many_as_there_are_homes() {
+        let mut state = GameState::default();
+        state.resources.attractiveness = THRESHOLDS[0];
+
+        update(&mut state, 2.0);
+        assert_eq!(state.population.value(), 2.0);
+        assert_eq!(state.agents.len(), 2);
+        // Each newcomer is named in the chronicle
+        assert_eq!(state.town_chronicle.recent_population_changes(5).len(), 2);
+
+        // The same threshold doesn't send a second wave
+        update(&mut state, 10.0);
+        assert_eq!(state.population.value(), 2.0);
+
+        // Newcomers who find no bed don't count towards the town
+        on_wonder_completed(&mut state, Wonder::ColosseumOfHeroes);
+        update(&mut state, 10.0);
+        assert_eq!(state.population.value(), 2.0);
+        assert_eq!(state.agents.len(), 2);
+    }
+}
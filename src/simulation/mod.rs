@@ -1,10 +1,38 @@
 //! Simulation module - The idle loop and time progression
 
-pub use tick::{simulate_ticks, TickTimer};
+pub use tick::{simulate_ticks, TickTimer, TURBO_TIME_SCALE};
 
 pub mod agents;
+pub mod auto_restore;
 pub mod camera;
+pub mod collapse;
+pub mod construction;
+pub mod crowding;
+pub mod departures;
+pub mod festival;
+pub mod gossip;
+pub mod housing;
+pub mod jobs;
+pub mod lod;
 pub mod map;
+pub mod migration;
+pub mod neglect;
+pub mod offline;
+pub mod production;
+pub mod reactions;
+pub mod resource_popups;
+pub mod rng;
 pub mod seasons;
+pub mod starvation;
+pub mod stockpiles;
+pub mod thoughts;
 pub mod tick;
+pub mod trade_offers;
 pub mod traits;
+pub mod unrest;
+pub mod walking;
+pub mod warnings;
+pub mod wonder_funding;
+pub mod workplaces;
+pub mod yearly;
+pub mod zone_history;
@@ -0,0 +1,112 @@
+//! Neglect - understaffed buildings slowly lose condition and need repair
+
+use super::{housing, workplaces};
+use crate::data::{GameState, ZoneTemplate};
+use crate::narrative::LogCategory;
+use crate::zones::Zone;
+
+/// Workers (or, for a home, residents) a zone needs to be fully staffed
+const STAFF_PER_ZONE: f32 = 1.5;
+
+/// Neglect never takes a building below this condition
+const NEGLECT_FLOOR: f32 = 0.3;
+
+/// Crossing below this condition logs a repair reminder
+const DISREPAIR_WARNING: f32 = 0.6;
+
+/// Share of a zone's staff that is there (0-1): its own workers, or a home's residents
+pub fn staffing(state: &GameState, zone: &Zone, template: &ZoneTemplate) -> f32 {
+    let staff = if workplaces::is_workplace(template) {
+        workplaces::workers_at(&state.agents, zone.id).count()
+    } else {
+        housing::residents(&state.agents, Some(zone.id))
+    };
+    (staff as f32 / STAFF_PER_ZONE).min(1.0)
+}
+
+/// Wear down operating zones by their template's natural decay rate, scaled by
+/// how understaffed each one is and the upkeep efficiency factor. A fully staffed
+/// zone doesn't decay.
+pub fn apply_neglect(state: &mut GameState, hours: f32, maintenance_factor: f32) {
+    let wear: Vec<(usize, f32)> = state
+        .zones
+        .iter()
+        .enumerate()
+        .filter(|(_, z)| z.is_operating() && z.condition > NEGLECT_FLOOR)
+        .filter_map(|(i, zone)| {
+            let template = state.get_template(&zone.template_id)?;
+            let understaffed = 1.0 - staffing(state, zone, template);
+            let wear = template.decay.natural_rate * understaffed * maintenance_factor * hours;
+            (wear > 0.0).then_some((i, wear))
+        })
+        .collect();
+
+    let mut warnings = Vec::new();
+    for (i, wear) in wear {
+        let zone = &mut state.zones[i];
+        let before = zone.condition;
+        zone.condition = (zone.condition - wear).max(NEGLECT_FLOOR);
+        if before >= DISREPAIR_WARNING && zone.condition < DISREPAIR_WARNING {
+            let template_id = zone.template_id.clone();
+            if let Some(template) = state.get_template(&template_id) {
+                warnings.push(template.name.clone());
+            }
+        }
+    }
+
+    for name in warnings {
+        state.log.add(
+            state.game_time_hours,
+            format!("{} is falling into disrepair - it needs more hands.", name),
+            LogCategory::Zone,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::simulation::agents::Agent;
+    use crate::simulation::rng::SimRng;
+    use macroquad::prelude::Vec2;
+
+    #[test]
+    fn test_only_the_understaffed_zone_wears_down() {
+        let mut state = GameState::default();
+        state
+            .zone_templates
+            .extend(crate::assets::load_zones().unwrap());
+        for template in &mut state.zone_templates {
+            template.decay.natural_rate = 0.1;
+        }
+        state.setup_starting_town();
+        let zone_id = |state: &GameState, template_id: &str| {
+            state
+                .zones
+                .iter()
+                .find(|z| z.template_id == template_id)
+                .unwrap()
+                .id
+        };
+        let market = zone_id(&state, "community_market");
+        let workshop = zone_id(&state, "scavengers_workshop");
+        for zone in &mut state.zones {
+            if zone.id == market || zone.id == workshop {
+                zone.restore(1.0);
+            }
+        }
+
+        // Two villagers work the market; nobody works the workshop
+        let mut rng = SimRng::new(1);
+        for id in 0..2 {
+            let mut agent = Agent::new(id, Vec2::ZERO, &mut rng);
+            agent.workplace = Some(market);
+            state.agents.push(agent);
+        }
+
+        apply_neglect(&mut state, 1.0, 1.0);
+        let condition = |id| state.zone(id).unwrap().condition;
+        assert_eq!(condition(market), 1.0);
+        assert!(condition(workshop) < 1.0);
+    }
+}
@@ -0,0 +1,148 @@
+//! Time away - catch up on hours the game wasn't running and welcome the player back
+
+use crate::data::{Difficulty, GameState};
+use crate::narrative::{ChronicleEventType, LogCategory};
+
+/// A gap between frames longer than this (real seconds) counts as time away
+pub const AWAY_GAP_SECONDS: f32 = 60.0;
+
+/// Catch-up is simulated in slices of this many game hours
+const CATCH_UP_STEP_HOURS: f32 = 1.0;
+
+/// Notable events listed in the welcome back message
+const MAX_EVENTS: usize = 2;
+
+/// Game hours that passed between a save at `saved_at` and `now` (unix seconds):
+/// 1 real second = 1 game minute. Zero for a save with no timestamp.
+pub fn hours_since_save(saved_at: f64, now: f64) -> f32 {
+    if saved_at <= 0.0 {
+        return 0.0;
+    }
+    ((now - saved_at).max(0.0) / 60.0) as f32
+}
+
+/// What happened while the player was away
+#[derive(Debug, Clone, Default)]
+pub struct OfflineSummary {
+    pub hours_away: f32,
+    /// Hours actually simulated (after the offline cap)
+    pub hours_simulated: f32,
+    pub materials_gained: f32,
+    pub population_change: f32,
+    /// Seasons entered while away, in order
+    pub seasons: Vec<String>,
+    /// Notable chronicle entries from the time away
+    pub events: Vec<String>,
+    pub difficulty: Difficulty,
+}
+
+impl OfflineSummary {
+    /// Short paragraph for the welcome back dialog
+    pub fn dialog_text(&self) -> String {
+        let mut text = format!(
+            "You were away for {:.1}h ({} difficulty)",
+            self.hours_away,
+            self.difficulty.name()
+        );
+        if self.hours_simulated < self.hours_away {
+            text.push_str(&format!(
+                " (the town kept going for {:.0}h)",
+                self.hours_simulated
+            ));
+        }
+        text.push_str(&format!(
+            ". Materials {:+.0}, population {:+.1}.",
+            self.materials_gained, self.population_change
+        ));
+        if !self.seasons.is_empty() {
+            text.push_str(&format!(" Seasons: {}.", self.seasons.join(", ")));
+        }
+        if !self.events.is_empty() {
+            text.push_str(&format!(" Notable: {}.", self.events.join("; ")));
+        }
+        text
+    }
+}
+
+/// Run the town forward for `hours_away` game hours (capped by `offline_cap_hours`)
+/// and greet the player with a summary
+pub fn process_offline_time(state: &mut GameState, hours_away: f32) -> OfflineSummary {
+    let hours = hours_away.min(state.offline_cap_hours()).max(0.0);
+    let started_at = state.game_time_hours;
+    let materials_before = state.resources.materials;
+    let population_before = state.population.value();
+    let mut seasons = Vec::new();
+
+    let mut remaining = hours;
+    while remaining > 0.0 {
+        let step = remaining.min(CATCH_UP_STEP_HOURS);
+        // One tick per game minute (1 real second = 1 game minute)
+        let minutes = (step * 60.0).round() as u32;
+        let season_before = state.season_state.season;
+        super::simulate_ticks(state, minutes.max(1), 1.0);
+        if state.season_state.season != season_before {
+            seasons.push(state.season_state.season.name().to_string());
+        }
+        remaining -= step;
+    }
+
+    let mut events: Vec<String> = state
+        .town_chronicle
+        .events()
+        .iter()
+        .filter(|e| e.timestamp >= started_at)
+        .filter(|e| !matches!(e.event_type, ChronicleEventType::SeasonChanged { .. }))
+        .map(|e| e.display_text())
+        .collect();
+    let skip = events.len().saturating_sub(MAX_EVENTS);
+    events.drain(..skip);
+
+    let summary = OfflineSummary {
+        hours_away,
+        hours_simulated: hours,
+        materials_gained: state.resources.materials - materials_before,
+        population_change: state.population.value() - population_before,
+        seasons,
+        events,
+        difficulty: state.difficulty,
+    };
+
+    state.log.add(
+        state.game_time_hours,
+        format!("Welcome back! {:.1}h passed while you were away.", hours),
+        LogCategory::System,
+    );
+    state
+        .tutorial
+        .show_dialog("Welcome Back", &summary.dialog_text(), true);
+    summary
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hours_since_save() {
+        assert_eq!(hours_since_save(0.0, 5000.0), 0.0);
+        // An hour away is 60 game hours
+        assert_eq!(hours_since_save(1000.0, 1000.0 + 3600.0), 60.0);
+        // A clock that went backwards isn't time away
+        assert_eq!(hours_since_save(1000.0, 900.0), 0.0);
+    }
+
+    #[test]
+    fn test_dialog_text_mentions_cap() {
+        let summary = OfflineSummary {
+            hours_away: 100.0,
+            hours_simulated: 72.0,
+            materials_gained: 40.0,
+            population_change: 1.5,
+            ..Default::default()
+        };
+        let text = summary.dialog_text();
+        assert!(text.contains("100.0h"));
+        assert!(text.contains("72h"));
+        assert!(text.contains("+40"));
+    }
+}
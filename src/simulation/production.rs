@@ -0,0 +1,280 @@
+//! Production math shared by the tick and the UI's rate display
+//!
+//! Both `simulate_ticks` and `GameState::net_production_rate` go through here,
+//! so the numbers the player sees are exactly the numbers the simulation applies.
+
+use crate::data::{GameState, ResourceDelta, TechBonuses, ZoneTemplate};
+use crate::economy::ResourceType;
+use crate::zones::Zone;
+
+/// Tech bonuses combined with wonder, ancestor, capital and morale production modifiers
+pub fn production_bonuses(state: &GameState) -> TechBonuses {
+    let mut bonuses = TechBonuses::from_tree(&state.tech_tree);
+
+    // --- WONDER BUFFS ---
+    let wonder_buffs =
+        crate::narrative::WonderBuffs::from_completed(&state.dynasty.completed_wonders);
+    bonuses.production_multi *= wonder_buffs.production;
+
+    // --- ANCESTOR BUFFS ---
+    let ancestor_buffs = state.dynasty.ancestor_buffs();
+    bonuses.production_multi *= 1.0 + ancestor_buffs.production;
+
+    // --- CAPITAL BONUS ---
+    if let Some(town_id) = state.region_map.active_town_id {
+        bonuses.production_multi *= 1.0 + state.region_map.capital_bonus(town_id);
+    }
+
+    // --- MORALE ---
+    // A despairing town works slower
+    bonuses.production_multi *= crate::simulation::reactions::morale_multiplier(&state.agents);
+
+    bonuses
+}
+
+/// Production for the current state
+pub struct ProductionRates {
+    /// Net materials/maintenance change per game minute (output - upkeep).
+    /// Population maintenance cost is not included.
+    pub per_minute: ResourceDelta,
+    /// Flat attractiveness value (not accumulated)
+    pub attractiveness: f32,
+    /// Flat stability value (not accumulated)
+    pub stability: f32,
+}
+
+/// Compute output minus upkeep for all active zones plus passive gathering
+pub fn production_rates(state: &GameState, bonuses: &TechBonuses) -> ProductionRates {
+    let mut total_output = ResourceDelta::default();
+    let mut total_upkeep = ResourceDelta::default();
+
+    // Passive gathering (see `passive_gathering`)
+    let (base_rate_per_min, pop_rate_per_min) = passive_gathering(state, bonuses);
+    total_output.materials += base_rate_per_min + pop_rate_per_min;
+
+    // Attractiveness and Stability are FLAT values, not accumulated resources.
+    // We calculate them from scratch each tick based on active sources.
+    let attractiveness: f32 = state
+        .attractiveness_sources()
+        .iter()
+        .map(|(_, amount)| amount)
+        .sum();
+    // Base stability (from tech), less the strain of the people it has to hold together
+    let mut stability = bonuses.stability_flat - super::unrest::population_strain(state);
+
+    for zone in &state.zones {
+        // Dormant ruins and mothballed zones neither produce nor cost upkeep
+        if !zone.is_operating() {
+            continue;
+        }
+
+        if let Some(template) = state.get_template(&zone.template_id) {
+            // Active Production (Requires activity/throughput)
+            // Materials and Maintenance (Service) require active work to produce
+            let (output, upkeep) = zone_rates(state, zone, template, bonuses);
+            total_output.materials += output.materials;
+            total_output.maintenance += output.maintenance;
+
+            // Passive Stats (Attractiveness, Stability) depend primarily on Condition
+            // A restored building improves the town even if no one is using it right this second
+            // (Attractiveness is summed in GameState::attractiveness_sources)
+            stability += template.output.stability * zone.condition;
+
+            // Accumulate upkeep (these are costs, will be subtracted)
+            total_upkeep.materials += upkeep.materials;
+            total_upkeep.maintenance += upkeep.maintenance;
+
+            // For Stability, upkeep reduces the flat value
+            stability -= template.upkeep.stability * bonuses.maintenance_factor;
+        }
+    }
+
+    ProductionRates {
+        per_minute: ResourceDelta {
+            materials: total_output.materials - total_upkeep.materials,
+            maintenance: total_output.maintenance - total_upkeep.maintenance,
+            attractiveness: 0.0,
+            stability: 0.0,
+        },
+        attractiveness,
+        stability,
+    }
+}
+
+/// Materials gathered passively per game minute: (base trickle, the population's foraging)
+fn passive_gathering(state: &GameState, bonuses: &TechBonuses) -> (f32, f32) {
+    // Get seasonal and weather modifiers (uses Season and Weather methods)
+    let season = state.season_state.season;
+    let weather = state.season_state.weather;
+    let farm_mult = season.farm_growth_multiplier();
+    // Movement techs already speed villagers up (`WorldInfo::speed_multiplier`),
+    // so only the season and weather slow foraging here
+    let move_mult = season.movement_multiplier() * (1.0 - weather.movement_penalty());
+
+    // 1. Base passive gain = 10.0 per game day (Buffed to prevent sticking)
+    // 2. Population gain = 0.2 * sqrt(pop) per day (Diminishing returns)
+    // Apply seasonal farm multiplier to production
+
+    // We need RATE per minute. Day = 1440 minutes.
+    let base_rate_per_min = (10.0 / 1440.0) * bonuses.production_multi * farm_mult;
+
+    // Population gain: Diminishing returns using SQRT
+    // Movement multiplier affects gathering efficiency
+    let pop_rate_per_min =
+        ((0.2 * state.population.value().sqrt()) / 1440.0) * bonuses.production_multi * move_mult;
+
+    (base_rate_per_min, pop_rate_per_min)
+}
+
+/// Activity and weather multiplier on one zone's output
+fn output_multiplier(state: &GameState, zone: &Zone, template: &ZoneTemplate) -> f32 {
+    let throughput = zone.calculate_throughput(template);
+    crate::economy::calculate_output(throughput, &state.resources)
+        * state.season_state.weather.zone_multiplier(template)
+}
+
+/// Full-storage multiplier on one of a zone's output channels
+fn storage_factor(state: &GameState, template: &ZoneTemplate, kind: ResourceType) -> f32 {
+    super::stockpiles::channel_factor(&state.resources, template, state.config.storage_limit, kind)
+}
+
+/// Materials/maintenance output and upkeep of one operating zone, per game minute
+fn zone_rates(
+    state: &GameState,
+    zone: &Zone,
+    template: &ZoneTemplate,
+    bonuses: &TechBonuses,
+) -> (ResourceDelta, ResourceDelta) {
+    let multiplier = output_multiplier(state, zone, template);
+
+    let output = ResourceDelta {
+        materials: template.output.materials
+            * multiplier
+            * storage_factor(state, template, ResourceType::Materials)
+            * bonuses.production_multi,
+        maintenance: template.output.maintenance
+            * multiplier
+            * storage_factor(state, template, ResourceType::Maintenance),
+        ..Default::default()
+    };
+    // Apply Efficiency Multiplier to upkeep
+    let upkeep = ResourceDelta {
+        materials: template.upkeep.materials * bonuses.maintenance_factor,
+        maintenance: template.upkeep.maintenance * bonuses.maintenance_factor,
+        ..Default::default()
+    };
+    (output, upkeep)
+}
+
+/// Net materials/maintenance change per game hour from one zone (output - upkeep),
+/// or None if the zone is dormant or disabled
+pub fn zone_net_per_hour(state: &GameState, zone: &Zone) -> Option<ResourceDelta> {
+    if !zone.is_operating() {
+        return None;
+    }
+    let template = state.get_template(&zone.template_id)?;
+    let bonuses = production_bonuses(state);
+    let (output, upkeep) = zone_rates(state, zone, template, &bonuses);
+    Some(ResourceDelta {
+        materials: (output.materials - upkeep.materials) * 60.0,
+        maintenance: (output.maintenance - upkeep.maintenance) * 60.0,
+        ..Default::default()
+    })
+}
+
+/// One line of the itemised materials audit, per game hour
+#[derive(Debug, Clone)]
+pub struct MaterialsLine {
+    pub source: String,
+    /// Materials made, after every multiplier
+    pub output: f32,
+    /// Materials spent on upkeep, after efficiency techs
+    pub upkeep: f32,
+    /// Net maintenance (output - upkeep)
+    pub maintenance: f32,
+    /// Activity, weather and storage multiplier on a zone's output (1 for gathering)
+    pub multiplier: f32,
+}
+
+/// Every source of the net materials rate, itemised: passive gathering first,
+/// then each operating zone. The lines add up to `production_rates`.
+pub fn materials_breakdown(state: &GameState, bonuses: &TechBonuses) -> Vec<MaterialsLine> {
+    let (base, foraging) = passive_gathering(state, bonuses);
+    let gathering = |source: &str, per_minute: f32| MaterialsLine {
+        source: source.to_string(),
+        output: per_minute * 60.0,
+        upkeep: 0.0,
+        maintenance: 0.0,
+        multiplier: 1.0,
+    };
+    let mut lines = vec![
+        gathering("Passive gathering", base),
+        gathering("Villagers foraging", foraging),
+    ];
+
+    for zone in state.zones.iter().filter(|z| z.is_operating()) {
+        let template = match state.get_template(&zone.template_id) {
+            Some(template) => template,
+            None => continue,
+        };
+        let (output, upkeep) = zone_rates(state, zone, template, bonuses);
+        lines.push(MaterialsLine {
+            source: template.name.clone(),
+            output: output.materials * 60.0,
+            upkeep: upkeep.materials * 60.0,
+            maintenance: (output.maintenance - upkeep.maintenance) * 60.0,
+            multiplier: output_multiplier(state, zone, template)
+                * storage_factor(state, template, ResourceType::Materials),
+        });
+    }
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_materials_breakdown_adds_up_to_the_net_rate() {
+        let mut state = GameState::default();
+        state
+            .zone_templates
+            .extend(crate::assets::load_zones().unwrap());
+        state.setup_starting_town();
+        for zone in &mut state.zones {
+            zone.restore(1.0);
+        }
+        let bonuses = production_bonuses(&state);
+
+        let lines = materials_breakdown(&state, &bonuses);
+        assert_eq!(lines.len(), 2 + state.zones.len());
+        let itemised: f32 = lines.iter().map(|l| l.output - l.upkeep).sum();
+        let net = production_rates(&state, &bonuses).per_minute.materials * 60.0;
+        assert!((itemised - net).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_movement_techs_do_not_boost_gathering() {
+        let mut state = GameState::default();
+        state.population.add(25.0);
+        let mut bonuses = production_bonuses(&state);
+        let before = passive_gathering(&state, &bonuses);
+        bonuses.movement_multi = 1.5;
+        assert_eq!(passive_gathering(&state, &bonuses), before);
+    }
+
+    #[test]
+    fn test_capital_lifts_production() {
+        let mut state = GameState::default();
+        let town_id = state.region_map.active_town_id.unwrap();
+        for node in &mut state.region_map.nodes {
+            node.is_capital = false;
+        }
+        let base = production_bonuses(&state).production_multi;
+
+        state.region_map.get_node_mut(town_id).unwrap().is_capital = true;
+        let expected = base * (1.0 + state.region_map.capital_bonus(town_id));
+        assert!(expected > base);
+        assert!((production_bonuses(&state).production_multi - expected).abs() < 1e-6);
+    }
+}
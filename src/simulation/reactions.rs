@@ -0,0 +1,62 @@
+//! Emotional reactions - town events that move villager spirit, and spirit's effect on the town
+
+use super::agents::Agent;
+
+/// Average spirit at or above which morale has no effect on the town
+const CONTENT_SPIRIT: f32 = 0.5;
+
+/// Production multiplier when every villager is in despair
+const DESPAIR_MULTIPLIER: f32 = 0.7;
+
+/// Something that happened in town that villagers feel
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TownEvent {
+    /// A storm battered the buildings
+    StormDamage,
+    /// A neighbour left town
+    VillagerLost,
+    /// An archived town fell into crisis
+    TownInCrisis,
+    /// The stockpile is about to run dry
+    Shortage,
+    /// A riot broke out over low stability
+    Unrest,
+    /// A wonder was finished somewhere in the region
+    WonderCompleted,
+}
+
+impl TownEvent {
+    /// Base spirit change before traits
+    pub fn spirit_impact(&self) -> f32 {
+        match self {
+            TownEvent::StormDamage => -0.03,
+            TownEvent::VillagerLost => -0.05,
+            TownEvent::TownInCrisis => -0.1,
+            TownEvent::Shortage => -0.08,
+            TownEvent::Unrest => -0.06,
+            TownEvent::WonderCompleted => 0.2,
+        }
+    }
+}
+
+/// Let every villager react to an event
+pub fn broadcast(agents: &mut [Agent], event: TownEvent) {
+    for agent in agents {
+        agent.react_to(event);
+    }
+}
+
+/// Average villager spirit (content if nobody lives here yet)
+pub fn town_spirit(agents: &[Agent]) -> f32 {
+    if agents.is_empty() {
+        return 1.0;
+    }
+    agents.iter().map(|a| a.spirit).sum::<f32>() / agents.len() as f32
+}
+
+/// Production multiplier from town-wide morale
+/// (growth uses the happiness index directly, see `PopulationPressure::tick`)
+pub fn morale_multiplier(agents: &[Agent]) -> f32 {
+    let content = (town_spirit(agents) / CONTENT_SPIRIT).min(1.0);
+    DESPAIR_MULTIPLIER + (1.0 - DESPAIR_MULTIPLIER) * content
+}
@@ -0,0 +1,56 @@
+//! Resource popups - floating text over the zones behind each resource change
+
+use crate::data::{GameState, MapRect, ZoneTemplate};
+use crate::economy::{ResourceType, Resources};
+use macroquad::prelude::Vec2;
+
+/// Smallest batched change shown for each resource; smaller ones are added up first
+const STEPS: [(ResourceType, f32); 4] = [
+    (ResourceType::Materials, 1.0),
+    (ResourceType::Attractiveness, 0.05),
+    (ResourceType::Stability, 0.05),
+    (ResourceType::Grain, 1.0),
+];
+
+/// Where text goes when no zone is open yet
+const FALLBACK_POS: Vec2 = Vec2::new(500.0, 300.0);
+
+/// Pop up the resource changes since `before`, each near a zone that produces it
+pub fn spawn(state: &mut GameState, before: &Resources) {
+    if !state.settings.floating_text {
+        return;
+    }
+
+    for (kind, step) in STEPS {
+        let amount = state.resources.get(kind) - before.get(kind);
+        if amount == 0.0 {
+            continue;
+        }
+        let pos = match kind {
+            ResourceType::Attractiveness => anchor(state, |t| t.output.attractiveness > 0.0),
+            ResourceType::Stability => anchor(state, |t| t.output.stability > 0.0),
+            ResourceType::Grain => anchor(state, |t| t.food_output > 0.0),
+            _ => anchor(state, |_| true),
+        };
+        state.floating_texts.accumulate(kind, amount, step, pos);
+    }
+}
+
+/// Centre of the first operating zone whose template matches, else of any operating zone
+fn anchor(state: &GameState, matches: impl Fn(&ZoneTemplate) -> bool) -> Vec2 {
+    let operating: Vec<(&ZoneTemplate, MapRect)> = state
+        .zones
+        .iter()
+        .filter(|z| z.is_operating())
+        .filter_map(|z| {
+            let template = state.get_template(&z.template_id)?;
+            Some((template, z.footprint(template)?))
+        })
+        .collect();
+
+    operating
+        .iter()
+        .find(|(t, _)| matches(t))
+        .or_else(|| operating.first())
+        .map_or(FALLBACK_POS, |(_, rect)| rect.world_center())
+}
@@ -1,8 +1,20 @@
 //! Season and weather system
 
-use macroquad_toolkit::rng;
+use super::rng::SimRng;
+use crate::data::{GameState, ZoneCategory, ZoneTemplate};
+use crate::narrative::{ChronicleEventType, LogCategory};
+use crate::region::Biome;
 use serde::{Deserialize, Serialize};
 
+/// Farm output while rain waters the crops
+const RAIN_FARM_BONUS: f32 = 1.2;
+
+/// Market trade while fog keeps shoppers at home
+const FOG_MARKET_MULT: f32 = 0.8;
+
+/// Outdoor work (farms, workshops, building sites) while it snows
+const SNOW_OUTDOOR_MULT: f32 = 0.75;
+
 /// The four seasons of the year
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Season {
@@ -72,6 +84,41 @@ impl Season {
             Season::Winter => -0.03, // Cold is demoralizing
         }
     }
+
+    /// Game hours the working day starts and ends, and when villagers turn in for
+    /// the night - long summer days, short winter ones
+    pub fn day_rhythm(&self) -> DayRhythm {
+        match self {
+            Season::Spring => DayRhythm {
+                work_start: 9.0,
+                work_end: 17.0,
+                night_start: 22.0,
+            },
+            Season::Summer => DayRhythm {
+                work_start: 8.0,
+                work_end: 18.0,
+                night_start: 23.0,
+            },
+            Season::Autumn => DayRhythm {
+                work_start: 9.0,
+                work_end: 16.5,
+                night_start: 21.0,
+            },
+            Season::Winter => DayRhythm {
+                work_start: 9.5,
+                work_end: 15.0,
+                night_start: 20.0,
+            },
+        }
+    }
+}
+
+/// Boundaries of the villagers' day (morning always begins at 6:00)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DayRhythm {
+    pub work_start: f32,
+    pub work_end: f32,
+    pub night_start: f32,
 }
 
 impl Default for Season {
@@ -89,6 +136,10 @@ pub enum Weather {
     Storm,
     Snow,
     Fog,
+    /// Scorching heat, mostly in deserts
+    Heat,
+    /// Wind-blown sand and grit
+    Dust,
 }
 
 impl Weather {
@@ -100,6 +151,8 @@ impl Weather {
             Weather::Storm => "Stormy",
             Weather::Snow => "Snowing",
             Weather::Fog => "Foggy",
+            Weather::Heat => "Heatwave",
+            Weather::Dust => "Dust Storm",
         }
     }
 
@@ -108,6 +161,44 @@ impl Weather {
         matches!(self, Weather::Rain | Weather::Storm)
     }
 
+    /// Pace of work done outdoors, including building sites
+    pub fn outdoor_work_multiplier(&self) -> f32 {
+        match self {
+            Weather::Snow => SNOW_OUTDOOR_MULT,
+            _ => 1.0,
+        }
+    }
+
+    /// Output multiplier for a zone of this kind: rain helps farms, fog keeps
+    /// shoppers from markets, snow slows farms and workshops
+    pub fn zone_multiplier(&self, template: &ZoneTemplate) -> f32 {
+        let farm = template.food_output > 0.0;
+        let mut multiplier = 1.0;
+        if farm || template.category == ZoneCategory::Infrastructure {
+            multiplier *= self.outdoor_work_multiplier();
+        }
+        if farm && self.waters_crops() {
+            multiplier *= RAIN_FARM_BONUS;
+        }
+        if *self == Weather::Fog && template.category == ZoneCategory::Market {
+            multiplier *= FOG_MARKET_MULT;
+        }
+        multiplier
+    }
+
+    /// What the weather is doing to the town, for the season HUD
+    pub fn effect_note(&self) -> Option<&'static str> {
+        match self {
+            Weather::Rain => Some("Rain: +20% farm output"),
+            Weather::Storm => Some("Storm: +20% farms, damage risk"),
+            Weather::Fog => Some("Fog: -20% market trade"),
+            Weather::Snow => Some("Snow: -25% outdoor work"),
+            Weather::Heat => Some("Heat: villagers tire faster"),
+            Weather::Dust => Some("Dust: villagers tire faster"),
+            Weather::Sunny | Weather::Cloudy => None,
+        }
+    }
+
     /// Chance per hour to damage buildings (0.0 - 1.0)
     pub fn building_damage_chance(&self) -> f32 {
         match self {
@@ -125,6 +216,8 @@ impl Weather {
             Weather::Storm => 0.7,
             Weather::Snow => 0.75,
             Weather::Fog => 0.85,
+            Weather::Heat => 0.9,
+            Weather::Dust => 0.8,
         }
     }
 
@@ -137,6 +230,17 @@ impl Weather {
             Weather::Storm => 0.4,
             Weather::Snow => 0.3,
             Weather::Fog => 0.5,
+            Weather::Heat => 0.05,
+            Weather::Dust => 0.6,
+        }
+    }
+
+    /// Extra agent energy drained per game hour
+    pub fn energy_drain(&self) -> f32 {
+        match self {
+            Weather::Heat => 0.04,
+            Weather::Dust => 0.01,
+            _ => 0.0,
         }
     }
 }
@@ -147,6 +251,14 @@ impl Default for Weather {
     }
 }
 
+/// What an update did to the season
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SeasonTransition {
+    NoChange,
+    /// The season that ended and the one that began
+    SeasonChanged(Season, Season),
+}
+
 /// Current season and weather state
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SeasonState {
@@ -183,8 +295,15 @@ impl SeasonState {
     pub const DAYS_PER_SEASON: f32 = 12.0;
 
     /// Update season state with elapsed game hours
-    /// Returns true if season changed
-    pub fn update(&mut self, game_hours: f32) -> bool {
+    /// Weather is rolled from the table for the given biome, with storms scaled by `storm_factor`.
+    /// Returns the seasons either side of a change, if one happened
+    pub fn update(
+        &mut self,
+        game_hours: f32,
+        biome: Biome,
+        storm_factor: f32,
+        rng: &mut SimRng,
+    ) -> SeasonTransition {
         let game_days = game_hours / 24.0;
         self.total_days += game_days;
         self.day_in_season += game_days;
@@ -192,72 +311,53 @@ impl SeasonState {
         // Update weather duration
         self.weather_duration -= game_hours;
         if self.weather_duration <= 0.0 {
-            self.roll_new_weather();
+            self.roll_new_weather(biome, storm_factor, rng);
         }
 
         // Check for season change
         if self.day_in_season >= Self::DAYS_PER_SEASON {
             self.day_in_season -= Self::DAYS_PER_SEASON;
-            self.season = self.season.next();
-            return true;
+            let from = self.season;
+            self.season = from.next();
+            return SeasonTransition::SeasonChanged(from, self.season);
         }
 
-        false
+        SeasonTransition::NoChange
     }
 
-    /// Roll new weather based on season probabilities
-    fn roll_new_weather(&mut self) {
-        let roll = rng::gen_range(0.0, 1.0);
-
-        self.weather = match self.season {
-            Season::Spring => {
-                if roll < 0.4 {
-                    Weather::Sunny
-                } else if roll < 0.7 {
-                    Weather::Cloudy
-                } else if roll < 0.9 {
-                    Weather::Rain
-                } else {
-                    Weather::Fog
-                }
-            }
-            Season::Summer => {
-                if roll < 0.6 {
-                    Weather::Sunny
-                } else if roll < 0.85 {
-                    Weather::Cloudy
-                } else {
-                    Weather::Storm
-                }
-            }
-            Season::Autumn => {
-                if roll < 0.3 {
-                    Weather::Sunny
-                } else if roll < 0.5 {
-                    Weather::Cloudy
-                } else if roll < 0.75 {
-                    Weather::Rain
-                } else if roll < 0.9 {
-                    Weather::Fog
-                } else {
-                    Weather::Storm
-                }
-            }
-            Season::Winter => {
-                if roll < 0.3 {
-                    Weather::Sunny
-                } else if roll < 0.5 {
-                    Weather::Cloudy
-                } else if roll < 0.8 {
-                    Weather::Snow
-                } else {
-                    Weather::Fog
-                }
+    /// Roll new weather based on season and biome probabilities
+    fn roll_new_weather(&mut self, biome: Biome, storm_factor: f32, rng: &mut SimRng) {
+        let mut table = weather_table(self.season, biome);
+        bias(&mut table, Weather::Storm, storm_factor, 0.0);
+        let total: f32 = table.iter().map(|(_, w)| w).sum();
+        let mut roll = rng.range(0.0, total);
+
+        self.weather = table.last().map(|(w, _)| *w).unwrap_or_default();
+        for (weather, weight) in &table {
+            if roll < *weight {
+                self.weather = *weather;
+                break;
             }
-        };
+            roll -= weight;
+        }
 
         // Set new duration (3-12 hours)
-        self.weather_duration = rng::gen_range(3.0, 12.0);
+        self.weather_duration = rng.range(3.0, 12.0);
+    }
+
+    /// How far through the current season we are (0-1)
+    pub fn season_progress(&self) -> f32 {
+        (self.day_in_season / Self::DAYS_PER_SEASON).clamp(0.0, 1.0)
+    }
+
+    /// Days left until the next season begins
+    pub fn days_until_next(&self) -> f32 {
+        (Self::DAYS_PER_SEASON - self.day_in_season).max(0.0)
+    }
+
+    /// Current year (starting at 0)
+    pub fn year(&self) -> u32 {
+        (self.total_days / (Self::DAYS_PER_SEASON * 4.0)) as u32
     }
 
     /// Get current season display string
@@ -270,3 +370,135 @@ impl SeasonState {
         )
     }
 }
+
+/// The town's reactions to a new season, run exactly once per transition:
+/// the log and chronicle note it, spring opens with the festival, and leaving
+/// winter closes the year
+pub fn on_season_changed(state: &mut GameState, from: Season, to: Season) {
+    state.log.add(
+        state.game_time_hours,
+        format!("{} has arrived!", to.name()),
+        LogCategory::Event,
+    );
+    state.town_chronicle.record(
+        state.game_time_hours,
+        ChronicleEventType::SeasonChanged {
+            season: to.name().to_string(),
+        },
+    );
+    super::festival::on_season_changed(state, to);
+    super::yearly::on_season_changed(state, from);
+}
+
+/// Weighted weather table for a season, biased by the local biome.
+/// Plains and Forest use the temperate base tables unchanged.
+fn weather_table(season: Season, biome: Biome) -> Vec<(Weather, f32)> {
+    let mut table = match season {
+        Season::Spring => vec![
+            (Weather::Sunny, 0.4),
+            (Weather::Cloudy, 0.3),
+            (Weather::Rain, 0.2),
+            (Weather::Fog, 0.1),
+        ],
+        Season::Summer => vec![
+            (Weather::Sunny, 0.6),
+            (Weather::Cloudy, 0.25),
+            (Weather::Storm, 0.15),
+        ],
+        Season::Autumn => vec![
+            (Weather::Sunny, 0.3),
+            (Weather::Cloudy, 0.2),
+            (Weather::Rain, 0.25),
+            (Weather::Fog, 0.15),
+            (Weather::Storm, 0.1),
+        ],
+        Season::Winter => vec![
+            (Weather::Sunny, 0.3),
+            (Weather::Cloudy, 0.2),
+            (Weather::Snow, 0.3),
+            (Weather::Fog, 0.2),
+        ],
+    };
+
+    match biome {
+        Biome::Plains | Biome::Forest => {}
+        Biome::Desert => {
+            // Rain is rare; heat and dust take its place
+            bias(&mut table, Weather::Rain, 0.1, 0.0);
+            bias(&mut table, Weather::Storm, 0.3, 0.0);
+            bias(&mut table, Weather::Snow, 0.1, 0.0);
+            bias(&mut table, Weather::Fog, 0.2, 0.0);
+            let heat = match season {
+                Season::Summer => 0.4,
+                Season::Spring | Season::Autumn => 0.15,
+                Season::Winter => 0.0,
+            };
+            bias(&mut table, Weather::Heat, 1.0, heat);
+            bias(&mut table, Weather::Dust, 1.0, 0.15);
+        }
+        Biome::Coast => {
+            // Sea fog rolls in year-round
+            bias(&mut table, Weather::Fog, 2.5, 0.15);
+            bias(&mut table, Weather::Storm, 1.3, 0.0);
+        }
+        Biome::Tundra => {
+            // Snow falls outside winter too
+            let snow = match season {
+                Season::Spring => 0.25,
+                Season::Summer => 0.1,
+                Season::Autumn => 0.3,
+                Season::Winter => 0.0,
+            };
+            bias(&mut table, Weather::Snow, 1.5, snow);
+            bias(&mut table, Weather::Rain, 0.5, 0.0);
+        }
+        Biome::Swamp => {
+            bias(&mut table, Weather::Fog, 2.0, 0.15);
+            bias(&mut table, Weather::Rain, 1.6, 0.2);
+        }
+        Biome::Mountains => {
+            let snow = match season {
+                Season::Spring | Season::Autumn => 0.1,
+                Season::Summer | Season::Winter => 0.0,
+            };
+            bias(&mut table, Weather::Snow, 1.3, snow);
+            bias(&mut table, Weather::Storm, 1.2, 0.0);
+        }
+    }
+
+    table
+}
+
+/// Scale an existing weather weight, or add it with a base weight if absent
+fn bias(table: &mut Vec<(Weather, f32)>, weather: Weather, factor: f32, base_if_missing: f32) {
+    if let Some(entry) = table.iter_mut().find(|(w, _)| *w == weather) {
+        entry.1 *= factor;
+    } else if base_if_missing > 0.0 {
+        table.push((weather, base_if_missing));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_update_reports_each_season_change_once() {
+        let mut seasons = SeasonState::default();
+        let mut rng = SimRng::new(1);
+        let day = 24.0;
+        let before_end = (SeasonState::DAYS_PER_SEASON - 1.0) * day;
+        assert_eq!(
+            seasons.update(before_end, Biome::Plains, 1.0, &mut rng),
+            SeasonTransition::NoChange
+        );
+        assert_eq!(
+            seasons.update(day, Biome::Plains, 1.0, &mut rng),
+            SeasonTransition::SeasonChanged(Season::Spring, Season::Summer)
+        );
+        assert_eq!(
+            seasons.update(1.0, Biome::Plains, 1.0, &mut rng),
+            SeasonTransition::NoChange
+        );
+    }
+}
@@ -0,0 +1,151 @@
+//! Starvation - villagers who can't get food weaken and eventually leave for good
+
+use super::gossip::{self, Rumor};
+use super::reactions::{self, TownEvent};
+use crate::data::{GameState, ZoneCategory};
+use crate::narrative::{ChronicleEventType, LogCategory};
+
+/// Grain or flour eaten as an emergency ration by a starving villager
+const RATION: f32 = 1.0;
+
+/// Hunger restored by an emergency ration
+const RATION_HUNGER: f32 = 0.5;
+
+/// Energy and spirit lost per game hour while starving
+const STARVING_DRAIN: f32 = 0.05;
+
+/// Game hours at zero hunger before a villager is lost
+const STARVATION_HOURS: f32 = 72.0;
+
+/// Without a market nobody can buy a meal, so starvation runs this much faster
+const NO_MARKET_MULTIPLIER: f32 = 2.0;
+
+/// Whether any working market can feed villagers
+pub fn market_active(state: &GameState) -> bool {
+    state.zones.iter().filter(|z| z.is_operating()).any(|z| {
+        state
+            .get_template(&z.template_id)
+            .is_some_and(|t| t.category == ZoneCategory::Market)
+    })
+}
+
+/// Operating farms and docks add grain to the stockpile (farmers bring in more,
+/// a nearly full granary less)
+pub fn harvest_food(state: &mut GameState, hours: f32) {
+    let farm_mult = state.season_state.season.farm_growth_multiplier()
+        * super::jobs::farmer_multiplier(&state.agents);
+    let weather = state.season_state.weather;
+    let limit = state.config.storage_limit;
+    let harvest: f32 = state
+        .zones
+        .iter()
+        .filter(|z| z.is_operating())
+        .filter_map(|z| {
+            state.get_template(&z.template_id).map(|t| {
+                t.food_output
+                    * z.condition
+                    * weather.zone_multiplier(t)
+                    * super::stockpiles::output_factor(&state.resources, t, limit)
+            })
+        })
+        .sum();
+    state.resources.grain += harvest * farm_mult * hours;
+}
+
+/// Feed starving villagers from the stockpile, weaken those who can't be fed,
+/// and lose anyone who has gone hungry too long
+pub fn update(state: &mut GameState, hours: f32) {
+    let pace = if market_active(state) {
+        1.0
+    } else {
+        NO_MARKET_MULTIPLIER
+    };
+
+    let mut lost = Vec::new();
+    for agent in &mut state.agents {
+        if agent.hunger > 0.0 {
+            agent.starving_hours = 0.0;
+            continue;
+        }
+
+        // Emergency rations, flour first (it keeps less well)
+        if let Some(food) = ration_stock(&mut state.resources) {
+            *food -= RATION;
+            agent.hunger = RATION_HUNGER;
+            agent.starving_hours = 0.0;
+            continue;
+        }
+
+        agent.starving_hours += hours * pace;
+        let drain = STARVING_DRAIN * pace * hours;
+        agent.energy = (agent.energy - drain).max(0.0);
+        agent.spirit = (agent.spirit - drain).max(0.0);
+        if agent.starving_hours >= STARVATION_HOURS {
+            lost.push(agent.id);
+        }
+    }
+
+    for id in lost {
+        let (name, pos) = match state.agents.iter().position(|a| a.id == id) {
+            Some(index) => {
+                let agent = state.agents.remove(index);
+                (agent.name, agent.pos)
+            }
+            None => continue,
+        };
+        state.population.remove(1.0);
+        state.stats.agents_died += 1;
+        state.town_chronicle.record(
+            state.game_time_hours,
+            ChronicleEventType::VillagerLost {
+                name: name.clone(),
+                reason: "Starvation".to_string(),
+            },
+        );
+        state.log.add(
+            state.game_time_hours,
+            format!("{} has starved. The town needs food!", name),
+            LogCategory::Event,
+        );
+        reactions::broadcast(&mut state.agents, TownEvent::VillagerLost);
+        let rumor = Rumor::already_felt(format!("{} starved", name), state.game_time_hours);
+        gossip::witness(state, pos, rumor);
+    }
+}
+
+/// The stockpile a ration can be taken from, if any has enough
+fn ration_stock(resources: &mut crate::economy::Resources) -> Option<&mut f32> {
+    if resources.flour >= RATION {
+        Some(&mut resources.flour)
+    } else if resources.grain >= RATION {
+        Some(&mut resources.grain)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::simulation::agents::Agent;
+    use crate::simulation::rng::SimRng;
+    use macroquad::prelude::vec2;
+
+    #[test]
+    fn test_rations_feed_starving_villager() {
+        let mut state = GameState::default();
+        let mut agent = Agent::new(1, vec2(0.0, 0.0), &mut SimRng::default());
+        agent.hunger = 0.0;
+        state.agents.push(agent);
+        state.resources.grain = 1.5;
+
+        update(&mut state, 1.0);
+        assert_eq!(state.agents[0].hunger, RATION_HUNGER);
+        assert!((state.resources.grain - 0.5).abs() < 1e-6);
+
+        // Nothing left: the villager starts starving
+        state.agents[0].hunger = 0.0;
+        update(&mut state, 1.0);
+        assert!(state.agents[0].starving_hours > 0.0);
+    }
+}
@@ -0,0 +1,111 @@
+//! Stockpiles - zones slow down as the store of what they make fills up,
+//! and workers are sent first to zones making what the town is short of
+
+use crate::data::{GameState, ZoneTemplate};
+use crate::economy::{ResourceType, Resources};
+
+/// Share of the storage limit at which output starts to ease off
+const THROTTLE_FROM: f32 = 0.8;
+
+/// Below this share of the storage limit a resource counts as scarce
+const SCARCE_BELOW: f32 = 0.1;
+
+/// The resource a zone mainly exists to make, if any
+pub fn primary_output(template: &ZoneTemplate) -> Option<ResourceType> {
+    if template.food_output > 0.0 {
+        Some(ResourceType::Grain)
+    } else if template.output.materials > 0.0
+        && template.output.materials >= template.output.maintenance
+    {
+        Some(ResourceType::Materials)
+    } else if template.output.maintenance > 0.0 {
+        Some(ResourceType::Maintenance)
+    } else {
+        None
+    }
+}
+
+/// Output multiplier (0-1) for a zone given how full its store is: full speed
+/// up to `THROTTLE_FROM` of the limit, easing to nothing at the limit.
+/// A limit of 0 means storage is unlimited.
+pub fn output_factor(resources: &Resources, template: &ZoneTemplate, limit: f32) -> f32 {
+    let kind = match primary_output(template) {
+        Some(kind) if limit > 0.0 => kind,
+        _ => return 1.0,
+    };
+    let fill = resources.get(kind) / limit;
+    ((1.0 - fill) / (1.0 - THROTTLE_FROM)).clamp(0.0, 1.0)
+}
+
+/// Output multiplier (0-1) for one of a zone's output channels. Only the zone's
+/// primary output eases off as its store fills; everything else it makes carries on.
+pub fn channel_factor(
+    resources: &Resources,
+    template: &ZoneTemplate,
+    limit: f32,
+    kind: ResourceType,
+) -> f32 {
+    if primary_output(template) == Some(kind) {
+        output_factor(resources, template, limit)
+    } else {
+        1.0
+    }
+}
+
+/// Whether a zone has stopped because its store is full
+pub fn is_storage_full(state: &GameState, template: &ZoneTemplate) -> bool {
+    output_factor(&state.resources, template, state.config.storage_limit) <= 0.0
+}
+
+/// Whether a zone makes something the town is running short of
+pub fn makes_scarce(state: &GameState, template: &ZoneTemplate) -> bool {
+    let limit = state.config.storage_limit;
+    match primary_output(template) {
+        Some(kind) if limit > 0.0 => state.resources.get(kind) < limit * SCARCE_BELOW,
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_output_eases_off_as_the_store_fills() {
+        let templates = crate::assets::load_zones().unwrap();
+        let farm = templates
+            .iter()
+            .find(|t| t.food_output > 0.0)
+            .expect("a farm template");
+        assert_eq!(primary_output(farm), Some(ResourceType::Grain));
+
+        let mut resources = Resources {
+            grain: 50.0,
+            ..Default::default()
+        };
+        assert_eq!(output_factor(&resources, farm, 100.0), 1.0);
+        resources.grain = 90.0;
+        assert!((output_factor(&resources, farm, 100.0) - 0.5).abs() < 1e-4);
+        resources.grain = 120.0;
+        assert_eq!(output_factor(&resources, farm, 100.0), 0.0);
+        // No limit, no throttling
+        assert_eq!(output_factor(&resources, farm, 0.0), 1.0);
+    }
+
+    #[test]
+    fn test_full_store_only_stops_the_primary_output() {
+        let templates = crate::assets::load_zones().unwrap();
+        let farm = templates
+            .iter()
+            .find(|t| t.food_output > 0.0 && t.output.materials > 0.0)
+            .expect("a farm that also makes materials");
+
+        let resources = Resources {
+            grain: 120.0,
+            ..Default::default()
+        };
+        let factor = |kind| channel_factor(&resources, farm, 100.0, kind);
+        assert_eq!(factor(ResourceType::Grain), 0.0);
+        assert_eq!(factor(ResourceType::Materials), 1.0);
+    }
+}
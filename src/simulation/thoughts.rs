@@ -0,0 +1,92 @@
+//! Agent thoughts - short contextual bubbles explaining what a villager wants
+
+use super::agents::{Agent, AgentState};
+use serde::{Deserialize, Serialize};
+
+/// How often (in agent update time) a thought is re-evaluated, so bubbles don't flicker
+pub const THOUGHT_REFRESH: f32 = 0.3;
+
+/// Needs below this level are worth thinking about
+const NEED_THRESHOLD: f32 = 0.4;
+
+/// Spirit below this level means the villager is fed up with the town
+const UNHAPPY_THRESHOLD: f32 = 0.2;
+
+/// What an agent is currently thinking about
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Thought {
+    Hungry,
+    Lonely,
+    Tired,
+    Unhappy,
+    Working,
+    Building,
+}
+
+impl Thought {
+    /// Work out the most pressing thought from the agent's needs and current activity
+    pub fn assess(agent: &Agent) -> Option<Thought> {
+        if agent.spirit < UNHAPPY_THRESHOLD {
+            return Some(Thought::Unhappy);
+        }
+
+        // The lowest need wins if it is pressing
+        let needs = [
+            (agent.hunger, Thought::Hungry),
+            (agent.social, Thought::Lonely),
+            (agent.energy, Thought::Tired),
+        ];
+        let (lowest, need_thought) = needs
+            .iter()
+            .copied()
+            .min_by(|a, b| a.0.total_cmp(&b.0))
+            .unwrap_or((1.0, Thought::Hungry));
+        if lowest < NEED_THRESHOLD {
+            return Some(need_thought);
+        }
+
+        // Otherwise explain the current activity
+        match agent.state {
+            AgentState::Shopping { .. } => Some(Thought::Hungry),
+            AgentState::Socializing { .. } => Some(Thought::Lonely),
+            AgentState::GoingHome | AgentState::Sleeping => Some(Thought::Tired),
+            AgentState::Working { .. } => Some(Thought::Working),
+            AgentState::Building { .. } => Some(Thought::Building),
+            _ => None,
+        }
+    }
+
+    /// Texture name for the bubble icon (if one exists)
+    pub fn icon(&self) -> Option<&'static str> {
+        match self {
+            Thought::Hungry => Some("icon_thought_shopping"),
+            Thought::Lonely => Some("icon_thought_social"),
+            Thought::Tired => Some("icon_thought_sleep"),
+            Thought::Working | Thought::Building => Some("icon_thought_working"),
+            Thought::Unhappy => None,
+        }
+    }
+
+    /// Text shown when no icon is available
+    pub fn text(&self) -> &'static str {
+        match self {
+            Thought::Hungry => "Food",
+            Thought::Lonely => "<3",
+            Thought::Tired => "Zzz",
+            Thought::Unhappy => ">:(",
+            Thought::Working => "W",
+            Thought::Building => "B",
+        }
+    }
+
+    /// Text colour for the fallback bubble
+    pub fn color(&self) -> [f32; 4] {
+        match self {
+            Thought::Hungry => [1.0, 0.8, 0.3, 1.0],
+            Thought::Lonely => [1.0, 0.5, 0.7, 1.0],
+            Thought::Tired => [0.7, 0.7, 1.0, 1.0],
+            Thought::Unhappy => [1.0, 0.3, 0.3, 1.0],
+            Thought::Working | Thought::Building => [1.0, 1.0, 1.0, 1.0],
+        }
+    }
+}
@@ -1,26 +1,7 @@
 //! Game tick system - Time management and simulation stepping
 
-use macroquad_toolkit::rng;
-
-// Helper struct for aggregating tech effects
-#[derive(Default)]
-struct TechBonuses {
-    production_multi: f32,
-    maintenance_factor: f32,
-    attractiveness_flat: f32,
-    housing_flat: f32,
-}
-// Manually impl default to set multipliers to 1.0
-impl TechBonuses {
-    fn default() -> Self {
-        Self {
-            production_multi: 1.0,
-            maintenance_factor: 1.0,
-            attractiveness_flat: 0.0,
-            housing_flat: 0.0,
-        }
-    }
-}
+use crate::simulation::reactions::{self, TownEvent};
+use crate::simulation::seasons::{self, SeasonTransition};
 
 /// Manages game tick timing
 ///
@@ -53,6 +34,10 @@ impl TickTimer {
     }
 }
 
+/// Time scale of turbo mode: six game hours per real second, for catching up.
+/// The whole frame's worth of ticks runs as one batch, so agents only take one step.
+pub const TURBO_TIME_SCALE: f32 = 360.0;
+
 /// Tracks saved-time information for offline progress calculations.
 pub struct TimeTracker {
     pub total_hours: f32,
@@ -72,11 +57,14 @@ impl TimeTracker {
 /// Simulate multiple ticks at once (for catching up or fast-forward)
 ///
 /// More efficient than calling game_tick() many times - batches calculations.
+/// The economy (see `economy_step`) still steps once per tick, so a batch ends up
+/// where the same ticks run one at a time would.
 /// Time scale: 1 real second = 1 game minute at 1x speed
 pub fn simulate_ticks(state: &mut crate::data::GameState, num_ticks: u32, tick_seconds: f32) {
     if num_ticks == 0 {
         return;
     }
+    let resources_before = state.resources;
 
     // For efficiency, we batch similar operations
     let total_seconds = num_ticks as f32 * tick_seconds;
@@ -90,50 +78,76 @@ pub fn simulate_ticks(state: &mut crate::data::GameState, num_ticks: u32, tick_s
     let _pop_before = state.population.value();
 
     // Update game time
+    let day_before = (state.game_time_hours / 24.0) as u32;
     state.game_time_hours += total_hours;
 
-    // Update season and weather
-    let season_changed = state.season_state.update(total_hours);
-    if season_changed {
-        let season_name = state.season_state.season.name().to_string();
-        state.log.add(
-            state.game_time_hours,
-            format!("{} has arrived!", season_name),
-            crate::narrative::LogCategory::Event,
-        );
-        // Record in chronicle
-        state.town_chronicle.record(
-            state.game_time_hours,
-            crate::narrative::ChronicleEventType::SeasonChanged {
-                season: season_name,
-            },
-        );
-    }
-
-    // Apply seasonal morale bonus to agents
+    // Update season and weather (weather tables depend on the local biome)
+    let biome = state
+        .region_map
+        .active_town()
+        .map(|node| node.biome)
+        .unwrap_or_default();
+    let disasters = state.config.disaster_multiplier;
+    let transition = state
+        .season_state
+        .update(total_hours, biome, disasters, &mut state.rng);
+    if let SeasonTransition::SeasonChanged(from, to) = transition {
+        seasons::on_season_changed(state, from, to);
+    }
+
+    // Apply seasonal morale bonus to agents, and weather fatigue (heatwaves)
     let morale_change = state.season_state.season.morale_bonus() * total_hours / 24.0;
+    let energy_drain = state.season_state.weather.energy_drain() * total_hours;
     for agent in &mut state.agents {
         agent.spirit = (agent.spirit + morale_change).clamp(0.0, 1.0);
+        agent.energy = (agent.energy - energy_drain).max(0.0);
     }
 
+    // --- TECH, WONDER, ANCESTOR AND CAPITAL BONUSES ---
+    let bonuses = crate::simulation::production::production_bonuses(state);
+
     // Update caravans (uses Caravan::update)
     let days_elapsed = total_hours / 24.0;
+    let routes = &state.trade_manager.routes;
+    let mut deliveries = Vec::new();
     for caravan in &mut state.trade_manager.caravans {
-        // Assume 2-day travel time for now
-        let (arrived, returned) = caravan.update(2.0, days_elapsed);
+        let (arrived, returned) = caravan.update(crate::region::CARAVAN_TRAVEL_DAYS, days_elapsed);
         if arrived {
-            // Caravan delivered goods - could add resources here
+            deliveries.push(caravan.route_id);
         }
         if returned {
-            // Caravan returned home - could respawn it
+            // Reload for the next trip (trade techs increase cargo)
+            if let Some(route) = routes.iter().find(|r| r.id == caravan.route_id) {
+                caravan.cargo_amount = route.amount_per_trip * bonuses.trade_multi;
+            }
         }
     }
 
+    for route_id in deliveries {
+        crate::simulation::trade_offers::deliver(state, route_id, bonuses.trade_multi);
+    }
+    crate::simulation::trade_offers::update(state);
+    let day = (state.game_time_hours / 24.0) as u32;
+    let local = state
+        .region_map
+        .active_town()
+        .map(|node| node.resource_potentials.clone());
+    state
+        .market_prices
+        .update(day, local.as_ref(), &mut state.rng);
+    if day > day_before {
+        crate::simulation::zone_history::sample(state);
+    }
+
     // Update town proxies (uses TownProxyManager methods)
-    state.town_proxies.update_all(days_elapsed);
+    let crises_before = state.town_proxies.crisis_count();
+    state.town_proxies.update_all(days_elapsed, disasters);
 
     // Check for proxy crises (uses crisis_count, all, get)
     let crisis_count = state.town_proxies.crisis_count();
+    if crisis_count > crises_before {
+        reactions::broadcast(&mut state.agents, TownEvent::TownInCrisis);
+    }
     if crisis_count > 0 {
         // Could add notification about towns in crisis
         for proxy in state.town_proxies.all() {
@@ -147,163 +161,125 @@ pub fn simulate_ticks(state: &mut crate::data::GameState, num_ticks: u32, tick_s
     // Count active zones for population growth
     let active_zones = state.zones.iter().filter(|z| !z.dormant).count();
 
-    // --- TECH BONUSES ---
-    let mut bonuses = TechBonuses::default();
-    for tech in &state.tech_tree {
-        if tech.unlocked {
-            match tech.effect {
-                crate::data::TechEffect::ProductionMulti(m) => bonuses.production_multi *= m,
-                crate::data::TechEffect::EfficiencyMulti(m) => bonuses.maintenance_factor *= m,
-                crate::data::TechEffect::AttractivenessFlat(v) => bonuses.attractiveness_flat += v,
-                crate::data::TechEffect::HousingGlobal(v) => bonuses.housing_flat += v,
-            }
-        }
-    }
-
-    // --- WONDER BUFFS ---
-    let wonder_buffs =
-        crate::narrative::WonderBuffs::from_completed(&state.dynasty.completed_wonders);
-    bonuses.production_multi *= wonder_buffs.production;
-
-    // --- ANCESTOR BUFFS ---
-    let ancestor_buffs = state.dynasty.ancestor_buffs();
-    bonuses.production_multi *= 1.0 + ancestor_buffs.production;
-
     // Calculate total housing capacity (Base + Tech)
-    // Add base capacity of 2.0 for "Campsite" so players aren't soft-locked if they restore non-housing first.
-    let housing_capacity = state.calculate_housing_capacity() + bonuses.housing_flat + 2.0;
-
-    // Population grows based on attractiveness and capacity
-    // Boost growth based on active zones and attractiveness
-    // Tech bonus to attractiveness applied here? Or to resource?
-    // Let's apply to resource delta actually, so it persists.
+    // Plus the campsite, so players aren't soft-locked if they restore non-housing first.
+    let housing_capacity = state.calculate_housing_capacity()
+        + crate::simulation::housing::campsite_beds(state) as f32;
+
+    // Population, wear, production and upkeep run once per tick, even in a batch,
+    // so turbo and catch-up compound the same way real time does
+    crate::simulation::construction::release_hired_builders(state);
+    let happiness = reactions::town_spirit(&state.agents);
+    let mut net_delta = crate::data::ResourceDelta::default();
+    for _ in 0..num_ticks {
+        let delta = economy_step(
+            state,
+            &bonuses,
+            active_zones,
+            housing_capacity,
+            happiness,
+            tick_seconds, // 1:1 real seconds to game minutes
+        );
+        net_delta.materials += delta.materials;
+        net_delta.maintenance += delta.maintenance;
+    }
 
-    let growth_bonus = active_zones as f32 * 0.5;
-    state.population.tick(
-        state.resources.attractiveness * (1.0 + growth_bonus),
-        housing_capacity,
-        game_minutes, // Use game time, not real time
-    );
+    // Big jumps in attractiveness (and finished wonders) bring newcomers all at once
+    crate::simulation::migration::update(state, housing_capacity);
 
-    // Get seasonal and weather modifiers (uses Season and Weather methods)
-    let season = state.season_state.season;
+    // Get weather modifiers (uses Weather methods)
     let weather = state.season_state.weather;
-    let farm_mult = season.farm_growth_multiplier();
-    let move_mult = season.movement_multiplier() * (1.0 - weather.movement_penalty());
     let _weather_visibility = weather.visibility_reduction();
-    let _waters_crops = weather.waters_crops();
-    let building_damage = weather.building_damage_chance();
+    let building_damage = weather.building_damage_chance() * disasters;
 
     // Apply random building damage during storms
-    if building_damage > 0.0 && rng::gen_range(0.0, 1.0) < building_damage * game_minutes / 60.0 {
+    if building_damage > 0.0 && state.rng.chance(building_damage * game_minutes / 60.0) {
         for zone in &mut state.zones {
             if !zone.dormant {
                 zone.condition = (zone.condition - 0.01).max(0.5);
             }
         }
+        reactions::broadcast(&mut state.agents, TownEvent::StormDamage);
+        witness_storm(state);
     }
 
-    // Calculate and apply resource changes (batched)
-    let mut total_output = crate::data::ResourceDelta::default();
-    let mut total_upkeep = crate::data::ResourceDelta::default();
-
-    // PASSIVE GATHERING:
-    // 1. Base passive gain = 10.0 per game day (Buffed to prevent sticking)
-    // 2. Population gain = 0.2 * sqrt(pop) per day (Diminishing returns)
-    // Apply seasonal farm multiplier to production
-
-    // We need RATE per minute. Day = 1440 minutes.
-    let base_rate_per_min = (10.0 / 1440.0) * bonuses.production_multi * farm_mult;
-
-    // Population gain: Diminishing returns using SQRT
-    // Movement multiplier affects gathering efficiency
-    let pop_rate_per_min =
-        ((0.2 * state.population.value().sqrt()) / 1440.0) * bonuses.production_multi * move_mult;
-
-    // Add rates to accumulator
-    total_output.materials += base_rate_per_min + pop_rate_per_min;
-
-    // REFACTORED: Attractiveness and Stability are now FLAT values, not accumulated resources.
-    // We calculate them from scratch each tick based on active sources.
-    let mut calculated_attractiveness = bonuses.attractiveness_flat;
-    let mut calculated_stability = 0.0; // Base stability
-
-    for zone in &state.zones {
-        if zone.dormant {
-            continue;
-        }
-
-        if let Some(template) = state
-            .zone_templates
-            .iter()
-            .find(|t| t.id == zone.template_id)
-        {
-            let throughput = zone.calculate_throughput(template);
-            let multiplier = crate::economy::calculate_output(throughput, &state.resources);
-
-            // Active Production (Requires activity/throughput)
-            // Materials and Maintenance (Service) require active work to produce
-            total_output.materials +=
-                template.output.materials * multiplier * bonuses.production_multi;
-            total_output.maintenance += template.output.maintenance * multiplier;
-
-            // Passive Stats (Attractiveness, Stability) depend primarily on Condition
-            // A restored building improves the town even if no one is using it right this second
-            let passive_mult = zone.condition;
-            calculated_attractiveness += template.output.attractiveness * passive_mult;
-            calculated_stability += template.output.stability * passive_mult;
-
-            // Accumulate upkeep (these are costs, will be subtracted)
-            // Apply Efficiency Multiplier to upkeep
-            total_upkeep.materials += template.upkeep.materials * bonuses.maintenance_factor;
-            total_upkeep.maintenance += template.upkeep.maintenance * bonuses.maintenance_factor;
-
-            // For Attr/Stab, upkeep reduces the flat value
-            calculated_attractiveness -=
-                template.upkeep.attractiveness * bonuses.maintenance_factor;
-            calculated_stability -= template.upkeep.stability * bonuses.maintenance_factor;
-        }
-    }
-
-    // Apply net resource changes (output - upkeep) × game time
-    let mut net_delta = crate::data::ResourceDelta {
-        materials: (total_output.materials - total_upkeep.materials) * game_minutes,
-        maintenance: (total_output.maintenance - total_upkeep.maintenance) * game_minutes,
-        attractiveness: 0.0, // Calculated directly below
-        stability: 0.0,      // Calculated directly below
-    };
-
-    // Update flat stats directly
-    // Soft Cap / Decay is removed as requested - they are just flat values now.
-    state.resources.attractiveness = calculated_attractiveness.max(0.0);
-    state.resources.stability = calculated_stability.max(0.0);
+    // Too little stability for the town's size breeds riots
+    crate::simulation::unrest::update(state, total_hours);
 
     // --- AGENT SIMULATION ---
     // Target agent count based on population (capped for performance/visual clutter)
     // Use round() to avoid flickering at integer boundaries
-    let target_agents = (state.population.value().round() as usize).min(50);
+    let target_agents = (state.population.value().round() as usize).min(state.settings.agent_cap);
 
-    // Spawn (uses Agent::with_job and with_home builder methods)
+    // Spawn newcomers into free homes (uses Agent::with_job and with_home builder methods)
     while state.agents.len() < target_agents {
-        // Spawn at a random location (ideally at a house, but random for now)
-        let id = rng::random_u64();
-        let x = rng::gen_range(500.0, 800.0);
-        let y = rng::gen_range(500.0, 800.0);
-        let home_pos = macroquad::prelude::vec2(
-            x + rng::gen_range(-50.0, 50.0),
-            y + rng::gen_range(-50.0, 50.0),
-        );
-        let job = crate::simulation::agents::Job::Laborer;
+        let (home, home_center) = match crate::simulation::housing::free_home(state) {
+            Some(home) => home,
+            None => {
+                crate::simulation::housing::report_no_housing(state);
+                break;
+            }
+        };
+        let id = state.rng.next_u64();
+        // Spread housemates out a little so they don't stack
+        let home_pos = home_center
+            + macroquad::prelude::vec2(state.rng.range(-20.0, 20.0), state.rng.range(-20.0, 20.0));
+        // Keep roughly one builder per four villagers, everyone else labours
+        let builders = state
+            .agents
+            .iter()
+            .filter(|a| a.job == crate::simulation::agents::Job::Builder)
+            .count();
+        let job = if builders * 4 < state.agents.len() + 1 {
+            crate::simulation::agents::Job::Builder
+        } else {
+            crate::simulation::agents::Job::Laborer
+        };
 
-        let agent = crate::simulation::agents::Agent::new(id, macroquad::prelude::vec2(x, y))
+        let agent = crate::simulation::agents::Agent::new(id, home_pos, &mut state.rng)
             .with_job(job)
-            .with_home(home_pos);
+            .with_home(home, home_pos);
+        state.town_chronicle.record(
+            state.game_time_hours,
+            crate::narrative::ChronicleEventType::VillagerArrived {
+                name: agent.name.clone(),
+            },
+        );
         state.agents.push(agent);
+        state.stats.agents_born += 1;
+    }
+    if state.agents.len() >= target_agents {
+        state.housing_full = false;
+    }
+
+    // Despawn (if population drops). Only villagers over the agent cap are
+    // dropped quietly; the rest have moved away and go in the chronicle.
+    let moved_away = target_agents < state.settings.agent_cap;
+    // The newest go first, but heroes honoured in the Hall stay put
+    let keep = target_agents.min(state.agents.len());
+    let mut departed = Vec::new();
+    while state.agents.len() > keep {
+        match state.agents.iter().rposition(|a| !a.immortalized) {
+            Some(index) => departed.push(state.agents.remove(index)),
+            None => break,
+        }
     }
-
-    // Despawn (if population drops)
-    while state.agents.len() > target_agents {
-        state.agents.pop();
+    for agent in &departed {
+        if moved_away {
+            state.town_chronicle.record(
+                state.game_time_hours,
+                crate::narrative::ChronicleEventType::VillagerLost {
+                    name: agent.name.clone(),
+                    reason: "Moved away".to_string(),
+                },
+            );
+            state.stats.agents_left += 1;
+        } else {
+            state.stats.agents_died += 1;
+        }
+    }
+    if !departed.is_empty() {
+        reactions::broadcast(&mut state.agents, TownEvent::VillagerLost);
     }
 
     // Update game hour (24-hour cycle, 1 game minute = 1 real second)
@@ -329,14 +305,14 @@ pub fn simulate_ticks(state: &mut crate::data::GameState, num_ticks: u32, tick_s
     let mut parks = Vec::new();
     let mut construction_sites = Vec::new();
 
-    for (zone_idx, zone) in state.zones.iter().enumerate() {
+    for zone in &state.zones {
         if let Some(template) = state
             .zone_templates
             .iter()
             .find(|t| t.id == zone.template_id)
         {
             // Get Center Position
-            let pos = if let Some(rect) = template.map_rect {
+            let pos = if let Some(rect) = zone.footprint(template) {
                 macroquad::prelude::vec2(
                     (rect.x as f32 + rect.w as f32 / 2.0) * crate::ui::map_renderer::TILE_SIZE,
                     (rect.y as f32 + rect.h as f32 / 2.0) * crate::ui::map_renderer::TILE_SIZE,
@@ -347,7 +323,7 @@ pub fn simulate_ticks(state: &mut crate::data::GameState, num_ticks: u32, tick_s
 
             // Check for construction sites
             if zone.is_under_construction() {
-                construction_sites.push((pos, zone_idx));
+                construction_sites.push((pos, zone.id));
                 continue;
             }
 
@@ -364,65 +340,61 @@ pub fn simulate_ticks(state: &mut crate::data::GameState, num_ticks: u32, tick_s
         }
     }
 
+    let amenities: Vec<_> = markets.iter().chain(parks.iter()).copied().collect();
+    let crowded = crate::simulation::crowding::crowded_spots(&state.agents, &amenities);
+    let amenity_version =
+        crate::simulation::agents::amenity_version(&[&markets, &workshops, &parks]);
     let world_info = crate::simulation::agents::WorldInfo {
         markets,
         workshops,
         parks,
         construction_sites,
+        workplaces: crate::simulation::workplaces::positions(state),
+        crowded,
         game_hour: state.game_hour,
+        season: state.season_state.season,
+        speed_multiplier: bonuses.movement_multi,
+        amenity_version,
+        walkable: state.world_map.walkable(),
     };
-    for agent in &mut state.agents {
-        agent.update(agent_delta, &world_info);
-    }
-
-    // Apply population-based maintenance cost
-    let maint_cost = state.calculate_maintenance_cost() * game_minutes;
-    net_delta.maintenance -= maint_cost;
-
-    // Add floating text for significant material changes (uses add_gain/add_loss)
-    if net_delta.materials.abs() > 0.5 {
-        // Find a zone to spawn the text near (first active zone)
-        let spawn_pos = if let Some(zone) = state.zones.iter().find(|z| !z.dormant) {
-            if let Some(template) = state
-                .zone_templates
-                .iter()
-                .find(|t| t.id == zone.template_id)
-            {
-                if let Some(rect) = template.map_rect {
-                    macroquad::prelude::vec2(
-                        (rect.x as f32 + rect.w as f32 / 2.0) * crate::ui::map_renderer::TILE_SIZE,
-                        (rect.y as f32 + rect.h as f32 / 2.0) * crate::ui::map_renderer::TILE_SIZE,
-                    )
-                } else {
-                    macroquad::prelude::vec2(500.0, 300.0)
-                }
-            } else {
-                macroquad::prelude::vec2(500.0, 300.0)
-            }
-        } else {
-            macroquad::prelude::vec2(500.0, 300.0)
-        };
+    crate::simulation::lod::update_agents(
+        &mut state.agents,
+        &world_info,
+        agent_delta,
+        state.agent_tick,
+        state.view_rect,
+        &state.settings,
+        &mut state.agent_rng,
+    );
+    state.agent_tick += 1;
+    crate::simulation::gossip::spread(state);
+    crate::simulation::gossip::befriend(state);
 
-        if net_delta.materials > 0.0 {
-            state
-                .floating_texts
-                .add_gain(net_delta.materials, "Materials", spawn_pos);
-        } else {
-            state
-                .floating_texts
-                .add_loss(net_delta.materials.abs(), "Materials", spawn_pos);
-        }
-    }
+    // An empty town with nothing in store eventually falls
+    crate::simulation::collapse::update(state, total_hours);
 
-    // PARTICLE SYSTEM UPDATE
-    state
-        .particle_system
-        .update(game_minutes * 60.0 * agent_delta); // Approximate sync with frame time
+    // Laborers move into whatever jobs the town is short of, then everyone finds a workplace
+    crate::simulation::jobs::rebalance(state);
+    crate::simulation::workplaces::assign(state);
+
+    // Farms fill the food stockpile; villagers nobody can feed start to starve
+    crate::simulation::starvation::harvest_food(state, total_hours);
+    crate::simulation::starvation::update(state, total_hours);
+
+    // Villagers who have been miserable for days give up and walk away
+    crate::simulation::departures::update(state, total_hours);
+
+    // Surplus materials rebuild and repair the town when the player allows it
+    crate::simulation::auto_restore::update(state);
 
     // TUTORIAL UPDATE
     // Build context for tutorial triggers
     let active_zones = state.zones.iter().filter(|z| !z.dormant).count();
     let tutorial_ctx = crate::narrative::tutorial::TutorialContext {
+        town_name: state
+            .region_map
+            .active_town()
+            .map_or("Quiteville", |node| node.name.as_str()),
         zones_active: active_zones,
         agent_count: state.agents.len(),
         materials: state.resources.materials,
@@ -431,104 +403,80 @@ pub fn simulate_ticks(state: &mut crate::data::GameState, num_ticks: u32, tick_s
     };
     state.tutorial.update(tutorial_ctx, game_minutes);
 
-    // Weather particles
-    let weather = state.season_state.weather;
-    let particle_count = match weather {
-        crate::simulation::seasons::Weather::Rain => 2,
-        crate::simulation::seasons::Weather::Storm => 5,
-        crate::simulation::seasons::Weather::Snow => 2,
-        _ => 0,
-    };
+    // Update stats and check achievements
+    update_stats_and_achievements(state, net_delta.materials.max(0.0));
+    crate::narrative::firsts::check_villager_firsts(state);
 
-    if particle_count > 0 {
-        use crate::ui::particles::ParticleType;
-        let screen_w = macroquad::window::screen_width();
-        let screen_h = macroquad::window::screen_height();
-
-        // Spawn weather particles in "world" space relative to camera?
-        // Actually weather usually falls across the screen.
-        // But our particle system is world-space.
-        // We should spawn them around the camera view.
-        let cam_center = state.camera.target;
-        let spawn_w = screen_w / state.camera.zoom;
-        let spawn_h = screen_h / state.camera.zoom;
-
-        for _ in 0..particle_count {
-            let x = cam_center.x - spawn_w / 2.0 + rng::gen_range(0.0, spawn_w);
-            let y = cam_center.y - spawn_h / 2.0 + rng::gen_range(0.0, spawn_h);
-            let pos = macroquad::prelude::vec2(x, y);
-
-            let (vel, color, life, p_type) = match weather {
-                crate::simulation::seasons::Weather::Snow => (
-                    macroquad::prelude::vec2(rng::gen_range(-10.0, 10.0), 30.0),
-                    macroquad::prelude::WHITE,
-                    4.0,
-                    ParticleType::Snow,
-                ),
-                _ => (
-                    // Rain/Storm
-                    macroquad::prelude::vec2(-5.0, 200.0),
-                    macroquad::prelude::Color::new(0.6, 0.6, 1.0, 0.6),
-                    1.5,
-                    ParticleType::Rain,
-                ),
-            };
+    state.last_tick_delta = state.resources.change_since(&resources_before, num_ticks);
+    crate::simulation::resource_popups::spawn(state, &resources_before);
 
-            state
-                .particle_system
-                .spawn(pos, vel, life, 3.0, color, p_type);
-        }
-    }
+    // Surplus can flow into the wonder under construction
+    crate::simulation::wonder_funding::auto_fund(state, net_delta.materials);
 
-    // Chimney Smoke (only in winter)
-    // Only spawn occasionally
-    if state.season_state.season == crate::simulation::seasons::Season::Winter
-        && rng::gen_range(0.0, 1.0) < 0.1
-    {
-        use crate::ui::particles::ParticleType;
-        // Find active houses
-        for zone in &state.zones {
-            if !zone.dormant && zone.activity > 0.0 {
-                if let Some(template) = state
-                    .zone_templates
-                    .iter()
-                    .find(|t| t.id == zone.template_id)
-                {
-                    if template.category == crate::data::ZoneCategory::Residential {
-                        if let Some(rect) = template.map_rect {
-                            let tile_size = crate::ui::map_renderer::TILE_SIZE;
-                            // Randomly pick a spot on roof properly
-                            let center_x = (rect.x as f32 + rect.w as f32 * 0.5) * tile_size;
-                            let center_y = (rect.y as f32 + rect.h as f32 * 0.2) * tile_size; // Top of building
-
-                            state.particle_system.spawn(
-                                macroquad::prelude::vec2(center_x, center_y),
-                                macroquad::prelude::vec2(
-                                    rng::gen_range(-5.0, 5.0),
-                                    rng::gen_range(-20.0, -10.0),
-                                ),
-                                rng::gen_range(2.0, 4.0),
-                                rng::gen_range(4.0, 8.0),
-                                macroquad::prelude::Color::new(0.8, 0.8, 0.8, 0.4),
-                                ParticleType::Smoke,
-                            );
-                        }
-                    }
-                }
-            }
-        }
+    // Warn ahead of time if the stockpile is draining
+    let materials_per_hour = net_delta.materials / game_minutes * 60.0;
+    if crate::simulation::warnings::check_materials(state, materials_per_hour) {
+        reactions::broadcast(&mut state.agents, TownEvent::Shortage);
     }
 
-    // Update stats and check achievements
-    update_stats_and_achievements(state, net_delta.materials.max(0.0));
+    // Production now depends on the last restore/upgrade, so it can no longer be undone
+    crate::undo::expire(state);
+}
+
+/// One tick of the town's economy: population pressure, building wear, production,
+/// upkeep and construction. Returns the materials/maintenance change it applied.
+fn economy_step(
+    state: &mut crate::data::GameState,
+    bonuses: &crate::data::TechBonuses,
+    active_zones: usize,
+    housing_capacity: f32,
+    happiness: f32,
+    game_minutes: f32,
+) -> crate::data::ResourceDelta {
+    // Population grows based on attractiveness and capacity, boosted by active zones.
+    // Unhappy villagers don't draw newcomers (and a miserable town empties out)
+    let growth_bonus = active_zones as f32 * 0.5;
+    state.population.tick(
+        &state.config.population_curve,
+        state.resources.attractiveness * (1.0 + growth_bonus),
+        housing_capacity,
+        happiness,
+        game_minutes, // Use game time, not real time
+    );
 
-    state.resources.apply_delta(&net_delta);
+    // Understaffed buildings slowly wear down (efficiency techs slow it)
+    crate::simulation::neglect::apply_neglect(
+        state,
+        game_minutes / 60.0,
+        bonuses.maintenance_factor,
+    );
+
+    // Calculate and apply resource changes (shared with GameState::net_production_rate)
+    let rates = crate::simulation::production::production_rates(state, bonuses);
+
+    // Update flat stats directly
+    // No soft cap or decay: both are recomputed from production every tick
+    state.resources.attractiveness = rates.attractiveness.max(0.0);
+    state.resources.stability = rates.stability.max(0.0);
+
+    // Builders on site push construction forward
+    crate::simulation::construction::advance_construction(state, game_minutes);
+
+    // Net resource changes (output - upkeep) × game time, less population maintenance cost
+    let delta = crate::data::ResourceDelta {
+        materials: rates.per_minute.materials * game_minutes,
+        maintenance: (rates.per_minute.maintenance - state.calculate_maintenance_cost())
+            * game_minutes,
+        attractiveness: 0.0, // Set directly above
+        stability: 0.0,      // Set directly above
+    };
+    state.resources.apply_delta(&delta);
+    delta
 }
 
 /// Update game stats and check for achievement unlocks
 fn update_stats_and_achievements(state: &mut crate::data::GameState, resources_gained: f32) {
     use crate::data::ZoneCategory;
-    use crate::simulation::seasons::Season;
 
     // Update stats
     state.stats.add_resources(resources_gained);
@@ -604,15 +552,6 @@ fn update_stats_and_achievements(state: &mut crate::data::GameState, resources_g
         state.achievements.unlock("veteran");
     }
 
-    // Check winter survivor (when season changes FROM winter)
-    if state.season_state.season == Season::Spring && state.season_state.day_in_season < 1.0 {
-        // Just transitioned from winter
-        if state.stats.winters_survived == 0 {
-            state.stats.winters_survived = 1;
-            state.achievements.unlock("winter_survivor");
-        }
-    }
-
     // Check dynasty achievements
     if state.dynasty.hall_of_heroes.len() >= 1 {
         state.achievements.unlock("remembered");
@@ -649,6 +588,30 @@ fn update_stats_and_achievements(state: &mut crate::data::GameState, resources_g
     }
 }
 
+/// Villagers near one of the battered buildings saw the storm hit it
+fn witness_storm(state: &mut crate::data::GameState) {
+    let hit: Vec<_> = state
+        .zones
+        .iter()
+        .filter(|z| !z.dormant)
+        .filter_map(|z| {
+            let template = state.get_template(&z.template_id)?;
+            let rect = z.footprint(template)?;
+            Some((template.name.clone(), rect.world_center()))
+        })
+        .collect();
+    if hit.is_empty() {
+        return;
+    }
+    let (name, pos) = hit[state.rng.range_int(0, hit.len())].clone();
+    // Everyone already reacted to the storm; witnesses just remember where it hit
+    let rumor = crate::simulation::gossip::Rumor::already_felt(
+        format!("The storm battered the {}", name),
+        state.game_time_hours,
+    );
+    crate::simulation::gossip::witness(state, pos, rumor);
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -668,6 +631,110 @@ mod tests {
         assert_eq!(timer.update(0.5), 1);
     }
 
+    #[test]
+    fn test_batched_ticks_match_real_time() {
+        // A working town: a few restored, busy zones and some people
+        let mut town = crate::data::GameState::default();
+        town.zone_templates
+            .extend(crate::assets::load_zones().unwrap());
+        town.setup_starting_town();
+        for zone in town.zones.iter_mut().take(3) {
+            zone.restore(1.0);
+            zone.construction_state = crate::zones::ConstructionState::Complete;
+            zone.activity = 1.0;
+        }
+        town.population.add(4.0);
+        town.resources.materials = 50.0;
+        // Storms are random; keep them out so both runs see the same weather
+        town.config.disaster_multiplier = 0.0;
+
+        let mut real_time = town.clone();
+        let mut turbo = town;
+
+        // An hour of play, one tick at a time versus a single turbo batch
+        for _ in 0..600 {
+            simulate_ticks(&mut real_time, 1, 0.1);
+        }
+        simulate_ticks(&mut turbo, 600, 0.1);
+
+        eprintln!(
+            "mat {} {} maint {} {} pop {} {}",
+            real_time.resources.materials,
+            turbo.resources.materials,
+            real_time.resources.maintenance,
+            turbo.resources.maintenance,
+            real_time.population.value(),
+            turbo.population.value()
+        );
+        let close = |a: f32, b: f32| (a - b).abs() <= a.abs().max(b.abs()) * 0.02 + 0.05;
+        assert!((real_time.game_time_hours - turbo.game_time_hours).abs() < 0.001);
+        assert!(real_time.resources.materials != 50.0);
+        assert!(close(
+            real_time.resources.materials,
+            turbo.resources.materials
+        ));
+        assert!(close(
+            real_time.resources.maintenance,
+            turbo.resources.maintenance
+        ));
+        assert!(close(
+            real_time.population.value(),
+            turbo.population.value()
+        ));
+        assert_eq!(real_time.agents.len(), turbo.agents.len());
+    }
+
+    #[test]
+    fn test_growth_arrivals_are_chronicled_and_heroes_stay() {
+        let mut state = crate::data::GameState::default();
+        state.population.add(1.0);
+        simulate_ticks(&mut state, 1, 0.1);
+        assert_eq!(state.agents.len(), 1);
+        assert_eq!(state.town_chronicle.recent_population_changes(5).len(), 1);
+
+        // The town shrinks back, but the hero at the tail isn't the one to go
+        let mut rng = crate::simulation::rng::SimRng::new(1);
+        let mut hero =
+            crate::simulation::agents::Agent::new(99, macroquad::prelude::Vec2::ZERO, &mut rng);
+        hero.immortalized = true;
+        state.agents.push(hero);
+        simulate_ticks(&mut state, 1, 0.1);
+        assert_eq!(state.agents.len(), 1);
+        assert_eq!(state.agents[0].id, 99);
+    }
+
+    #[test]
+    fn test_camera_does_not_steer_the_simulation_rng() {
+        // A crowd big enough for off-screen agents to update less often
+        let mut town = crate::data::GameState::default();
+        town.settings.agent_lod_threshold = 10;
+        let crowd = 30;
+        town.population.add(crowd as f32);
+        let mut rng = crate::simulation::rng::SimRng::new(1);
+        for id in 0..crowd {
+            let pos = macroquad::prelude::vec2(id as f32 * 10.0, 0.0);
+            town.agents
+                .push(crate::simulation::agents::Agent::new(id, pos, &mut rng));
+        }
+
+        let mut watched = town.clone();
+        watched.view_rect = Some(macroquad::prelude::Rect::new(0.0, 0.0, 50.0, 50.0));
+        let mut unwatched = town;
+        unwatched.view_rect = None;
+        for _ in 0..20 {
+            simulate_ticks(&mut watched, 1, 0.1);
+            simulate_ticks(&mut unwatched, 1, 0.1);
+        }
+        eprintln!(
+            "{:?} {:?} {:?} {}",
+            watched.rng,
+            unwatched.rng,
+            watched.agent_rng,
+            watched.agents.len()
+        );
+        assert_eq!(watched.rng.next_u64(), unwatched.rng.next_u64());
+    }
+
     #[test]
     fn test_offline_hours_calculation() {
         let tracker = TimeTracker {
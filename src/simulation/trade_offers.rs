@@ -0,0 +1,108 @@
+//! Trade offers - other towns periodically ask to buy goods on a regular route
+
+use crate::data::GameState;
+use crate::narrative::LogCategory;
+use crate::region::TradeGood;
+
+/// Most offers open at once
+const MAX_OPEN_OFFERS: usize = 3;
+
+/// Chance each game day that a new offer arrives
+const DAILY_OFFER_CHANCE: f32 = 0.5;
+
+/// How long an offer stays open (game days)
+const OFFER_LIFETIME_DAYS: f32 = 5.0;
+
+/// Expire old offers and roll for a new one once per game day
+pub fn update(state: &mut GameState) {
+    for offer in state.trade_manager.expire_offers(state.game_time_hours) {
+        let town = town_name(state, offer.town_id);
+        state.log.add(
+            state.game_time_hours,
+            format!("{}'s offer for {} has lapsed.", town, offer.good.name()),
+            LogCategory::Event,
+        );
+    }
+
+    let day = (state.game_time_hours / 24.0) as u32;
+    if day <= state.trade_manager.last_offer_day {
+        return;
+    }
+    state.trade_manager.last_offer_day = day;
+
+    if state.trade_manager.offers.len() >= MAX_OPEN_OFFERS || !state.rng.chance(DAILY_OFFER_CHANCE)
+    {
+        return;
+    }
+
+    // Any other settled town may come asking
+    let active = state.region_map.active_town_id;
+    let buyers: Vec<u32> = state
+        .region_map
+        .nodes
+        .iter()
+        .filter(|n| n.settled && Some(n.id) != active)
+        .map(|n| n.id)
+        .collect();
+    if buyers.is_empty() {
+        return;
+    }
+
+    let town_id = buyers[state.rng.range_int(0, buyers.len())];
+    let goods = TradeGood::all();
+    let good = goods[state.rng.range_int(0, goods.len())];
+    let amount = (state.rng.range(10.0, 30.0) / 5.0).round() * 5.0;
+    // The buyer pays by what the good is worth to them
+    let value = state
+        .region_map
+        .get_node(town_id)
+        .map_or(good.base_value(), |n| {
+            good.local_value(&n.resource_potentials)
+        });
+    let payment = (amount * value * state.rng.range(1.2, 1.8)).round();
+    let expires_at = state.game_time_hours + OFFER_LIFETIME_DAYS * 24.0;
+
+    state
+        .trade_manager
+        .add_offer(town_id, good, amount, payment, expires_at);
+    let town = town_name(state, town_id);
+    state.log.add(
+        state.game_time_hours,
+        format!(
+            "{} will buy {:.0} {}/trip for {:.0} Materials",
+            town,
+            amount,
+            good.name(),
+            payment
+        ),
+        LogCategory::Event,
+    );
+}
+
+/// Settle deliveries for paid routes: goods leave the stockpile and payment comes in.
+/// Short stock means a partial delivery and a proportionally smaller payment.
+pub fn deliver(state: &mut GameState, route_id: u32, cargo_multiplier: f32) {
+    let route = match state.trade_manager.routes.iter().find(|r| r.id == route_id) {
+        Some(route) => route.clone(),
+        None => return,
+    };
+    if route.payment_per_trip <= 0.0 || Some(route.from_town) != state.region_map.active_town_id {
+        return;
+    }
+
+    let wanted = route.amount_per_trip * cargo_multiplier;
+    let stock = state.resources.get_mut(route.good.resource());
+    let shipped = wanted.min((*stock).max(0.0));
+    *stock -= shipped;
+    if wanted > 0.0 {
+        state.resources.materials += route.payment_per_trip * shipped / wanted;
+    }
+}
+
+fn town_name(state: &GameState, town_id: u32) -> String {
+    state
+        .region_map
+        .get_node(town_id)
+        .map(|n| n.name.clone())
+        .unwrap_or("Unknown".to_string())
+}
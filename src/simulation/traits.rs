@@ -1,6 +1,6 @@
 //! Agent traits for personality and emergent storytelling
 
-use macroquad_toolkit::rng;
+use super::rng::SimRng;
 use serde::{Deserialize, Serialize};
 
 /// Personality traits that affect agent behavior
@@ -109,6 +109,18 @@ impl Trait {
         }
     }
 
+    /// How strongly an event moves spirit (bad news is `negative`)
+    pub fn reaction_modifier(&self, negative: bool) -> f32 {
+        match (self, negative) {
+            (Trait::Sensitive, _) => 2.0,
+            (Trait::Tough, true) => 0.4,
+            (Trait::Optimist, true) => 0.75,
+            (Trait::Optimist, false) => 1.5,
+            (Trait::Pessimist, true) => 1.25,
+            _ => 1.0,
+        }
+    }
+
     /// Social need decay multiplier
     pub fn social_decay_modifier(&self) -> f32 {
         match self {
@@ -119,7 +131,7 @@ impl Trait {
 }
 
 /// Generate random traits for a new agent (1-3 traits)
-pub fn generate_random_traits() -> Vec<Trait> {
+pub fn generate_random_traits(rng: &mut SimRng) -> Vec<Trait> {
     let all_traits = [
         Trait::Hardworking,
         Trait::Lazy,
@@ -138,11 +150,11 @@ pub fn generate_random_traits() -> Vec<Trait> {
         Trait::Sensitive,
     ];
 
-    let count = rng::gen_range(1u32, 4) as usize;
+    let count = rng.range_int(1, 4);
     let mut traits = Vec::with_capacity(count);
 
     for _ in 0..count {
-        let idx = rng::gen_range(0, all_traits.len());
+        let idx = rng.range_int(0, all_traits.len());
         let t = all_traits[idx];
         // Avoid duplicates and conflicting traits
         if !traits.contains(&t) && !conflicts_with(&traits, t) {
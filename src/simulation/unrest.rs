@@ -0,0 +1,93 @@
+//! Unrest - a crowded town with too little holding it together riots
+
+use super::gossip::{self, Rumor};
+use super::reactions::{self, TownEvent};
+use crate::data::GameState;
+use crate::narrative::{ChronicleEventType, LogCategory};
+
+/// Stability each villager costs; a growing town needs civic buildings to keep up
+const STRAIN_PER_VILLAGER: f32 = 0.01;
+
+/// Below this stability the town is in unrest
+pub const UNREST_THRESHOLD: f32 = 0.1;
+
+/// A hamlet smaller than this is too few people to riot
+const MIN_RIOT_POPULATION: f32 = 5.0;
+
+/// Chance per game hour of a riot at zero stability (less the closer to the threshold)
+const RIOT_CHANCE_PER_HOUR: f32 = 0.1;
+
+/// Condition a riot knocks off the building it breaks out at
+const RIOT_DAMAGE: f32 = 0.15;
+
+/// Riots never take a building below this condition
+const RIOT_FLOOR: f32 = 0.1;
+
+/// Stability the population costs (subtracted in `production_rates`)
+pub fn population_strain(state: &GameState) -> f32 {
+    state.population.value() * STRAIN_PER_VILLAGER
+}
+
+/// Whether stability is low enough (and the town big enough) for riots
+pub fn in_unrest(state: &GameState) -> bool {
+    state.resources.stability < UNREST_THRESHOLD && state.population.value() >= MIN_RIOT_POPULATION
+}
+
+/// Roll for a riot while the town is in unrest
+pub fn update(state: &mut GameState, hours: f32) {
+    if !in_unrest(state) {
+        return;
+    }
+    let severity = 1.0 - state.resources.stability / UNREST_THRESHOLD;
+    if !state.rng.chance(RIOT_CHANCE_PER_HOUR * severity * hours) {
+        return;
+    }
+
+    let operating: Vec<usize> = (0..state.zones.len())
+        .filter(|&i| state.zones[i].is_operating())
+        .collect();
+    if operating.is_empty() {
+        return;
+    }
+    let index = operating[state.rng.range_int(0, operating.len())];
+    let zone = &mut state.zones[index];
+    zone.condition = (zone.condition - RIOT_DAMAGE).max(RIOT_FLOOR);
+
+    let zone = &state.zones[index];
+    let template = state.get_template(&zone.template_id);
+    let name = template.map_or("a building".to_string(), |t| t.name.clone());
+    let site = state.zone_rect(zone);
+
+    reactions::broadcast(&mut state.agents, TownEvent::Unrest);
+    state.log.add(
+        state.game_time_hours,
+        format!("Unrest! A riot broke out at the {}.", name),
+        LogCategory::Event,
+    );
+    state.town_chronicle.record(
+        state.game_time_hours,
+        ChronicleEventType::Disaster {
+            description: format!("Riot at the {}", name),
+        },
+    );
+    if let Some(rect) = site {
+        let rumor = Rumor::already_felt(
+            format!("A riot broke out at the {}", name),
+            state.game_time_hours,
+        );
+        gossip::witness(state, rect.world_center(), rumor);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_empty_town_does_not_riot() {
+        let mut state = GameState::default();
+        state.resources.stability = 0.0;
+        assert!(!in_unrest(&state));
+        assert_eq!(population_strain(&state), 0.0);
+    }
+}
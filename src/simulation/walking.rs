@@ -0,0 +1,124 @@
+//! Villager walking - heading for a spot, routing around water, and giving up
+//! on walks that can't get anywhere
+
+use macroquad::prelude::*;
+
+use super::agents::{Agent, WorldInfo};
+use super::rng::SimRng;
+
+/// Steps in a row a villager can find no way forward before giving up on a walk
+pub const MAX_STALLED_STEPS: u32 = 30;
+
+/// Random spots tried when picking somewhere dry to wander to
+const WANDER_ATTEMPTS: usize = 8;
+
+impl Agent {
+    /// Step toward `target`, heading straight there over open ground and
+    /// routing around water (see `Walkable::next_waypoint`) when it's in the way.
+    /// Returns false if there was no way forward.
+    pub fn step_toward(&mut self, target: Vec2, world: &WorldInfo, delta: f32) -> bool {
+        let reach = self.speed * world.speed_multiplier * delta;
+        let direct = self.pos + (target - self.pos).normalize_or_zero() * reach;
+        // Anyone already standing in water may walk out of it
+        let open = world.walkable.clear_line(self.pos, target) && world.walkable.at(direct);
+        if !world.walkable.at(self.pos) || open {
+            self.pos = direct;
+            return true;
+        }
+        match world.walkable.next_waypoint(self.pos, target) {
+            Some(waypoint) => {
+                self.pos += (waypoint - self.pos).normalize_or_zero() * reach;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Take a step of a walk to `target`. Returns true once the walk has stalled
+    /// for `MAX_STALLED_STEPS` steps and should be given up.
+    pub fn walk(&mut self, target: Vec2, world: &WorldInfo, delta: f32) -> bool {
+        if self.step_toward(target, world, delta) {
+            self.stalled_steps = 0;
+            return false;
+        }
+        self.stalled_steps += 1;
+        if self.stalled_steps < MAX_STALLED_STEPS {
+            return false;
+        }
+        self.stalled_steps = 0;
+        true
+    }
+
+    /// Somewhere dry to wander to (or nowhere, after a few tries all land in water)
+    pub fn pick_random_target(&self, world: &WorldInfo, rng: &mut SimRng) -> Vec2 {
+        for _ in 0..WANDER_ATTEMPTS {
+            let target = vec2(rng.range(100.0, 1500.0), rng.range(100.0, 1500.0));
+            if world.walkable.at(target) {
+                return target;
+            }
+        }
+        self.pos
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::simulation::agents::AgentState;
+
+    fn world() -> WorldInfo {
+        WorldInfo {
+            markets: Vec::new(),
+            workshops: Vec::new(),
+            parks: Vec::new(),
+            construction_sites: Vec::new(),
+            workplaces: Vec::new(),
+            crowded: Vec::new(),
+            game_hour: 12.0,
+            season: Default::default(),
+            speed_multiplier: 1.0,
+            amenity_version: 0,
+            walkable: Default::default(),
+        }
+    }
+
+    #[test]
+    fn test_villager_takes_the_bridge_and_gives_up_on_the_unreachable() {
+        use crate::simulation::map::{TileType, WorldMap};
+        use crate::ui::map_renderer::TILE_SIZE;
+
+        let mut rng = SimRng::new(1);
+        let mut map = WorldMap::default();
+        let mut town = world();
+        town.walkable = map.walkable();
+
+        // From the west bank to the east bank, up north of the only bridge
+        let start = vec2(40.5 * TILE_SIZE, 5.5 * TILE_SIZE);
+        let target = vec2(48.5 * TILE_SIZE, 5.5 * TILE_SIZE);
+        let mut agent = Agent::new(1, start, &mut rng);
+        agent.state = AgentState::Wandering { target };
+        for _ in 0..2000 {
+            agent.update(0.1, &town, &mut rng);
+            assert!(town.walkable.at(agent.pos), "waded in at {}", agent.pos);
+            if agent.pos.distance(target) < 10.0 {
+                break;
+            }
+        }
+        assert!(agent.pos.distance(target) < 10.0);
+
+        // Without the bridge there's no way across, so they stop trying
+        map.set_rect(46, 0, 2, 50, TileType::Water, None);
+        town.walkable = map.walkable();
+        agent.pos = start;
+        agent.state = AgentState::Wandering { target };
+        for _ in 0..MAX_STALLED_STEPS {
+            agent.update(0.1, &town, &mut rng);
+        }
+        assert!(!matches!(agent.state, AgentState::Wandering { target: t } if t == target));
+
+        // Wander targets are always on dry land
+        for _ in 0..100 {
+            assert!(town.walkable.at(agent.pick_random_target(&town, &mut rng)));
+        }
+    }
+}
@@ -0,0 +1,73 @@
+//! Predictive resource warnings - tell the player before a stockpile runs dry
+
+use crate::data::GameState;
+use crate::narrative::LogCategory;
+
+/// Warn when materials will run out within this many game hours
+pub const MATERIALS_WARNING_HOURS: f32 = 12.0;
+
+/// Minimum game hours between repeated warnings while still draining
+const WARNING_COOLDOWN_HOURS: f32 = 6.0;
+
+/// Current low-materials warning (transient, rebuilt from the live rate)
+#[derive(Debug, Clone, Default)]
+pub struct MaterialsWarning {
+    /// Estimated game hours until materials hit zero, while the warning is active
+    pub hours_left: Option<f32>,
+    /// Game time the warning was last logged (for throttling)
+    last_warned: Option<f32>,
+}
+
+/// Game hours until `stock` is empty at `rate_per_hour`, or None if it isn't draining
+pub fn hours_until_empty(stock: f32, rate_per_hour: f32) -> Option<f32> {
+    if rate_per_hour >= 0.0 || stock <= 0.0 {
+        None
+    } else {
+        Some(stock / -rate_per_hour)
+    }
+}
+
+/// Check the net materials rate and raise (or clear) the low-materials warning.
+/// Returns true when a new warning was issued.
+pub fn check_materials(state: &mut GameState, materials_per_hour: f32) -> bool {
+    let now = state.game_time_hours;
+    let warning = &mut state.materials_warning;
+
+    let hours = match hours_until_empty(state.resources.materials, materials_per_hour) {
+        Some(hours) if hours <= MATERIALS_WARNING_HOURS => hours,
+        _ => {
+            warning.hours_left = None;
+            // Production recovered, so the next shortfall warns straight away
+            if materials_per_hour >= 0.0 {
+                warning.last_warned = None;
+            }
+            return false;
+        }
+    };
+
+    warning.hours_left = Some(hours);
+    let due = warning
+        .last_warned
+        .is_none_or(|last| now - last >= WARNING_COOLDOWN_HOURS);
+    if due {
+        warning.last_warned = Some(now);
+        state.log.add(
+            now,
+            format!("Materials running out in ~{:.0}h", hours.ceil()),
+            LogCategory::System,
+        );
+    }
+    due
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hours_until_empty() {
+        assert_eq!(hours_until_empty(10.0, 1.0), None);
+        assert_eq!(hours_until_empty(0.0, -1.0), None);
+        assert_eq!(hours_until_empty(10.0, -2.0), Some(5.0));
+    }
+}
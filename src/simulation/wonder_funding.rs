@@ -0,0 +1,107 @@
+//! Wonder funding - manual contributions and automatic funding from surplus materials
+
+use crate::data::GameState;
+use crate::narrative::{ChronicleEventType, LogCategory};
+use crate::scene::Scene;
+use crate::simulation::reactions::{self, TownEvent};
+
+/// Put materials into the wonder at `node_id`, announcing finished stages and wonders.
+/// Returns the materials actually used.
+pub fn contribute(state: &mut GameState, node_id: u32, amount: f32) -> f32 {
+    let node = match state.region_map.get_node_mut(node_id) {
+        Some(node) => node,
+        None => return 0.0,
+    };
+    let wonder_site = match node.wonder_site.as_mut() {
+        Some(site) => site,
+        None => return 0.0,
+    };
+
+    let (used, stage_done, wonder_done) = wonder_site.contribute(amount, state.game_time_hours);
+    if used <= 0.0 {
+        return 0.0;
+    }
+    state.resources.materials -= used;
+
+    let wonder = wonder_site.wonder;
+    if stage_done {
+        let stage_name = wonder_site
+            .current_stage
+            .checked_sub(1)
+            .and_then(|i| wonder.stages().get(i).map(|s| s.name.clone()))
+            .unwrap_or("Stage".to_string());
+        let stages_total = wonder.stages().len();
+        state.log.add(
+            state.game_time_hours,
+            format!(
+                "{}: {} completed! ({}/{})",
+                wonder.name(),
+                stage_name,
+                wonder_site.current_stage,
+                stages_total
+            ),
+            LogCategory::Milestone,
+        );
+        state.town_chronicle.record(
+            state.game_time_hours,
+            ChronicleEventType::Special {
+                description: format!("{} of {} was finished", stage_name, wonder.name()),
+            },
+        );
+    }
+
+    if wonder_done {
+        state.dynasty.add_wonder(wonder);
+        state.dynasty.add_legacy_points(100);
+        crate::simulation::migration::on_wonder_completed(state, wonder);
+
+        // The whole region celebrates; the lift in spirit wears off as it usually does
+        state.wonder_celebration.start(node_id, wonder);
+        reactions::broadcast(&mut state.agents, TownEvent::WonderCompleted);
+
+        state.log.add(
+            state.game_time_hours,
+            format!(
+                "🏛️ {} has been completed! (+100 Legacy Points)",
+                wonder.name()
+            ),
+            LogCategory::Milestone,
+        );
+
+        // Check if this triggers ending
+        if wonder.is_endgame() {
+            state.log.add(
+                state.game_time_hours,
+                "The Cloud Spire reaches into the heavens. Your legacy is complete.".to_string(),
+                LogCategory::Milestone,
+            );
+            state.ending.start();
+            state.scene_manager.transition_to(Scene::Ending);
+        }
+    }
+
+    used
+}
+
+/// Send a slice of this tick's material surplus to the first wonder under construction
+pub fn auto_fund(state: &mut GameState, surplus: f32) {
+    if !state.settings.wonder_auto_fund || surplus <= 0.0 {
+        return;
+    }
+
+    let node_id = match state
+        .region_map
+        .nodes
+        .iter()
+        .find(|n| n.wonder_site.as_ref().is_some_and(|w| !w.completed))
+    {
+        Some(node) => node.id,
+        None => return,
+    };
+
+    let amount =
+        (surplus * state.settings.wonder_auto_fund_share).min(state.resources.materials.max(0.0));
+    if amount > 0.0 {
+        contribute(state, node_id, amount);
+    }
+}
@@ -0,0 +1,174 @@
+//! Workplaces - every villager (except builders) is assigned a zone to work at,
+//! spread evenly across the town's working buildings
+
+use super::agents::{Agent, AgentState, Job};
+use super::stockpiles;
+use crate::data::{GameState, ZoneCategory, ZoneTemplate};
+use crate::zones::ZoneId;
+use macroquad::prelude::Vec2;
+
+/// Jobs that are given a workplace (builders go wherever there's building to do)
+const JOBS: [Job; 5] = [
+    Job::Laborer,
+    Job::Farmer,
+    Job::Cook,
+    Job::Scavenger,
+    Job::Hauler,
+];
+
+/// Whether a working zone of this kind takes workers (homes don't)
+pub fn is_workplace(template: &ZoneTemplate) -> bool {
+    template.category != ZoneCategory::Residential
+}
+
+/// Whether a job is at home in a zone of this kind
+fn suits(job: Job, template: &ZoneTemplate) -> bool {
+    match job {
+        Job::Farmer => template.food_output > 0.0,
+        Job::Cook => template.category == ZoneCategory::Market,
+        Job::Laborer | Job::Scavenger | Job::Hauler => {
+            template.category == ZoneCategory::Infrastructure
+        }
+        Job::Builder => false,
+    }
+}
+
+/// Working zones that take workers, with their centres (for agents to walk to)
+pub fn positions(state: &GameState) -> Vec<(ZoneId, Vec2)> {
+    state
+        .zones
+        .iter()
+        .filter(|z| z.is_operating())
+        .filter(|z| state.get_template(&z.template_id).is_some_and(is_workplace))
+        .filter_map(|z| state.zone_rect(z).map(|rect| (z.id, rect.world_center())))
+        .collect()
+}
+
+/// Villagers working at a zone: those assigned to it, plus builders on its site
+pub fn workers_at(agents: &[Agent], zone: ZoneId) -> impl Iterator<Item = &Agent> {
+    agents.iter().filter(move |agent| {
+        agent.workplace == Some(zone)
+            || matches!(agent.state, AgentState::Building { zone: site, .. } if site == zone)
+    })
+}
+
+/// Release villagers whose workplace closed or stood idle with its store full
+/// (or who became builders or are leaving town) and give everyone without one
+/// the least staffed zone that suits their job, favouring zones that make what
+/// the town is short of
+pub fn assign(state: &mut GameState) {
+    // Open workplaces, the jobs that suit each, and whether they make something scarce
+    let open: Vec<(ZoneId, Vec<Job>, bool)> = state
+        .zones
+        .iter()
+        .filter(|z| z.is_operating())
+        .filter_map(|z| {
+            let template = state.get_template(&z.template_id)?;
+            if !is_workplace(template) || stockpiles::is_storage_full(state, template) {
+                return None;
+            }
+            let jobs = JOBS.into_iter().filter(|&job| suits(job, template));
+            let scarce = stockpiles::makes_scarce(state, template);
+            Some((z.id, jobs.collect(), scarce))
+        })
+        .collect();
+
+    for agent in &mut state.agents {
+        let still_open = agent
+            .workplace
+            .is_some_and(|id| open.iter().any(|(open_id, _, _)| *open_id == id));
+        let leaving = matches!(agent.state, AgentState::Leaving { .. });
+        if agent.job == Job::Builder || leaving || !still_open {
+            agent.workplace = None;
+        }
+    }
+
+    for i in 0..state.agents.len() {
+        let job = state.agents[i].job;
+        if job == Job::Builder
+            || state.agents[i].workplace.is_some()
+            || matches!(state.agents[i].state, AgentState::Leaving { .. })
+        {
+            continue;
+        }
+        // Anywhere will do if nothing suits the job
+        let suited: Vec<(ZoneId, bool)> = open
+            .iter()
+            .filter(|(_, jobs, _)| jobs.contains(&job))
+            .map(|(id, _, scarce)| (*id, *scarce))
+            .collect();
+        let suited = if suited.is_empty() {
+            open.iter().map(|(id, _, scarce)| (*id, *scarce)).collect()
+        } else {
+            suited
+        };
+        // Short supplies come first
+        let any_scarce = suited.iter().any(|(_, scarce)| *scarce);
+        let candidates: Vec<ZoneId> = suited
+            .into_iter()
+            .filter(|(_, scarce)| *scarce || !any_scarce)
+            .map(|(id, _)| id)
+            .collect();
+        let staff = |id: ZoneId| {
+            state
+                .agents
+                .iter()
+                .filter(|a| a.workplace == Some(id))
+                .count()
+        };
+        let least_staffed = candidates.into_iter().min_by_key(|&id| staff(id));
+        state.agents[i].workplace = least_staffed;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::simulation::rng::SimRng;
+    use macroquad::prelude::vec2;
+
+    #[test]
+    fn test_villagers_spread_across_workplaces_and_leave_closed_ones() {
+        let mut state = GameState::default();
+        state
+            .zone_templates
+            .extend(crate::assets::load_zones().unwrap());
+        state.setup_starting_town();
+        for zone in &mut state.zones {
+            zone.restore(1.0);
+        }
+        let mut rng = SimRng::new(1);
+        for id in 0..4 {
+            state.agents.push(Agent::new(id, vec2(0.0, 0.0), &mut rng));
+        }
+
+        assign(&mut state);
+        let workshops: Vec<ZoneId> = open_ids(&state, ZoneCategory::Infrastructure);
+        assert!(!workshops.is_empty());
+        for id in &workshops {
+            assert!(workers_at(&state.agents, *id).count() >= 4 / workshops.len());
+        }
+
+        // Closing a workplace sends its workers elsewhere
+        let closed = state.agents[0].workplace.unwrap();
+        if let Some(index) = state.zone_index(closed) {
+            state.zones[index].disabled = true;
+        }
+        assign(&mut state);
+        assert!(state.agents.iter().all(|a| a.workplace != Some(closed)));
+        assert!(state.agents.iter().all(|a| a.workplace.is_some()));
+    }
+
+    fn open_ids(state: &GameState, category: ZoneCategory) -> Vec<ZoneId> {
+        state
+            .zones
+            .iter()
+            .filter(|z| {
+                state
+                    .get_template(&z.template_id)
+                    .is_some_and(|t| t.category == category)
+            })
+            .map(|z| z.id)
+            .collect()
+    }
+}
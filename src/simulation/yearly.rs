@@ -0,0 +1,94 @@
+//! Yearly recap - counts winters survived and summarises each year when spring returns
+
+use serde::{Deserialize, Serialize};
+
+use crate::data::GameState;
+use crate::narrative::ChronicleEventType;
+use crate::simulation::seasons::Season;
+
+/// Most chronicle highlights listed in a summary
+const MAX_HIGHLIGHTS: usize = 5;
+
+/// Town figures at the start of a year (saved, so a recap spans sessions)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct YearSnapshot {
+    pub started_at: f32,
+    pub population: f32,
+    pub resources_collected: f32,
+    pub agents_born: u32,
+    pub agents_died: u32,
+    #[serde(default)]
+    pub agents_left: u32,
+    pub zones_restored: u32,
+}
+
+impl YearSnapshot {
+    pub fn take(state: &GameState) -> Self {
+        Self {
+            started_at: state.game_time_hours,
+            population: state.population.value(),
+            resources_collected: state.stats.resources_collected,
+            agents_born: state.stats.agents_born,
+            agents_died: state.stats.agents_died,
+            agents_left: state.stats.agents_left,
+            zones_restored: state.stats.zones_restored,
+        }
+    }
+}
+
+/// The recap shown when a year ends
+#[derive(Debug, Clone)]
+pub struct YearSummary {
+    pub year: u32,
+    pub population_change: f32,
+    pub materials_gained: f32,
+    pub births: u32,
+    pub deaths: u32,
+    pub departures: u32,
+    pub zones_built: u32,
+    /// Notable chronicle entries from the year
+    pub highlights: Vec<String>,
+}
+
+impl YearSummary {
+    fn between(state: &GameState, start: &YearSnapshot) -> Self {
+        let highlights: Vec<String> = state
+            .town_chronicle
+            .events()
+            .iter()
+            .filter(|e| e.timestamp >= start.started_at)
+            .filter(|e| !matches!(e.event_type, ChronicleEventType::SeasonChanged { .. }))
+            .map(|e| e.display_text())
+            .collect();
+        let skip = highlights.len().saturating_sub(MAX_HIGHLIGHTS);
+
+        Self {
+            // The year that just ended (year() has already rolled over)
+            year: state.season_state.year(),
+            population_change: state.population.value() - start.population,
+            materials_gained: state.stats.resources_collected - start.resources_collected,
+            births: state.stats.agents_born.saturating_sub(start.agents_born),
+            deaths: state.stats.agents_died.saturating_sub(start.agents_died),
+            departures: state.stats.agents_left.saturating_sub(start.agents_left),
+            zones_built: state
+                .stats
+                .zones_restored
+                .saturating_sub(start.zones_restored),
+            highlights: highlights.into_iter().skip(skip).collect(),
+        }
+    }
+}
+
+/// Called exactly once per season transition, with the season that just ended
+pub fn on_season_changed(state: &mut GameState, previous: Season) {
+    if previous != Season::Winter {
+        return;
+    }
+
+    state.stats.winters_survived += 1;
+    state.achievements.unlock("winter_survivor");
+
+    // Leaving winter closes the year
+    state.year_summary = Some(YearSummary::between(state, &state.year_start));
+    state.year_start = YearSnapshot::take(state);
+}
@@ -0,0 +1,52 @@
+//! Zone output history - the last few days of each working zone's output, for tooltips
+
+use crate::data::GameState;
+use crate::simulation::production::zone_net_per_hour;
+use std::collections::VecDeque;
+
+/// Days of output kept per zone
+pub const HISTORY_DAYS: usize = 14;
+
+/// Record today's net output for every working zone. Zones that stop working drop
+/// their history, so only active zones hold a buffer.
+pub fn sample(state: &mut GameState) {
+    let samples: Vec<Option<f32>> = state
+        .zones
+        .iter()
+        .map(|zone| zone_net_per_hour(state, zone).map(|net| net.materials))
+        .collect();
+
+    for (zone, sample) in state.zones.iter_mut().zip(samples) {
+        match sample {
+            Some(output) => {
+                if zone.output_history.len() >= HISTORY_DAYS {
+                    zone.output_history.pop_front();
+                }
+                zone.output_history.push_back(output);
+            }
+            None => zone.output_history = VecDeque::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_history_is_bounded_and_only_for_working_zones() {
+        let mut state = GameState::default();
+        state
+            .zone_templates
+            .extend(crate::assets::load_zones().unwrap());
+        state.setup_starting_town();
+        state.zones[0].dormant = false;
+        state.zones[0].condition = 1.0;
+
+        for _ in 0..HISTORY_DAYS + 3 {
+            sample(&mut state);
+        }
+        assert_eq!(state.zones[0].output_history.len(), HISTORY_DAYS);
+        assert!(state.zones[1].output_history.is_empty());
+    }
+}
@@ -0,0 +1,189 @@
+//! Blueprints panel (P) - save a layout of the town's buildings and stamp it elsewhere,
+//! plus the map overlay for picking buildings and placing a stamp
+
+use crate::data::GameState;
+use crate::simulation::camera::Camera2D;
+use crate::ui::map_renderer::TILE_SIZE;
+use crate::ui::theme;
+use crate::ui::theme::colors;
+use crate::zones::blueprint;
+use crate::PlayerAction;
+use macroquad::prelude::*;
+use macroquad_toolkit::ui::draw_ui_text;
+
+const WIDTH: f32 = 280.0;
+const HEADER_H: f32 = 34.0;
+/// Row of draft controls under the header
+const CONTROLS_H: f32 = 40.0;
+const ROW_H: f32 = 34.0;
+const PADDING: f32 = 10.0;
+/// Width the build menu takes on the right when open
+const BUILD_MENU_W: f32 = 350.0;
+
+/// Where the panel sits (right side, left of the build menu), or None while it's hidden
+pub fn panel_rect(state: &GameState) -> Option<Rect> {
+    if !state.show_blueprints {
+        return None;
+    }
+    let rows = state.dynasty.blueprints.len().max(1);
+    let height = HEADER_H + CONTROLS_H + rows as f32 * ROW_H + PADDING;
+    let right = if state.show_build_menu {
+        screen_width() - BUILD_MENU_W
+    } else {
+        screen_width()
+    };
+    Some(Rect::new(right - WIDTH - 10.0, 90.0, WIDTH, height))
+}
+
+/// Draw the saved blueprints and the draft controls
+pub fn draw_blueprints_panel(state: &GameState) -> Option<PlayerAction> {
+    let rect = panel_rect(state)?;
+    let mut action = None;
+
+    theme::draw_panel(rect.x, rect.y, rect.w, rect.h);
+    draw_ui_text(
+        "Blueprints (P to close)",
+        rect.x + PADDING,
+        rect.y + 24.0,
+        20.0,
+        colors::accent(),
+    );
+
+    // Drafting: pick buildings on the map, then save their layout
+    let controls_y = rect.y + HEADER_H;
+    match &state.blueprint_draft {
+        Some(picked) => {
+            draw_ui_text(
+                &format!("{} picked", picked.len()),
+                rect.x + PADDING,
+                controls_y + 20.0,
+                16.0,
+                colors::text(),
+            );
+            if picked.len() >= blueprint::MIN_BLUEPRINT_ZONES
+                && theme::draw_button(rect.x + 100.0, controls_y, 80.0, 30.0, "Save")
+            {
+                let name = blueprint::next_name(&state.dynasty.blueprints);
+                action = Some(PlayerAction::SaveBlueprint(name));
+            }
+            if theme::draw_button(rect.x + 190.0, controls_y, 80.0, 30.0, "Cancel") {
+                action = Some(PlayerAction::DraftBlueprint(false));
+            }
+        }
+        None => {
+            if theme::draw_button(
+                rect.x + PADDING,
+                controls_y,
+                rect.w - PADDING * 2.0,
+                30.0,
+                "New from town buildings",
+            ) {
+                action = Some(PlayerAction::DraftBlueprint(true));
+            }
+        }
+    }
+
+    // Saved layouts, kept by the dynasty
+    let rows_y = controls_y + CONTROLS_H;
+    if state.dynasty.blueprints.is_empty() {
+        draw_ui_text(
+            "No blueprints yet.",
+            rect.x + PADDING,
+            rows_y + 20.0,
+            16.0,
+            LIGHTGRAY,
+        );
+    }
+    for (i, saved) in state.dynasty.blueprints.iter().enumerate() {
+        let y = rows_y + i as f32 * ROW_H;
+        draw_ui_text(
+            &format!("{} ({})", saved.name, saved.zones.len()),
+            rect.x + PADDING,
+            y + 20.0,
+            16.0,
+            colors::text(),
+        );
+        let placing = state.placing_blueprint.as_ref() == Some(&saved.name);
+        let label = if placing { "Cancel" } else { "Place" };
+        if theme::draw_button(rect.x + 160.0, y, 70.0, 28.0, label) {
+            action = Some(PlayerAction::StartPlacingBlueprint(if placing {
+                None
+            } else {
+                Some(saved.name.clone())
+            }));
+        }
+        if theme::draw_button(rect.x + 240.0, y, 30.0, 28.0, "x") {
+            action = Some(PlayerAction::DeleteBlueprint(saved.name.clone()));
+        }
+    }
+
+    action
+}
+
+/// Outline the buildings picked for a draft, or show where a stamp would land
+pub fn draw_map_overlay(state: &GameState, camera: &Camera2D) {
+    if let Some(picked) = &state.blueprint_draft {
+        for rect in picked
+            .iter()
+            .filter_map(|&id| state.zone(id).and_then(|zone| state.zone_rect(zone)))
+        {
+            let pos =
+                camera.world_to_screen(vec2(rect.x as f32 * TILE_SIZE, rect.y as f32 * TILE_SIZE));
+            let width = rect.w as f32 * TILE_SIZE * camera.zoom;
+            let height = rect.h as f32 * TILE_SIZE * camera.zoom;
+            draw_rectangle_lines(pos.x, pos.y, width, height, 3.0, colors::accent());
+        }
+        let mouse: Vec2 = mouse_position().into();
+        draw_ui_text(
+            "Click buildings to add them - Esc to stop",
+            mouse.x + 16.0,
+            mouse.y - 8.0,
+            16.0,
+            colors::accent(),
+        );
+    }
+
+    if let Some(name) = &state.placing_blueprint {
+        draw_stamp_ghost(state, camera, name);
+    }
+}
+
+/// Each building of the blueprint at its spot with the layout's corner under the mouse
+fn draw_stamp_ghost(state: &GameState, camera: &Camera2D, name: &str) {
+    let saved = match state.dynasty.blueprints.iter().find(|b| b.name == name) {
+        Some(saved) => saved,
+        None => return,
+    };
+    let mouse: Vec2 = mouse_position().into();
+    let tile = (camera.screen_to_world(mouse) / TILE_SIZE).floor();
+    if tile.x < 0.0 || tile.y < 0.0 {
+        return;
+    }
+
+    let placements = blueprint::placements(state, saved, tile.x as usize, tile.y as usize);
+    let problem = blueprint::problem(state, &placements);
+    let cost = blueprint::cost(&placements);
+    let color = if problem.is_some() || state.resources.materials < cost {
+        RED
+    } else {
+        GREEN
+    };
+    for (_, site) in &placements {
+        let pos =
+            camera.world_to_screen(vec2(site.x as f32 * TILE_SIZE, site.y as f32 * TILE_SIZE));
+        let width = site.w as f32 * TILE_SIZE * camera.zoom;
+        let height = site.h as f32 * TILE_SIZE * camera.zoom;
+        draw_rectangle(pos.x, pos.y, width, height, Color { a: 0.25, ..color });
+        draw_rectangle_lines(pos.x, pos.y, width, height, 2.0, color);
+    }
+
+    let hint = match problem {
+        Some(problem) => format!("Can't lay out {} here: {}", name, problem),
+        None => format!(
+            "Click to move {} buildings here ({:.0} Mat) - Esc to cancel",
+            placements.len(),
+            cost
+        ),
+    };
+    draw_ui_text(&hint, mouse.x + 16.0, mouse.y - 8.0, 16.0, color);
+}
@@ -0,0 +1,179 @@
+use crate::data::GameState;
+use crate::narrative::{ChronicleEvent, ChronicleEventType};
+use crate::ui::theme;
+use crate::ui::theme::colors;
+use crate::PlayerAction;
+use macroquad::prelude::*;
+use macroquad_toolkit::ui::{draw_ui_text, measure_ui_text};
+
+/// Height of the tallest density bar (the busiest day)
+const MAX_BAR_H: f32 = 40.0;
+
+/// Day columns narrower than this show bars only, no icons
+const MIN_ICON_SPACING: f32 = 16.0;
+
+/// Browse the town's history day by day
+pub fn draw_timeline(state: &GameState, x: f32, y: f32, w: f32, h: f32) -> Option<PlayerAction> {
+    draw_rectangle(x, y, w, h, Color::new(0.1, 0.1, 0.15, 0.8));
+    draw_rectangle_lines(x, y, w, h, 1.0, GRAY);
+
+    let chronicle = &state.town_chronicle;
+    let today = (state.game_time_hours / 24.0) as u32 + 1;
+    // Older events are trimmed, so history starts at the earliest one kept
+    let first_day = chronicle
+        .events()
+        .first()
+        .map_or(today, |e| e.day)
+        .min(today);
+    let selected = state.chronicle_day.unwrap_or(today).clamp(first_day, today);
+    let mut action = None;
+
+    // Events per day, for the density bars
+    let span = (today - first_day + 1) as usize;
+    let mut counts = vec![0usize; span];
+    for event in chronicle.events() {
+        let index = event.day.saturating_sub(first_day) as usize;
+        if let Some(count) = counts.get_mut(index) {
+            *count += 1;
+        }
+    }
+    let busiest = counts.iter().copied().max().unwrap_or(0).max(1);
+
+    // Timeline strip
+    let strip = Rect::new(x + 20.0, y + 30.0, w - 40.0, MAX_BAR_H + 30.0);
+    let baseline = strip.y + strip.h - 14.0;
+    let day_w = strip.w / span as f32;
+    let day_x = |day: u32| strip.x + ((day - first_day) as f32 + 0.5) * day_w;
+
+    draw_line(strip.x, baseline, strip.x + strip.w, baseline, 1.0, GRAY);
+    for (i, &count) in counts.iter().enumerate() {
+        if count == 0 {
+            continue;
+        }
+        let day = first_day + i as u32;
+        let cx = day_x(day);
+        let bar_h = 4.0 + (MAX_BAR_H - 4.0) * count as f32 / busiest as f32;
+        let bar_w = (day_w - 1.0).clamp(1.0, 8.0);
+        draw_rectangle(
+            cx - bar_w / 2.0,
+            baseline - bar_h,
+            bar_w,
+            bar_h,
+            density_color(count, busiest),
+        );
+
+        if day_w >= MIN_ICON_SPACING {
+            if let Some(event) = chronicle.events_on_day(day).first() {
+                let icon = event.event_type.icon();
+                let icon_w = measure_ui_text(icon, None, 12, 1.0).width;
+                draw_ui_text(icon, cx - icon_w / 2.0, baseline - bar_h - 4.0, 12.0, WHITE);
+            }
+        }
+    }
+
+    // Day labels at either end
+    draw_ui_text(
+        &format!("Day {}", first_day),
+        strip.x,
+        baseline + 14.0,
+        12.0,
+        GRAY,
+    );
+    let today_label = format!("Day {}", today);
+    let today_w = measure_ui_text(&today_label, None, 12, 1.0).width;
+    draw_ui_text(
+        &today_label,
+        strip.x + strip.w - today_w,
+        baseline + 14.0,
+        12.0,
+        GRAY,
+    );
+
+    // Scrubber handle
+    let handle_x = day_x(selected);
+    draw_line(handle_x, strip.y, handle_x, baseline, 2.0, GOLD);
+    draw_circle(handle_x, baseline, 5.0, GOLD);
+
+    // Click or drag along the strip to pick a day
+    let mouse: Vec2 = mouse_position().into();
+    if is_mouse_button_down(MouseButton::Left) && strip.contains(mouse) {
+        let day = (first_day + ((mouse.x - strip.x) / day_w) as u32).min(today);
+        if day != selected {
+            action = Some(PlayerAction::SetChronicleDay(day));
+        }
+    }
+
+    // Arrow keys step one day; the buttons jump to the previous/next day with events
+    if is_key_pressed(KeyCode::Left) && selected > first_day {
+        action = Some(PlayerAction::SetChronicleDay(selected - 1));
+    }
+    if is_key_pressed(KeyCode::Right) && selected < today {
+        action = Some(PlayerAction::SetChronicleDay(selected + 1));
+    }
+    let list_y = strip.y + strip.h + 24.0;
+    let previous = chronicle.events().iter().rev().find(|e| e.day < selected);
+    let next = chronicle.events().iter().find(|e| e.day > selected);
+    if let Some(event) = previous {
+        if theme::draw_button(x + w - 250.0, list_y - 20.0, 110.0, 28.0, "< Previous") {
+            action = Some(PlayerAction::SetChronicleDay(event.day));
+        }
+    }
+    if let Some(event) = next {
+        if theme::draw_button(x + w - 130.0, list_y - 20.0, 110.0, 28.0, "Next >") {
+            action = Some(PlayerAction::SetChronicleDay(event.day));
+        }
+    }
+
+    // Events on the selected day
+    draw_ui_text(
+        &format!("Day {}", selected),
+        x + 20.0,
+        list_y,
+        22.0,
+        colors::accent(),
+    );
+    let events = chronicle.events_on_day(selected);
+    let mut row_y = list_y + 30.0;
+    if events.is_empty() {
+        draw_ui_text(
+            "Nothing was recorded this day.",
+            x + 20.0,
+            row_y,
+            16.0,
+            GRAY,
+        );
+    }
+    for event in events {
+        if row_y > y + h - 10.0 {
+            break;
+        }
+        draw_event_row(event, x + 20.0, row_y);
+        row_y += 22.0;
+    }
+
+    action
+}
+
+/// Time of day, icon and description of one event
+fn draw_event_row(event: &ChronicleEvent, x: f32, y: f32) {
+    let hour = event.timestamp % 24.0;
+    let time = format!("{:02}:{:02}", hour as u32, (hour.fract() * 60.0) as u32);
+    draw_ui_text(&time, x, y, 14.0, GRAY);
+    draw_ui_text(event.event_type.icon(), x + 50.0, y, 16.0, GOLD);
+
+    let color = if matches!(
+        event.event_type,
+        ChronicleEventType::Disaster { .. } | ChronicleEventType::TownCollapsed { .. }
+    ) {
+        colors::warn()
+    } else {
+        colors::text()
+    };
+    draw_ui_text(&event.display_text(), x + 70.0, y, 16.0, color);
+}
+
+/// Busier days are drawn brighter
+fn density_color(count: usize, busiest: usize) -> Color {
+    let t = count as f32 / busiest as f32;
+    Color::new(0.3 + 0.5 * t, 0.5 + 0.3 * t, 0.3, 1.0)
+}
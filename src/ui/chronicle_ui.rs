@@ -1,11 +1,31 @@
 use crate::data::GameState;
-use crate::narrative::TownRecord;
+use crate::narrative::{MetaUpgrade, TownRecord};
+use crate::ui::chronicle_timeline;
 use crate::ui::theme;
 use crate::ui::theme::colors;
 use crate::PlayerAction;
 use macroquad::prelude::*;
 use macroquad_toolkit::ui::draw_ui_text;
 
+/// Which page of the chronicle is showing
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ChronicleTab {
+    #[default]
+    Dynasty,
+    Timeline,
+    LegacyUpgrades,
+}
+
+impl ChronicleTab {
+    pub fn name(&self) -> &'static str {
+        match self {
+            ChronicleTab::Dynasty => "Dynasty",
+            ChronicleTab::Timeline => "Timeline",
+            ChronicleTab::LegacyUpgrades => "Legacy Upgrades",
+        }
+    }
+}
+
 pub fn draw_chronicle_ui(
     state: &GameState,
     x: f32,
@@ -37,13 +57,65 @@ pub fn draw_chronicle_ui(
         YELLOW,
     );
 
-    // Tab-like sections
-    let content_y = y + 60.0;
-    let content_h = h - 130.0;
+    // Tabs
+    let tabs = [
+        ChronicleTab::Dynasty,
+        ChronicleTab::Timeline,
+        ChronicleTab::LegacyUpgrades,
+    ];
+    for (i, tab) in tabs.iter().enumerate() {
+        let tab_x = x + 20.0 + i as f32 * 170.0;
+        let label = if state.chronicle_tab == *tab {
+            format!("> {}", tab.name())
+        } else {
+            tab.name().to_string()
+        };
+        if theme::draw_button(tab_x, y + 55.0, 160.0, 28.0, &label) {
+            action = Some(PlayerAction::SetChronicleTab(*tab));
+        }
+    }
+
+    let content_y = y + 95.0;
+    let content_h = h - 165.0;
+
+    match state.chronicle_tab {
+        ChronicleTab::Dynasty => draw_overview(state, x, content_y, w, content_h, &mut action),
+        ChronicleTab::Timeline => {
+            if let Some(act) =
+                chronicle_timeline::draw_timeline(state, x + 10.0, content_y, w - 20.0, content_h)
+            {
+                action = Some(act);
+            }
+        }
+        ChronicleTab::LegacyUpgrades => {
+            draw_legacy_upgrades(state, x + 10.0, content_y, w - 20.0, content_h, &mut action)
+        }
+    }
+
+    // Close button - draw last to be on top, and make it larger for better click target
+    let close_btn_w = 50.0;
+    let close_btn_h = 35.0;
+    let close_btn_x = x + w - close_btn_w - 10.0;
+    let close_btn_y = y + 10.0;
+    if theme::draw_button(close_btn_x, close_btn_y, close_btn_w, close_btn_h, "Close") {
+        action = Some(PlayerAction::ToggleChronicle);
+    }
+
+    action
+}
 
+/// Dynasty, achievements and lifetime stats
+fn draw_overview(
+    state: &GameState,
+    x: f32,
+    content_y: f32,
+    w: f32,
+    content_h: f32,
+    action: &mut Option<PlayerAction>,
+) {
     // Left half: Dynasty info (Towns, Heroes, Ancestors)
     let left_w = w * 0.55;
-    draw_dynasty_section(state, x + 10.0, content_y, left_w, content_h, &mut action);
+    draw_dynasty_section(state, x + 10.0, content_y, left_w, content_h, action);
 
     // Right half: Achievements & Stats
     let right_x = x + left_w + 20.0;
@@ -60,17 +132,62 @@ pub fn draw_chronicle_ui(
         right_w,
         content_h * 0.52,
     );
+}
 
-    // Close button - draw last to be on top, and make it larger for better click target
-    let close_btn_w = 50.0;
-    let close_btn_h = 35.0;
-    let close_btn_x = x + w - close_btn_w - 10.0;
-    let close_btn_y = y + 10.0;
-    if theme::draw_button(close_btn_x, close_btn_y, close_btn_w, close_btn_h, "Close") {
-        action = Some(PlayerAction::ToggleChronicle);
-    }
+/// Permanent upgrades bought with legacy points
+fn draw_legacy_upgrades(
+    state: &GameState,
+    x: f32,
+    y: f32,
+    w: f32,
+    h: f32,
+    action: &mut Option<PlayerAction>,
+) {
+    draw_rectangle(x, y, w, h, Color::new(0.1, 0.1, 0.15, 0.8));
+    draw_rectangle_lines(x, y, w, h, 1.0, GRAY);
+    draw_ui_text(
+        "Permanent bonuses for every town your dynasty founds",
+        x + 10.0,
+        y + 20.0,
+        16.0,
+        colors::accent(),
+    );
 
-    action
+    let upgrades = &state.dynasty.meta_upgrades;
+    let row_h = 60.0;
+    for (i, upgrade) in MetaUpgrade::all().iter().enumerate() {
+        let row_y = y + 40.0 + i as f32 * row_h;
+        let level = upgrades.level(*upgrade);
+
+        draw_ui_text(
+            &format!("{} ({}/{})", upgrade.name(), level, upgrade.max_level()),
+            x + 10.0,
+            row_y + 18.0,
+            16.0,
+            WHITE,
+        );
+        draw_ui_text(
+            upgrade.description(),
+            x + 10.0,
+            row_y + 38.0,
+            13.0,
+            LIGHTGRAY,
+        );
+
+        match upgrades.next_cost(*upgrade) {
+            Some(cost) => {
+                let label = format!("Buy ({} LP)", cost);
+                if theme::draw_button(x + w - 170.0, row_y + 10.0, 150.0, 30.0, &label)
+                    && state.dynasty.legacy_points >= cost
+                {
+                    *action = Some(PlayerAction::BuyMetaUpgrade(*upgrade));
+                }
+            }
+            None => {
+                draw_ui_text("MAXED", x + w - 120.0, row_y + 30.0, 16.0, GOLD);
+            }
+        }
+    }
 }
 
 fn draw_dynasty_section(
@@ -85,7 +202,7 @@ fn draw_dynasty_section(
     draw_rectangle_lines(x, y, w, h, 1.0, GRAY);
 
     // Section header
-    draw_ui_text("Dynasty", x + 10.0, y + 20.0, 18.0, colors::ACCENT);
+    draw_ui_text("Dynasty", x + 10.0, y + 20.0, 18.0, colors::accent());
 
     let mut sy = y + 45.0;
     let line_h = 22.0;
@@ -108,6 +225,11 @@ fn draw_dynasty_section(
         let text = format!("  {} - {}", hero.name, hero.description);
         draw_ui_text(&text, x + 10.0, sy, 13.0, LIGHTGRAY);
         sy += line_h - 4.0;
+        if let Some(epitaph) = &hero.epitaph {
+            let line = format!("    \"{}\"", epitaph);
+            draw_ui_text(&line, x + 10.0, sy, 12.0, colors::secondary());
+            sy += line_h - 4.0;
+        }
     }
     if state.dynasty.hall_of_heroes.is_empty() {
         draw_ui_text("  No heroes immortalized yet.", x + 10.0, sy, 12.0, GRAY);
@@ -117,7 +239,7 @@ fn draw_dynasty_section(
     sy += 10.0;
 
     // Ancestors
-    draw_ui_text("Active Ancestors:", x + 10.0, sy, 15.0, colors::SECONDARY);
+    draw_ui_text("Active Ancestors:", x + 10.0, sy, 15.0, colors::secondary());
     sy += line_h;
 
     for ancestor in state.dynasty.ancestors.iter().take(4) {
@@ -162,6 +284,30 @@ fn draw_dynasty_section(
         draw_ui_text("  No wonders completed yet.", x + 10.0, sy, 12.0, GRAY);
     }
 
+    // Prestige: pass the town on and start fresh, after a second click to be sure
+    let points = state.prestige_legacy_points();
+    if state.confirm_new_town {
+        draw_ui_text(
+            "Leave this town behind for good?",
+            x + w - 380.0,
+            y + h - 55.0,
+            14.0,
+            colors::warn(),
+        );
+        let confirm_label = format!("Yes, New Town (+{} LP)", points);
+        if theme::draw_button(x + w - 250.0, y + h - 45.0, 240.0, 30.0, &confirm_label) {
+            *action = Some(PlayerAction::FoundNewTown);
+        }
+        if theme::draw_button(x + w - 350.0, y + h - 45.0, 90.0, 30.0, "Cancel") {
+            *action = Some(PlayerAction::AskFoundNewTown(false));
+        }
+    } else {
+        let found_label = format!("New Town (+{} LP)", points);
+        if theme::draw_button(x + w - 190.0, y + h - 45.0, 180.0, 30.0, &found_label) {
+            *action = Some(PlayerAction::AskFoundNewTown(true));
+        }
+    }
+
     // Retire hero button (if there's a top agent eligible)
     if !state.agents.is_empty() {
         let hero_btn_y = y + h - 45.0;
@@ -228,7 +374,7 @@ fn draw_stats_section(state: &GameState, x: f32, y: f32, w: f32, h: f32) {
     draw_rectangle(x, y, w, h, Color::new(0.1, 0.12, 0.1, 0.8));
     draw_rectangle_lines(x, y, w, h, 1.0, GRAY);
 
-    draw_ui_text("Lifetime Stats", x + 10.0, y + 20.0, 18.0, colors::ACCENT);
+    draw_ui_text("Lifetime Stats", x + 10.0, y + 20.0, 18.0, colors::accent());
 
     let stats = &state.stats;
     let col1_x = x + 10.0;
@@ -295,6 +441,14 @@ fn draw_stats_section(state: &GameState, x: f32, y: f32, w: f32, h: f32) {
         WHITE,
     );
     sy += line_h;
+    draw_ui_text(
+        &format!("Agents Left: {}", stats.agents_left),
+        col2_x,
+        sy,
+        13.0,
+        WHITE,
+    );
+    sy += line_h;
     draw_ui_text(
         &format!("Techs: {}", stats.techs_researched),
         col2_x,
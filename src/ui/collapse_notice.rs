@@ -0,0 +1,57 @@
+use crate::data::GameState;
+use crate::ui::theme;
+use crate::ui::theme::colors;
+use crate::PlayerAction;
+use macroquad::prelude::*;
+use macroquad_toolkit::ui::draw_ui_text;
+
+/// Draw the "town has fallen" modal once the town collapses
+pub fn draw_collapse_notice(state: &GameState) -> Option<PlayerAction> {
+    if !state.town_failed {
+        return None;
+    }
+    let screen_w = screen_width();
+    let screen_h = screen_height();
+
+    let w = 480.0;
+    let h = 240.0;
+    let x = (screen_w - w) / 2.0;
+    let y = (screen_h - h) / 2.0;
+
+    draw_rectangle(0.0, 0.0, screen_w, screen_h, Color::new(0.0, 0.0, 0.0, 0.6));
+    theme::draw_panel(x, y, w, h);
+    let town_name = state
+        .region_map
+        .active_town()
+        .map_or("The town", |n| n.name.as_str());
+    theme::draw_header(&format!("{} Has Fallen", town_name), x + 20.0, y + 40.0);
+
+    let lines = [
+        ("Nobody is left, and the stores are empty.", colors::warn()),
+        (
+            "The dynasty remembers. Its heroes and upgrades carry on.",
+            colors::text(),
+        ),
+        ("A collapsed town earns only part of its legacy.", LIGHTGRAY),
+    ];
+    let mut row_y = y + 85.0;
+    for (text, color) in lines {
+        draw_ui_text(text, x + 20.0, row_y, 18.0, color);
+        row_y += 26.0;
+    }
+
+    let btn_w = 240.0;
+    let btn_h = 36.0;
+    let label = format!("Found a New Town (+{} LP)", state.prestige_legacy_points());
+    if theme::draw_button(
+        x + w - btn_w - 20.0,
+        y + h - btn_h - 20.0,
+        btn_w,
+        btn_h,
+        &label,
+    ) {
+        return Some(PlayerAction::FoundNewTown);
+    }
+
+    None
+}
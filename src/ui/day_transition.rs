@@ -0,0 +1,122 @@
+//! Day transitions - a brief glow around the screen the moment dawn breaks or dusk falls
+
+use macroquad::prelude::*;
+use macroquad_toolkit::ui::{draw_ui_text, measure_ui_text};
+
+/// Game hour the sun comes up
+pub const DAWN_HOUR: f32 = 6.0;
+
+/// Game hour night sets in
+pub const DUSK_HOUR: f32 = 20.0;
+
+/// Real seconds the vignette takes to swell and fade
+const DURATION: f32 = 2.5;
+
+/// Vignette bands drawn inward from the screen edge
+const BANDS: usize = 8;
+const BAND_W: f32 = 14.0;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Moment {
+    Dawn,
+    Dusk,
+}
+
+impl Moment {
+    fn color(&self) -> Color {
+        match self {
+            Moment::Dawn => Color::new(1.0, 0.8, 0.45, 1.0),
+            Moment::Dusk => Color::new(0.55, 0.25, 0.5, 1.0),
+        }
+    }
+
+    fn caption(&self) -> &'static str {
+        match self {
+            Moment::Dawn => "Dawn breaks",
+            Moment::Dusk => "Dusk falls",
+        }
+    }
+}
+
+/// Watches the clock between frames and plays the vignette when it passes dawn or dusk
+#[derive(Debug, Clone, Default)]
+pub struct DayTransition {
+    last_hour: Option<f32>,
+    /// Moment being shown and real seconds since it started
+    playing: Option<(Moment, f32)>,
+}
+
+impl DayTransition {
+    /// Call every frame with the current game hour and real frame time
+    pub fn update(&mut self, game_hour: f32, delta: f32) {
+        if let Some((_, elapsed)) = &mut self.playing {
+            *elapsed += delta;
+            if *elapsed >= DURATION {
+                self.playing = None;
+            }
+        }
+
+        if let Some(last) = self.last_hour {
+            if crossed(last, game_hour, DAWN_HOUR) {
+                self.playing = Some((Moment::Dawn, 0.0));
+            } else if crossed(last, game_hour, DUSK_HOUR) {
+                self.playing = Some((Moment::Dusk, 0.0));
+            }
+        }
+        self.last_hour = Some(game_hour);
+    }
+
+    /// Draw the vignette, a faint flash and a caption (nothing when idle)
+    pub fn draw(&self) {
+        let (moment, elapsed) = match self.playing {
+            Some(playing) => playing,
+            None => return,
+        };
+        // Swell then fade
+        let strength = (elapsed / DURATION * std::f32::consts::PI).sin();
+        let color = moment.color();
+        let (w, h) = (screen_width(), screen_height());
+
+        draw_rectangle(
+            0.0,
+            0.0,
+            w,
+            h,
+            Color {
+                a: 0.08 * strength,
+                ..color
+            },
+        );
+        for band in 0..BANDS {
+            let inset = band as f32 * BAND_W;
+            let alpha = 0.3 * strength * (1.0 - band as f32 / BANDS as f32);
+            draw_rectangle_lines(
+                inset,
+                inset,
+                w - inset * 2.0,
+                h - inset * 2.0,
+                BAND_W,
+                Color { a: alpha, ..color },
+            );
+        }
+
+        let caption = moment.caption();
+        let caption_w = measure_ui_text(caption, None, 28, 1.0).width;
+        draw_ui_text(
+            caption,
+            (w - caption_w) / 2.0,
+            h * 0.25,
+            28.0,
+            Color::new(1.0, 1.0, 1.0, strength),
+        );
+    }
+}
+
+/// Whether the clock passed `hour` going from `from` to `to` (wrapping at midnight)
+fn crossed(from: f32, to: f32, hour: f32) -> bool {
+    if to >= from {
+        from < hour && to >= hour
+    } else {
+        from < hour || to >= hour
+    }
+}
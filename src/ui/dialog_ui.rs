@@ -7,6 +7,16 @@ use macroquad_toolkit::ui::draw_ui_text;
 
 /// Draw the active guide dialog if any
 pub fn draw_guide_dialog(state: &GameState) -> Option<PlayerAction> {
+    // The opening camera sweep can be skipped along with the rest of the tutorial
+    if state.tutorial.in_camera_intro() {
+        let (btn_w, btn_h) = (140.0, 36.0);
+        let btn_x = screen_width() - btn_w - 20.0;
+        let btn_y = screen_height() - btn_h - 20.0;
+        if theme::draw_button(btn_x, btn_y, btn_w, btn_h, "Skip Tutorial") {
+            return Some(PlayerAction::SkipTutorial);
+        }
+    }
+
     if let Some(dialog) = &state.tutorial.active_dialog {
         let screen_w = screen_width();
         let screen_h = screen_height();
@@ -42,7 +52,7 @@ pub fn draw_guide_dialog(state: &GameState) -> Option<PlayerAction> {
             portrait_size,
             portrait_size,
             2.0,
-            colors::ACCENT,
+            colors::accent(),
         );
 
         // Draw Portrait Text or Texture
@@ -78,7 +88,7 @@ pub fn draw_guide_dialog(state: &GameState) -> Option<PlayerAction> {
         let wrapped = crate::ui::text_util::wrap_text(&dialog.text, font_size, text_w);
         let mut text_y = content_y + 40.0;
         for line in wrapped {
-            draw_ui_text(&line, content_x, text_y, font_size, colors::TEXT);
+            draw_ui_text(&line, content_x, text_y, font_size, colors::text());
             text_y += 24.0;
         }
 
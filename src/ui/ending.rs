@@ -0,0 +1,141 @@
+//! Ending scene - the camera pulls back over the town while the dynasty's story is told,
+//! then the player carries on or begins New Game+
+
+use crate::data::GameState;
+use crate::ui::theme;
+use crate::ui::theme::colors;
+use crate::PlayerAction;
+use macroquad::prelude::*;
+use macroquad_toolkit::ui::{draw_ui_text, measure_ui_text};
+
+/// Real seconds the camera takes to pull back over the town
+const CAMERA_SWEEP: f32 = 6.0;
+
+/// Real seconds before the first line appears
+const TEXT_DELAY: f32 = 1.5;
+
+/// Real seconds between each line of the closing text appearing
+const LINE_INTERVAL: f32 = 1.5;
+
+/// Real seconds since the ending began
+#[derive(Debug, Clone, Default)]
+pub struct Ending {
+    elapsed: f32,
+}
+
+impl Ending {
+    pub fn start(&mut self) {
+        self.elapsed = 0.0;
+    }
+
+    /// Call every frame with the real frame time while the ending scene is showing
+    pub fn update(&mut self, delta: f32) {
+        self.elapsed += delta;
+    }
+
+    /// How far through the camera sweep the ending is (0-1)
+    pub fn camera_progress(&self) -> f32 {
+        (self.elapsed / CAMERA_SWEEP).min(1.0)
+    }
+}
+
+/// Draw the closing text over the town; the buttons appear once it's all been told
+pub fn draw_ending(state: &GameState) -> Option<PlayerAction> {
+    let elapsed = state.ending.elapsed;
+    let screen_w = screen_width();
+    let screen_h = screen_height();
+
+    let darkness = (elapsed / CAMERA_SWEEP).min(1.0);
+    draw_rectangle(
+        0.0,
+        0.0,
+        screen_w,
+        screen_h,
+        Color::new(0.02, 0.02, 0.08, 0.75 * darkness),
+    );
+
+    let lines = summary(state);
+    let mut row_y = screen_h * 0.25;
+    for (i, (text, color)) in lines.iter().enumerate() {
+        let shown_at = TEXT_DELAY + i as f32 * LINE_INTERVAL;
+        let alpha = ((elapsed - shown_at) / LINE_INTERVAL).clamp(0.0, 1.0);
+        let size = if i == 0 { 32 } else { 22 };
+        let w = measure_ui_text(text, None, size, 1.0).width;
+        draw_ui_text(
+            text,
+            (screen_w - w) / 2.0,
+            row_y,
+            size as f32,
+            Color { a: alpha, ..*color },
+        );
+        row_y += if i == 0 { 60.0 } else { 36.0 };
+    }
+
+    // The buttons wait until the last line is up
+    let all_shown = TEXT_DELAY + lines.len() as f32 * LINE_INTERVAL;
+    if elapsed < all_shown {
+        return None;
+    }
+    let btn_w = 200.0;
+    let btn_h = 40.0;
+    let btn_y = row_y + 30.0;
+    if theme::draw_button(
+        screen_w / 2.0 - btn_w - 10.0,
+        btn_y,
+        btn_w,
+        btn_h,
+        "Keep Playing",
+    ) {
+        return Some(PlayerAction::DismissEnding);
+    }
+    if theme::draw_button(screen_w / 2.0 + 10.0, btn_y, btn_w, btn_h, "New Game+") {
+        return Some(PlayerAction::NewGamePlus);
+    }
+    let hint = "New Game+ begins a new region. The dynasty carries on with every legacy upgrade.";
+    let hint_w = measure_ui_text(hint, None, 16, 1.0).width;
+    draw_ui_text(
+        hint,
+        (screen_w - hint_w) / 2.0,
+        btn_y + btn_h + 30.0,
+        16.0,
+        LIGHTGRAY,
+    );
+    None
+}
+
+/// The dynasty's story, one line at a time
+fn summary(state: &GameState) -> Vec<(String, Color)> {
+    let dynasty = &state.dynasty;
+    let stats = &state.stats;
+    let days = (stats.total_play_hours / 24.0).floor() as u32;
+    vec![
+        (
+            "The Cloud Spire reaches into the heavens.".to_string(),
+            GOLD,
+        ),
+        (
+            format!(
+                "{} towns founded across the region.",
+                dynasty.past_towns.len() + 1
+            ),
+            colors::text(),
+        ),
+        (
+            format!("{} wonders raised.", dynasty.completed_wonders.len()),
+            colors::text(),
+        ),
+        (
+            format!("{} heroes immortalized.", stats.heroes_immortalized),
+            colors::text(),
+        ),
+        (
+            format!(
+                "{} days and {:.0} hours of town life.",
+                days,
+                stats.total_play_hours - days as f32 * 24.0
+            ),
+            colors::text(),
+        ),
+        ("Your legacy is complete.".to_string(), GOLD),
+    ]
+}
@@ -1,5 +1,6 @@
 //! Floating text for resource change notifications
 
+use crate::economy::ResourceType;
 use macroquad::prelude::*;
 use macroquad_toolkit::ui::draw_ui_text;
 
@@ -41,11 +42,16 @@ impl FloatingText {
 #[derive(Debug, Clone, Default)]
 pub struct FloatingTextManager {
     texts: Vec<FloatingText>,
+    /// Changes too small to show yet, per resource
+    pending: Vec<(ResourceType, f32)>,
 }
 
 impl FloatingTextManager {
     pub fn new() -> Self {
-        Self { texts: Vec::new() }
+        Self {
+            texts: Vec::new(),
+            pending: Vec::new(),
+        }
     }
 
     /// Add a new floating text
@@ -54,22 +60,36 @@ impl FloatingTextManager {
             .push(FloatingText::new(text, world_pos, color, 2.0));
     }
 
-    /// Add a resource gain notification (green)
-    pub fn add_gain(&mut self, amount: f32, resource_name: &str, world_pos: Vec2) {
-        if amount.abs() < 0.01 {
+    /// Add a change to a resource's running total, popping up a text in the resource's
+    /// colour once the total reaches `threshold`
+    pub fn accumulate(&mut self, kind: ResourceType, amount: f32, threshold: f32, world_pos: Vec2) {
+        let index = match self.pending.iter().position(|(k, _)| *k == kind) {
+            Some(index) => index,
+            None => {
+                self.pending.push((kind, 0.0));
+                self.pending.len() - 1
+            }
+        };
+        let total = &mut self.pending[index].1;
+        *total += amount;
+        if total.abs() < threshold {
             return;
         }
-        let text = format!("+{:.1} {}", amount, resource_name);
-        self.add(text, world_pos, GREEN);
+
+        let total = std::mem::take(total);
+        let text = if total.abs() >= 1.0 {
+            format!("{:+.1} {}", total, kind.name())
+        } else {
+            format!("{:+.2} {}", total, kind.name())
+        };
+        let [r, g, b, a] = kind.color();
+        self.add(text, world_pos, Color::new(r, g, b, a));
     }
 
-    /// Add a resource loss notification (red)
-    pub fn add_loss(&mut self, amount: f32, resource_name: &str, world_pos: Vec2) {
-        if amount.abs() < 0.01 {
-            return;
-        }
-        let text = format!("-{:.1} {}", amount.abs(), resource_name);
-        self.add(text, world_pos, RED);
+    /// Drop all texts and pending totals (when floating text is turned off)
+    pub fn clear(&mut self) {
+        self.texts.clear();
+        self.pending.clear();
     }
 
     /// Update all floating texts and remove expired ones
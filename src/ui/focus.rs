@@ -0,0 +1,224 @@
+//! Focus mode - darken the town around the selected zone or villager (F) and
+//! show everything about it beside the cutout
+
+use crate::data::{GameState, Selection};
+use crate::simulation::{housing, production, workplaces};
+use crate::ui::theme;
+use crate::ui::theme::colors;
+use crate::zones::ZoneId;
+use macroquad::prelude::*;
+use macroquad_toolkit::ui::{draw_ui_text, measure_ui_text};
+
+/// Screen pixels of map left visible around the selection
+const PADDING: f32 = 16.0;
+/// World pixels shown around a villager
+const AGENT_RADIUS: f32 = 40.0;
+
+const LINE_H: f32 = 20.0;
+const FONT_SIZE: u16 = 16;
+
+/// Screen rect left undimmed around the selection, or None with nothing to focus on
+fn cutout(state: &GameState) -> Option<Rect> {
+    let camera = &state.camera;
+    let (top_left, bottom_right) = match state.selection {
+        Selection::Zone(id) => {
+            let rect = state.zone(id).and_then(|zone| state.zone_rect(zone))?;
+            let rect = rect.world_rect();
+            (rect.point(), rect.point() + rect.size())
+        }
+        Selection::Agent(id) => {
+            let agent = state.agents.iter().find(|a| a.id == id)?;
+            let reach = Vec2::splat(AGENT_RADIUS);
+            (agent.pos - reach, agent.pos + reach)
+        }
+        Selection::None => return None,
+    };
+    let top_left = camera.world_to_screen(top_left) - Vec2::splat(PADDING);
+    let bottom_right = camera.world_to_screen(bottom_right) + Vec2::splat(PADDING);
+    let size = bottom_right - top_left;
+    Some(Rect::new(top_left.x, top_left.y, size.x, size.y))
+}
+
+/// Dim the map outside the selection and list its details beside it
+pub fn draw_focus_overlay(state: &GameState) {
+    let rect = match cutout(state) {
+        Some(rect) => rect,
+        None => return,
+    };
+    let (screen_w, screen_h) = (screen_width(), screen_height());
+    let shade = Color::new(0.0, 0.0, 0.0, 0.65);
+
+    // Four bands around the cutout
+    draw_rectangle(0.0, 0.0, screen_w, rect.y.max(0.0), shade);
+    draw_rectangle(
+        0.0,
+        rect.bottom(),
+        screen_w,
+        screen_h - rect.bottom(),
+        shade,
+    );
+    draw_rectangle(0.0, rect.y, rect.x.max(0.0), rect.h, shade);
+    draw_rectangle(rect.right(), rect.y, screen_w - rect.right(), rect.h, shade);
+    draw_rectangle_lines(rect.x, rect.y, rect.w, rect.h, 2.0, colors::accent());
+
+    let lines = details(state);
+    if lines.is_empty() {
+        return;
+    }
+    let width = lines
+        .iter()
+        .map(|(line, _)| measure_ui_text(line, None, FONT_SIZE, 1.0).width)
+        .fold(0.0_f32, f32::max)
+        + 20.0;
+    let height = lines.len() as f32 * LINE_H + 14.0;
+    // Right of the cutout, or left of it when that would run off screen
+    let x = if rect.right() + 10.0 + width <= screen_w {
+        rect.right() + 10.0
+    } else {
+        (rect.x - 10.0 - width).max(0.0)
+    };
+    let y = rect.y.clamp(0.0, (screen_h - height).max(0.0));
+
+    theme::draw_panel(x, y, width, height);
+    for (i, (line, color)) in lines.iter().enumerate() {
+        draw_ui_text(
+            line,
+            x + 10.0,
+            y + 22.0 + i as f32 * LINE_H,
+            FONT_SIZE as f32,
+            *color,
+        );
+    }
+}
+
+/// Everything worth knowing about the selection, one line each (title first)
+fn details(state: &GameState) -> Vec<(String, Color)> {
+    match state.selection {
+        Selection::Zone(id) => zone_details(state, id),
+        Selection::Agent(id) => agent_details(state, id),
+        Selection::None => Vec::new(),
+    }
+}
+
+fn zone_details(state: &GameState, id: ZoneId) -> Vec<(String, Color)> {
+    let zone = match state.zone(id) {
+        Some(zone) => zone,
+        None => return Vec::new(),
+    };
+    if !zone.surveyed {
+        return vec![(super::zones::UNSURVEYED_NAME.to_string(), colors::accent())];
+    }
+    let template = match state.get_template(&zone.template_id) {
+        Some(template) => template,
+        None => return Vec::new(),
+    };
+
+    let mut lines = vec![
+        (template.name.clone(), colors::accent()),
+        (
+            format!(
+                "Condition {:.0}% • Activity {:.0}%",
+                zone.condition * 100.0,
+                zone.activity * 100.0
+            ),
+            colors::text(),
+        ),
+    ];
+    let status = if zone.is_under_construction() {
+        "Under construction"
+    } else if zone.dormant {
+        "In ruins"
+    } else if zone.disabled {
+        "Disabled"
+    } else {
+        "Operating"
+    };
+    lines.push((format!("Status: {}", status), colors::text()));
+    if let Some(net) = production::zone_net_per_hour(state, zone) {
+        let color = if net.materials < 0.0 {
+            colors::warn()
+        } else {
+            colors::text()
+        };
+        lines.push((
+            format!(
+                "Net: {:+.2} Materials/h, {:+.2} Maintenance/h",
+                net.materials, net.maintenance
+            ),
+            color,
+        ));
+    }
+    if template.population.capacity > 0.0 {
+        lines.push((
+            format!(
+                "Residents: {} (houses {:.0})",
+                housing::residents(&state.agents, Some(id)),
+                template.population.capacity * zone.condition
+            ),
+            colors::text(),
+        ));
+    }
+    let workers: Vec<&str> = workplaces::workers_at(&state.agents, id)
+        .map(|agent| agent.name.as_str())
+        .collect();
+    if !workers.is_empty() {
+        lines.push((format!("Workers ({}):", workers.len()), colors::text()));
+        for name in workers {
+            lines.push((format!("  {}", name), LIGHTGRAY));
+        }
+    }
+    lines
+}
+
+fn agent_details(state: &GameState, id: u64) -> Vec<(String, Color)> {
+    let agent = match state.agents.iter().find(|a| a.id == id) {
+        Some(agent) => agent,
+        None => return Vec::new(),
+    };
+    let zone_name = |zone: Option<ZoneId>| {
+        zone.and_then(|id| state.zone(id))
+            .and_then(|z| state.get_template(&z.template_id))
+            .map(|t| t.name.clone())
+    };
+
+    let mut lines = vec![
+        (agent.name.clone(), colors::accent()),
+        (format!("Job: {}", agent.job.name()), colors::text()),
+        (
+            format!(
+                "Home: {}",
+                zone_name(agent.home).unwrap_or_else(|| "the campsite".to_string())
+            ),
+            colors::text(),
+        ),
+        (
+            format!(
+                "Works at: {}",
+                zone_name(agent.workplace).unwrap_or_else(|| "nowhere yet".to_string())
+            ),
+            colors::text(),
+        ),
+        (
+            format!(
+                "Energy {:.0}% • Hunger {:.0}% • Social {:.0}% • Spirit {:.0}%",
+                agent.energy * 100.0,
+                agent.hunger * 100.0,
+                agent.social * 100.0,
+                agent.spirit * 100.0
+            ),
+            colors::text(),
+        ),
+    ];
+    if !agent.traits.is_empty() {
+        let traits: Vec<&str> = agent.traits.iter().map(|t| t.name()).collect();
+        lines.push((format!("Traits: {}", traits.join(", ")), colors::text()));
+    }
+    lines.push((
+        format!("Knows {} pieces of news", agent.rumors.len()),
+        LIGHTGRAY,
+    ));
+    for feat in agent.feats.to_strings() {
+        lines.push((format!("• {}", feat), GOLD));
+    }
+    lines
+}
@@ -0,0 +1,140 @@
+//! Formula overlay - the live numbers behind the economy (F3, off by default)
+//!
+//! Everything here comes from the same functions `simulate_ticks` uses, so it
+//! shows exactly what the simulation is applying this tick.
+
+use crate::data::GameState;
+use crate::economy::{
+    effective_population, maintenance_cost, maintenance_factor, material_factor, stability_factor,
+};
+use crate::simulation::production::{production_bonuses, production_rates};
+use macroquad::prelude::*;
+use macroquad_toolkit::ui::{draw_ui_text, measure_ui_text};
+
+const LINE_H: f32 = 18.0;
+const FONT_SIZE: u16 = 14;
+const TOP: f32 = 80.0;
+
+/// Box of formula readouts, centred under the top bar
+pub fn draw_formula_overlay(state: &GameState) {
+    let lines = formula_lines(state);
+    let width = lines
+        .iter()
+        .map(|(line, _)| measure_ui_text(line, None, FONT_SIZE, 1.0).width)
+        .fold(0.0_f32, f32::max)
+        + 20.0;
+    let height = overlay_height(lines.len());
+    let x = (screen_width() - width) / 2.0;
+    let y = TOP;
+
+    draw_rectangle(x, y, width, height, Color::new(0.0, 0.0, 0.0, 0.75));
+    draw_rectangle_lines(x, y, width, height, 1.0, GRAY);
+    for (i, (line, color)) in lines.iter().enumerate() {
+        draw_ui_text(
+            line,
+            x + 10.0,
+            y + 20.0 + i as f32 * LINE_H,
+            FONT_SIZE as f32,
+            *color,
+        );
+    }
+}
+
+/// Screen y just below the overlay (the materials audit hangs underneath)
+pub fn overlay_bottom(state: &GameState) -> f32 {
+    TOP + overlay_height(formula_lines(state).len())
+}
+
+fn overlay_height(lines: usize) -> f32 {
+    lines as f32 * LINE_H + 14.0
+}
+
+/// One readout per line, with the formula beside the value it produces
+fn formula_lines(state: &GameState) -> Vec<(String, Color)> {
+    let resources = &state.resources;
+    let bonuses = production_bonuses(state);
+    let rates = production_rates(state, &bonuses);
+
+    let materials = material_factor(resources.materials);
+    let maintenance = maintenance_factor(resources.maintenance);
+    let stability = stability_factor(resources.stability);
+
+    let pressure = state.population.value();
+    let k = state.config.population_k;
+    let beta = state.config.maintenance_cost_coefficient;
+    let effective = effective_population(pressure, k);
+    let upkeep_per_hour = maintenance_cost(effective, beta) * 60.0;
+    let net = state.net_production_rate();
+
+    let delta_color = |value: f32| if value < 0.0 { RED } else { GREEN };
+    vec![
+        (
+            "Formulas (F3 to hide, F4 itemises materials)".to_string(),
+            WHITE,
+        ),
+        (
+            format!(
+                "Material factor M/(M+1): M={:.1} -> {:.3}",
+                resources.materials, materials
+            ),
+            LIGHTGRAY,
+        ),
+        (
+            format!(
+                "Maintenance factor √M/(√M+1): M={:.1} -> {:.3}",
+                resources.maintenance, maintenance
+            ),
+            LIGHTGRAY,
+        ),
+        (
+            format!(
+                "Stability factor ln(S+1)/ln(S+2): S={:.1} -> {:.3}",
+                resources.stability, stability
+            ),
+            LIGHTGRAY,
+        ),
+        (
+            format!(
+                "Zone output multiplier: {:.3}",
+                materials * maintenance * stability
+            ),
+            LIGHTGRAY,
+        ),
+        (
+            format!(
+                "Production multiplier (tech, wonders, ancestors, capital, morale): x{:.2}",
+                bonuses.production_multi
+            ),
+            LIGHTGRAY,
+        ),
+        (
+            format!(
+                "Effective population P/(P+K): P={:.1}, K={:.0} -> {:.3}",
+                pressure, k, effective
+            ),
+            LIGHTGRAY,
+        ),
+        (
+            format!(
+                "Population upkeep βE²: β={:.3} -> {:.3} maintenance/h",
+                beta, upkeep_per_hour
+            ),
+            LIGHTGRAY,
+        ),
+        (
+            format!(
+                "Attractiveness {:.1} • Stability {:.1}",
+                rates.attractiveness, rates.stability
+            ),
+            LIGHTGRAY,
+        ),
+        (
+            format!("Net materials: {:+.2}/h", net.materials),
+            delta_color(net.materials),
+        ),
+        (
+            format!("Net maintenance: {:+.2}/h", net.maintenance),
+            delta_color(net.maintenance),
+        ),
+    ]
+}
@@ -0,0 +1,173 @@
+use crate::data::GameState;
+use crate::ui::theme;
+use crate::ui::theme::colors;
+use crate::PlayerAction;
+use macroquad::prelude::*;
+use macroquad_toolkit::ui::draw_ui_text;
+
+/// Longest name that fits on a Hall of Heroes line
+const MAX_NAME_CHARS: usize = 24;
+
+/// Longest epitaph that can be typed
+const MAX_EPITAPH_CHARS: usize = 80;
+
+const FIELD_H: f32 = 36.0;
+
+/// Which text field the keyboard is typing into
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HeroField {
+    Name,
+    Epitaph,
+}
+
+/// Name and epitaph being written for a villager before they are immortalized (UI only, not saved)
+#[derive(Debug, Clone, PartialEq)]
+pub struct HeroDraft {
+    pub agent_id: u64,
+    pub name: String,
+    pub epitaph: String,
+    pub field: HeroField,
+}
+
+impl HeroDraft {
+    pub fn new(agent_id: u64, name: &str) -> Self {
+        Self {
+            agent_id,
+            name: name.to_string(),
+            epitaph: String::new(),
+            field: HeroField::Epitaph,
+        }
+    }
+
+    /// Name to record, falling back to the villager's own if the field was cleared
+    pub fn display_name(&self, fallback: &str) -> String {
+        let name = self.name.trim();
+        if name.is_empty() {
+            fallback.to_string()
+        } else {
+            name.to_string()
+        }
+    }
+
+    /// Epitaph to record, if one was written
+    pub fn epitaph(&self) -> Option<String> {
+        let epitaph = self.epitaph.trim();
+        (!epitaph.is_empty()).then(|| epitaph.to_string())
+    }
+}
+
+/// Draw the immortalize dialog if a hero is being written up
+pub fn draw_hero_editor(state: &GameState) -> Option<PlayerAction> {
+    let draft = state.hero_draft.as_ref()?;
+    let screen_w = screen_width();
+    let screen_h = screen_height();
+
+    let w = 480.0;
+    let h = 260.0;
+    let x = (screen_w - w) / 2.0;
+    let y = (screen_h - h) / 2.0;
+
+    draw_rectangle(0.0, 0.0, screen_w, screen_h, Color::new(0.0, 0.0, 0.0, 0.5));
+    theme::draw_panel(x, y, w, h);
+    theme::draw_header("Immortalize Hero", x + 20.0, y + 40.0);
+
+    // Typing goes to the focused field; Tab switches fields
+    let mut next = draft.clone();
+    let limit = match draft.field {
+        HeroField::Name => MAX_NAME_CHARS,
+        HeroField::Epitaph => MAX_EPITAPH_CHARS,
+    };
+    let text = match next.field {
+        HeroField::Name => &mut next.name,
+        HeroField::Epitaph => &mut next.epitaph,
+    };
+    while let Some(c) = get_char_pressed() {
+        if !c.is_control() && text.chars().count() < limit {
+            text.push(c);
+        }
+    }
+    if is_key_pressed(KeyCode::Backspace) {
+        text.pop();
+    }
+    if is_key_pressed(KeyCode::Tab) {
+        next.field = match next.field {
+            HeroField::Name => HeroField::Epitaph,
+            HeroField::Epitaph => HeroField::Name,
+        };
+    }
+
+    let mut action = None;
+    let field_w = w - 40.0;
+    let name_y = y + 80.0;
+    let epitaph_y = name_y + FIELD_H + 34.0;
+    if draw_field(x + 20.0, name_y, field_w, "Name", &next, HeroField::Name) {
+        next.field = HeroField::Name;
+    }
+    if draw_field(
+        x + 20.0,
+        epitaph_y,
+        field_w,
+        "Epitaph (optional)",
+        &next,
+        HeroField::Epitaph,
+    ) {
+        next.field = HeroField::Epitaph;
+    }
+    if &next != draft {
+        action = Some(PlayerAction::EditHero(Some(next)));
+    }
+
+    let btn_w = 120.0;
+    let btn_h = 36.0;
+    let btn_y = y + h - btn_h - 16.0;
+    if theme::draw_button(x + w - btn_w * 2.0 - 30.0, btn_y, btn_w, btn_h, "Cancel")
+        || is_key_pressed(KeyCode::Escape)
+    {
+        action = Some(PlayerAction::EditHero(None));
+    }
+    if theme::draw_button(x + w - btn_w - 20.0, btn_y, btn_w, btn_h, "★ Immortalize")
+        || is_key_pressed(KeyCode::Enter)
+    {
+        action = Some(PlayerAction::ImmortalizeHero(draft.agent_id));
+    }
+    action
+}
+
+/// Labelled text box; returns true when clicked
+fn draw_field(x: f32, y: f32, w: f32, label: &str, draft: &HeroDraft, field: HeroField) -> bool {
+    let focused = draft.field == field;
+    let value = match field {
+        HeroField::Name => &draft.name,
+        HeroField::Epitaph => &draft.epitaph,
+    };
+
+    draw_ui_text(label, x, y - 6.0, 16.0, LIGHTGRAY);
+    draw_rectangle(x, y, w, FIELD_H, colors::panel_bg());
+    let border = if focused {
+        colors::accent()
+    } else {
+        colors::panel_border()
+    };
+    draw_rectangle_lines(x, y, w, FIELD_H, 2.0, border);
+
+    // Blinking caret in the focused field
+    let caret = if focused && (get_time() * 2.0) as i64 % 2 == 0 {
+        "_"
+    } else {
+        ""
+    };
+    draw_ui_text(
+        &format!("{}{}", value, caret),
+        x + 8.0,
+        y + 24.0,
+        18.0,
+        colors::text(),
+    );
+
+    let (mx, my) = mouse_position();
+    is_mouse_button_pressed(MouseButton::Left)
+        && mx >= x
+        && mx <= x + w
+        && my >= y
+        && my <= y + FIELD_H
+}
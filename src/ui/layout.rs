@@ -1,10 +1,16 @@
+use super::hero_editor::HeroDraft;
 use super::theme;
 use super::zones;
-use crate::data::GameState;
+use crate::data::{GameState, ZoneTemplate};
+use crate::simulation::workplaces;
 use crate::ui::theme::colors;
+use crate::zones::survey;
 use crate::PlayerAction;
 use macroquad::prelude::*;
-use macroquad_toolkit::ui::draw_ui_text;
+use macroquad_toolkit::ui::{draw_ui_text, measure_ui_text};
+
+/// Names listed in the selection panel before the rest are counted
+const MAX_WORKER_NAMES: usize = 3;
 
 /// Draw the main content layout
 pub fn draw_main_layout(state: &GameState) -> Option<PlayerAction> {
@@ -89,26 +95,116 @@ pub fn draw_main_layout(state: &GameState) -> Option<PlayerAction> {
         action = Some(PlayerAction::ToggleBuildMenu);
     }
 
+    // Pinned zones quick bar (above the buttons)
+    if let Some(act) = super::pinned_zones::draw_pinned_bar(state, screen_w, btn_y) {
+        action = Some(act);
+    }
+
+    // Festival button (top right, under the season display) when this year's festival is due
+    if !state.show_build_menu
+        && state.festival.available(state.season_state.year())
+        && !state.festival.is_active(state.game_time_hours)
+    {
+        let (fx, fy, fw, fh) = festival_button_rect(screen_w);
+        let label = format!(
+            "Hold Festival ({:.0} Mat)",
+            crate::simulation::festival::FESTIVAL_COST
+        );
+        if theme::draw_button(fx, fy, fw, fh, &label) {
+            action = Some(PlayerAction::HoldFestival);
+        }
+    }
+
     // Selection Panel (Floating, Bottom Center) - REMOVED per user request
     // Moved to Left Panel
 
     action
 }
 
+/// Screen rect of the festival button (x, y, w, h)
+pub fn festival_button_rect(screen_w: f32) -> (f32, f32, f32, f32) {
+    (
+        screen_w - 205.0,
+        70.0 + super::season_hud::HUD_H + 6.0,
+        195.0,
+        30.0,
+    )
+}
+
+/// Selection details for a ruin nobody has identified yet
+fn draw_unsurveyed_selection(
+    state: &GameState,
+    idx: usize,
+    x: f32,
+    y: f32,
+    h: f32,
+) -> Option<PlayerAction> {
+    draw_ui_text(zones::UNSURVEYED_NAME, x + 10.0, y + 30.0, 30.0, WHITE);
+    draw_ui_text(zones::UNSURVEYED_HINT, x + 10.0, y + 60.0, 18.0, LIGHTGRAY);
+
+    let btn_x = x + 10.0;
+    let btn_y = y + h - 50.0;
+    let btn_w = 120.0;
+    let btn_h = 30.0;
+    let cost = crate::data::ConstructionCost::Materials(survey::SURVEY_COST);
+    zones::draw_cost_breakdown(state, &cost, btn_x + btn_w + 10.0, btn_y + 20.0);
+    if state.resources.has(&cost) {
+        if theme::draw_button(btn_x, btn_y, btn_w, btn_h, "Survey") {
+            return Some(PlayerAction::SurveyZone(idx));
+        }
+    } else {
+        draw_rectangle(btn_x, btn_y, btn_w, btn_h, DARKGRAY);
+        draw_ui_text("Survey", btn_x + 20.0, btn_y + 20.0, 16.0, GRAY);
+    }
+    None
+}
+
 fn draw_selection_panel(state: &GameState, x: f32, y: f32, w: f32, h: f32) -> Option<PlayerAction> {
     theme::draw_panel(x, y, w, h);
 
     let mut action = None;
 
     match state.selection {
-        crate::data::Selection::Zone(idx) => {
-            if let Some(zone) = state.zones.get(idx) {
-                if let Some(template) = state
+        crate::data::Selection::Zone(id) => {
+            if let Some((idx, zone)) = state.zones.iter().enumerate().find(|(_, z)| z.id == id) {
+                if !zone.surveyed {
+                    action = draw_unsurveyed_selection(state, idx, x, y, h);
+                } else if let Some(template) = state
                     .zone_templates
                     .iter()
                     .find(|t| t.id == zone.template_id)
                 {
                     draw_ui_text(&template.name, x + 10.0, y + 30.0, 30.0, WHITE);
+
+                    // Working markets open the market exchange
+                    if template.category == crate::data::ZoneCategory::Market
+                        && zone.is_operating()
+                        && theme::draw_button(x + w - 110.0, y + 46.0, 100.0, 30.0, "Trade")
+                    {
+                        action = Some(PlayerAction::EditMarketTrade(Some(
+                            state.market_draft.unwrap_or_default(),
+                        )));
+                    }
+
+                    // Move the building to a new site (the next map click places it)
+                    let moving = state.relocating == Some(id);
+                    let move_label = if moving { "Cancel" } else { "Move" };
+                    if zone.footprint(template).is_some()
+                        && theme::draw_button(x + w - 110.0, y + 82.0, 100.0, 30.0, move_label)
+                    {
+                        action = Some(PlayerAction::StartRelocating(if moving {
+                            None
+                        } else {
+                            Some(id)
+                        }));
+                    }
+
+                    // Pin toggle for the quick bar
+                    let pinned = state.pinned_zones.contains(&id);
+                    let star = if pinned { "★" } else { "☆" };
+                    if theme::draw_button(x + w - 40.0, y + 8.0, 30.0, 30.0, star) {
+                        action = Some(PlayerAction::TogglePinZone(id));
+                    }
                     draw_ui_text(
                         &format!("Condition: {:.0}%", zone.condition * 100.0),
                         x + 10.0,
@@ -116,13 +212,17 @@ fn draw_selection_panel(state: &GameState, x: f32, y: f32, w: f32, h: f32) -> Op
                         20.0,
                         LIGHTGRAY,
                     );
-                    draw_ui_text(
-                        &format!("Activity: {:.0}%", zone.activity * 100.0),
-                        x + 10.0,
-                        y + 85.0,
-                        20.0,
-                        LIGHTGRAY,
-                    );
+                    let storage_full = zone.is_operating()
+                        && crate::simulation::stockpiles::is_storage_full(state, template);
+                    let (activity_text, activity_color) = if storage_full {
+                        ("Idle: storage full".to_string(), colors::warn())
+                    } else {
+                        (
+                            format!("Activity: {:.0}%", zone.activity * 100.0),
+                            LIGHTGRAY,
+                        )
+                    };
+                    draw_ui_text(&activity_text, x + 10.0, y + 85.0, 20.0, activity_color);
 
                     // Outputs
                     let mut output_y = 115.0;
@@ -145,11 +245,40 @@ fn draw_selection_panel(state: &GameState, x: f32, y: f32, w: f32, h: f32) -> Op
                             20.0,
                             WHITE,
                         );
+                        output_y += 25.0;
+                    }
+
+                    // Who works here (lines to them are drawn on the map)
+                    let workers: Vec<&str> = workplaces::workers_at(&state.agents, id)
+                        .map(|agent| agent.name.as_str())
+                        .collect();
+                    if !workers.is_empty() || workplaces::is_workplace(template) {
+                        let (text, color) = match workers.len() {
+                            0 => ("Workers: none".to_string(), colors::warn()),
+                            n if n > MAX_WORKER_NAMES => (
+                                format!(
+                                    "Workers: {} (+{} more)",
+                                    workers[..MAX_WORKER_NAMES].join(", "),
+                                    n - MAX_WORKER_NAMES
+                                ),
+                                LIGHTGRAY,
+                            ),
+                            _ => (format!("Workers: {}", workers.join(", ")), LIGHTGRAY),
+                        };
+                        draw_ui_text(&text, x + 10.0, y + output_y, 16.0, color);
                     }
 
                     // Status line at bottom (adjusted Y dynamically)
                     let status_y = y + h - 50.0; // Stick to bottom
 
+                    // Mothball/reopen toggle for working zones (right of the upgrade button)
+                    if !zone.dormant && !zone.is_under_construction() {
+                        let label = if zone.disabled { "Enable" } else { "Disable" };
+                        if theme::draw_button(x + w - 110.0, status_y - 35.0, 100.0, 30.0, label) {
+                            action = Some(PlayerAction::ToggleZoneDisabled(idx));
+                        }
+                    }
+
                     if zone.is_under_construction() {
                         // Show construction progress
                         let progress = zone.construction_progress(template.construction_work);
@@ -158,7 +287,7 @@ fn draw_selection_panel(state: &GameState, x: f32, y: f32, w: f32, h: f32) -> Op
                             x + 10.0,
                             status_y + 20.0,
                             20.0,
-                            colors::WARN,
+                            colors::warn(),
                         );
                     } else if zone.condition < 1.0 {
                         // Restore/Repair Button - use ERROR color for dormant, WARN for damaged
@@ -168,18 +297,46 @@ fn draw_selection_panel(state: &GameState, x: f32, y: f32, w: f32, h: f32) -> Op
                         let btn_x = x + 10.0;
                         let btn_y = status_y;
 
-                        let label = if zone.dormant { "Restore" } else { "Repair" };
-                        if theme::draw_button(btn_x, btn_y, btn_w, btn_h, label) {
-                            action = Some(PlayerAction::RestoreZone(idx));
+                        let label = if zone.dormant { "Rebuild" } else { "Repair" };
+                        let cost = &template.construction_cost;
+                        let missing_tech = state.missing_tech(template);
+                        if let Some(tech) = &missing_tech {
+                            draw_ui_text(
+                                &format!("Requires: {}", tech),
+                                btn_x + btn_w + 10.0,
+                                btn_y + 20.0,
+                                16.0,
+                                colors::warn(),
+                            );
+                        } else {
+                            zones::draw_cost_breakdown(
+                                state,
+                                cost,
+                                btn_x + btn_w + 10.0,
+                                btn_y + 20.0,
+                            );
+                        }
+                        if missing_tech.is_none() && state.resources.has(cost) {
+                            if theme::draw_button(btn_x, btn_y, btn_w, btn_h, label) {
+                                action = Some(PlayerAction::RestoreZone(idx));
+                            }
+                        } else {
+                            // Greyed out until every part of the cost is in stock
+                            draw_rectangle(btn_x, btn_y, btn_w, btn_h, DARKGRAY);
+                            draw_ui_text(label, btn_x + 20.0, btn_y + 20.0, 16.0, GRAY);
                         }
                     } else {
-                        draw_ui_text(
-                            "STATUS: FULLY RESTORED",
-                            x + 10.0,
-                            status_y + 20.0,
-                            20.0,
-                            GREEN,
-                        );
+                        if zone.disabled {
+                            draw_ui_text("STATUS: DISABLED", x + 10.0, status_y + 20.0, 20.0, GRAY);
+                        } else {
+                            draw_ui_text(
+                                "STATUS: FULLY RESTORED",
+                                x + 10.0,
+                                status_y + 20.0,
+                                20.0,
+                                GREEN,
+                            );
+                        }
 
                         // Check if zone can be upgraded
                         if let Some(upgrade_to) = crate::zones::upgrades::can_upgrade(state, idx) {
@@ -193,16 +350,32 @@ fn draw_selection_panel(state: &GameState, x: f32, y: f32, w: f32, h: f32) -> Op
                             if let Some(target_template) =
                                 state.zone_templates.iter().find(|t| t.id == upgrade_to)
                             {
+                                let target_label = format!("→ {}:", target_template.name);
                                 draw_ui_text(
-                                    &format!(
-                                        "→ {}: {} mat",
-                                        target_template.name, target_template.construction_cost
-                                    ),
+                                    &target_label,
                                     x + 10.0,
                                     status_y - 45.0,
                                     14.0,
-                                    if can_afford { colors::ACCENT } else { GRAY },
+                                    if can_afford { colors::accent() } else { GRAY },
                                 );
+                                let label_w = measure_ui_text(&target_label, None, 14, 1.0).width;
+                                draw_upgrade_comparison(template, target_template, x + w + 10.0, y);
+                                if let Some(tech) = state.missing_tech(target_template) {
+                                    draw_ui_text(
+                                        &format!("Requires: {}", tech),
+                                        x + 16.0 + label_w,
+                                        status_y - 45.0,
+                                        14.0,
+                                        colors::warn(),
+                                    );
+                                } else {
+                                    zones::draw_cost_breakdown(
+                                        state,
+                                        &target_template.construction_cost,
+                                        x + 16.0 + label_w,
+                                        status_y - 45.0,
+                                    );
+                                }
                             }
 
                             if can_afford {
@@ -228,7 +401,7 @@ fn draw_selection_panel(state: &GameState, x: f32, y: f32, w: f32, h: f32) -> Op
                     x + 10.0,
                     y + 52.0,
                     18.0,
-                    colors::ACCENT,
+                    colors::accent(),
                 );
 
                 // Stats section
@@ -279,6 +452,9 @@ fn draw_selection_panel(state: &GameState, x: f32, y: f32, w: f32, h: f32) -> Op
                     crate::simulation::agents::AgentState::Building { .. } => {
                         "Building".to_string()
                     }
+                    crate::simulation::agents::AgentState::Leaving { .. } => {
+                        "Leaving town".to_string()
+                    }
                 };
                 draw_ui_text(
                     &format!("Doing: {}", state_text),
@@ -301,20 +477,18 @@ fn draw_selection_panel(state: &GameState, x: f32, y: f32, w: f32, h: f32) -> Op
                 }
 
                 // Immortalize button (bottom of panel)
-                // Only show if agent has accomplished something
-                let has_feats = agent.feats.days_lived >= 30
-                    || agent.feats.buildings_helped > 0
-                    || agent.feats.resources_hauled > 0
-                    || agent.feats.social_events > 0;
-
-                if has_feats {
+                // Only show if agent has accomplished something (and isn't already honoured)
+                if agent.feats.is_notable() && !agent.immortalized {
                     let btn_x = x + 10.0;
                     let btn_y = y + h - 45.0;
                     let btn_w = 130.0;
                     let btn_h = 30.0;
 
                     if theme::draw_button(btn_x, btn_y, btn_w, btn_h, "★ Immortalize") {
-                        action = Some(PlayerAction::ImmortalizeHero(agent.id));
+                        action = Some(PlayerAction::EditHero(Some(HeroDraft::new(
+                            agent.id,
+                            &agent.name,
+                        ))));
                     }
                 }
             }
@@ -322,6 +496,13 @@ fn draw_selection_panel(state: &GameState, x: f32, y: f32, w: f32, h: f32) -> Op
         _ => {}
     }
 
+    // Focus mode toggle (F), beside the pin star
+    if state.selection != crate::data::Selection::None {
+        if theme::draw_button(x + w - 75.0, y + 8.0, 30.0, 30.0, "F") {
+            action = Some(PlayerAction::ToggleFocusMode);
+        }
+    }
+
     action
 }
 
@@ -381,7 +562,7 @@ fn draw_log_panel(state: &GameState, x: f32, y: f32, w: f32, h: f32) {
         }
 
         let color = match entry.category {
-            crate::narrative::LogCategory::System => colors::TEXT,
+            crate::narrative::LogCategory::System => colors::text(),
             crate::narrative::LogCategory::Zone => GREEN,
             crate::narrative::LogCategory::Population => PURPLE,
             crate::narrative::LogCategory::Milestone => GOLD,
@@ -403,3 +584,68 @@ fn draw_log_panel(state: &GameState, x: f32, y: f32, w: f32, h: f32) {
         current_y += 5.0;
     }
 }
+
+/// Current and upgraded stats side by side in a box beside the selection panel
+/// (changes for the better in green, for the worse in red)
+fn draw_upgrade_comparison(current: &ZoneTemplate, target: &ZoneTemplate, x: f32, y: f32) {
+    let changes = crate::zones::upgrades::compare(current, target);
+    let row_h = 18.0;
+    let w = 320.0;
+    let h = 50.0 + changes.len() as f32 * row_h;
+    theme::draw_panel(x, y, w, h);
+    draw_ui_text(
+        &format!("{} → {}", current.name, target.name),
+        x + 10.0,
+        y + 22.0,
+        16.0,
+        WHITE,
+    );
+
+    let columns = [x + 10.0, x + 140.0, x + 200.0, x + 260.0];
+    for (text, column) in ["", "Now", "After", "Change"].iter().zip(columns) {
+        draw_ui_text(text, column, y + 40.0, 13.0, GRAY);
+    }
+    for (i, change) in changes.iter().enumerate() {
+        let row_y = y + 40.0 + (i + 1) as f32 * row_h;
+        let delta = change.delta();
+        let color = if delta == 0.0 {
+            GRAY
+        } else if change.is_improvement() {
+            GREEN
+        } else {
+            RED
+        };
+        draw_ui_text(change.label, columns[0], row_y, 14.0, LIGHTGRAY);
+        draw_ui_text(
+            &format_stat(change.current),
+            columns[1],
+            row_y,
+            14.0,
+            LIGHTGRAY,
+        );
+        draw_ui_text(
+            &format_stat(change.upgraded),
+            columns[2],
+            row_y,
+            14.0,
+            LIGHTGRAY,
+        );
+        let sign = if delta > 0.0 { "+" } else { "" };
+        draw_ui_text(
+            &format!("{}{}", sign, format_stat(delta)),
+            columns[3],
+            row_y,
+            14.0,
+            color,
+        );
+    }
+}
+
+/// Whole numbers for big stats, two decimals for small per-tick rates
+fn format_stat(value: f32) -> String {
+    if value.abs() >= 10.0 {
+        format!("{:.0}", value)
+    } else {
+        format!("{:.2}", value)
+    }
+}
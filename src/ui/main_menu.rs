@@ -0,0 +1,321 @@
+use crate::data::GameState;
+use crate::region::MAX_TOWN_NAME_CHARS;
+use crate::ui::theme;
+use crate::ui::theme::colors;
+use crate::PlayerAction;
+use macroquad::prelude::*;
+use macroquad_toolkit::ui::{draw_ui_text, measure_ui_text};
+
+const BUTTON_W: f32 = 240.0;
+const BUTTON_H: f32 = 44.0;
+const BUTTON_GAP: f32 = 14.0;
+
+/// Step used by the agent cap buttons on the settings page
+const AGENT_CAP_STEP: usize = 10;
+
+/// Step used by the offline cap buttons on the settings page
+const OFFLINE_CAP_STEP_HOURS: f32 = 12.0;
+
+/// Real minutes added or removed per click of the autosave stepper
+const AUTOSAVE_STEP_MINUTES: f32 = 1.0;
+
+/// Materials added or removed per click of the auto-restore reserve stepper
+const RESERVE_STEP: f32 = 25.0;
+
+/// Longest seed that can be typed (always fits in a u64)
+const MAX_SEED_DIGITS: usize = 19;
+
+/// Which page of the main menu is showing
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MenuPage {
+    #[default]
+    Title,
+    NewGame,
+    Settings,
+}
+
+/// Draw the main menu scene
+pub fn draw_main_menu(state: &GameState) -> Option<PlayerAction> {
+    let screen_w = screen_width();
+    let screen_h = screen_height();
+
+    draw_centered("Quiteville", screen_h * 0.22, 64.0, colors::accent());
+    draw_centered(
+        "A small town that grows when you're not watching.",
+        screen_h * 0.22 + 40.0,
+        20.0,
+        LIGHTGRAY,
+    );
+
+    let x = (screen_w - BUTTON_W) / 2.0;
+    let y = screen_h * 0.38;
+    match state.menu_page {
+        MenuPage::Title => draw_title_page(state, x, y),
+        MenuPage::NewGame => draw_new_game_page(state, x, y),
+        MenuPage::Settings => draw_settings_page(state, x, y),
+    }
+}
+
+fn draw_title_page(state: &GameState, x: f32, y: f32) -> Option<PlayerAction> {
+    let mut entries = Vec::new();
+    if state.game_in_progress {
+        entries.push(("Continue", PlayerAction::ContinueGame));
+    } else if state.autosave_found {
+        entries.push(("Resume Autosave", PlayerAction::ResumeAutosave));
+    }
+    entries.push(("New Game", PlayerAction::SetMenuPage(MenuPage::NewGame)));
+    entries.push(("Settings", PlayerAction::SetMenuPage(MenuPage::Settings)));
+    entries.push(("Quit", PlayerAction::QuitGame));
+
+    let mut action = None;
+    for (i, (label, entry_action)) in entries.into_iter().enumerate() {
+        let by = y + i as f32 * (BUTTON_H + BUTTON_GAP);
+        if theme::draw_button(x, by, BUTTON_W, BUTTON_H, label) {
+            action = Some(entry_action);
+        }
+    }
+    action
+}
+
+/// Seed and town name entry before starting a new town
+fn draw_new_game_page(state: &GameState, x: f32, y: f32) -> Option<PlayerAction> {
+    let mut action = None;
+    let row_h = BUTTON_H + BUTTON_GAP;
+
+    // Typing edits the focused field (the seed takes digits only); Tab switches
+    let naming = state.typing_town_name;
+    let mut input = if naming {
+        state.town_name_input.clone()
+    } else {
+        state.seed_input.clone()
+    };
+    while let Some(c) = get_char_pressed() {
+        if naming {
+            if !c.is_control() && input.chars().count() < MAX_TOWN_NAME_CHARS {
+                input.push(c);
+            }
+        } else if c.is_ascii_digit() && input.len() < MAX_SEED_DIGITS {
+            input.push(c);
+        }
+    }
+    if is_key_pressed(KeyCode::Backspace) {
+        input.pop();
+    }
+    if naming && input != state.town_name_input {
+        action = Some(PlayerAction::SetTownNameInput(input));
+    } else if !naming && input != state.seed_input {
+        action = Some(PlayerAction::SetSeedInput(input));
+    }
+    if is_key_pressed(KeyCode::Tab) {
+        action = Some(PlayerAction::TypeTownName(!naming));
+    }
+
+    if draw_text_field(x, y, "Region seed", &state.seed_input, !naming) {
+        action = Some(PlayerAction::TypeTownName(false));
+    }
+    let name_x = x + BUTTON_W + 16.0;
+    if draw_text_field(name_x, y, "Town name", &state.town_name_input, naming) {
+        action = Some(PlayerAction::TypeTownName(true));
+    }
+    if state.town_name_input.is_empty() && !naming {
+        draw_ui_text("Quiteville", name_x + 10.0, y + 29.0, 22.0, GRAY);
+    }
+
+    if theme::draw_button(x, y + row_h, BUTTON_W, BUTTON_H, "Random Seed") {
+        let seed = crate::simulation::rng::clock_seed();
+        action = Some(PlayerAction::SetSeedInput(seed.to_string()));
+    }
+
+    // Difficulty cycles through the presets
+    let difficulty = state.difficulty_choice;
+    let difficulty_y = y + row_h * 2.0;
+    let difficulty_label = format!("Difficulty: {}", difficulty.name());
+    if theme::draw_button(x, difficulty_y, BUTTON_W, BUTTON_H, &difficulty_label) {
+        action = Some(PlayerAction::SetDifficultyChoice(difficulty.next()));
+    }
+    draw_ui_text(
+        difficulty.description(),
+        x + BUTTON_W + 16.0,
+        difficulty_y + 28.0,
+        16.0,
+        LIGHTGRAY,
+    );
+
+    let seed = state.seed_input.parse::<u64>().ok();
+    let start_y = y + row_h * 3.0;
+    match seed {
+        Some(seed) => {
+            if theme::draw_button(x, start_y, BUTTON_W, BUTTON_H, "Start")
+                || is_key_pressed(KeyCode::Enter)
+            {
+                action = Some(PlayerAction::NewGame(seed, difficulty));
+            }
+        }
+        None => {
+            draw_rectangle(x, start_y, BUTTON_W, BUTTON_H, DARKGRAY);
+            draw_ui_text("Enter a seed", x + 10.0, start_y + 28.0, 20.0, GRAY);
+        }
+    }
+
+    if theme::draw_button(x, y + row_h * 4.0, BUTTON_W, BUTTON_H, "Back")
+        || is_key_pressed(KeyCode::Escape)
+    {
+        action = Some(PlayerAction::SetMenuPage(MenuPage::Title));
+    }
+    action
+}
+
+/// Labelled text box with a blinking caret when focused; returns true when clicked
+fn draw_text_field(x: f32, y: f32, label: &str, value: &str, focused: bool) -> bool {
+    draw_ui_text(label, x, y - 8.0, 18.0, LIGHTGRAY);
+    draw_rectangle(x, y, BUTTON_W, BUTTON_H, colors::panel_bg());
+    let border = if focused {
+        colors::accent()
+    } else {
+        colors::panel_border()
+    };
+    draw_rectangle_lines(x, y, BUTTON_W, BUTTON_H, 2.0, border);
+    let caret = if focused && (get_time() * 2.0) as i64 % 2 == 0 {
+        "_"
+    } else {
+        ""
+    };
+    draw_ui_text(
+        &format!("{}{}", value, caret),
+        x + 10.0,
+        y + 29.0,
+        22.0,
+        colors::text(),
+    );
+
+    let (mx, my) = mouse_position();
+    is_mouse_button_pressed(MouseButton::Left)
+        && mx >= x
+        && mx <= x + BUTTON_W
+        && my >= y
+        && my <= y + BUTTON_H
+}
+
+fn draw_settings_page(state: &GameState, x: f32, y: f32) -> Option<PlayerAction> {
+    let settings = &state.settings;
+    let mut action = None;
+    let row_h = BUTTON_H + BUTTON_GAP;
+    // Two columns: general options on the left, auto-restore and aids on the right
+    let right = x + (BUTTON_W + BUTTON_GAP) / 2.0;
+    let x = x - (BUTTON_W + BUTTON_GAP) / 2.0;
+
+    let on_off = |on: bool| if on { "On" } else { "Off" };
+    let edge_label = format!("Edge scrolling: {}", on_off(settings.edge_scroll));
+    if theme::draw_button(x, y, BUTTON_W, BUTTON_H, &edge_label) {
+        action = Some(PlayerAction::ToggleEdgeScroll);
+    }
+
+    let fund_label = format!("Wonder auto-fund: {}", on_off(settings.wonder_auto_fund));
+    if theme::draw_button(x, y + row_h, BUTTON_W, BUTTON_H, &fund_label) {
+        action = Some(PlayerAction::ToggleWonderAutoFund);
+    }
+
+    // Agent cap stepper
+    let cap_text = format!("Villagers: {}", settings.agent_cap);
+    if let Some(step) = draw_stepper(x, y + row_h * 2.0, &cap_text) {
+        let cap = if step < 0 {
+            settings.agent_cap.saturating_sub(AGENT_CAP_STEP)
+        } else {
+            settings.agent_cap + AGENT_CAP_STEP
+        };
+        action = Some(PlayerAction::SetAgentCap(cap));
+    }
+
+    // Offline cap stepper (base hours, up to the difficulty's cap; tech and legacy
+    // bonuses add on top)
+    let difficulty_cap = state.config.offline_time_cap_hours;
+    let base_cap = settings
+        .offline_cap_hours
+        .map_or(difficulty_cap, |hours| hours.min(difficulty_cap));
+    let offline_text = format!("Offline cap: {:.0}h", state.offline_cap_hours());
+    if let Some(step) = draw_stepper(x, y + row_h * 3.0, &offline_text) {
+        let cap = (base_cap + step as f32 * OFFLINE_CAP_STEP_HOURS).min(difficulty_cap);
+        action = Some(PlayerAction::SetOfflineCap(cap));
+    }
+
+    let popup_label = format!("Floating text: {}", on_off(settings.floating_text));
+    if theme::draw_button(x, y + row_h * 4.0, BUTTON_W, BUTTON_H, &popup_label) {
+        action = Some(PlayerAction::ToggleFloatingText);
+    }
+
+    let auto_label = format!("Auto-restore: {}", on_off(settings.auto_restore));
+    if theme::draw_button(right, y, BUTTON_W, BUTTON_H, &auto_label) {
+        action = Some(PlayerAction::ToggleAutoRestore);
+    }
+
+    // Materials auto-restore never dips below
+    let reserve_text = format!("Reserve: {:.0} Mat", settings.auto_restore_reserve);
+    if let Some(step) = draw_stepper(right, y + row_h, &reserve_text) {
+        let reserve = settings.auto_restore_reserve + step as f32 * RESERVE_STEP;
+        action = Some(PlayerAction::SetAutoRestoreReserve(reserve));
+    }
+
+    let priority_label = format!("Priority: {} first", settings.auto_restore_priority.name());
+    if theme::draw_button(right, y + row_h * 2.0, BUTTON_W, BUTTON_H, &priority_label) {
+        action = Some(PlayerAction::ToggleRestorePriority);
+    }
+
+    let coords_label = format!("Coordinates: {}", on_off(settings.show_coordinates));
+    if theme::draw_button(right, y + row_h * 3.0, BUTTON_W, BUTTON_H, &coords_label) {
+        action = Some(PlayerAction::ToggleCoordinates);
+    }
+
+    let formulas_label = format!("Formulas (F3): {}", on_off(settings.show_formulas));
+    if theme::draw_button(right, y + row_h * 4.0, BUTTON_W, BUTTON_H, &formulas_label) {
+        action = Some(PlayerAction::ToggleFormulaOverlay);
+    }
+
+    // Autosave interval stepper (down to zero turns it off)
+    let autosave_text = if settings.autosave_minutes > 0.0 {
+        format!("Autosave: {:.0} min", settings.autosave_minutes)
+    } else {
+        "Autosave: Off".to_string()
+    };
+    if let Some(step) = draw_stepper(right, y + row_h * 5.0, &autosave_text) {
+        let minutes = settings.autosave_minutes + step as f32 * AUTOSAVE_STEP_MINUTES;
+        action = Some(PlayerAction::SetAutosaveInterval(minutes));
+    }
+
+    let scheme_label = format!("Colours: {}", settings.color_scheme.name());
+    if theme::draw_button(x, y + row_h * 5.0, BUTTON_W, BUTTON_H, &scheme_label) {
+        action = Some(PlayerAction::CycleColorScheme);
+    }
+
+    if theme::draw_button(x, y + row_h * 6.0, BUTTON_W, BUTTON_H, "Back")
+        || is_key_pressed(KeyCode::Escape)
+    {
+        action = Some(PlayerAction::SetMenuPage(MenuPage::Title));
+    }
+    action
+}
+
+/// `[-] label [+]` row; returns -1 or +1 when a button is clicked
+fn draw_stepper(x: f32, y: f32, label: &str) -> Option<i32> {
+    let step_w = BUTTON_H;
+    let label_w = measure_ui_text(label, None, 20, 1.0).width;
+    draw_ui_text(
+        label,
+        x + (BUTTON_W - label_w) / 2.0,
+        y + 28.0,
+        20.0,
+        colors::text(),
+    );
+
+    if theme::draw_button(x, y, step_w, BUTTON_H, "-") {
+        Some(-1)
+    } else if theme::draw_button(x + BUTTON_W - step_w, y, step_w, BUTTON_H, "+") {
+        Some(1)
+    } else {
+        None
+    }
+}
+
+fn draw_centered(text: &str, y: f32, size: f32, color: Color) {
+    let w = measure_ui_text(text, None, size as u16, 1.0).width;
+    draw_ui_text(text, (screen_width() - w) / 2.0, y, size, color);
+}
@@ -1,11 +1,24 @@
 use crate::data::GameState;
+use crate::simulation::agents::{Agent, Job};
 use crate::simulation::camera::Camera2D;
 use crate::simulation::map::TileType;
+use crate::simulation::seasons::Season;
+use crate::zones::ReawakeningStage;
 use macroquad::prelude::*;
 use macroquad_toolkit::ui::draw_ui_text;
 
 pub const TILE_SIZE: f32 = 32.0;
 
+/// Zone activity indicators are hidden below this zoom level
+const ACTIVITY_MIN_ZOOM: f32 = 0.5;
+
+/// Zones above this activity get extra villagers milling around
+const BUSTLE_THRESHOLD: f32 = 0.6;
+
+/// Opacity of the affordability wash over ruins, and over the one under the mouse
+const RESTORE_TINT_ALPHA: f32 = 0.08;
+const RESTORE_TINT_HOVER_ALPHA: f32 = 0.2;
+
 /// Draw the world map and agents
 pub fn draw_map(state: &GameState) {
     let map = &state.world_map;
@@ -41,11 +54,13 @@ pub fn draw_map(state: &GameState) {
                     TileType::Floor => "tile_floor",
                     TileType::Wall => "tile_wall",
                     TileType::Ruins => "tile_ruins",
+                    // Planks are drawn over the water below
+                    TileType::Bridge => "tile_water",
                 };
 
                 // If it's a zone and dormant, force ruins appearance on ground
-                let is_dormant_zone = if let Some(z_idx) = tile.zone_id {
-                    state.zones.get(z_idx).map(|z| z.dormant).unwrap_or(false)
+                let is_dormant_zone = if let Some(zone_id) = tile.zone_id {
+                    state.zone(zone_id).map(|z| z.dormant).unwrap_or(false)
                 } else {
                     false
                 };
@@ -71,6 +86,9 @@ pub fn draw_map(state: &GameState) {
                     // Fallback color
                     draw_rectangle(screen_pos.x, screen_pos.y, size, size, GRAY);
                 }
+                if tile.kind == TileType::Bridge {
+                    draw_bridge_planks(screen_pos, size);
+                }
 
                 // Grid lines (faint)
                 if camera.zoom > 0.8 {
@@ -88,9 +106,10 @@ pub fn draw_map(state: &GameState) {
     }
 
     // 2. Draw Buildings (Active Zones and Under Construction)
-    for (zone_idx, zone) in state.zones.iter().enumerate() {
-        // Skip dormant zones that aren't under construction
+    for zone in &state.zones {
+        // Skip dormant zones that aren't under construction (just tint their ruins)
         if zone.dormant && !zone.is_under_construction() {
+            draw_restore_tint(state, camera, zone);
             continue;
         }
 
@@ -99,7 +118,7 @@ pub fn draw_map(state: &GameState) {
             .iter()
             .find(|t| t.id == zone.template_id)
         {
-            if let Some(rect) = template.map_rect {
+            if let Some(rect) = zone.footprint(template) {
                 let world_pos = vec2(rect.x as f32 * TILE_SIZE, rect.y as f32 * TILE_SIZE);
                 let screen_pos = camera.world_to_screen(world_pos);
 
@@ -138,28 +157,71 @@ pub fn draw_map(state: &GameState) {
                     _ => "tile_ruins", // Fallback
                 };
 
-                // Tint for construction or normal
-                let tint = if is_under_construction {
-                    Color::new(0.6, 0.6, 0.8, 0.7) // Blue-ish transparent for construction
-                } else {
-                    WHITE
-                };
-
-                if let Some(tex) = state.assets.get(tex_name) {
-                    draw_texture_ex(
-                        tex,
-                        screen_pos.x,
-                        screen_pos.y,
-                        tint,
-                        DrawTextureParams {
-                            dest_size: Some(vec2(width, height)),
-                            ..Default::default()
-                        },
-                    );
-                } else {
-                    // Fallback box
-                    let color = if is_under_construction { BLUE } else { BROWN };
-                    draw_rectangle(screen_pos.x, screen_pos.y, width, height, color);
+                // Rebuilt ruins come back in stages: scaffolding over the rubble, then the
+                // building rising from the ground up, then finished (brightness follows activity)
+                let stage = zone.reawakening();
+                let texture = state.assets.get(tex_name);
+                match (stage, texture) {
+                    (ReawakeningStage::Ruin | ReawakeningStage::Scaffolding, _) => {
+                        if let Some(ruins) = state.assets.get("tile_ruins") {
+                            draw_texture_ex(
+                                ruins,
+                                screen_pos.x,
+                                screen_pos.y,
+                                WHITE,
+                                DrawTextureParams {
+                                    dest_size: Some(vec2(width, height)),
+                                    ..Default::default()
+                                },
+                            );
+                        } else {
+                            draw_rectangle(screen_pos.x, screen_pos.y, width, height, DARKBROWN);
+                        }
+                        draw_scaffolding(screen_pos, width, height, camera.zoom);
+                    }
+                    (ReawakeningStage::Partial, Some(tex)) => {
+                        // Lower half of the finished building, scaffolding around the rest
+                        let source_h = tex.height() / 2.0;
+                        draw_texture_ex(
+                            tex,
+                            screen_pos.x,
+                            screen_pos.y + height / 2.0,
+                            WHITE,
+                            DrawTextureParams {
+                                dest_size: Some(vec2(width, height / 2.0)),
+                                source: Some(Rect::new(0.0, source_h, tex.width(), source_h)),
+                                ..Default::default()
+                            },
+                        );
+                        draw_scaffolding(screen_pos, width, height, camera.zoom);
+                    }
+                    (ReawakeningStage::Partial, None) => {
+                        draw_rectangle(
+                            screen_pos.x,
+                            screen_pos.y + height / 2.0,
+                            width,
+                            height / 2.0,
+                            BROWN,
+                        );
+                        draw_scaffolding(screen_pos, width, height, camera.zoom);
+                    }
+                    (ReawakeningStage::Awake, Some(tex)) => {
+                        let brightness = 0.6 + 0.4 * zone.activity.clamp(0.0, 1.0);
+                        draw_texture_ex(
+                            tex,
+                            screen_pos.x,
+                            screen_pos.y,
+                            Color::new(brightness, brightness, brightness, 1.0),
+                            DrawTextureParams {
+                                dest_size: Some(vec2(width, height)),
+                                ..Default::default()
+                            },
+                        );
+                    }
+                    (ReawakeningStage::Awake, None) => {
+                        // Fallback box
+                        draw_rectangle(screen_pos.x, screen_pos.y, width, height, BROWN);
+                    }
                 }
 
                 // Draw construction progress bar for zones under construction
@@ -195,8 +257,13 @@ pub fn draw_map(state: &GameState) {
                     }
                 }
 
+                // Activity meter and bustle (hidden when zoomed far out)
+                if !is_under_construction && camera.zoom > ACTIVITY_MIN_ZOOM {
+                    draw_zone_activity(state, zone.id, zone.activity, screen_pos, width, height);
+                }
+
                 // Highlight selected zone
-                if matches!(state.selection, crate::data::Selection::Zone(idx) if idx == zone_idx) {
+                if matches!(state.selection, crate::data::Selection::Zone(id) if id == zone.id) {
                     draw_rectangle_lines(
                         screen_pos.x - 2.0,
                         screen_pos.y - 2.0,
@@ -210,6 +277,20 @@ pub fn draw_map(state: &GameState) {
         }
     }
 
+    // Where a zone being moved, a bridge or a blueprint would go
+    if let Some(id) = state.relocating {
+        draw_relocation_ghost(state, camera, id);
+    }
+    if state.placing_bridge {
+        draw_bridge_ghost(state, camera);
+    }
+    super::blueprints::draw_map_overlay(state, camera);
+
+    // Lines from the selected zone to the villagers who work there
+    if let crate::data::Selection::Zone(id) = state.selection {
+        draw_worker_lines(state, camera, id);
+    }
+
     // 3. Draw Agents
     draw_agents(state, camera);
 
@@ -232,29 +313,246 @@ pub fn draw_map(state: &GameState) {
 
     // 5. Draw Day/Night Overlay
     draw_day_night_overlay(state.game_hour);
+    state.day_transition.draw();
 
     // 6. Draw Particle System (Weather, Smoke)
     state.particle_system.draw(camera);
 
-    // 7. Draw Season/Weather HUD
-    draw_season_hud(state);
+    // 7. Dim everything but the selection in focus mode
+    if state.focus_mode {
+        super::focus::draw_focus_overlay(state);
+    }
 }
 
-/// Draw season and weather info in corner
-fn draw_season_hud(state: &GameState) {
-    let text = state.season_state.display_string();
-    let x = screen_width() - 200.0;
-    let y = 50.0;
+/// Wooden poles, planks and braces over a site being rebuilt
+fn draw_scaffolding(pos: Vec2, width: f32, height: f32, zoom: f32) {
+    let wood = Color::new(0.55, 0.38, 0.2, 0.9);
+    let thickness = (2.0 * zoom).max(1.0);
+    let bays = 3;
+    let levels = 3;
+    let bay_w = width / bays as f32;
+    let level_h = height / levels as f32;
+
+    for i in 0..=bays {
+        let x = pos.x + i as f32 * bay_w;
+        draw_line(x, pos.y, x, pos.y + height, thickness, wood);
+    }
+    for level in 0..levels {
+        let y = pos.y + level as f32 * level_h;
+        draw_line(pos.x, y, pos.x + width, y, thickness, wood);
+        // One brace per bay, alternating direction
+        for i in 0..bays {
+            let x = pos.x + i as f32 * bay_w;
+            if (i + level) % 2 == 0 {
+                draw_line(x, y, x + bay_w, y + level_h, thickness * 0.6, wood);
+            } else {
+                draw_line(x + bay_w, y, x, y + level_h, thickness * 0.6, wood);
+            }
+        }
+    }
+}
 
-    // Background
+/// Faint lines from a zone to each of its workers, with a ring around each worker
+fn draw_worker_lines(state: &GameState, camera: &Camera2D, id: crate::zones::ZoneId) {
+    let center = match state.zone(id).and_then(|zone| state.zone_rect(zone)) {
+        Some(rect) => camera.world_to_screen(rect.world_center()),
+        None => return,
+    };
+    let line_color = Color::new(1.0, 1.0, 1.0, 0.35);
+    for agent in crate::simulation::workplaces::workers_at(&state.agents, id) {
+        let pos = camera.world_to_screen(agent.pos);
+        draw_line(center.x, center.y, pos.x, pos.y, 1.5, line_color);
+        draw_circle_lines(pos.x, pos.y, 6.0 * camera.zoom.max(0.5), 1.5, GOLD);
+    }
+}
+
+/// Faint wash over a ruin: green if its restoration can be paid for right now,
+/// red if it costs more than the town has (or still waits on research).
+/// Stronger under the mouse.
+fn draw_restore_tint(state: &GameState, camera: &Camera2D, zone: &crate::zones::Zone) {
+    // Nothing to judge until the rubble has been surveyed
+    if !zone.surveyed {
+        return;
+    }
+    let template = match state.get_template(&zone.template_id) {
+        Some(template) => template,
+        None => return,
+    };
+    let rect = match zone.footprint(template) {
+        Some(rect) => rect,
+        None => return,
+    };
+    let screen_pos =
+        camera.world_to_screen(vec2(rect.x as f32 * TILE_SIZE, rect.y as f32 * TILE_SIZE));
+    let width = rect.w as f32 * TILE_SIZE * camera.zoom;
+    let height = rect.h as f32 * TILE_SIZE * camera.zoom;
+
+    let affordable =
+        state.resources.has(&template.construction_cost) && state.missing_tech(template).is_none();
+    let color = if affordable { GREEN } else { RED };
+    let (mx, my) = mouse_position();
+    let hovered = mx >= screen_pos.x
+        && mx <= screen_pos.x + width
+        && my >= screen_pos.y
+        && my <= screen_pos.y + height;
+    let alpha = if hovered {
+        RESTORE_TINT_HOVER_ALPHA
+    } else {
+        RESTORE_TINT_ALPHA
+    };
     draw_rectangle(
-        x - 5.0,
-        y - 15.0,
-        195.0,
-        25.0,
-        Color::new(0.0, 0.0, 0.0, 0.5),
+        screen_pos.x,
+        screen_pos.y,
+        width,
+        height,
+        Color { a: alpha, ..color },
     );
-    draw_ui_text(&text, x, y, 18.0, WHITE);
+}
+
+/// Outline of the site under the mouse: green if the zone can move there, red if not
+fn draw_relocation_ghost(state: &GameState, camera: &Camera2D, id: crate::zones::ZoneId) {
+    use crate::zones::relocate;
+
+    let mouse: Vec2 = mouse_position().into();
+    let tile = (camera.screen_to_world(mouse) / TILE_SIZE).floor();
+    if tile.x < 0.0 || tile.y < 0.0 {
+        return;
+    }
+    let site = match relocate::site_around(state, id, tile.x as usize, tile.y as usize) {
+        Some(site) => site,
+        None => return,
+    };
+
+    let problem = relocate::problem(state, id, site);
+    let color = if problem.is_some() { RED } else { GREEN };
+    let screen_pos =
+        camera.world_to_screen(vec2(site.x as f32 * TILE_SIZE, site.y as f32 * TILE_SIZE));
+    let width = site.w as f32 * TILE_SIZE * camera.zoom;
+    let height = site.h as f32 * TILE_SIZE * camera.zoom;
+    draw_rectangle(
+        screen_pos.x,
+        screen_pos.y,
+        width,
+        height,
+        Color { a: 0.25, ..color },
+    );
+    draw_rectangle_lines(screen_pos.x, screen_pos.y, width, height, 2.0, color);
+
+    let hint = match problem {
+        Some(problem) => format!("Can't build here: {}", problem),
+        None => format!(
+            "Click to move here ({:.0} Mat) - Esc to cancel",
+            relocate::RELOCATE_COST
+        ),
+    };
+    draw_ui_text(&hint, mouse.x + 16.0, mouse.y - 8.0, 16.0, color);
+}
+
+/// Wooden decking over a bridged water tile
+fn draw_bridge_planks(pos: Vec2, size: f32) {
+    let wood = Color::new(0.55, 0.38, 0.2, 1.0);
+    let inset = size * 0.1;
+    draw_rectangle(pos.x, pos.y + inset, size, size - inset * 2.0, wood);
+    let seam = Color::new(0.35, 0.24, 0.12, 1.0);
+    for i in 1..4 {
+        let x = pos.x + size * i as f32 / 4.0;
+        draw_line(x, pos.y + inset, x, pos.y + size - inset, 1.0, seam);
+    }
+}
+
+/// The tile a bridge would go on, green over open water
+fn draw_bridge_ghost(state: &GameState, camera: &Camera2D) {
+    use crate::simulation::map::BRIDGE_COST;
+
+    let mouse: Vec2 = mouse_position().into();
+    let tile = (camera.screen_to_world(mouse) / TILE_SIZE).floor();
+    if tile.x < 0.0 || tile.y < 0.0 {
+        return;
+    }
+    let problem = state
+        .world_map
+        .bridge_problem(tile.x as usize, tile.y as usize);
+    let color = if problem.is_some() { RED } else { GREEN };
+    let screen_pos = camera.world_to_screen(tile * TILE_SIZE);
+    let size = TILE_SIZE * camera.zoom;
+    draw_rectangle(
+        screen_pos.x,
+        screen_pos.y,
+        size,
+        size,
+        Color { a: 0.25, ..color },
+    );
+    draw_rectangle_lines(screen_pos.x, screen_pos.y, size, size, 2.0, color);
+
+    let hint = match problem {
+        Some(problem) => format!("Can't bridge here: {}", problem),
+        None => format!(
+            "Click to lay a bridge ({:.0} Mat) - Esc to stop",
+            BRIDGE_COST
+        ),
+    };
+    draw_ui_text(&hint, mouse.x + 16.0, mouse.y - 8.0, 16.0, color);
+}
+
+/// Small activity meter above a building, plus decorative villagers around busy zones
+fn draw_zone_activity(
+    state: &GameState,
+    zone_id: crate::zones::ZoneId,
+    activity: f32,
+    screen_pos: Vec2,
+    width: f32,
+    height: f32,
+) {
+    let zoom = state.camera.zoom;
+    let activity = activity.clamp(0.0, 1.0);
+
+    // Meter: dim when idle, bright when bustling
+    let bar_w = width * 0.6;
+    let bar_h = 4.0 * zoom;
+    let bar_x = screen_pos.x + (width - bar_w) / 2.0;
+    let bar_y = screen_pos.y - bar_h - 2.0 * zoom;
+    draw_rectangle(bar_x, bar_y, bar_w, bar_h, Color::new(0.0, 0.0, 0.0, 0.5));
+    draw_rectangle(
+        bar_x,
+        bar_y,
+        bar_w * activity,
+        bar_h,
+        Color::new(0.3 + 0.7 * activity, 0.8, 0.3, 0.6 + 0.4 * activity),
+    );
+
+    if activity < BUSTLE_THRESHOLD {
+        return;
+    }
+
+    // Extra villagers milling about (purely visual, not simulated agents)
+    let count = 1 + ((activity - BUSTLE_THRESHOLD) / (1.0 - BUSTLE_THRESHOLD) * 2.0) as usize;
+    let size = 16.0 * zoom;
+    let center = screen_pos + vec2(width / 2.0, height);
+    let t = get_time() as f32;
+
+    for i in 0..count {
+        let phase = zone_id.0 as f32 * 1.7 + i as f32 * 2.1;
+        let offset = vec2(
+            (t * 0.5 + phase).sin() * width * 0.4,
+            (t * 0.3 + phase).cos() * 6.0 * zoom,
+        );
+        let pos = center + offset;
+
+        if let Some(tex) = state.assets.get("agent_villager") {
+            draw_texture_ex(
+                tex,
+                pos.x - size / 2.0,
+                pos.y - size / 2.0,
+                Color::new(0.85, 0.85, 0.85, 0.9),
+                DrawTextureParams {
+                    dest_size: Some(vec2(size, size)),
+                    ..Default::default()
+                },
+            );
+        } else {
+            draw_circle(pos.x, pos.y, size / 2.0, LIGHTGRAY);
+        }
+    }
 }
 
 /// Draw a screen-wide tint based on time of day
@@ -291,6 +589,7 @@ fn draw_day_night_overlay(game_hour: f32) {
 }
 
 fn draw_agents(state: &GameState, camera: &Camera2D) {
+    let season = state.season_state.season;
     for agent in &state.agents {
         let screen_pos = camera.world_to_screen(agent.pos);
         let size = 24.0 * camera.zoom; // Agents slightly larger sprite
@@ -310,8 +609,11 @@ fn draw_agents(state: &GameState, camera: &Camera2D) {
             let draw_x = screen_pos.x - size / 2.0;
             let draw_y = screen_pos.y - size / 2.0;
 
-            // Tint with agent color
-            let color = Color::new(agent.color[0], agent.color[1], agent.color[2], 1.0);
+            // Tint with agent color, bundled up for the season
+            let color = Color {
+                a: 1.0,
+                ..clothing_tint(agent, season)
+            };
 
             draw_texture_ex(
                 tex,
@@ -325,26 +627,15 @@ fn draw_agents(state: &GameState, camera: &Camera2D) {
             );
         } else {
             // Fallback Circle
-            let color = Color::new(
-                agent.color[0],
-                agent.color[1],
-                agent.color[2],
-                agent.color[3],
-            );
+            let color = clothing_tint(agent, season);
             draw_circle(screen_pos.x, screen_pos.y, size / 2.0, color);
         }
+        draw_outfit(state, agent, season, screen_pos, size);
 
-        // State Indicators (Thoughts/Emotes) - Using Icons
-        let icon_name = match agent.state {
-            crate::simulation::agents::AgentState::Shopping { .. } => "icon_thought_shopping",
-            crate::simulation::agents::AgentState::Working { .. } => "icon_thought_working",
-            crate::simulation::agents::AgentState::Socializing { .. } => "icon_thought_social",
-            crate::simulation::agents::AgentState::GoingHome => "icon_thought_sleep",
-            _ => "",
-        };
-
-        if !icon_name.is_empty() {
-            if let Some(tex) = state.assets.get(icon_name) {
+        // Thought bubble (contextual, refreshed by the simulation)
+        if let Some(thought) = agent.thought {
+            let icon = thought.icon().and_then(|name| state.assets.get(name));
+            if let Some(tex) = icon {
                 let icon_size = 20.0 * camera.zoom;
                 draw_texture_ex(
                     tex,
@@ -357,21 +648,87 @@ fn draw_agents(state: &GameState, camera: &Camera2D) {
                     },
                 );
             } else {
-                // Fallback Text if icon missing
-                let text = match agent.state {
-                    crate::simulation::agents::AgentState::Shopping { .. } => "$",
-                    crate::simulation::agents::AgentState::Working { .. } => "W",
-                    crate::simulation::agents::AgentState::Socializing { .. } => "<3",
-                    crate::simulation::agents::AgentState::GoingHome => "Zzz",
-                    _ => "!",
-                };
-                draw_ui_text(text, screen_pos.x, screen_pos.y - size, 20.0, WHITE);
+                let c = thought.color();
+                draw_ui_text(
+                    thought.text(),
+                    screen_pos.x,
+                    screen_pos.y - size,
+                    20.0,
+                    Color::new(c[0], c[1], c[2], c[3]),
+                );
             }
         }
+    }
+}
 
-        // Status Indicator (Hungry/Tired?)
-        if agent.hunger < 0.3 && icon_name.is_empty() {
-            draw_ui_text("!", screen_pos.x, screen_pos.y - size, 20.0, RED);
+/// A villager's own colour, mixed toward warm clothes as the year cools
+fn clothing_tint(agent: &Agent, season: Season) -> Color {
+    let own = Color::new(
+        agent.color[0],
+        agent.color[1],
+        agent.color[2],
+        agent.color[3],
+    );
+    let (clothes, share) = match season {
+        Season::Spring | Season::Summer => return own,
+        Season::Autumn => (Color::new(0.6, 0.35, 0.2, 1.0), 0.25), // Russet shawls
+        Season::Winter => (Color::new(0.4, 0.35, 0.32, 1.0), 0.5), // Wool coats
+    };
+    Color::new(
+        own.r + (clothes.r - own.r) * share,
+        own.g + (clothes.g - own.g) * share,
+        own.b + (clothes.b - own.b) * share,
+        own.a,
+    )
+}
+
+/// Overlay art for a job (laborers wear nothing special)
+fn job_overlay(job: Job) -> Option<&'static str> {
+    match job {
+        Job::Laborer => None,
+        Job::Farmer => Some("agent_job_farmer"),
+        Job::Cook => Some("agent_job_cook"),
+        Job::Scavenger => Some("agent_job_scavenger"),
+        Job::Builder => Some("agent_job_builder"),
+        Job::Hauler => Some("agent_job_hauler"),
+    }
+}
+
+/// Hat colour drawn for a job when its overlay art is missing
+fn job_hat_color(job: Job) -> Color {
+    match job {
+        Job::Laborer => GRAY,
+        Job::Farmer => Color::new(0.9, 0.8, 0.35, 1.0), // Straw
+        Job::Cook => WHITE,
+        Job::Scavenger => Color::new(0.45, 0.3, 0.2, 1.0), // Leather
+        Job::Builder => ORANGE,
+        Job::Hauler => Color::new(0.3, 0.45, 0.75, 1.0),
+    }
+}
+
+/// Winter coat and job accessory over a villager, from overlay art where it
+/// exists and simple shapes where it doesn't
+fn draw_outfit(state: &GameState, agent: &Agent, season: Season, pos: Vec2, size: f32) {
+    let params = DrawTextureParams {
+        dest_size: Some(vec2(size, size)),
+        ..Default::default()
+    };
+    let (x, y) = (pos.x - size / 2.0, pos.y - size / 2.0);
+
+    if season == Season::Winter {
+        if let Some(tex) = state.assets.get("agent_coat_winter") {
+            draw_texture_ex(tex, x, y, WHITE, params.clone());
         }
     }
+
+    let overlay = match job_overlay(agent.job) {
+        Some(name) => name,
+        None => return,
+    };
+    if let Some(tex) = state.assets.get(overlay) {
+        draw_texture_ex(tex, x, y, WHITE, params);
+    } else {
+        let hat = job_hat_color(agent.job);
+        draw_circle(pos.x, pos.y - size * 0.35, size * 0.16, hat);
+    }
 }
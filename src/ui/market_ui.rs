@@ -0,0 +1,175 @@
+use crate::data::GameState;
+use crate::economy::market;
+use crate::economy::ResourceType;
+use crate::ui::theme;
+use crate::ui::theme::colors;
+use crate::PlayerAction;
+use macroquad::prelude::*;
+use macroquad_toolkit::ui::draw_ui_text;
+
+const ROW_H: f32 = 26.0;
+
+/// Step sizes offered for the trade amount
+const AMOUNT_STEPS: [f32; 2] = [1.0, 10.0];
+
+/// Goods picked for a market trade (UI only, not saved)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MarketDraft {
+    pub sell: ResourceType,
+    pub buy: ResourceType,
+    pub amount: f32,
+}
+
+impl Default for MarketDraft {
+    fn default() -> Self {
+        Self {
+            sell: ResourceType::Logs,
+            buy: ResourceType::Materials,
+            amount: 10.0,
+        }
+    }
+}
+
+/// Draw the market exchange if it is open
+pub fn draw_market(state: &GameState) -> Option<PlayerAction> {
+    let draft = state.market_draft?;
+    let screen_w = screen_width();
+    let screen_h = screen_height();
+
+    let w = 520.0;
+    let h = 430.0;
+    let x = (screen_w - w) / 2.0;
+    let y = (screen_h - h) / 2.0;
+
+    draw_rectangle(0.0, 0.0, screen_w, screen_h, Color::new(0.0, 0.0, 0.0, 0.5));
+    theme::draw_panel(x, y, w, h);
+    theme::draw_header("Market Exchange", x + 20.0, y + 40.0);
+    draw_ui_text(
+        &format!(
+            "Prices change daily. The market keeps {:.0}% of every trade.",
+            market::SPREAD * 100.0
+        ),
+        x + 20.0,
+        y + 64.0,
+        14.0,
+        LIGHTGRAY,
+    );
+
+    let mut action = None;
+    let mut next = draft;
+    let col_w = (w - 60.0) / 2.0;
+    let list_y = y + 100.0;
+    draw_ui_text("Sell", x + 20.0, list_y - 8.0, 18.0, colors::accent());
+    draw_ui_text(
+        "Buy",
+        x + 40.0 + col_w,
+        list_y - 8.0,
+        18.0,
+        colors::accent(),
+    );
+
+    for (row, kind) in market::tradeable().enumerate() {
+        let row_y = list_y + row as f32 * ROW_H;
+        let price = state.market_prices.price(kind).unwrap_or(0.0);
+        let label = |picked: bool| {
+            format!(
+                "{}{} ({:.0}) @ {:.2}",
+                if picked { "> " } else { "" },
+                kind.name(),
+                state.resources.get(kind).floor(),
+                price
+            )
+        };
+        if theme::draw_button(
+            x + 20.0,
+            row_y,
+            col_w,
+            ROW_H - 4.0,
+            &label(kind == draft.sell),
+        ) {
+            next.sell = kind;
+        }
+        if theme::draw_button(
+            x + 40.0 + col_w,
+            row_y,
+            col_w,
+            ROW_H - 4.0,
+            &label(kind == draft.buy),
+        ) {
+            next.buy = kind;
+        }
+    }
+
+    // Amount: - steps, value, + steps
+    let amount_y = list_y + market::tradeable().count() as f32 * ROW_H + 12.0;
+    let step_w = 44.0;
+    let mut bx = x + 20.0;
+    for step in AMOUNT_STEPS.iter().rev() {
+        if theme::draw_button(bx, amount_y, step_w, 26.0, &format!("-{:.0}", step)) {
+            next.amount = (next.amount - step).max(1.0);
+        }
+        bx += step_w + 6.0;
+    }
+    draw_ui_text(
+        &format!("{:.0}", draft.amount),
+        bx + 8.0,
+        amount_y + 19.0,
+        18.0,
+        colors::text(),
+    );
+    bx += 50.0;
+    for step in AMOUNT_STEPS {
+        if theme::draw_button(bx, amount_y, step_w, 26.0, &format!("+{:.0}", step)) {
+            next.amount += step;
+        }
+        bx += step_w + 6.0;
+    }
+
+    // What the trade would bring in
+    let quote = state
+        .market_prices
+        .quote(draft.sell, draft.buy, draft.amount);
+    let in_stock = state.resources.get(draft.sell) >= draft.amount;
+    let (summary, color) = match quote {
+        None => ("Pick two different goods".to_string(), GRAY),
+        Some(_) if !in_stock => (
+            format!("Not enough {} in stock", draft.sell.name()),
+            colors::warn(),
+        ),
+        Some(received) => (
+            format!(
+                "{:.0} {} for {:.1} {}",
+                draft.amount,
+                draft.sell.name(),
+                received,
+                draft.buy.name()
+            ),
+            colors::text(),
+        ),
+    };
+    draw_ui_text(&summary, x + 20.0, amount_y + 52.0, 16.0, color);
+
+    if next != draft {
+        action = Some(PlayerAction::EditMarketTrade(Some(next)));
+    }
+
+    let btn_w = 120.0;
+    let btn_h = 36.0;
+    let btn_y = y + h - btn_h - 16.0;
+    if theme::draw_button(x + w - btn_w * 2.0 - 30.0, btn_y, btn_w, btn_h, "Close")
+        || is_key_pressed(KeyCode::Escape)
+    {
+        action = Some(PlayerAction::EditMarketTrade(None));
+    }
+    if quote.is_some()
+        && in_stock
+        && theme::draw_button(x + w - btn_w - 20.0, btn_y, btn_w, btn_h, "Trade")
+    {
+        action = Some(PlayerAction::MarketTrade {
+            sell: draft.sell,
+            buy: draft.buy,
+            amount: draft.amount,
+        });
+    }
+    action
+}
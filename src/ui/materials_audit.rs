@@ -0,0 +1,162 @@
+//! Materials audit - where every material this hour comes from and goes (F4,
+//! under the formula overlay)
+//!
+//! Only worked out while it's on screen, from the same functions `simulate_ticks` uses.
+
+use crate::data::GameState;
+use crate::economy::{effective_population, maintenance_cost};
+use crate::simulation::production::{materials_breakdown, production_bonuses, MaterialsLine};
+use crate::PlayerAction;
+use macroquad::prelude::*;
+use macroquad_toolkit::ui::draw_ui_text;
+
+const WIDTH: f32 = 560.0;
+const ROW_H: f32 = 18.0;
+const FONT_SIZE: f32 = 14.0;
+/// Rows of zones shown before the table scrolls
+const VISIBLE_ROWS: usize = 12;
+/// Header row above the table, totals and multipliers below it
+const HEADER_H: f32 = 44.0;
+const FOOTER_H: f32 = 62.0;
+
+/// Left edge of each column after the source name
+const COLUMNS: [(f32, &str); 5] = [
+    (230.0, "Output/h"),
+    (300.0, "Upkeep/h"),
+    (370.0, "Net/h"),
+    (430.0, "Maint/h"),
+    (500.0, "Mult"),
+];
+
+/// Where the audit sits (under the formula overlay), or None while it's hidden
+pub fn panel_rect(state: &GameState) -> Option<Rect> {
+    if !state.settings.show_formulas || !state.show_materials_audit {
+        return None;
+    }
+    let height = HEADER_H + VISIBLE_ROWS as f32 * ROW_H + FOOTER_H;
+    let y = super::formula_overlay::overlay_bottom(state) + 10.0;
+    Some(Rect::new((screen_width() - WIDTH) / 2.0, y, WIDTH, height))
+}
+
+/// Draw the itemised table; scrolling it emits `SetAuditScroll`
+pub fn draw_materials_audit(state: &GameState) -> Option<PlayerAction> {
+    let rect = panel_rect(state)?;
+    let bonuses = production_bonuses(state);
+    let lines = materials_breakdown(state, &bonuses);
+
+    draw_rectangle(
+        rect.x,
+        rect.y,
+        rect.w,
+        rect.h,
+        Color::new(0.0, 0.0, 0.0, 0.8),
+    );
+    draw_rectangle_lines(rect.x, rect.y, rect.w, rect.h, 1.0, GRAY);
+    draw_ui_text(
+        "Materials audit (F4 to hide)",
+        rect.x + 10.0,
+        rect.y + 18.0,
+        FONT_SIZE,
+        WHITE,
+    );
+    draw_ui_text("Source", rect.x + 10.0, rect.y + 38.0, FONT_SIZE, LIGHTGRAY);
+    for (col_x, title) in COLUMNS {
+        draw_ui_text(title, rect.x + col_x, rect.y + 38.0, FONT_SIZE, LIGHTGRAY);
+    }
+
+    // Scroll the rows, a row at a time
+    let max_scroll = lines.len().saturating_sub(VISIBLE_ROWS) as f32;
+    let scroll = state.audit_scroll.clamp(0.0, max_scroll);
+    let mut action = None;
+    let mouse: Vec2 = mouse_position().into();
+    if rect.contains(mouse) {
+        let (_, wheel_y) = mouse_wheel();
+        if wheel_y != 0.0 {
+            let next = (scroll - wheel_y.signum()).clamp(0.0, max_scroll);
+            action = Some(PlayerAction::SetAuditScroll(next));
+        }
+    }
+
+    let rows_y = rect.y + HEADER_H;
+    for (i, line) in lines
+        .iter()
+        .skip(scroll as usize)
+        .take(VISIBLE_ROWS)
+        .enumerate()
+    {
+        draw_row(line, rect.x, rows_y + i as f32 * ROW_H);
+    }
+
+    // Totals, and what the rest of the tick does to them
+    let footer_y = rows_y + VISIBLE_ROWS as f32 * ROW_H;
+    draw_line(
+        rect.x + 10.0,
+        footer_y,
+        rect.x + rect.w - 10.0,
+        footer_y,
+        1.0,
+        GRAY,
+    );
+    let output: f32 = lines.iter().map(|l| l.output).sum();
+    let upkeep: f32 = lines.iter().map(|l| l.upkeep).sum();
+    let net = output - upkeep;
+    let totals = [
+        format!("Total ({} sources)", lines.len()),
+        format!("{:.2}", output),
+        format!("{:.2}", upkeep),
+        format!("{:+.2}", net),
+    ];
+    let net_color = if net < 0.0 { RED } else { GREEN };
+    draw_ui_text(&totals[0], rect.x + 10.0, footer_y + 16.0, FONT_SIZE, WHITE);
+    for (i, text) in totals[1..].iter().enumerate() {
+        let color = if i == 2 { net_color } else { WHITE };
+        draw_ui_text(
+            text,
+            rect.x + COLUMNS[i].0,
+            footer_y + 16.0,
+            FONT_SIZE,
+            color,
+        );
+    }
+
+    let effective = effective_population(state.population.value(), state.config.population_k);
+    let population_upkeep =
+        maintenance_cost(effective, state.config.maintenance_cost_coefficient) * 60.0;
+    draw_ui_text(
+        &format!(
+            "Production x{:.2} (all bonuses) • Upkeep x{:.2} (efficiency techs)",
+            bonuses.production_multi, bonuses.maintenance_factor
+        ),
+        rect.x + 10.0,
+        footer_y + 36.0,
+        FONT_SIZE - 1.0,
+        LIGHTGRAY,
+    );
+    draw_ui_text(
+        &format!(
+            "Population upkeep: {:.2} maintenance/h (not in the table)",
+            population_upkeep
+        ),
+        rect.x + 10.0,
+        footer_y + 54.0,
+        FONT_SIZE - 1.0,
+        LIGHTGRAY,
+    );
+    action
+}
+
+/// One source: its name, then output, upkeep, net, maintenance and multiplier
+fn draw_row(line: &MaterialsLine, x: f32, y: f32) {
+    let net = line.output - line.upkeep;
+    let cells = [
+        (format!("{:.2}", line.output), WHITE),
+        (format!("{:.2}", line.upkeep), WHITE),
+        (format!("{:+.2}", net), if net < 0.0 { RED } else { GREEN }),
+        (format!("{:+.2}", line.maintenance), LIGHTGRAY),
+        (format!("x{:.2}", line.multiplier), LIGHTGRAY),
+    ];
+    draw_ui_text(&line.source, x + 10.0, y + 14.0, FONT_SIZE, WHITE);
+    for ((col_x, _), (text, color)) in COLUMNS.iter().zip(cells) {
+        draw_ui_text(&text, x + col_x, y + 14.0, FONT_SIZE, color);
+    }
+}
@@ -6,27 +6,78 @@ use crate::data::GameState;
 use crate::PlayerAction;
 use macroquad::prelude::*;
 
+pub mod blueprints;
+pub mod chronicle_timeline;
 pub mod chronicle_ui;
+pub mod collapse_notice;
+pub mod day_transition;
 pub mod dialog_ui;
+pub mod ending;
 pub mod floating_text;
+pub mod focus;
+pub mod formula_overlay;
+pub mod hero_editor;
 pub mod layout;
+pub mod main_menu;
 pub mod map_renderer;
+pub mod market_ui;
+pub mod materials_audit;
+pub mod nav_overlay;
 pub mod particles;
+pub mod pinned_zones;
+pub mod placement;
+pub mod population_ticker;
+pub mod region_archive;
+pub mod region_legend;
+pub mod region_paths;
 pub mod region_ui;
+pub mod resource_alerts;
 pub mod resources;
+pub mod save_toast;
+pub mod season_hud;
 pub mod tech;
 pub mod text_util;
 pub mod theme;
 pub mod tooltip;
+pub mod town_namer;
+pub mod wonder_celebration;
+pub mod year_summary;
 pub mod zones;
 
+/// "Quiteville", plus the name of the town the autosave resumes into. The window
+/// can't be retitled once it is open, so a rename shows from the next launch.
+pub fn window_title() -> String {
+    match crate::save::saved_town_name(crate::save::AUTOSAVE_PATH) {
+        Some(name) if name != "Quiteville" => format!("Quiteville - {}", name),
+        _ => "Quiteville".to_owned(),
+    }
+}
+
 /// Draw the entire game UI and return any player action triggered
 pub fn draw_game_ui(state: &GameState, time_scale: f32, paused: bool) -> Option<PlayerAction> {
-    // 1. Top Bar (Resources & Time)
-    resources::draw_top_bar(state, time_scale, paused);
+    // 1. Top Bar (Resources & Time), season under its right end (dropdowns open over it)
+    season_hud::draw_season_hud(state, 70.0);
+    let bar_action = resources::draw_top_bar(state, time_scale, paused);
+    resource_alerts::draw_banner(state, 70.0);
+    state.wonder_celebration.draw_banner(110.0);
 
     // 2. Main Content Area (Layout)
-    let action = layout::draw_main_layout(state);
+    let mut action = layout::draw_main_layout(state).or(bar_action);
+    if state.settings.show_coordinates {
+        nav_overlay::draw_town_readout(state);
+    }
+    if state.settings.show_formulas {
+        formula_overlay::draw_formula_overlay(state);
+        if let Some(act) = materials_audit::draw_materials_audit(state) {
+            action = Some(act);
+        }
+    }
+    if !state.show_build_menu {
+        population_ticker::draw_population_ticker(state);
+    }
+    if let Some(act) = blueprints::draw_blueprints_panel(state) {
+        action = Some(act);
+    }
 
     // 3. Tech Tree Modal
     if state.show_tech_tree {
@@ -54,10 +105,38 @@ pub fn draw_game_ui(state: &GameState, time_scale: f32, paused: bool) -> Option<
         }
     }
 
-    // 5. Guide Dialog (Overlay)
+    // 5. Yearly recap
+    if let Some(act) = year_summary::draw_year_summary(state) {
+        return Some(act);
+    }
+
+    // 6. Collapsed town
+    if let Some(act) = collapse_notice::draw_collapse_notice(state) {
+        return Some(act);
+    }
+
+    // 7. Immortalize dialog
+    if let Some(act) = hero_editor::draw_hero_editor(state) {
+        return Some(act);
+    }
+
+    // 8. Market exchange
+    if let Some(act) = market_ui::draw_market(state) {
+        return Some(act);
+    }
+
+    // 8b. Renaming the town
+    if let Some(act) = town_namer::draw_town_namer(state) {
+        return Some(act);
+    }
+
+    // 9. Guide Dialog (Overlay)
     if let Some(act) = dialog_ui::draw_guide_dialog(state) {
         return Some(act);
     }
 
+    // 10. Top bar tooltips (drawn last so panels don't cover them)
+    resources::draw_top_bar_tooltips(state);
+
     action
 }
@@ -0,0 +1,73 @@
+//! Navigation aid - a compass and coordinate readout (toggled in settings)
+
+use crate::data::GameState;
+use crate::region::TownNode;
+use crate::ui::map_renderer::TILE_SIZE;
+use macroquad::prelude::*;
+use macroquad_toolkit::ui::draw_ui_text;
+
+const BOX_W: f32 = 220.0;
+const BOX_H: f32 = 60.0;
+const COMPASS_R: f32 = 22.0;
+
+/// Town view: the tile under the mouse and the tile the camera is centred on
+pub fn draw_town_readout(state: &GameState) {
+    let mouse_world = state.camera.screen_to_world(mouse_position().into());
+    let (mouse_x, mouse_y) = world_to_tile(mouse_world);
+    let (camera_x, camera_y) = world_to_tile(state.camera.target);
+    draw_readout(&[
+        format!("Mouse: tile {}, {}", mouse_x, mouse_y),
+        format!("Camera: tile {}, {}", camera_x, camera_y),
+    ]);
+}
+
+/// Region view: where the hovered town sits on the map (0-1 across and down)
+pub fn draw_region_readout(hovered: Option<&TownNode>) {
+    let line = match hovered {
+        Some(node) => format!(
+            "{}: {:.2}, {:.2}",
+            node.name, node.position[0], node.position[1]
+        ),
+        None => "Hover a town to locate it".to_string(),
+    };
+    draw_readout(&[line]);
+}
+
+fn world_to_tile(pos: Vec2) -> (i32, i32) {
+    (
+        (pos.x / TILE_SIZE).floor() as i32,
+        (pos.y / TILE_SIZE).floor() as i32,
+    )
+}
+
+/// Box in the bottom-right corner: compass on the left, lines of text beside it
+fn draw_readout(lines: &[String]) {
+    let x = screen_width() - BOX_W - 10.0;
+    let y = screen_height() - BOX_H - 10.0;
+    draw_rectangle(x, y, BOX_W, BOX_H, Color::new(0.0, 0.0, 0.0, 0.5));
+    draw_compass(vec2(x + 8.0 + COMPASS_R, y + BOX_H / 2.0));
+
+    let text_x = x + COMPASS_R * 2.0 + 16.0;
+    let text_y = y + BOX_H / 2.0 - (lines.len() as f32 - 1.0) * 10.0 + 5.0;
+    for (i, line) in lines.iter().enumerate() {
+        draw_ui_text(line, text_x, text_y + i as f32 * 20.0, 14.0, WHITE);
+    }
+}
+
+/// Neither map rotates, so north is always up
+fn draw_compass(center: Vec2) {
+    draw_circle_lines(center.x, center.y, COMPASS_R, 1.5, LIGHTGRAY);
+    let north = vec2(center.x, center.y - COMPASS_R + 13.0);
+    let south = vec2(center.x, center.y + COMPASS_R - 6.0);
+    let left = vec2(center.x - 4.0, center.y);
+    let right = vec2(center.x + 4.0, center.y);
+    draw_triangle(north, left, right, RED);
+    draw_triangle(south, left, right, LIGHTGRAY);
+    draw_ui_text(
+        "N",
+        center.x - 4.0,
+        center.y - COMPASS_R + 12.0,
+        12.0,
+        WHITE,
+    );
+}
@@ -148,3 +148,131 @@ impl ParticleSystem {
         }
     }
 }
+
+/// Weather bursts spawned per real second (one per tick at normal speed)
+const WEATHER_BURSTS_PER_SECOND: f32 = 10.0;
+
+/// Chance per real second of a puff of chimney smoke in winter
+const SMOKE_CHANCE_PER_SECOND: f32 = 1.0;
+
+/// Chance per real second of a puff of dust over a site with builders at work
+const BUILD_DUST_CHANCE_PER_SECOND: f32 = 3.0;
+
+/// Spawn ambient weather and chimney smoke and advance the particles.
+/// Runs once per frame, separate from the simulation, so turbo speed doesn't flood the sky.
+pub fn update_ambient(state: &mut crate::data::GameState, delta: f32) {
+    use crate::simulation::seasons::{Season, Weather};
+    use macroquad_toolkit::rng;
+
+    state.particle_system.update(delta);
+
+    let weather = state.season_state.weather;
+    let particle_count = match weather {
+        Weather::Rain => 2,
+        Weather::Storm => 5,
+        Weather::Snow => 2,
+        Weather::Dust => 3,
+        _ => 0,
+    };
+
+    if particle_count > 0 && rng::gen_range(0.0, 1.0) < WEATHER_BURSTS_PER_SECOND * delta {
+        // Weather falls across the view, so spawn around the camera in world space
+        let cam_center = state.camera.target;
+        let spawn_w = screen_width() / state.camera.zoom;
+        let spawn_h = screen_height() / state.camera.zoom;
+
+        for _ in 0..particle_count {
+            let x = cam_center.x - spawn_w / 2.0 + rng::gen_range(0.0, spawn_w);
+            let y = cam_center.y - spawn_h / 2.0 + rng::gen_range(0.0, spawn_h);
+
+            let (vel, color, life, p_type) = match weather {
+                Weather::Snow => (
+                    vec2(rng::gen_range(-10.0, 10.0), 30.0),
+                    WHITE,
+                    4.0,
+                    ParticleType::Snow,
+                ),
+                Weather::Dust => (
+                    vec2(120.0, rng::gen_range(-10.0, 10.0)),
+                    Color::new(0.8, 0.65, 0.4, 0.5),
+                    2.0,
+                    ParticleType::Dust,
+                ),
+                _ => (
+                    // Rain/Storm
+                    vec2(-5.0, 200.0),
+                    Color::new(0.6, 0.6, 1.0, 0.6),
+                    1.5,
+                    ParticleType::Rain,
+                ),
+            };
+
+            state
+                .particle_system
+                .spawn(vec2(x, y), vel, life, 3.0, color, p_type);
+        }
+    }
+
+    // Dust kicked up over sites where builders are at work
+    if rng::gen_range(0.0, 1.0) < BUILD_DUST_CHANCE_PER_SECOND * delta {
+        let sites: Vec<Vec2> = state
+            .zones
+            .iter()
+            .filter(|z| z.is_under_construction())
+            .filter(|z| crate::simulation::construction::builder_effort(state, z.id) > 0.0)
+            .filter_map(|z| state.zone_rect(z))
+            .map(|rect| rect.world_center())
+            .collect();
+        for center in sites {
+            let tile_size = crate::ui::map_renderer::TILE_SIZE;
+            let offset = vec2(
+                rng::gen_range(-tile_size, tile_size),
+                rng::gen_range(0.0, tile_size),
+            );
+            state.particle_system.spawn(
+                center + offset,
+                vec2(rng::gen_range(-15.0, 15.0), rng::gen_range(-25.0, -10.0)),
+                rng::gen_range(0.8, 1.5),
+                rng::gen_range(3.0, 6.0),
+                Color::new(0.75, 0.65, 0.5, 0.6),
+                ParticleType::Dust,
+            );
+        }
+    }
+
+    // Chimney smoke rises from lived-in homes in winter
+    if state.season_state.season != Season::Winter
+        || rng::gen_range(0.0, 1.0) >= SMOKE_CHANCE_PER_SECOND * delta
+    {
+        return;
+    }
+    let tile_size = crate::ui::map_renderer::TILE_SIZE;
+    for zone in &state.zones {
+        if zone.dormant || zone.activity <= 0.0 {
+            continue;
+        }
+        // Field borrows (not get_template) so the particle system can be borrowed mutably
+        let template = state
+            .zone_templates
+            .iter()
+            .find(|t| t.id == zone.template_id);
+        let rect = match template {
+            Some(t) if t.category == crate::data::ZoneCategory::Residential => zone.footprint(t),
+            _ => None,
+        };
+        if let Some(rect) = rect {
+            // Top of the building
+            let center_x = (rect.x as f32 + rect.w as f32 * 0.5) * tile_size;
+            let center_y = (rect.y as f32 + rect.h as f32 * 0.2) * tile_size;
+
+            state.particle_system.spawn(
+                vec2(center_x, center_y),
+                vec2(rng::gen_range(-5.0, 5.0), rng::gen_range(-20.0, -10.0)),
+                rng::gen_range(2.0, 4.0),
+                rng::gen_range(4.0, 8.0),
+                Color::new(0.8, 0.8, 0.8, 0.4),
+                ParticleType::Smoke,
+            );
+        }
+    }
+}
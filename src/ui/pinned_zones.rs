@@ -0,0 +1,84 @@
+//! Pinned zones - a quick bar for jumping to the buildings the player cares about
+
+use crate::data::GameState;
+use crate::ui::theme;
+use crate::ui::theme::colors;
+use crate::PlayerAction;
+use macroquad::prelude::*;
+use macroquad_toolkit::ui::{draw_ui_text, measure_ui_text};
+
+/// Pins available (one per number key)
+pub const MAX_PINNED: usize = 6;
+
+const SLOT_W: f32 = 130.0;
+const SLOT_H: f32 = 28.0;
+const SLOT_SPACING: f32 = 6.0;
+
+/// Gap between the quick bar and the bottom buttons
+const BAR_GAP: f32 = 8.0;
+
+/// Screen rect of the quick bar (x, y, w, h), None while nothing is pinned.
+/// Sits centred just above the bottom buttons (top edge at `buttons_y`).
+pub fn bar_rect(state: &GameState, screen_w: f32, buttons_y: f32) -> Option<(f32, f32, f32, f32)> {
+    let count = state.pinned_zones.len();
+    if count == 0 {
+        return None;
+    }
+    let w = SLOT_W * count as f32 + SLOT_SPACING * (count - 1) as f32;
+    Some((
+        (screen_w - w) / 2.0,
+        buttons_y - SLOT_H - BAR_GAP,
+        w,
+        SLOT_H,
+    ))
+}
+
+/// Draw the pinned-zone quick bar; clicking a slot centres the camera on that zone
+pub fn draw_pinned_bar(state: &GameState, screen_w: f32, buttons_y: f32) -> Option<PlayerAction> {
+    let (x, y, _, _) = bar_rect(state, screen_w, buttons_y)?;
+    let mut action = None;
+
+    for (slot, &id) in state.pinned_zones.iter().enumerate() {
+        let name = state
+            .zone(id)
+            .and_then(|z| state.get_template(&z.template_id))
+            .map_or("?", |t| t.name.as_str());
+        let label = fit_label(&format!("{} {}", slot + 1, name), SLOT_W - 12.0);
+        let slot_x = x + (SLOT_W + SLOT_SPACING) * slot as f32;
+
+        let selected =
+            matches!(state.selection, crate::data::Selection::Zone(selected) if selected == id);
+        if selected {
+            draw_rectangle_lines(
+                slot_x - 2.0,
+                y - 2.0,
+                SLOT_W + 4.0,
+                SLOT_H + 4.0,
+                2.0,
+                colors::accent(),
+            );
+        }
+        if theme::draw_button(slot_x, y, SLOT_W, SLOT_H, &label) {
+            action = Some(PlayerAction::FocusZone(id));
+        }
+    }
+
+    draw_ui_text("★", x - 20.0, y + 20.0, 18.0, colors::accent());
+    action
+}
+
+/// Shorten a label with an ellipsis until it fits the slot
+fn fit_label(label: &str, max_width: f32) -> String {
+    if measure_ui_text(label, None, 14, 1.0).width <= max_width {
+        return label.to_string();
+    }
+    let mut chars: Vec<char> = label.chars().collect();
+    while !chars.is_empty() {
+        chars.pop();
+        let candidate = format!("{}…", chars.iter().collect::<String>());
+        if measure_ui_text(&candidate, None, 14, 1.0).width <= max_width {
+            return candidate;
+        }
+    }
+    String::new()
+}
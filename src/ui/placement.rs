@@ -0,0 +1,59 @@
+//! Placement modes - moving a zone, laying bridges and drafting or stamping a
+//! blueprint each take over clicks on the map until finished or cancelled
+
+use crate::data::GameState;
+use crate::zones;
+use crate::PlayerAction;
+use macroquad::prelude::Vec2;
+
+/// Whether a placement mode has the map's clicks
+pub fn is_active(state: &GameState) -> bool {
+    state.relocating.is_some()
+        || state.placing_bridge
+        || state.blueprint_draft.is_some()
+        || state.placing_blueprint.is_some()
+}
+
+/// The action that leaves the current placement mode (Escape or a right click)
+pub fn cancel(state: &GameState) -> Option<PlayerAction> {
+    if state.relocating.is_some() {
+        Some(PlayerAction::StartRelocating(None))
+    } else if state.placing_bridge {
+        Some(PlayerAction::PlaceBridges(false))
+    } else if state.blueprint_draft.is_some() {
+        Some(PlayerAction::DraftBlueprint(false))
+    } else if state.placing_blueprint.is_some() {
+        Some(PlayerAction::StartPlacingBlueprint(None))
+    } else {
+        None
+    }
+}
+
+/// What a left click on the map does in the current placement mode
+pub fn map_click(state: &GameState, world_pos: Vec2) -> Option<PlayerAction> {
+    let tile = (world_pos / super::map_renderer::TILE_SIZE).floor();
+    if tile.x < 0.0 || tile.y < 0.0 {
+        return None;
+    }
+    let (x, y) = (tile.x as usize, tile.y as usize);
+
+    // Placing a zone being moved
+    if let Some(id) = state.relocating {
+        return zones::relocate::site_around(state, id, x, y)
+            .map(|site| PlayerAction::RelocateZone(id, site.x, site.y));
+    }
+    // Bridging the water tile under the mouse
+    if state.placing_bridge {
+        return Some(PlayerAction::BuildBridge(x, y));
+    }
+    // Stamping a blueprint with its corner under the mouse
+    if let Some(name) = &state.placing_blueprint {
+        return Some(PlayerAction::PlaceBlueprint(name.clone(), x, y));
+    }
+    // Picking the building under the mouse for a blueprint
+    if state.blueprint_draft.is_some() {
+        let zone = state.world_map.get_tile(x, y).and_then(|t| t.zone_id)?;
+        return Some(PlayerAction::ToggleBlueprintZone(zone));
+    }
+    None
+}
@@ -0,0 +1,62 @@
+//! Population ticker - the latest arrivals and departures by name, bottom right
+
+use crate::data::GameState;
+use crate::narrative::ChronicleEventType;
+use crate::ui::theme::colors;
+use macroquad::prelude::*;
+use macroquad_toolkit::ui::{draw_ui_text, measure_ui_text};
+
+/// Most names shown at once
+const MAX_ENTRIES: usize = 4;
+
+/// Game hours a name stays on the ticker (fading over the last quarter)
+const SHOW_HOURS: f32 = 48.0;
+
+const LINE_H: f32 = 20.0;
+const FONT_SIZE: u16 = 15;
+
+/// Draw recent arrivals and departures (nothing while the town is quiet)
+pub fn draw_population_ticker(state: &GameState) {
+    let now = state.game_time_hours;
+    let entries: Vec<(String, Color, f32)> = state
+        .town_chronicle
+        .recent_population_changes(MAX_ENTRIES)
+        .into_iter()
+        .filter(|e| now - e.timestamp < SHOW_HOURS)
+        .map(|e| {
+            let alpha = ((SHOW_HOURS - (now - e.timestamp)) / (SHOW_HOURS * 0.25)).min(1.0);
+            let color = match e.event_type {
+                ChronicleEventType::VillagerLost { .. } => colors::warn(),
+                _ => colors::accent(),
+            };
+            (e.display_text(), color, alpha)
+        })
+        .collect();
+    if entries.is_empty() {
+        return;
+    }
+
+    let width = entries
+        .iter()
+        .map(|(text, _, _)| measure_ui_text(text, None, FONT_SIZE, 1.0).width)
+        .fold(0.0_f32, f32::max)
+        + 20.0;
+    let height = entries.len() as f32 * LINE_H + 10.0;
+    let x = screen_width() - width - 10.0;
+    let y = screen_height() - height - 20.0;
+
+    draw_rectangle(x, y, width, height, Color::new(0.0, 0.0, 0.0, 0.5));
+    // Newest at the bottom, nearest the corner
+    for (i, (text, color, alpha)) in entries.iter().rev().enumerate() {
+        draw_ui_text(
+            text,
+            x + 10.0,
+            y + 20.0 + i as f32 * LINE_H,
+            FONT_SIZE as f32,
+            Color {
+                a: *alpha,
+                ..*color
+            },
+        );
+    }
+}
@@ -0,0 +1,133 @@
+//! Region map panels for archived towns: the list with their debts, and the
+//! selected town's economy with the way back to it
+
+use crate::data::GameState;
+use crate::region::TownProxy;
+use crate::ui::theme;
+use crate::PlayerAction;
+use macroquad::prelude::*;
+use macroquad_toolkit::ui::{draw_ui_text, measure_ui_text};
+
+/// Height of the archived town panel shown under the selected town
+pub const PROXY_PANEL_H: f32 = 170.0;
+
+/// List archived towns with their stockpile status and a button to cover debts
+pub fn draw_archived_towns(state: &GameState, x: f32, y: f32) -> Option<PlayerAction> {
+    let proxies = state.town_proxies.all();
+    if proxies.is_empty() {
+        return None;
+    }
+
+    let mut action = None;
+    let row_h = 36.0;
+    let height = 30.0 + proxies.len() as f32 * row_h;
+    draw_rectangle(x, y, 200.0, height, Color::from_rgba(0, 0, 0, 180));
+    draw_ui_text("Archived Towns", x + 10.0, y + 20.0, 16.0, WHITE);
+
+    for (i, proxy) in proxies.iter().enumerate() {
+        let row_y = y + 30.0 + i as f32 * row_h;
+        let name = state
+            .region_map
+            .get_node(proxy.town_id)
+            .map(|n| n.name.as_str())
+            .unwrap_or("Unknown");
+        let status_color = if proxy.in_crisis { RED } else { LIGHTGRAY };
+        draw_ui_text(
+            &format!("{} - {}", name, proxy.status()),
+            x + 10.0,
+            row_y + 12.0,
+            13.0,
+            status_color,
+        );
+
+        let debt = proxy.debt();
+        if debt > 0.0 {
+            draw_ui_text(
+                &format!("Debt: {:.0}", debt),
+                x + 10.0,
+                row_y + 28.0,
+                12.0,
+                ORANGE,
+            );
+            if theme::draw_button(x + 120.0, row_y + 14.0, 70.0, 20.0, "Cover") {
+                action = Some(PlayerAction::CoverProxyDebt(proxy.town_id));
+            }
+        } else {
+            draw_ui_text(
+                &format!("Stock: {:.0} Mat", proxy.stockpile_materials),
+                x + 10.0,
+                row_y + 28.0,
+                12.0,
+                GRAY,
+            );
+        }
+    }
+
+    action
+}
+
+/// An archived town's population, economy and stockpiles, with a button to travel there
+pub fn draw_proxy_details(proxy: &TownProxy, x: f32, y: f32) -> Option<PlayerAction> {
+    let mut action = None;
+    draw_rectangle(x, y, 220.0, PROXY_PANEL_H, Color::from_rgba(0, 0, 0, 180));
+    draw_ui_text("Archived Town", x + 10.0, y + 20.0, 16.0, WHITE);
+    let status_color = if proxy.in_crisis {
+        RED
+    } else if proxy.status() == "Struggling" {
+        ORANGE
+    } else {
+        GREEN
+    };
+    let status_x = x + 210.0 - measure_ui_text(proxy.status(), None, 14, 1.0).width;
+    draw_ui_text(proxy.status(), status_x, y + 20.0, 14.0, status_color);
+
+    draw_ui_text(
+        &format!(
+            "Population: {} • archived {:.1} days",
+            proxy.population, proxy.days_archived
+        ),
+        x + 10.0,
+        y + 40.0,
+        13.0,
+        LIGHTGRAY,
+    );
+
+    // Net rate and stockpile per good
+    let goods = [
+        ("Materials", proxy.net_materials, proxy.stockpile_materials),
+        ("Food", proxy.net_food, proxy.stockpile_food),
+        ("Wood", proxy.net_wood, proxy.stockpile_wood),
+        ("Stone", proxy.net_stone, proxy.stockpile_stone),
+    ];
+    for (i, (name, rate, stock)) in goods.iter().enumerate() {
+        let row_y = y + 60.0 + i as f32 * 16.0;
+        let color = if *stock < 0.0 {
+            RED
+        } else if *rate < 0.0 {
+            ORANGE
+        } else {
+            LIGHTGRAY
+        };
+        draw_ui_text(
+            &format!("{}: {:.0} ({:+.1}/day)", name, stock, rate),
+            x + 10.0,
+            row_y,
+            12.0,
+            color,
+        );
+    }
+
+    let (note, note_color) = if proxy.in_crisis {
+        ("In crisis - its stores have run dry", RED)
+    } else if proxy.needs_attention() {
+        ("Long left alone - worth a visit", ORANGE)
+    } else {
+        ("Getting by without you", GRAY)
+    };
+    draw_ui_text(note, x + 10.0, y + 126.0, 12.0, note_color);
+
+    if theme::draw_button(x + 10.0, y + 136.0, 200.0, 26.0, "Travel Here") {
+        action = Some(PlayerAction::TravelToTown(proxy.town_id));
+    }
+    action
+}
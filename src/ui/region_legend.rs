@@ -0,0 +1,203 @@
+//! Region map legend - the key to node shapes, road colours and biomes
+
+use crate::region::{Biome, Route, TownNode};
+use crate::PlayerAction;
+use macroquad::prelude::*;
+use macroquad_toolkit::ui::draw_ui_text;
+
+/// What a town node is, as the map colours it (and the legend explains it)
+#[derive(Debug, Clone, Copy)]
+pub enum NodeKind {
+    Capital,
+    Settled,
+    Unsettled,
+    WonderSite,
+    WonderClaimed,
+}
+
+impl NodeKind {
+    pub const ALL: [NodeKind; 5] = [
+        NodeKind::Capital,
+        NodeKind::Settled,
+        NodeKind::Unsettled,
+        NodeKind::WonderSite,
+        NodeKind::WonderClaimed,
+    ];
+
+    pub fn of(node: &TownNode) -> Self {
+        if node.is_wonder_site {
+            if node.wonder_site.is_some() {
+                NodeKind::WonderClaimed
+            } else {
+                NodeKind::WonderSite
+            }
+        } else if node.settled {
+            if node.is_capital {
+                NodeKind::Capital
+            } else {
+                NodeKind::Settled
+            }
+        } else {
+            NodeKind::Unsettled
+        }
+    }
+
+    pub fn color(self) -> Color {
+        match self {
+            NodeKind::Capital => GOLD,
+            NodeKind::Settled => GREEN,
+            NodeKind::Unsettled => GRAY,
+            NodeKind::WonderSite => MAGENTA,
+            NodeKind::WonderClaimed => PURPLE,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            NodeKind::Capital => "Capital",
+            NodeKind::Settled => "Settled town",
+            NodeKind::Unsettled => "Unsettled",
+            NodeKind::WonderSite => "Wonder site",
+            NodeKind::WonderClaimed => "Wonder built or underway",
+        }
+    }
+
+    /// Wonder sites are drawn as diamonds, towns as circles
+    pub fn is_diamond(self) -> bool {
+        matches!(self, NodeKind::WonderSite | NodeKind::WonderClaimed)
+    }
+}
+
+/// How a route is drawn
+#[derive(Debug, Clone, Copy)]
+pub enum RouteKind {
+    Paved,
+    Dirt,
+    Undiscovered,
+}
+
+impl RouteKind {
+    pub const ALL: [RouteKind; 3] = [RouteKind::Paved, RouteKind::Dirt, RouteKind::Undiscovered];
+
+    pub fn of(route: &Route) -> Self {
+        if !route.discovered {
+            RouteKind::Undiscovered
+        } else if route.quality > 0.5 {
+            RouteKind::Paved
+        } else {
+            RouteKind::Dirt
+        }
+    }
+
+    pub fn color(self) -> Color {
+        match self {
+            RouteKind::Paved => Color::from_rgba(180, 160, 140, 255),
+            RouteKind::Dirt => Color::from_rgba(120, 100, 80, 255),
+            RouteKind::Undiscovered => Color::from_rgba(60, 60, 60, 100),
+        }
+    }
+
+    pub fn thickness(self) -> f32 {
+        match self {
+            RouteKind::Undiscovered => 1.0,
+            _ => 3.0,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            RouteKind::Paved => "Paved road",
+            RouteKind::Dirt => "Dirt road",
+            RouteKind::Undiscovered => "Undiscovered",
+        }
+    }
+}
+
+/// A biome's map colour at the given opacity
+pub fn biome_color(biome: Biome, alpha: f32) -> Color {
+    let [r, g, b, _] = biome.map_color();
+    Color::new(r, g, b, alpha)
+}
+
+const LEGEND_W: f32 = 220.0;
+const LEGEND_HEADER_H: f32 = 26.0;
+const LEGEND_ROW_H: f32 = 18.0;
+
+/// Key to the node shapes, road colours and biomes, collapsed to its header
+/// when hidden (clicking the header or pressing L toggles it)
+pub fn draw_legend(expanded: bool, screen_width: f32, screen_height: f32) -> Option<PlayerAction> {
+    let biome_rows = Biome::all().len().div_ceil(2);
+    let rows = NodeKind::ALL.len() + 1 + RouteKind::ALL.len() + biome_rows;
+    let height = if expanded {
+        LEGEND_HEADER_H + (rows + 3) as f32 * LEGEND_ROW_H + 8.0
+    } else {
+        LEGEND_HEADER_H
+    };
+    let x = screen_width - LEGEND_W - 10.0;
+    let y = screen_height - height - 30.0;
+    draw_rectangle(x, y, LEGEND_W, height, Color::from_rgba(0, 0, 0, 180));
+    let arrow = if expanded { "▾" } else { "▸" };
+    draw_ui_text(
+        &format!("{} Legend (L)", arrow),
+        x + 10.0,
+        y + 18.0,
+        16.0,
+        WHITE,
+    );
+
+    let mouse: Vec2 = mouse_position().into();
+    let header = Rect::new(x, y, LEGEND_W, LEGEND_HEADER_H);
+    let action = if header.contains(mouse) && is_mouse_button_released(MouseButton::Left) {
+        Some(PlayerAction::ToggleRegionLegend)
+    } else {
+        None
+    };
+    if !expanded {
+        return action;
+    }
+
+    let mut row_y = y + LEGEND_HEADER_H;
+    let heading = |text: &str, row_y: &mut f32| {
+        draw_ui_text(text, x + 10.0, *row_y + 13.0, 13.0, LIGHTGRAY);
+        *row_y += LEGEND_ROW_H;
+    };
+
+    heading("Towns", &mut row_y);
+    for kind in NodeKind::ALL {
+        let (cx, cy) = (x + 20.0, row_y + 9.0);
+        if kind.is_diamond() {
+            draw_poly(cx, cy, 4, 6.0, 45.0, kind.color());
+        } else {
+            draw_circle(cx, cy, 6.0, kind.color());
+        }
+        draw_ui_text(kind.label(), x + 34.0, row_y + 13.0, 13.0, WHITE);
+        row_y += LEGEND_ROW_H;
+    }
+    draw_circle_lines(x + 20.0, row_y + 9.0, 6.0, 2.0, YELLOW);
+    draw_ui_text("Your current town", x + 34.0, row_y + 13.0, 13.0, WHITE);
+    row_y += LEGEND_ROW_H;
+
+    heading("Routes", &mut row_y);
+    for kind in RouteKind::ALL {
+        let line_y = row_y + 9.0;
+        draw_line(
+            x + 12.0,
+            line_y,
+            x + 28.0,
+            line_y,
+            kind.thickness(),
+            kind.color(),
+        );
+        draw_ui_text(kind.label(), x + 34.0, row_y + 13.0, 13.0, WHITE);
+        row_y += LEGEND_ROW_H;
+    }
+
+    heading("Biomes", &mut row_y);
+    for (i, biome) in Biome::all().into_iter().enumerate() {
+        let col_x = x + 10.0 + (i % 2) as f32 * (LEGEND_W / 2.0);
+        let cell_y = row_y + (i / 2) as f32 * LEGEND_ROW_H;
+        draw_circle(col_x + 10.0, cell_y + 9.0, 6.0, biome_color(biome, 0.5));
+        draw_ui_text(biome.name(), col_x + 24.0, cell_y + 13.0, 13.0, WHITE);
+    }
+    action
+}
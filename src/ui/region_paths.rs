@@ -0,0 +1,62 @@
+//! Region map path highlighting - the way a hovered caravan travels, or the
+//! way from the active town to the selected one
+
+use crate::region::{RegionMap, TownNode};
+use macroquad::prelude::*;
+
+/// Highlight the hovered caravan's trade path (`caravan` is its two ends), or else the
+/// way from the active town to the selected one (uses RegionMap::path_between)
+pub fn draw_highlighted_path(
+    region: &RegionMap,
+    caravan: Option<(u32, u32)>,
+    padding: f32,
+    map_width: f32,
+    map_height: f32,
+) {
+    let highlight = if let Some((from, to)) = caravan {
+        Some((from, to, ORANGE))
+    } else {
+        match (region.active_town_id, region.selected_node) {
+            (Some(active), Some(selected)) if active != selected => {
+                Some((active, selected, SKYBLUE))
+            }
+            _ => None,
+        }
+    };
+    if let Some((from, to, color)) = highlight {
+        let path = region
+            .path_between(from, to)
+            .unwrap_or_else(|| vec![from, to]);
+        draw_path(region, &path, color, padding, map_width, map_height);
+    }
+}
+
+/// Draw a path of nodes as a bold line over the routes, ringing both ends
+fn draw_path(
+    region: &RegionMap,
+    path: &[u32],
+    color: Color,
+    padding: f32,
+    map_width: f32,
+    map_height: f32,
+) {
+    let points: Vec<Vec2> = path
+        .iter()
+        .filter_map(|&id| region.get_node(id))
+        .map(|node| node_to_screen(node, padding, map_width, map_height))
+        .collect();
+    for leg in points.windows(2) {
+        draw_line(leg[0].x, leg[0].y, leg[1].x, leg[1].y, 6.0, color);
+    }
+    for end in [points.first(), points.last()].into_iter().flatten() {
+        draw_circle_lines(end.x, end.y, 40.0, 3.0, color);
+    }
+}
+
+/// Convert node position (0-1) to screen coordinates
+pub fn node_to_screen(node: &TownNode, padding: f32, map_width: f32, map_height: f32) -> Vec2 {
+    vec2(
+        padding + node.position[0] * map_width,
+        padding + node.position[1] * map_height,
+    )
+}
@@ -1,16 +1,30 @@
 //! Region map UI rendering
 
-use crate::region::{RegionMap, TownNode, TradeManager};
+use crate::data::GameState;
+use crate::region::{Caravan, TownNode, CARAVAN_TRAVEL_DAYS};
+use crate::ui::region_archive;
+use crate::ui::region_legend::{self, biome_color, NodeKind, RouteKind};
+use crate::ui::region_paths::{self, node_to_screen};
+use crate::ui::text_util::{format_thousands, wrap_text};
+use crate::ui::theme;
+use crate::ui::town_namer::TownNameDraft;
+use crate::PlayerAction;
 use macroquad::prelude::*;
 use macroquad_toolkit::ui::{draw_ui_text, measure_ui_text};
 
+/// How close (in pixels) the mouse must be to a caravan to show its tooltip
+const CARAVAN_HOVER_RADIUS: f32 = 10.0;
+
 /// Render the region/world map with trade info
 pub fn draw_region_map(
-    region: &RegionMap,
-    trade_manager: &TradeManager,
+    state: &GameState,
     screen_width: f32,
     screen_height: f32,
-) {
+) -> Option<PlayerAction> {
+    let region = &state.region_map;
+    let trade_manager = &state.trade_manager;
+    let mut action = None;
+
     // Map dimensions with padding
     let padding = 50.0;
     let map_width = screen_width - padding * 2.0;
@@ -25,6 +39,29 @@ pub fn draw_region_map(
         Color::from_rgba(20, 30, 20, 255),
     );
 
+    // Where each caravan is on screen, so the one under the mouse can be highlighted
+    let mouse_pos: Vec2 = mouse_position().into();
+    let caravans: Vec<(&Caravan, &TownNode, &TownNode, Vec2)> = trade_manager
+        .caravans
+        .iter()
+        .filter_map(|caravan| {
+            let route = trade_manager
+                .routes
+                .iter()
+                .find(|r| r.id == caravan.route_id)?;
+            let from_node = region.get_node(route.from_town)?;
+            let to_node = region.get_node(route.to_town)?;
+            let from_screen = node_to_screen(from_node, padding, map_width, map_height);
+            let to_screen = node_to_screen(to_node, padding, map_width, map_height);
+            let caravan_pos = caravan.get_visual_position(from_screen, to_screen);
+            Some((caravan, from_node, to_node, caravan_pos))
+        })
+        .collect();
+    let hovered_caravan = caravans
+        .iter()
+        .find(|(_, _, _, pos)| pos.distance(mouse_pos) < CARAVAN_HOVER_RADIUS)
+        .map(|&(caravan, from_node, to_node, _)| (caravan, from_node, to_node));
+
     // Draw routes first (below nodes)
     for route in &region.routes {
         let from = region.get_node(route.from);
@@ -34,45 +71,25 @@ pub fn draw_region_map(
             let from_pos = node_to_screen(from, padding, map_width, map_height);
             let to_pos = node_to_screen(to, padding, map_width, map_height);
 
-            let color = if route.discovered {
-                if route.quality > 0.5 {
-                    Color::from_rgba(180, 160, 140, 255) // Paved road
-                } else {
-                    Color::from_rgba(120, 100, 80, 255) // Dirt road
-                }
-            } else {
-                Color::from_rgba(60, 60, 60, 100) // Undiscovered
-            };
-
-            let thickness = if route.discovered { 3.0 } else { 1.0 };
+            let kind = RouteKind::of(route);
+            let (color, thickness) = (kind.color(), kind.thickness());
             draw_line(from_pos.x, from_pos.y, to_pos.x, to_pos.y, thickness, color);
         }
     }
 
-    // Draw caravans on routes (uses Caravan::get_visual_position and TradeGood::name)
-    for caravan in &trade_manager.caravans {
-        if let Some(route) = trade_manager
-            .routes
-            .iter()
-            .find(|r| r.id == caravan.route_id)
-        {
-            let from = region.get_node(route.from_town);
-            let to = region.get_node(route.to_town);
-
-            if let (Some(from_node), Some(to_node)) = (from, to) {
-                let from_screen = node_to_screen(from_node, padding, map_width, map_height);
-                let to_screen = node_to_screen(to_node, padding, map_width, map_height);
-
-                let caravan_pos = caravan.get_visual_position(from_screen, to_screen);
-
-                // Draw caravan icon
-                draw_circle(caravan_pos.x, caravan_pos.y, 8.0, ORANGE);
-                draw_ui_text("🚚", caravan_pos.x - 6.0, caravan_pos.y + 4.0, 12.0, WHITE);
+    // Highlight the hovered caravan's path, or the way to the selected town
+    region_paths::draw_highlighted_path(
+        region,
+        hovered_caravan.map(|(_, from, to)| (from.id, to.id)),
+        padding,
+        map_width,
+        map_height,
+    );
 
-                // Show cargo name on hover would go here
-                let _cargo_name = caravan.cargo.name();
-            }
-        }
+    // Draw caravans on routes (uses Caravan::get_visual_position and TradeGood::name)
+    for (_, _, _, caravan_pos) in &caravans {
+        draw_circle(caravan_pos.x, caravan_pos.y, 8.0, ORANGE);
+        draw_ui_text("🚚", caravan_pos.x - 6.0, caravan_pos.y + 4.0, 12.0, WHITE);
     }
 
     // Draw nodes
@@ -82,8 +99,7 @@ pub fn draw_region_map(
         let is_active = region.active_town_id == Some(node.id);
 
         // Biome background circle
-        let biome_color = node.biome.map_color();
-        let bg_color = Color::new(biome_color[0], biome_color[1], biome_color[2], 0.5);
+        let bg_color = biome_color(node.biome, 0.5);
 
         if node.is_wonder_site {
             // Wonder sites are diamond shaped
@@ -93,21 +109,7 @@ pub fn draw_region_map(
         }
 
         // Node circle or diamond
-        let node_color = if node.is_wonder_site {
-            if node.wonder_site.is_some() {
-                PURPLE // Active construction
-            } else {
-                MAGENTA // Available wonder site
-            }
-        } else if node.settled {
-            if node.is_capital {
-                GOLD
-            } else {
-                GREEN
-            }
-        } else {
-            GRAY
-        };
+        let node_color = NodeKind::of(node).color();
 
         if node.is_wonder_site {
             draw_poly(pos.x, pos.y, 4, 20.0, 45.0, node_color);
@@ -157,6 +159,8 @@ pub fn draw_region_map(
             }
         }
 
+        state.wonder_celebration.draw_fireworks(node.id, pos);
+
         // Name label
         let name_x = pos.x - measure_ui_text(&node.name, None, 16, 1.0).width / 2.0;
         draw_ui_text(&node.name, name_x, pos.y + 40.0, 16.0, WHITE);
@@ -187,6 +191,15 @@ pub fn draw_region_map(
     // Draw title
     draw_ui_text("REGION MAP", screen_width / 2.0 - 80.0, 35.0, 32.0, WHITE);
 
+    // Seed (so a good map can be shared and replayed)
+    let seed_text = format!("Seed: {}", region.seed);
+    let seed_w = measure_ui_text(&seed_text, None, 14, 1.0).width;
+    let seed_x = screen_width / 2.0 - (seed_w + 60.0) / 2.0;
+    draw_ui_text(&seed_text, seed_x, 56.0, 14.0, LIGHTGRAY);
+    if theme::draw_button(seed_x + seed_w + 8.0, 42.0, 52.0, 20.0, "Copy") {
+        action = Some(PlayerAction::CopySeed);
+    }
+
     // Draw region info panel (uses active_town, settled_count, routes_from)
     let panel_x = 10.0;
     let panel_y = 60.0;
@@ -265,22 +278,349 @@ pub fn draw_region_map(
         );
     }
 
+    // Archived towns and their outstanding debts
+    if let Some(act) = region_archive::draw_archived_towns(state, panel_x, panel_y + 150.0) {
+        action = Some(act);
+    }
+
+    // Whole-dynasty totals (bottom left)
+    draw_region_totals(state, panel_x, screen_height - 140.0);
+
+    // Map key (bottom right), under the town panels so their buttons stay on top
+    if let Some(act) = region_legend::draw_legend(
+        state.settings.show_region_legend,
+        screen_width,
+        screen_height,
+    ) {
+        action = Some(act);
+    }
+
+    // Selected town details, plus the archived town's economy if it has one
+    let mut offers_y = panel_y + SELECTED_PANEL_H + 20.0;
+    if let Some(node) = region.selected_node.and_then(|id| region.get_node(id)) {
+        offers_y = panel_y + selected_panel_height(node) + 20.0;
+        if let Some(act) = draw_selected_node(state, node, screen_width - 230.0, panel_y) {
+            action = Some(act);
+        }
+        if let Some(proxy) = state.town_proxies.get(node.id) {
+            if let Some(act) =
+                region_archive::draw_proxy_details(proxy, screen_width - 230.0, offers_y)
+            {
+                action = Some(act);
+            }
+            offers_y += region_archive::PROXY_PANEL_H + 10.0;
+        }
+    }
+
+    // Open trade offers (below the selected town panels)
+    if let Some(act) = draw_trade_offers(state, screen_width - 230.0, offers_y) {
+        action = Some(act);
+    }
+
+    // Node selection (panel buttons take priority)
+    if action.is_none() && is_mouse_button_released(MouseButton::Left) {
+        let mouse: Vec2 = mouse_position().into();
+        if let Some(node) = region
+            .nodes
+            .iter()
+            .find(|n| node_to_screen(n, padding, map_width, map_height).distance(mouse) < 25.0)
+        {
+            let selection = if region.selected_node == Some(node.id) {
+                None
+            } else {
+                Some(node.id)
+            };
+            action = Some(PlayerAction::SelectRegionNode(selection));
+        }
+    }
+
     // Draw instructions
     draw_ui_text(
-        "Click a town to select • Press M to return to town",
-        screen_width / 2.0 - 180.0,
+        "Click a town to select • L for the legend • Press M to return to town",
+        screen_width / 2.0 - 240.0,
         screen_height - 20.0,
         16.0,
         LIGHTGRAY,
     );
+
+    if state.settings.show_coordinates {
+        let hovered_node = region
+            .nodes
+            .iter()
+            .find(|n| node_to_screen(n, padding, map_width, map_height).distance(mouse_pos) < 25.0);
+        super::nav_overlay::draw_region_readout(hovered_node);
+    }
+
+    if let Some((caravan, from_node, to_node)) = hovered_caravan {
+        draw_caravan_tooltip(caravan, from_node, to_node, mouse_pos);
+    }
+
+    // The rename dialog sits over the map and takes every click
+    if state.town_name_draft.is_some() {
+        return super::town_namer::draw_town_namer(state);
+    }
+
+    action
+}
+
+/// Population and economy summed over the active town and every archived town
+struct RegionTotals {
+    population: f32,
+    materials_per_day: f32,
+    towns: usize,
+    in_crisis: usize,
+}
+
+impl RegionTotals {
+    /// Gathered from the live town and proxy data each time the region view is drawn
+    fn gather(state: &GameState) -> Self {
+        let proxies = state.town_proxies.all();
+        let active_materials_per_day = state.net_production_rate().materials * 24.0;
+        Self {
+            population: state.population.value()
+                + proxies.iter().map(|p| p.population as f32).sum::<f32>(),
+            materials_per_day: active_materials_per_day
+                + proxies.iter().map(|p| p.net_materials).sum::<f32>(),
+            towns: 1 + proxies.len(),
+            in_crisis: state.town_proxies.crisis_count(),
+        }
+    }
+}
+
+/// Aggregate panel for the whole dynasty's economy
+fn draw_region_totals(state: &GameState, x: f32, y: f32) {
+    let totals = RegionTotals::gather(state);
+    draw_rectangle(x, y, 200.0, 100.0, Color::from_rgba(0, 0, 0, 180));
+    draw_ui_text("All Towns", x + 10.0, y + 20.0, 16.0, WHITE);
+
+    draw_ui_text(
+        &format!(
+            "Population: {:.0} in {} towns",
+            totals.population, totals.towns
+        ),
+        x + 10.0,
+        y + 40.0,
+        14.0,
+        LIGHTGRAY,
+    );
+    let rate_color = if totals.materials_per_day < 0.0 {
+        RED
+    } else {
+        GREEN
+    };
+    draw_ui_text(
+        &format!("Materials: {:+.1}/day", totals.materials_per_day),
+        x + 10.0,
+        y + 60.0,
+        14.0,
+        rate_color,
+    );
+    let (crisis_text, crisis_color) = if totals.in_crisis > 0 {
+        (format!("In crisis: {}", totals.in_crisis), RED)
+    } else {
+        ("No towns in crisis".to_string(), GRAY)
+    };
+    draw_ui_text(&crisis_text, x + 10.0, y + 80.0, 14.0, crisis_color);
 }
 
-/// Convert node position (0-1) to screen coordinates
-fn node_to_screen(node: &TownNode, padding: f32, map_width: f32, map_height: f32) -> Vec2 {
-    vec2(
-        padding + node.position[0] * map_width,
-        padding + node.position[1] * map_height,
-    )
+/// Height of the selected town panel (wonder sites add a row)
+const SELECTED_PANEL_H: f32 = 110.0;
+
+/// Extra height of the selected node panel while a wonder is being built there
+const WONDER_DETAILS_H: f32 = 70.0;
+
+/// Lines of the wonder's reward shown under its progress
+const WONDER_GRANT_LINES: usize = 2;
+
+/// Open offers from other towns, each with a button to accept it as a trade route
+fn draw_trade_offers(state: &GameState, x: f32, y: f32) -> Option<PlayerAction> {
+    let offers = &state.trade_manager.offers;
+    if offers.is_empty() {
+        return None;
+    }
+
+    let mut action = None;
+    let row_h = 40.0;
+    let height = 30.0 + offers.len() as f32 * row_h;
+    draw_rectangle(x, y, 220.0, height, Color::from_rgba(0, 0, 0, 180));
+    draw_ui_text("Trade Offers", x + 10.0, y + 20.0, 16.0, WHITE);
+
+    for (i, offer) in offers.iter().enumerate() {
+        let row_y = y + 30.0 + i as f32 * row_h;
+        let name = state
+            .region_map
+            .get_node(offer.town_id)
+            .map(|n| n.name.as_str())
+            .unwrap_or("Unknown");
+        draw_ui_text(
+            &format!(
+                "{} wants {:.0} {}",
+                name,
+                offer.amount_per_trip,
+                offer.good.name()
+            ),
+            x + 10.0,
+            row_y + 12.0,
+            13.0,
+            LIGHTGRAY,
+        );
+
+        let days_left = ((offer.expires_at - state.game_time_hours) / 24.0).max(0.0);
+        draw_ui_text(
+            &format!(
+                "{:.0} Mat/trip • {:.1}d left",
+                offer.payment_per_trip, days_left
+            ),
+            x + 10.0,
+            row_y + 28.0,
+            12.0,
+            GRAY,
+        );
+        if theme::draw_button(x + 150.0, row_y + 14.0, 60.0, 20.0, "Accept") {
+            action = Some(PlayerAction::AcceptTradeOffer(offer.id));
+        }
+    }
+
+    action
+}
+
+/// Height of the selected node panel (taller while a wonder is going up there)
+fn selected_panel_height(node: &TownNode) -> f32 {
+    if node.wonder_site.as_ref().is_some_and(|w| !w.completed) {
+        SELECTED_PANEL_H + WONDER_DETAILS_H
+    } else {
+        SELECTED_PANEL_H
+    }
+}
+
+/// Details for the selected node, with the option to move the capital there
+/// or to fund the wonder being built on it
+fn draw_selected_node(state: &GameState, node: &TownNode, x: f32, y: f32) -> Option<PlayerAction> {
+    let mut action = None;
+    let building_wonder = node.wonder_site.as_ref().filter(|w| !w.completed);
+    draw_rectangle(
+        x,
+        y,
+        220.0,
+        selected_panel_height(node),
+        Color::from_rgba(0, 0, 0, 180),
+    );
+    draw_ui_text(&node.name, x + 10.0, y + 20.0, 16.0, WHITE);
+
+    let status = if node.is_capital {
+        "Capital"
+    } else if node.is_wonder_site {
+        "Wonder Site"
+    } else if node.settled {
+        "Settled"
+    } else {
+        "Unsettled"
+    };
+    draw_ui_text(
+        &format!("{} • {}", node.biome.name(), status),
+        x + 10.0,
+        y + 40.0,
+        14.0,
+        LIGHTGRAY,
+    );
+
+    if node.settled && !node.is_capital {
+        let cost = crate::region::CAPITAL_RELOCATION_COST;
+        let color = if state.dynasty.legacy_points >= cost {
+            LIGHTGRAY
+        } else {
+            ORANGE
+        };
+        draw_ui_text(
+            &format!("Legacy: {} / {}", state.dynasty.legacy_points, cost),
+            x + 10.0,
+            y + 58.0,
+            13.0,
+            color,
+        );
+        if theme::draw_button(x + 10.0, y + 70.0, 125.0, 30.0, "Make Capital") {
+            action = Some(PlayerAction::SetCapital(node.id));
+        }
+    }
+
+    if node.settled && building_wonder.is_none() {
+        let rename_x = if node.is_capital { x + 10.0 } else { x + 145.0 };
+        if theme::draw_button(rename_x, y + 70.0, 65.0, 30.0, "Rename") {
+            let draft = TownNameDraft::new(node.id, &node.name);
+            action = Some(PlayerAction::EditTownName(Some(draft)));
+        }
+    }
+
+    if let Some(site) = building_wonder {
+        let stage_name = site
+            .current_stage_info()
+            .map(|s| s.name)
+            .unwrap_or_default();
+        draw_ui_text(
+            &format!(
+                "{} {:.0}% • {:.0}% total",
+                stage_name,
+                site.stage_progress_percent() * 100.0,
+                site.overall_progress() * 100.0
+            ),
+            x + 10.0,
+            y + 58.0,
+            13.0,
+            LIGHTGRAY,
+        );
+
+        let rate = site.average_rate_per_day(state.game_time_hours);
+        let eta = site.estimated_completion(rate);
+        let eta_text = if eta.is_finite() {
+            format!("ETA: ~{:.1} days at {:.0}/day", eta, rate)
+        } else {
+            "ETA: not yet funded".to_string()
+        };
+
+        // Concrete targets to stockpile toward
+        draw_ui_text(
+            &format!(
+                "Need {} more for {}",
+                format_thousands(site.remaining_current_stage()),
+                stage_name
+            ),
+            x + 10.0,
+            y + 76.0,
+            13.0,
+            WHITE,
+        );
+        draw_ui_text(
+            &format!(
+                "{} total remaining",
+                format_thousands(site.remaining_total())
+            ),
+            x + 10.0,
+            y + 92.0,
+            12.0,
+            GRAY,
+        );
+        draw_ui_text(&eta_text, x + 10.0, y + 108.0, 12.0, GRAY);
+
+        // What finishing it will grant
+        let grants = wrap_text(site.wonder.description(), 12.0, 200.0);
+        for (i, line) in grants.iter().take(WONDER_GRANT_LINES).enumerate() {
+            draw_ui_text(line, x + 10.0, y + 126.0 + i as f32 * 14.0, 12.0, SKYBLUE);
+        }
+
+        if theme::draw_button(x + 10.0, y + 146.0, 95.0, 24.0, "Give 10") {
+            action = Some(PlayerAction::ContributeToWonder(node.id, 10.0));
+        }
+        let auto_label = if state.settings.wonder_auto_fund {
+            "Auto: On"
+        } else {
+            "Auto: Off"
+        };
+        if theme::draw_button(x + 115.0, y + 146.0, 95.0, 24.0, auto_label) {
+            action = Some(PlayerAction::ToggleWonderAutoFund);
+        }
+    }
+
+    action
 }
 
 /// Draw a tooltip for a hovered node (uses biome multipliers)
@@ -326,6 +666,43 @@ pub fn draw_node_tooltip(node: &TownNode, mouse_pos: Vec2) {
         lines.push("Status: Unsettled".to_string());
     }
 
+    draw_tooltip_lines(&lines, mouse_pos);
+}
+
+/// Draw a tooltip for a hovered caravan: what it carries, where, and when it arrives
+fn draw_caravan_tooltip(caravan: &Caravan, from: &TownNode, to: &TownNode, mouse_pos: Vec2) {
+    let cargo = if caravan.outbound {
+        format!(
+            "Cargo: {:.0} {} (worth ~{:.0} Materials)",
+            caravan.cargo_amount,
+            caravan.cargo.name(),
+            caravan.cargo_worth(&to.resource_potentials)
+        )
+    } else {
+        "Cargo: none (returning empty)".to_string()
+    };
+    let (leg_start, leg_end) = if caravan.outbound {
+        (from, to)
+    } else {
+        (to, from)
+    };
+    let hours_left = caravan.days_left(CARAVAN_TRAVEL_DAYS) * 24.0;
+    let lines = vec![
+        format!("{} Caravan", caravan.cargo.name()),
+        cargo,
+        format!("Route: {} → {}", from.name, to.name),
+        format!("Heading to {} from {}", leg_end.name, leg_start.name),
+        format!(
+            "Progress: {:.0}% (arrives in ~{:.0}h)",
+            caravan.progress * 100.0,
+            hours_left.ceil()
+        ),
+    ];
+    draw_tooltip_lines(&lines, mouse_pos);
+}
+
+/// Tooltip box beside the mouse; the first line is the title
+fn draw_tooltip_lines(lines: &[String], mouse_pos: Vec2) {
     let max_width = lines
         .iter()
         .map(|l| measure_ui_text(l, None, 14, 1.0).width)
@@ -0,0 +1,92 @@
+//! Resource alerts - flag a stockpile the moment it runs dry
+
+use crate::data::GameState;
+use crate::economy::{ResourceType, Resources};
+use crate::ui::theme::colors;
+use macroquad::prelude::*;
+use macroquad_toolkit::ui::{draw_ui_text, measure_ui_text};
+
+/// Stockpiles that stall the town when they bottom out, with the name used in the alert
+const WATCHED: [(ResourceType, &str); 2] = [
+    (ResourceType::Materials, "materials"),
+    (ResourceType::Maintenance, "maintenance"),
+];
+
+/// Seconds the out-of-stock banner stays up
+const BANNER_SECONDS: f32 = 5.0;
+
+/// Blinks per second of an empty resource's top-bar number
+const FLASH_RATE: f64 = 3.0;
+
+/// Watches the stockpiles frame to frame (UI only, not saved)
+#[derive(Debug, Clone, Default)]
+pub struct ResourceAlerts {
+    /// Last frame's stockpile (None until the first update)
+    previous: Option<Resources>,
+    /// Message and seconds left for the banner under the top bar
+    banner: Option<(String, f32)>,
+}
+
+impl ResourceAlerts {
+    /// Compare against last frame; returns an alert for each stockpile that just hit zero
+    pub fn update(&mut self, resources: &Resources, delta: f32) -> Vec<String> {
+        if let Some((_, time_left)) = &mut self.banner {
+            *time_left -= delta;
+            if *time_left <= 0.0 {
+                self.banner = None;
+            }
+        }
+
+        let mut alerts = Vec::new();
+        if let Some(previous) = self.previous {
+            for (kind, name) in WATCHED {
+                if previous.get(kind) > 0.0 && resources.get(kind) <= 0.0 {
+                    let message = format!("Out of {}! Production has stalled.", name);
+                    self.banner = Some((message.clone(), BANNER_SECONDS));
+                    alerts.push(message);
+                }
+            }
+        }
+        self.previous = Some(*resources);
+        alerts
+    }
+
+    /// Forget the last stockpile (a different town is loaded)
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+}
+
+/// Top-bar colour for a resource: blinking red while a watched stockpile is empty
+pub fn value_color(state: &GameState, kind: ResourceType, normal: Color) -> Color {
+    let watched = WATCHED.iter().any(|(k, _)| *k == kind);
+    let blink_on = (get_time() * FLASH_RATE * 2.0) as i64 % 2 == 0;
+    if watched && state.resources.get(kind) <= 0.0 && blink_on {
+        RED
+    } else {
+        normal
+    }
+}
+
+/// Banner under the top bar when a stockpile has just run out
+pub fn draw_banner(state: &GameState, y: f32) {
+    let (message, time_left) = match &state.resource_alerts.banner {
+        Some(banner) => banner,
+        None => return,
+    };
+    // Fades out over the last second
+    let alpha = time_left.min(1.0);
+    let w = measure_ui_text(message, None, 20, 1.0).width + 40.0;
+    let x = (screen_width() - w) / 2.0;
+
+    draw_rectangle(x, y, w, 34.0, Color::new(0.4, 0.05, 0.05, 0.9 * alpha));
+    draw_rectangle_lines(x, y, w, 34.0, 2.0, Color::new(1.0, 0.3, 0.3, alpha));
+    let text = colors::text();
+    draw_ui_text(
+        message,
+        x + 20.0,
+        y + 23.0,
+        20.0,
+        Color::new(text.r, text.g, text.b, alpha),
+    );
+}
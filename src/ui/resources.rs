@@ -1,49 +1,306 @@
 use crate::data::GameState;
+use crate::economy::{ResourceGroup, ResourceType};
+use crate::ui::theme;
 use crate::ui::theme::colors;
+use crate::PlayerAction;
 use macroquad::prelude::*;
-use macroquad_toolkit::ui::draw_ui_text;
+use macroquad_toolkit::ui::{draw_ui_text, measure_ui_text};
+
+/// Left edge and spacing of the resource readouts in the top bar
+const RESOURCE_START_X: f32 = 300.0;
+const RESOURCE_SPACING: f32 = 180.0;
+
+/// Width reserved for a resource's icon badge before its label
+const ICON_W: f32 = 20.0;
+
+/// Height of the top bar
+const BAR_HEIGHT: f32 = 60.0;
+
+/// Collapsible groups shown as dropdowns on the right of the bar
+const DROPDOWN_GROUPS: [ResourceGroup; 2] = [ResourceGroup::Raw, ResourceGroup::Processed];
+const DROPDOWN_W: f32 = 220.0;
+const DROPDOWN_ROW_H: f32 = 24.0;
 
 /// Draw the top bar with resources and time
-pub fn draw_top_bar(state: &GameState, time_scale: f32, paused: bool) {
+pub fn draw_top_bar(state: &GameState, time_scale: f32, paused: bool) -> Option<PlayerAction> {
     let screen_w = screen_width();
-    let bar_height = 60.0;
+    let mut action = None;
 
     // Background
-    draw_rectangle(0.0, 0.0, screen_w, bar_height, colors::PANEL_BG);
-    draw_line(0.0, bar_height, screen_w, bar_height, 1.0, GRAY);
+    draw_rectangle(0.0, 0.0, screen_w, BAR_HEIGHT, colors::panel_bg());
+    draw_line(0.0, BAR_HEIGHT, screen_w, BAR_HEIGHT, 1.0, GRAY);
 
     // Time & Status (Left)
     let status_text = if paused { "PAUSED" } else { "RUNNING" };
+    let (speed_text, speed_color) = if state.turbo {
+        (
+            format!("TURBO x{:.0}", crate::simulation::TURBO_TIME_SCALE),
+            colors::warn(),
+        )
+    } else {
+        (format!("x{:.0}", time_scale), colors::text())
+    };
     let time_text = format!(
-        "Time: {:.1}h | x{:.0} | {}",
-        state.game_time_hours, time_scale, status_text
+        "Time: {:.1}h | {} | {}",
+        state.game_time_hours, speed_text, status_text
     );
-    draw_ui_text(&time_text, 10.0, 35.0, 20.0, colors::TEXT);
+    draw_ui_text(&time_text, 10.0, 35.0, 20.0, speed_color);
+
+    // Turbo catches up fast without the 2x/4x steps (T)
+    let turbo_label = if state.turbo {
+        "> Turbo (T)"
+    } else {
+        "Turbo (T)"
+    };
+    if theme::draw_button(10.0, 40.0, 100.0, 18.0, turbo_label) {
+        action = Some(PlayerAction::ToggleTurbo);
+    }
 
-    // Resources (Right/Center)
+    // Abstract resources (always visible)
     // Layout: Materials | Maintenance | Attractiveness | Stability (Pop/Cap)
+    let start_x = RESOURCE_START_X;
+    let spacing = RESOURCE_SPACING;
+    let rates = state.net_production_rate();
+    for (i, kind) in ResourceGroup::Abstract.members().iter().enumerate() {
+        let x = start_x + spacing * i as f32;
+        draw_resource_item(state, *kind, x, 35.0);
 
-    let r = &state.resources;
-    let start_x = 300.0;
-    let spacing = 180.0;
-
-    draw_resource_item("Materials", r.materials, start_x, 35.0, GREEN);
-    draw_resource_item("Maint.", r.maintenance, start_x + spacing, 35.0, ORANGE);
-    draw_resource_item(
-        "Attr.",
-        r.attractiveness,
-        start_x + spacing * 2.0,
-        35.0,
-        PINK,
-    );
-    draw_resource_item("Stab.", r.stability, start_x + spacing * 3.0, 35.0, SKYBLUE);
+        // Accumulating resources also show their live net rate
+        let rate = match kind {
+            ResourceType::Materials => Some(rates.materials),
+            ResourceType::Maintenance => Some(rates.maintenance),
+            _ => None,
+        };
+        if let Some(rate) = rate {
+            draw_rate(rate, x + ICON_W, 53.0);
+        }
+
+        if *kind == ResourceType::Stability {
+            draw_stability_strain(state, x + ICON_W, 53.0);
+        }
+
+        if *kind == ResourceType::Materials {
+            if let Some(hours) = state.materials_warning.hours_left {
+                let warning = format!("Out in ~{:.0}h", hours.ceil());
+                draw_ui_text(&warning, x + ICON_W + 80.0, 53.0, 14.0, RED);
+            }
+        }
+    }
 
     // Pop/Cap
     let cap = state.calculate_housing_capacity();
     let pop_text = format!("Pop: {:.0}/{:.0}", state.population.value(), cap);
     draw_ui_text(&pop_text, start_x + spacing * 4.0, 35.0, 20.0, PURPLE);
+
+    // Happiness index (average villager spirit) under the population
+    let happiness = crate::simulation::reactions::town_spirit(&state.agents);
+    draw_happiness(happiness, start_x + spacing * 4.0, 53.0);
+
+    // Collapsible groups (Raw, Processed)
+    let mut dropdown_y = BAR_HEIGHT + 2.0;
+    for (i, group) in DROPDOWN_GROUPS.iter().enumerate() {
+        let expanded = state.expanded_resource_groups.contains(group);
+        let marker = if expanded { "v" } else { ">" };
+        let label = format!("{} {}", group.name(), marker);
+        let (bx, by, bw, bh) = group_button_rect(screen_w, i);
+        if theme::draw_button(bx, by, bw, bh, &label) {
+            action = Some(PlayerAction::ToggleResourceGroup(*group));
+        }
+
+        if expanded {
+            let members = group.members();
+            let h = members.len() as f32 * DROPDOWN_ROW_H + 10.0;
+            let x = screen_w - DROPDOWN_W - 10.0;
+            theme::draw_panel(x, dropdown_y, DROPDOWN_W, h);
+            for (row, kind) in members.iter().enumerate() {
+                let row_y = dropdown_y + 22.0 + row as f32 * DROPDOWN_ROW_H;
+                draw_resource_item(state, *kind, x + 10.0, row_y);
+            }
+            dropdown_y += h + 4.0;
+        }
+    }
+
+    action
+}
+
+/// Screen rect (x, y, w, h) of a group toggle button
+fn group_button_rect(screen_w: f32, index: usize) -> (f32, f32, f32, f32) {
+    (screen_w - 120.0, 4.0 + index as f32 * 27.0, 110.0, 24.0)
+}
+
+/// Whether the mouse is over the top bar or one of its open dropdowns
+pub fn is_mouse_over_top_bar(state: &GameState, mouse: Vec2) -> bool {
+    if mouse.y <= BAR_HEIGHT {
+        return true;
+    }
+
+    // Each open dropdown is its rows plus padding and a gap (see draw_top_bar)
+    let height: f32 = DROPDOWN_GROUPS
+        .iter()
+        .filter(|g| state.expanded_resource_groups.contains(*g))
+        .map(|g| g.members().len() as f32 * DROPDOWN_ROW_H + 14.0)
+        .sum();
+    if height <= 0.0 {
+        return false;
+    }
+    mouse.x >= screen_width() - DROPDOWN_W - 10.0 && mouse.y <= BAR_HEIGHT + 2.0 + height
+}
+
+/// Icon badge, label, value and trend arrow for one resource
+fn draw_resource_item(state: &GameState, kind: ResourceType, x: f32, y: f32) {
+    let c = kind.color();
+    let color = Color::new(c[0], c[1], c[2], c[3]);
+
+    // Icon badge
+    draw_circle(x + 8.0, y - 6.0, 8.0, color);
+    draw_ui_text(kind.icon(), x + 4.0, y - 1.0, 14.0, BLACK);
+
+    let text = resource_label(state, kind);
+    let text_color = crate::ui::resource_alerts::value_color(state, kind, color);
+    draw_ui_text(&text, x + ICON_W, y, 20.0, text_color);
+
+    // Trend from the last tick's net change
+    let delta = state.last_tick_delta.get(kind);
+    let trend_x = x + ICON_W + measure_ui_text(&text, None, 20, 1.0).width + 4.0;
+    if delta > TREND_EPSILON {
+        draw_ui_text("▲", trend_x, y, 16.0, GREEN);
+    } else if delta < -TREND_EPSILON {
+        draw_ui_text("▼", trend_x, y, 16.0, RED);
+    }
 }
 
-fn draw_resource_item(label: &str, value: f32, x: f32, y: f32, color: Color) {
-    draw_ui_text(&format!("{}: {:.1}", label, value), x, y, 20.0, color);
+/// Net rate per game hour, red when the resource is draining
+fn draw_rate(rate: f32, x: f32, y: f32) {
+    let color = if rate < 0.0 { RED } else { LIGHTGRAY };
+    draw_ui_text(&format!("{:+.2}/hr", rate), x, y, 14.0, color);
+}
+
+/// What the population costs in stability, or an unrest warning once it is too low
+fn draw_stability_strain(state: &GameState, x: f32, y: f32) {
+    use crate::simulation::unrest;
+
+    if unrest::in_unrest(state) {
+        draw_ui_text("Unrest! Riots likely", x, y, 14.0, RED);
+    } else {
+        let strain = unrest::population_strain(state);
+        draw_ui_text(&format!("Crowding -{:.2}", strain), x, y, 14.0, LIGHTGRAY);
+    }
+}
+
+/// Face icon and percentage for the town happiness index
+fn draw_happiness(happiness: f32, x: f32, y: f32) {
+    use crate::population::{CONTENT_HAPPINESS, MISERABLE_HAPPINESS};
+
+    let (color, mood) = if happiness >= CONTENT_HAPPINESS {
+        (GREEN, 1.0)
+    } else if happiness > MISERABLE_HAPPINESS {
+        (YELLOW, 0.0)
+    } else {
+        (RED, -1.0)
+    };
+
+    // Face: head, eyes, and a mouth that curves with the mood
+    let (cx, cy) = (x + 6.0, y - 5.0);
+    draw_circle(cx, cy, 6.0, color);
+    draw_circle(cx - 2.0, cy - 1.5, 1.0, BLACK);
+    draw_circle(cx + 2.0, cy - 1.5, 1.0, BLACK);
+    let mouth_y = cy + 2.5;
+    let curve = mood * 1.5;
+    draw_line(cx - 3.0, mouth_y, cx, mouth_y + curve, 1.0, BLACK);
+    draw_line(cx, mouth_y + curve, cx + 3.0, mouth_y, 1.0, BLACK);
+
+    let text = format!("Happiness: {:.0}%", happiness * 100.0);
+    draw_ui_text(&text, x + 16.0, y, 14.0, color);
+}
+
+/// Changes smaller than this per tick are shown as flat
+const TREND_EPSILON: f32 = 0.0001;
+
+fn resource_label(state: &GameState, kind: ResourceType) -> String {
+    format!("{}: {:.1}", kind.name(), state.resources.get(kind))
+}
+
+/// Draw hover tooltips for the top bar (called after the rest of the UI so it sits on top)
+pub fn draw_top_bar_tooltips(state: &GameState) {
+    let mouse: Vec2 = mouse_position().into();
+
+    // Abstract resources: the whole slot, label and the line under it
+    for (i, kind) in ResourceGroup::Abstract.members().iter().enumerate() {
+        let x = RESOURCE_START_X + RESOURCE_SPACING * i as f32;
+        if mouse.x >= x
+            && mouse.x < x + RESOURCE_SPACING - 10.0
+            && mouse.y >= 15.0
+            && mouse.y <= 58.0
+        {
+            let pos = vec2(x + ICON_W, BAR_HEIGHT + 5.0);
+            if *kind == ResourceType::Attractiveness {
+                draw_attractiveness_breakdown(state, pos);
+            } else {
+                crate::ui::tooltip::draw_tooltip(&resource_help(state, *kind), pos);
+            }
+            return;
+        }
+    }
+
+    // Rows of the open dropdowns (laid out as in draw_top_bar)
+    let x = screen_width() - DROPDOWN_W - 10.0;
+    if mouse.x < x {
+        return;
+    }
+    let mut dropdown_y = BAR_HEIGHT + 2.0;
+    for group in DROPDOWN_GROUPS
+        .iter()
+        .filter(|g| state.expanded_resource_groups.contains(*g))
+    {
+        for (row, kind) in group.members().iter().enumerate() {
+            let row_top = dropdown_y + 5.0 + row as f32 * DROPDOWN_ROW_H;
+            if mouse.y >= row_top && mouse.y < row_top + DROPDOWN_ROW_H {
+                // Open to the left so it stays on screen
+                let text = resource_help(state, *kind);
+                let w = text
+                    .lines()
+                    .map(|line| measure_ui_text(line, None, 16, 1.0).width)
+                    .fold(0.0, f32::max);
+                crate::ui::tooltip::draw_tooltip(&text, vec2(x - w - 30.0, row_top));
+                return;
+            }
+        }
+        dropdown_y += group.members().len() as f32 * DROPDOWN_ROW_H + 14.0;
+    }
+}
+
+/// A resource's role and what raises and lowers it (from `ResourceType::help`)
+fn resource_help(state: &GameState, kind: ResourceType) -> String {
+    let help = kind.help();
+    format!(
+        "{}: {:.2}\n{}\n\n+ {}\n- {}",
+        kind.full_name(),
+        state.resources.get(kind),
+        help.role,
+        help.raised_by,
+        help.lowered_by
+    )
+}
+
+/// List every attractiveness source and the resulting total
+fn draw_attractiveness_breakdown(state: &GameState, pos: Vec2) {
+    let sources = state.attractiveness_sources();
+    let mut text = format!("{}\n", resource_help(state, ResourceType::Attractiveness));
+
+    if sources.is_empty() {
+        text.push_str("\nNo sources yet - restore a zone.");
+    }
+    for (label, amount) in &sources {
+        text.push_str(&format!("\n{:+.2}  {}", amount, label));
+    }
+
+    let raw_total: f32 = sources.iter().map(|(_, a)| a).sum();
+    if raw_total < 0.0 {
+        text.push_str(&format!(
+            "\n\nUpkeep exceeds sources ({:.2}), floored at 0",
+            raw_total
+        ));
+    }
+
+    crate::ui::tooltip::draw_tooltip(&text, pos);
 }
@@ -0,0 +1,48 @@
+//! "Saved" toast - a brief note in the top-right corner after the game autosaves
+
+use crate::ui::theme::colors;
+use macroquad::prelude::*;
+use macroquad_toolkit::ui::{draw_ui_text, measure_ui_text};
+
+/// Real seconds the toast stays up (fading over the last half second)
+const DURATION: f32 = 2.0;
+
+/// Real seconds left on the toast
+#[derive(Debug, Clone, Default)]
+pub struct SaveToast {
+    remaining: f32,
+}
+
+impl SaveToast {
+    pub fn show(&mut self) {
+        self.remaining = DURATION;
+    }
+
+    /// Call every frame with the real frame time
+    pub fn update(&mut self, delta: f32) {
+        self.remaining = (self.remaining - delta).max(0.0);
+    }
+
+    /// Draw the toast (nothing when idle)
+    pub fn draw(&self) {
+        if self.remaining <= 0.0 {
+            return;
+        }
+        let alpha = (self.remaining * 2.0).min(1.0);
+        let text = "Saved";
+        let w = measure_ui_text(text, None, 18, 1.0).width + 24.0;
+        let x = screen_width() - w - 10.0;
+        let y = 75.0;
+        draw_rectangle(x, y, w, 28.0, Color::new(0.0, 0.0, 0.0, 0.6 * alpha));
+        draw_ui_text(
+            text,
+            x + 12.0,
+            y + 20.0,
+            18.0,
+            Color {
+                a: alpha,
+                ..colors::text()
+            },
+        );
+    }
+}
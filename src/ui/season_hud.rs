@@ -0,0 +1,57 @@
+//! Season HUD - the season, weather, what the weather is doing and how long until the next season
+
+use crate::data::GameState;
+use macroquad::prelude::*;
+use macroquad_toolkit::ui::draw_ui_text;
+
+/// Height of the HUD box (the festival button sits below it)
+pub const HUD_H: f32 = 68.0;
+
+const HUD_W: f32 = 195.0;
+const BAR_H: f32 = 6.0;
+
+/// Draw season and weather info in the top-right corner, under the top bar
+pub fn draw_season_hud(state: &GameState, y: f32) {
+    let seasons = &state.season_state;
+    let x = screen_width() - HUD_W - 10.0;
+
+    draw_rectangle(x, y, HUD_W, HUD_H, Color::new(0.0, 0.0, 0.0, 0.5));
+    draw_ui_text(&seasons.display_string(), x + 5.0, y + 18.0, 18.0, WHITE);
+
+    // Progress through the season, in the season's own tint
+    let tint = seasons.season.color_tint();
+    let bar_x = x + 5.0;
+    let bar_y = y + 25.0;
+    let bar_w = HUD_W - 10.0;
+    draw_rectangle(bar_x, bar_y, bar_w, BAR_H, Color::new(1.0, 1.0, 1.0, 0.15));
+    draw_rectangle(
+        bar_x,
+        bar_y,
+        bar_w * seasons.season_progress(),
+        BAR_H,
+        Color::new(tint[0], tint[1], tint[2], 1.0),
+    );
+
+    // What comes next, tinted to match so winter is easy to spot coming
+    let next = seasons.season.next();
+    let next_tint = next.color_tint();
+    let days = seasons.days_until_next().ceil();
+    let label = format!(
+        "{} in {:.0} day{}",
+        next.name(),
+        days,
+        if days == 1.0 { "" } else { "s" }
+    );
+    draw_ui_text(
+        &label,
+        x + 5.0,
+        y + 46.0,
+        14.0,
+        Color::new(next_tint[0], next_tint[1], next_tint[2], 1.0),
+    );
+
+    // The weather's effect on the town, if it has one
+    if let Some(note) = seasons.weather.effect_note() {
+        draw_ui_text(note, x + 5.0, y + 63.0, 13.0, LIGHTGRAY);
+    }
+}
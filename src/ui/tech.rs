@@ -1,9 +1,156 @@
-use crate::data::GameState;
+use crate::data::{GameState, TechNode};
 use crate::ui::theme::colors;
 use crate::PlayerAction;
 use macroquad::prelude::*;
 use macroquad_toolkit::ui::draw_ui_text;
 
+const NODE_W: f32 = 180.0;
+const NODE_H: f32 = 80.0;
+const MIN_ZOOM: f32 = 0.5;
+const MAX_ZOOM: f32 = 1.5;
+
+/// Pan/zoom and keyboard selection state of the tech tree window (UI only, not saved)
+#[derive(Debug, Clone, PartialEq)]
+pub struct TechView {
+    /// Tree-space offset applied before zooming
+    pub offset: Vec2,
+    pub zoom: f32,
+    /// Mouse position and offset when the current drag started
+    pub drag_from: Option<(Vec2, Vec2)>,
+    /// Node highlighted by the keyboard cursor
+    pub selected: Option<String>,
+}
+
+impl Default for TechView {
+    fn default() -> Self {
+        Self {
+            offset: Vec2::ZERO,
+            zoom: 1.0,
+            drag_from: None,
+            selected: None,
+        }
+    }
+}
+
+impl TechView {
+    /// Screen position of a tech node's top-left corner
+    fn to_screen(&self, center: Vec2, tech: &TechNode) -> Vec2 {
+        center + (vec2(tech.x, tech.y) + self.offset) * self.zoom
+    }
+
+    /// Keep the view centre over the tree's bounding box
+    fn clamped(mut self, tree: &[TechNode]) -> Self {
+        self.zoom = self.zoom.clamp(MIN_ZOOM, MAX_ZOOM);
+        if let Some((min, max)) = tree_bounds(tree) {
+            self.offset = self.offset.clamp(-max, -min);
+        }
+        self
+    }
+
+    /// View centred on a node
+    fn centered_on(&self, tech: &TechNode) -> Self {
+        Self {
+            offset: -(vec2(tech.x, tech.y) + vec2(NODE_W, NODE_H) / 2.0),
+            ..self.clone()
+        }
+    }
+}
+
+/// Bounding box (min, max) of every node in tree space
+fn tree_bounds(tree: &[TechNode]) -> Option<(Vec2, Vec2)> {
+    let first = tree.first()?;
+    let mut min = vec2(first.x, first.y);
+    let mut max = min;
+    for tech in tree {
+        min = min.min(vec2(tech.x, tech.y));
+        max = max.max(vec2(tech.x + NODE_W, tech.y + NODE_H));
+    }
+    Some((min, max))
+}
+
+/// Whether a tech's prerequisites are met (it may still be unaffordable)
+fn can_unlock(state: &GameState, tech: &TechNode) -> bool {
+    if tech.unlocked {
+        false
+    } else if let Some(parent_id) = &tech.parent_id {
+        state
+            .tech_tree
+            .iter()
+            .find(|t| &t.id == parent_id)
+            .map(|p| p.unlocked)
+            .unwrap_or(false)
+    } else {
+        true // No parent = unlocked by default (to buy)
+    }
+}
+
+/// Nodes directly linked to a tech (its parent and children)
+fn connected<'a>(tree: &'a [TechNode], tech: &'a TechNode) -> impl Iterator<Item = &'a TechNode> {
+    tree.iter().filter(move |t| {
+        tech.parent_id.as_deref() == Some(t.id.as_str())
+            || t.parent_id.as_deref() == Some(tech.id.as_str())
+    })
+}
+
+/// Arrow keys move the cursor along connections, Enter researches the selected node
+fn keyboard_action(state: &GameState) -> Option<PlayerAction> {
+    let view = &state.tech_view;
+    let selected = view
+        .selected
+        .as_ref()
+        .and_then(|id| state.tech_tree.iter().find(|t| &t.id == id));
+
+    let direction = if is_key_pressed(KeyCode::Left) {
+        vec2(-1.0, 0.0)
+    } else if is_key_pressed(KeyCode::Right) {
+        vec2(1.0, 0.0)
+    } else if is_key_pressed(KeyCode::Up) {
+        vec2(0.0, -1.0)
+    } else if is_key_pressed(KeyCode::Down) {
+        vec2(0.0, 1.0)
+    } else {
+        Vec2::ZERO
+    };
+
+    if direction != Vec2::ZERO {
+        let target = match selected {
+            // Best-aligned connected node in the pressed direction
+            Some(current) => {
+                let from = vec2(current.x, current.y);
+                connected(&state.tech_tree, current)
+                    .map(|t| {
+                        (
+                            t,
+                            (vec2(t.x, t.y) - from).normalize_or_zero().dot(direction),
+                        )
+                    })
+                    .filter(|(_, alignment)| *alignment > 0.3)
+                    .max_by(|a, b| a.1.total_cmp(&b.1))
+                    .map(|(t, _)| t)
+            }
+            // First press picks something worth researching
+            None => state
+                .tech_tree
+                .iter()
+                .find(|t| can_unlock(state, t))
+                .or(state.tech_tree.first()),
+        };
+        let target = target?;
+        let mut next = view.centered_on(target).clamped(&state.tech_tree);
+        next.selected = Some(target.id.clone());
+        return Some(PlayerAction::SetTechView(next));
+    }
+
+    if is_key_pressed(KeyCode::Enter) {
+        let tech = selected?;
+        if can_unlock(state, tech) && state.resources.materials >= state.research_cost(tech) {
+            return Some(PlayerAction::Research(tech.id.clone()));
+        }
+    }
+
+    None
+}
+
 /// Draw the tech tree window
 pub fn draw_tech_tree_window(
     state: &GameState,
@@ -13,8 +160,8 @@ pub fn draw_tech_tree_window(
     h: f32,
 ) -> Option<PlayerAction> {
     // Background/Modal
-    draw_rectangle(x, y, w, h, colors::PANEL_BG);
-    draw_rectangle_lines(x, y, w, h, 2.0, colors::ACCENT);
+    draw_rectangle(x, y, w, h, colors::panel_bg());
+    draw_rectangle_lines(x, y, w, h, 2.0, colors::accent());
 
     // Header
     draw_ui_text("Research & Development", x + 20.0, y + 30.0, 30.0, WHITE);
@@ -31,10 +178,27 @@ pub fn draw_tech_tree_window(
         return Some(PlayerAction::ToggleTechTree);
     }
 
-    let center_x = x + w / 2.0;
-    let center_y = y + h / 2.0;
-    let node_w = 180.0;
-    let node_h = 80.0;
+    // Jump to the cheapest tech that can be researched right now
+    if draw_button("Next", x + w - 200.0, y + 10.0, 90.0, 30.0) {
+        let next = state
+            .tech_tree
+            .iter()
+            .filter(|t| can_unlock(state, t))
+            .min_by(|a, b| state.research_cost(a).total_cmp(&state.research_cost(b)));
+        if let Some(tech) = next {
+            let mut view = state.tech_view.centered_on(tech).clamped(&state.tech_tree);
+            view.selected = Some(tech.id.clone());
+            return Some(PlayerAction::SetTechView(view));
+        }
+    }
+
+    // Tree area (between the header and the tooltip line)
+    let area = Rect::new(x + 10.0, y + 70.0, w - 20.0, h - 110.0);
+    let view = &state.tech_view;
+    let center = vec2(area.x + area.w / 2.0, area.y + area.h / 2.0);
+    let node_size = vec2(NODE_W, NODE_H) * view.zoom;
+    let text_scale = view.zoom.max(0.7);
+    let mouse: Vec2 = mouse_position().into();
 
     let mut clicked_tech = None;
 
@@ -42,39 +206,33 @@ pub fn draw_tech_tree_window(
     for tech in &state.tech_tree {
         if let Some(parent_id) = &tech.parent_id {
             if let Some(parent) = state.tech_tree.iter().find(|t| &t.id == parent_id) {
-                let start_x = center_x + parent.x + node_w / 2.0;
-                let start_y = center_y + parent.y + node_h / 2.0;
-                let end_x = center_x + tech.x + node_w / 2.0;
-                let end_y = center_y + tech.y + node_h / 2.0;
+                let start = view.to_screen(center, parent) + node_size / 2.0;
+                let end = view.to_screen(center, tech) + node_size / 2.0;
+                if !area.contains(start) || !area.contains(end) {
+                    continue;
+                }
 
                 let color = if parent.unlocked { WHITE } else { DARKGRAY };
-                draw_line(start_x, start_y, end_x, end_y, 2.0, color);
+                draw_line(start.x, start.y, end.x, end.y, 2.0, color);
             }
         }
     }
 
     // Draw Nodes
     for tech in &state.tech_tree {
-        let nx = center_x + tech.x;
-        let ny = center_y + tech.y;
-
-        let can_unlock = if tech.unlocked {
-            false
-        } else if let Some(parent_id) = &tech.parent_id {
-            state
-                .tech_tree
-                .iter()
-                .find(|t| &t.id == parent_id)
-                .map(|p| p.unlocked)
-                .unwrap_or(false)
-        } else {
-            true // No parent = unlocked by default (to buy)
-        };
+        let pos = view.to_screen(center, tech);
+        let node = Rect::new(pos.x, pos.y, node_size.x, node_size.y);
+        // Skip nodes panned outside the window
+        if !area.contains(node.point()) || !area.contains(node.point() + node.size()) {
+            continue;
+        }
 
-        let afford = state.resources.materials >= tech.cost;
+        let unlockable = can_unlock(state, tech);
+        let cost = state.research_cost(tech);
+        let afford = state.resources.materials >= cost;
         let bg_color = if tech.unlocked {
             GREEN
-        } else if can_unlock {
+        } else if unlockable {
             if afford {
                 BLUE
             } else {
@@ -84,47 +242,106 @@ pub fn draw_tech_tree_window(
             DARKGRAY
         };
 
-        draw_rectangle(nx, ny, node_w, node_h, bg_color);
-        draw_rectangle_lines(nx, ny, node_w, node_h, 2.0, WHITE);
+        draw_rectangle(node.x, node.y, node.w, node.h, bg_color);
+        let is_selected = view.selected.as_ref() == Some(&tech.id);
+        if is_selected {
+            // Keyboard cursor: thick gold frame
+            draw_rectangle_lines(
+                node.x - 3.0,
+                node.y - 3.0,
+                node.w + 6.0,
+                node.h + 6.0,
+                4.0,
+                GOLD,
+            );
+        } else if node.contains(mouse) {
+            draw_rectangle_lines(node.x, node.y, node.w, node.h, 3.0, LIGHTGRAY);
+        } else {
+            draw_rectangle_lines(node.x, node.y, node.w, node.h, 2.0, WHITE);
+        }
 
         // Text
-        draw_ui_text(&tech.name, nx + 5.0, ny + 20.0, 20.0, WHITE);
+        draw_ui_text(
+            &tech.name,
+            node.x + 5.0,
+            node.y + 20.0 * text_scale,
+            20.0 * text_scale,
+            WHITE,
+        );
         if tech.unlocked {
-            draw_ui_text("RESEARCHED", nx + 5.0, ny + 40.0, 16.0, LIGHTGRAY);
+            draw_ui_text(
+                "RESEARCHED",
+                node.x + 5.0,
+                node.y + 40.0 * text_scale,
+                16.0 * text_scale,
+                LIGHTGRAY,
+            );
         } else {
             draw_ui_text(
-                &format!("Cost: {:.0} Mat", tech.cost),
-                nx + 5.0,
-                ny + 40.0,
-                16.0,
+                &format!("Cost: {:.0} Mat", cost),
+                node.x + 5.0,
+                node.y + 40.0 * text_scale,
+                16.0 * text_scale,
                 YELLOW,
             );
 
             // Tooltip/Description on hover?
             // For now just draw desc
-            if can_unlock {
-                // Make it a button
-                let mouse_pos = mouse_position();
-                if mouse_pos.0 >= nx
-                    && mouse_pos.0 <= nx + node_w
-                    && mouse_pos.1 >= ny
-                    && mouse_pos.1 <= ny + node_h
-                {
-                    // Show tooltip
-                    let _tooltip = format!("{}\nEffect: {:?}", tech.description, tech.effect);
-                    // Simplified tooltip for now
-                    draw_ui_text(&tech.description, x + 20.0, y + h - 30.0, 20.0, WHITE);
-
-                    if is_mouse_button_pressed(MouseButton::Left) && afford {
-                        clicked_tech = Some(tech.id.clone());
-                    }
+            if unlockable && node.contains(mouse) {
+                // Show description and effect along the bottom of the window
+                let tooltip = format!("{} ({})", tech.description, tech.effect.summary());
+                draw_ui_text(&tooltip, x + 20.0, y + h - 30.0, 20.0, WHITE);
+
+                if is_mouse_button_pressed(MouseButton::Left) && afford {
+                    clicked_tech = Some(tech.id.clone());
                 }
             }
         }
     }
 
     if let Some(id) = clicked_tech {
-        Some(PlayerAction::Research(id))
+        return Some(PlayerAction::Research(id));
+    }
+
+    // Confirmation hint for the keyboard cursor
+    if let Some(tech) = view
+        .selected
+        .as_ref()
+        .and_then(|id| state.tech_tree.iter().find(|t| &t.id == id))
+    {
+        if can_unlock(state, tech) {
+            let hint = format!(
+                "[Enter] Research {} for {:.0} Mat",
+                tech.name,
+                state.research_cost(tech)
+            );
+            draw_ui_text(&hint, x + w - 420.0, y + 55.0, 18.0, GOLD);
+        }
+    }
+
+    if let Some(act) = keyboard_action(state) {
+        return Some(act);
+    }
+
+    // Pan (drag) and zoom (wheel) inside the tree area
+    let mut next = view.clone();
+    if is_mouse_button_pressed(MouseButton::Left) && area.contains(mouse) {
+        next.drag_from = Some((mouse, view.offset));
+    }
+    if !is_mouse_button_down(MouseButton::Left) {
+        next.drag_from = None;
+    }
+    if let Some((start_mouse, start_offset)) = next.drag_from {
+        next.offset = start_offset + (mouse - start_mouse) / view.zoom;
+    }
+    let wheel = mouse_wheel().1;
+    if wheel != 0.0 && area.contains(mouse) {
+        next.zoom *= if wheel > 0.0 { 1.1 } else { 1.0 / 1.1 };
+    }
+
+    let next = next.clamped(&state.tech_tree);
+    if &next != view {
+        Some(PlayerAction::SetTechView(next))
     } else {
         None
     }
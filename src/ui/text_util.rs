@@ -2,3 +2,19 @@
 pub fn wrap_text(text: &str, font_size: f32, max_width: f32) -> Vec<String> {
     macroquad_toolkit::ui::wrap_text(text, max_width, font_size)
 }
+
+/// Whole number with thousands separators, e.g. 1,240
+pub fn format_thousands(value: f32) -> String {
+    let digits = (value.round().abs() as u64).to_string();
+    let mut grouped = String::new();
+    for (i, c) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i).is_multiple_of(3) {
+            grouped.push(',');
+        }
+        grouped.push(c);
+    }
+    if value.round() < 0.0 {
+        grouped.insert(0, '-');
+    }
+    grouped
+}
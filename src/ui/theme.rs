@@ -1,30 +1,138 @@
+use crate::data::ColorScheme;
 use macroquad::prelude::*;
 use macroquad_toolkit::ui::draw_ui_text;
+use std::cell::Cell;
 
-// Import toolkit utilities
-pub use macroquad_toolkit::ui::panel_with_shadow as draw_panel;
+/// The interface colours of one colour scheme
+#[derive(Debug, Clone, Copy)]
+pub struct Theme {
+    pub panel_bg: Color,
+    pub panel_border: Color,
+    pub text: Color,
+    pub accent: Color,
+    pub secondary: Color, // Ancestors
+    pub warn: Color,
+    pub button_bg: Color,
+    pub button_hover: Color,
+}
+
+impl Theme {
+    pub const DEFAULT: Theme = Theme {
+        panel_bg: Color::new(0.15, 0.15, 0.18, 0.95),
+        panel_border: Color::new(0.3, 0.3, 0.35, 1.0),
+        text: Color::new(0.9, 0.9, 0.9, 1.0),
+        accent: Color::new(0.4, 0.8, 0.4, 1.0),
+        secondary: Color::new(0.6, 0.5, 0.8, 1.0), // Purple for ancestors
+        warn: Color::new(0.9, 0.6, 0.2, 1.0),
+        button_bg: Color::new(0.25, 0.25, 0.3, 1.0),
+        button_hover: Color::new(0.35, 0.35, 0.4, 1.0),
+    };
+
+    pub const HIGH_CONTRAST: Theme = Theme {
+        panel_bg: Color::new(0.0, 0.0, 0.0, 1.0),
+        panel_border: Color::new(1.0, 1.0, 1.0, 1.0),
+        text: Color::new(1.0, 1.0, 1.0, 1.0),
+        accent: Color::new(1.0, 0.9, 0.0, 1.0),
+        secondary: Color::new(0.4, 0.9, 1.0, 1.0),
+        warn: Color::new(1.0, 0.4, 0.3, 1.0),
+        button_bg: Color::new(0.1, 0.1, 0.1, 1.0),
+        button_hover: Color::new(0.3, 0.3, 0.3, 1.0),
+    };
+
+    pub const NIGHT: Theme = Theme {
+        panel_bg: Color::new(0.05, 0.07, 0.14, 0.95),
+        panel_border: Color::new(0.2, 0.25, 0.4, 1.0),
+        text: Color::new(0.7, 0.75, 0.85, 1.0),
+        accent: Color::new(0.45, 0.6, 0.95, 1.0),
+        secondary: Color::new(0.65, 0.5, 0.9, 1.0),
+        warn: Color::new(0.85, 0.55, 0.35, 1.0),
+        button_bg: Color::new(0.1, 0.13, 0.24, 1.0),
+        button_hover: Color::new(0.17, 0.21, 0.35, 1.0),
+    };
+
+    pub const SEPIA: Theme = Theme {
+        panel_bg: Color::new(0.27, 0.2, 0.13, 0.95),
+        panel_border: Color::new(0.55, 0.42, 0.28, 1.0),
+        text: Color::new(0.96, 0.9, 0.78, 1.0),
+        accent: Color::new(0.85, 0.7, 0.4, 1.0),
+        secondary: Color::new(0.75, 0.55, 0.5, 1.0),
+        warn: Color::new(0.9, 0.5, 0.25, 1.0),
+        button_bg: Color::new(0.38, 0.29, 0.19, 1.0),
+        button_hover: Color::new(0.48, 0.37, 0.25, 1.0),
+    };
+
+    pub fn of(scheme: ColorScheme) -> Theme {
+        match scheme {
+            ColorScheme::Default => Theme::DEFAULT,
+            ColorScheme::HighContrast => Theme::HIGH_CONTRAST,
+            ColorScheme::Night => Theme::NIGHT,
+            ColorScheme::Sepia => Theme::SEPIA,
+        }
+    }
+}
+
+thread_local! {
+    /// Theme the UI is currently drawn with (set once a frame from the settings)
+    static CURRENT: Cell<Theme> = const { Cell::new(Theme::DEFAULT) };
+}
+
+/// Draw the interface in this colour scheme from now on
+pub fn apply(scheme: ColorScheme) {
+    CURRENT.with(|theme| theme.set(Theme::of(scheme)));
+}
 
+/// The theme the interface is currently drawn with
+pub fn current() -> Theme {
+    CURRENT.with(|theme| theme.get())
+}
+
+/// Colours of the current theme
 pub mod colors {
-    use macroquad::prelude::*;
-    pub const PANEL_BG: Color = Color::new(0.15, 0.15, 0.18, 0.95);
-    pub const PANEL_BORDER: Color = Color::new(0.3, 0.3, 0.35, 1.0);
-    pub const TEXT: Color = Color::new(0.9, 0.9, 0.9, 1.0);
-    pub const ACCENT: Color = Color::new(0.4, 0.8, 0.4, 1.0);
-    pub const SECONDARY: Color = Color::new(0.6, 0.5, 0.8, 1.0); // Purple for ancestors
-    pub const WARN: Color = Color::new(0.9, 0.6, 0.2, 1.0);
-    pub const BUTTON_BG: Color = Color::new(0.25, 0.25, 0.3, 1.0);
-    pub const BUTTON_HOVER: Color = Color::new(0.35, 0.35, 0.4, 1.0);
+    use super::current;
+    use macroquad::prelude::Color;
+
+    pub fn panel_bg() -> Color {
+        current().panel_bg
+    }
+    pub fn panel_border() -> Color {
+        current().panel_border
+    }
+    pub fn text() -> Color {
+        current().text
+    }
+    pub fn accent() -> Color {
+        current().accent
+    }
+    pub fn secondary() -> Color {
+        current().secondary
+    }
+    pub fn warn() -> Color {
+        current().warn
+    }
+    pub fn button_bg() -> Color {
+        current().button_bg
+    }
+    pub fn button_hover() -> Color {
+        current().button_hover
+    }
+}
+
+/// Helper to draw a standard panel with a drop shadow
+pub fn draw_panel(x: f32, y: f32, w: f32, h: f32) {
+    draw_rectangle(x + 4.0, y + 4.0, w, h, Color::new(0.0, 0.0, 0.0, 0.35));
+    draw_rectangle(x, y, w, h, colors::panel_bg());
+    draw_rectangle_lines(x, y, w, h, 2.0, colors::panel_border());
 }
 
 /// Helper to draw a standard button
 /// Returns true if clicked
 pub fn draw_button(x: f32, y: f32, w: f32, h: f32, text: &str) -> bool {
     let style = macroquad_toolkit::ui::ButtonStyle {
-        normal: colors::BUTTON_BG,
-        hovered: colors::BUTTON_HOVER,
-        pressed: colors::BUTTON_BG,
-        border: colors::PANEL_BORDER,
-        text_color: colors::TEXT,
+        normal: colors::button_bg(),
+        hovered: colors::button_hover(),
+        pressed: colors::button_bg(),
+        border: colors::panel_border(),
+        text_color: colors::text(),
         disabled: Color::new(0.1, 0.1, 0.1, 1.0),
     };
 
@@ -35,5 +143,5 @@ pub fn draw_button(x: f32, y: f32, w: f32, h: f32, text: &str) -> bool {
 /// Helper to draw a header text
 pub fn draw_header(text: &str, x: f32, y: f32) {
     draw_ui_text(text, x + 2.0, y + 2.0, 30.0, Color::new(0.0, 0.0, 0.0, 0.5)); // Shadow
-    draw_ui_text(text, x, y, 30.0, colors::ACCENT);
+    draw_ui_text(text, x, y, 30.0, colors::accent());
 }
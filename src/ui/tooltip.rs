@@ -2,7 +2,7 @@
 
 use crate::data::{GameState, ZoneTemplate};
 use crate::simulation::agents::Agent;
-use crate::zones::Zone;
+use crate::zones::{Zone, ZoneId};
 use macroquad::prelude::*;
 
 const TOOLTIP_BG: Color = Color::new(0.1, 0.1, 0.1, 0.9);
@@ -26,7 +26,18 @@ pub fn draw_tooltip(text: &str, pos: Vec2) {
 }
 
 /// Draw a tooltip for a zone
-pub fn draw_zone_tooltip(zone: &Zone, template: &ZoneTemplate, mouse_pos: Vec2) {
+pub fn draw_zone_tooltip(state: &GameState, zone: &Zone, template: &ZoneTemplate, mouse_pos: Vec2) {
+    if !zone.surveyed {
+        let text = format!(
+            "{}\n{}\nSurvey: {:.0} materials",
+            crate::ui::zones::UNSURVEYED_NAME,
+            crate::ui::zones::UNSURVEYED_HINT,
+            crate::zones::survey::SURVEY_COST
+        );
+        draw_tooltip(&text, mouse_pos + vec2(15.0, 15.0));
+        return;
+    }
+
     let mut text = format!("{}\n", template.name);
     text.push_str(&format!("Category: {:?}\n", template.category));
     text.push_str(&format!("Condition: {:.0}%\n", zone.condition * 100.0));
@@ -37,13 +48,65 @@ pub fn draw_zone_tooltip(zone: &Zone, template: &ZoneTemplate, mouse_pos: Vec2)
         text.push_str(&format!("⚒ Building: {:.0}%", progress * 100.0));
     } else if zone.dormant {
         text.push_str("Status: Dormant");
+    } else if zone.disabled {
+        text.push_str("Status: Disabled (no output or upkeep)");
+    } else if crate::simulation::stockpiles::is_storage_full(state, template) {
+        text.push_str("Status: Idle (storage full)");
     } else {
         text.push_str("Status: Active");
     }
 
+    // Markets and parks with a crowd around them do their visitors less good
+    let amenity = matches!(
+        template.category,
+        crate::data::ZoneCategory::Market | crate::data::ZoneCategory::Cultural
+    );
+    if let Some(rect) = zone
+        .footprint(template)
+        .filter(|_| amenity && !zone.dormant)
+    {
+        let center = rect.world_center();
+        if crate::simulation::crowding::is_crowded(&state.agents, center) {
+            let crowd = crate::simulation::crowding::crowd_at(&state.agents, center);
+            text.push_str(&format!("\nCrowded ({} villagers)", crowd));
+        }
+    }
+
+    if let Some(net) = crate::simulation::production::zone_net_per_hour(state, zone) {
+        text.push_str(&format!(
+            "\nNet: {:+.2} mat/h, {:+.2} maint/h",
+            net.materials, net.maintenance
+        ));
+    }
+    if zone.output_history.len() > 1 {
+        text.push_str(&format!(
+            "\nLast {} days: {}",
+            zone.output_history.len(),
+            sparkline(zone.output_history.iter().copied())
+        ));
+    }
+
     draw_tooltip(&text, mouse_pos + vec2(15.0, 15.0));
 }
 
+/// Tiny bar chart of a series in block characters, scaled between its lowest and highest value
+fn sparkline(values: impl Iterator<Item = f32> + Clone) -> String {
+    const BARS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+    let min = values.clone().fold(f32::INFINITY, f32::min);
+    let max = values.clone().fold(f32::NEG_INFINITY, f32::max);
+    let range = max - min;
+    values
+        .map(|v| {
+            let level = if range > f32::EPSILON {
+                ((v - min) / range * (BARS.len() - 1) as f32).round() as usize
+            } else {
+                BARS.len() / 2
+            };
+            BARS[level]
+        })
+        .collect()
+}
+
 /// Draw a tooltip for an agent
 pub fn draw_agent_tooltip(agent: &Agent, mouse_pos: Vec2) {
     let state_name = match agent.state {
@@ -55,13 +118,14 @@ pub fn draw_agent_tooltip(agent: &Agent, mouse_pos: Vec2) {
         crate::simulation::agents::AgentState::GoingHome => "Going Home",
         crate::simulation::agents::AgentState::Sleeping => "Sleeping",
         crate::simulation::agents::AgentState::Building { .. } => "Building",
+        crate::simulation::agents::AgentState::Leaving { .. } => "Leaving town",
     };
 
-    let mut trait_summary = String::new();
+    let mut details = String::new();
     if !agent.traits.is_empty() {
-        trait_summary.push_str("\n\nTraits:");
+        details.push_str("\n\nTraits:");
         for tr in &agent.traits {
-            trait_summary.push_str(&format!(
+            details.push_str(&format!(
                 "\n• {} ({:+}% work)",
                 tr.name(),
                 ((tr.work_speed_modifier() - 1.0) * 100.0) as i32
@@ -71,6 +135,21 @@ pub fn draw_agent_tooltip(agent: &Agent, mouse_pos: Vec2) {
         }
     }
 
+    let bests = agent.feats.personal_bests();
+    if !bests.is_empty() {
+        details.push_str("\n\nPersonal bests:");
+        for best in &bests {
+            details.push_str(&format!("\n• {}", best));
+        }
+    }
+
+    if !agent.rumors.is_empty() {
+        details.push_str("\n\nHeard:");
+        for rumor in agent.rumors.iter().rev() {
+            details.push_str(&format!("\n• {}", rumor.text));
+        }
+    }
+
     let text = format!(
         "Villager #{}\nJob: {}\n{}\n\nEnergy: {:.0}%\nHunger: {:.0}%\nSocial: {:.0}%\nSpirit: {:.0}%{}",
         agent.id % 1000,
@@ -80,7 +159,7 @@ pub fn draw_agent_tooltip(agent: &Agent, mouse_pos: Vec2) {
         (1.0 - agent.hunger) * 100.0, // Invert: low hunger = fed
         agent.social * 100.0,
         agent.spirit * 100.0,
-        trait_summary
+        details
     );
 
     draw_tooltip(&text, mouse_pos + vec2(15.0, 15.0));
@@ -90,14 +169,14 @@ pub fn draw_agent_tooltip(agent: &Agent, mouse_pos: Vec2) {
 pub fn get_hovered_zone(
     state: &GameState,
     mouse_world_pos: Vec2,
-) -> Option<(usize, &Zone, &ZoneTemplate)> {
-    for (idx, zone) in state.zones.iter().enumerate() {
+) -> Option<(ZoneId, &Zone, &ZoneTemplate)> {
+    for zone in &state.zones {
         if let Some(template) = state
             .zone_templates
             .iter()
             .find(|t| t.id == zone.template_id)
         {
-            if let Some(rect) = template.map_rect {
+            if let Some(rect) = zone.footprint(template) {
                 let zone_x = rect.x as f32 * crate::ui::map_renderer::TILE_SIZE;
                 let zone_y = rect.y as f32 * crate::ui::map_renderer::TILE_SIZE;
                 let zone_w = rect.w as f32 * crate::ui::map_renderer::TILE_SIZE;
@@ -108,7 +187,7 @@ pub fn get_hovered_zone(
                     && mouse_world_pos.y >= zone_y
                     && mouse_world_pos.y <= zone_y + zone_h
                 {
-                    return Some((idx, zone, template));
+                    return Some((zone.id, zone, template));
                 }
             }
         }
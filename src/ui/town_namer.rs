@@ -0,0 +1,89 @@
+//! Rename dialog - a new name for a settled town, offered again after founding one
+
+use crate::data::GameState;
+use crate::region::MAX_TOWN_NAME_CHARS;
+use crate::ui::theme;
+use crate::ui::theme::colors;
+use crate::PlayerAction;
+use macroquad::prelude::*;
+use macroquad_toolkit::ui::draw_ui_text;
+
+const FIELD_H: f32 = 36.0;
+
+/// Name being typed for a town before it is renamed (UI only, not saved)
+#[derive(Debug, Clone, PartialEq)]
+pub struct TownNameDraft {
+    pub town_id: u32,
+    pub name: String,
+}
+
+impl TownNameDraft {
+    pub fn new(town_id: u32, name: &str) -> Self {
+        Self {
+            town_id,
+            name: name.to_string(),
+        }
+    }
+}
+
+/// Draw the rename dialog if a town is being renamed
+pub fn draw_town_namer(state: &GameState) -> Option<PlayerAction> {
+    let draft = state.town_name_draft.as_ref()?;
+    let screen_w = screen_width();
+    let screen_h = screen_height();
+
+    let w = 420.0;
+    let h = 190.0;
+    let x = (screen_w - w) / 2.0;
+    let y = (screen_h - h) / 2.0;
+
+    draw_rectangle(0.0, 0.0, screen_w, screen_h, Color::new(0.0, 0.0, 0.0, 0.5));
+    theme::draw_panel(x, y, w, h);
+    theme::draw_header("Name Your Town", x + 20.0, y + 40.0);
+
+    let mut action = None;
+    let mut next = draft.clone();
+    while let Some(c) = get_char_pressed() {
+        if !c.is_control() && next.name.chars().count() < MAX_TOWN_NAME_CHARS {
+            next.name.push(c);
+        }
+    }
+    if is_key_pressed(KeyCode::Backspace) {
+        next.name.pop();
+    }
+    if &next != draft {
+        action = Some(PlayerAction::EditTownName(Some(next.clone())));
+    }
+
+    let field_y = y + 80.0;
+    draw_ui_text("Name", x + 20.0, field_y - 6.0, 16.0, LIGHTGRAY);
+    draw_rectangle(x + 20.0, field_y, w - 40.0, FIELD_H, colors::panel_bg());
+    draw_rectangle_lines(x + 20.0, field_y, w - 40.0, FIELD_H, 2.0, colors::accent());
+    let caret = if (get_time() * 2.0) as i64 % 2 == 0 {
+        "_"
+    } else {
+        ""
+    };
+    draw_ui_text(
+        &format!("{}{}", next.name, caret),
+        x + 28.0,
+        field_y + 24.0,
+        18.0,
+        colors::text(),
+    );
+
+    let btn_w = 120.0;
+    let btn_h = 36.0;
+    let btn_y = y + h - btn_h - 16.0;
+    if theme::draw_button(x + w - btn_w * 2.0 - 30.0, btn_y, btn_w, btn_h, "Cancel")
+        || is_key_pressed(KeyCode::Escape)
+    {
+        action = Some(PlayerAction::EditTownName(None));
+    }
+    if theme::draw_button(x + w - btn_w - 20.0, btn_y, btn_w, btn_h, "Rename")
+        || is_key_pressed(KeyCode::Enter)
+    {
+        action = Some(PlayerAction::RenameTown(draft.town_id, next.name));
+    }
+    action
+}
@@ -0,0 +1,96 @@
+//! Wonder celebration - a banner in every view and fireworks over the wonder's node on the region map
+
+use crate::narrative::Wonder;
+use crate::ui::theme::colors;
+use macroquad::prelude::*;
+use macroquad_toolkit::ui::{draw_ui_text, measure_ui_text};
+
+/// Real seconds the banner and fireworks last
+const DURATION: f32 = 8.0;
+
+/// Seconds between bursts over the node
+const BURST_INTERVAL: f32 = 1.2;
+
+/// Sparks in each burst and how fast they fly outward (pixels per second)
+const SPARKS: usize = 16;
+const SPARK_SPEED: f32 = 45.0;
+
+/// The wonder being celebrated and real seconds since it was finished
+#[derive(Debug, Clone, Default)]
+pub struct WonderCelebration {
+    playing: Option<(u32, Wonder, f32)>,
+}
+
+impl WonderCelebration {
+    /// Begin celebrating the wonder finished at `node_id`
+    pub fn start(&mut self, node_id: u32, wonder: Wonder) {
+        self.playing = Some((node_id, wonder, 0.0));
+    }
+
+    /// Call every frame with the real frame time
+    pub fn update(&mut self, delta: f32) {
+        if let Some((_, _, elapsed)) = &mut self.playing {
+            *elapsed += delta;
+            if *elapsed >= DURATION {
+                self.playing = None;
+            }
+        }
+    }
+
+    /// Announcement across the top of the screen (nothing when idle)
+    pub fn draw_banner(&self, y: f32) {
+        let (wonder, elapsed) = match self.playing {
+            Some((_, wonder, elapsed)) => (wonder, elapsed),
+            None => return,
+        };
+        // Fades out over the last second
+        let alpha = (DURATION - elapsed).min(1.0);
+        let message = format!(
+            "{} stands complete! The whole region celebrates.",
+            wonder.name()
+        );
+        let w = measure_ui_text(&message, None, 20, 1.0).width + 40.0;
+        let x = (screen_width() - w) / 2.0;
+
+        draw_rectangle(x, y, w, 34.0, Color::new(0.3, 0.22, 0.05, 0.9 * alpha));
+        draw_rectangle_lines(x, y, w, 34.0, 2.0, Color { a: alpha, ..GOLD });
+        draw_ui_text(
+            &message,
+            x + 20.0,
+            y + 23.0,
+            20.0,
+            Color {
+                a: alpha,
+                ..colors::text()
+            },
+        );
+    }
+
+    /// Bursts of sparks over the node if it's the one being celebrated (region view)
+    pub fn draw_fireworks(&self, node_id: u32, center: Vec2) {
+        let elapsed = match self.playing {
+            Some((id, _, elapsed)) if id == node_id => elapsed,
+            _ => return,
+        };
+        let bursts = (elapsed / BURST_INTERVAL).floor() as usize + 1;
+        for burst in 0..bursts {
+            let age = elapsed - burst as f32 * BURST_INTERVAL;
+            let life = BURST_INTERVAL * 1.5;
+            if age > life {
+                continue;
+            }
+            let fade = 1.0 - age / life;
+            // Each burst goes off a little to one side of the last, in its own colour
+            let offset = vec2(
+                ((burst * 37) % 60) as f32 - 30.0,
+                -20.0 - ((burst * 23) % 30) as f32,
+            );
+            let color = [GOLD, ORANGE, PINK, SKYBLUE][burst % 4];
+            for spark in 0..SPARKS {
+                let angle = spark as f32 / SPARKS as f32 * std::f32::consts::TAU;
+                let pos = center + offset + Vec2::from_angle(angle) * SPARK_SPEED * age;
+                draw_circle(pos.x, pos.y, 2.5, Color { a: fade, ..color });
+            }
+        }
+    }
+}
@@ -0,0 +1,95 @@
+use crate::data::GameState;
+use crate::ui::theme;
+use crate::ui::theme::colors;
+use crate::PlayerAction;
+use macroquad::prelude::*;
+use macroquad_toolkit::ui::draw_ui_text;
+
+/// Draw the end-of-year recap modal if one is waiting
+pub fn draw_year_summary(state: &GameState) -> Option<PlayerAction> {
+    let summary = state.year_summary.as_ref()?;
+    let screen_w = screen_width();
+    let screen_h = screen_height();
+
+    let w = 460.0;
+    let h = 324.0 + summary.highlights.len() as f32 * 20.0;
+    let x = (screen_w - w) / 2.0;
+    let y = (screen_h - h) / 2.0;
+
+    draw_rectangle(0.0, 0.0, screen_w, screen_h, Color::new(0.0, 0.0, 0.0, 0.5));
+    theme::draw_panel(x, y, w, h);
+    theme::draw_header(
+        &format!("Year {} in Review", summary.year),
+        x + 20.0,
+        y + 40.0,
+    );
+
+    let change_color = |value: f32| {
+        if value < 0.0 {
+            colors::warn()
+        } else {
+            colors::text()
+        }
+    };
+    let rows = [
+        (
+            format!("Population: {:+.1}", summary.population_change),
+            change_color(summary.population_change),
+        ),
+        (
+            format!("Materials gathered: {:.0}", summary.materials_gained),
+            colors::text(),
+        ),
+        (
+            format!(
+                "Arrivals: {}   Deaths: {}   Departures: {}",
+                summary.births, summary.deaths, summary.departures
+            ),
+            colors::text(),
+        ),
+        (
+            format!("Zones built: {}", summary.zones_built),
+            colors::text(),
+        ),
+        (
+            format!("Winters survived: {}", state.stats.winters_survived),
+            colors::secondary(),
+        ),
+        (
+            format!("Difficulty: {}", state.difficulty.name()),
+            LIGHTGRAY,
+        ),
+    ];
+
+    let mut row_y = y + 80.0;
+    for (text, color) in rows.iter() {
+        draw_ui_text(text, x + 20.0, row_y, 18.0, *color);
+        row_y += 24.0;
+    }
+
+    // Chronicle highlights
+    row_y += 10.0;
+    if summary.highlights.is_empty() {
+        draw_ui_text("A quiet year.", x + 20.0, row_y, 16.0, GRAY);
+    } else {
+        draw_ui_text("Notable events:", x + 20.0, row_y, 16.0, colors::accent());
+        for line in &summary.highlights {
+            row_y += 20.0;
+            draw_ui_text(&format!("• {}", line), x + 30.0, row_y, 14.0, LIGHTGRAY);
+        }
+    }
+
+    let btn_w = 120.0;
+    let btn_h = 36.0;
+    if theme::draw_button(
+        x + w - btn_w - 20.0,
+        y + h - btn_h - 20.0,
+        btn_w,
+        btn_h,
+        "Onwards",
+    ) {
+        return Some(PlayerAction::DismissYearSummary);
+    }
+
+    None
+}
@@ -1,8 +1,21 @@
-use crate::data::GameState;
+use crate::data::{cost_label, ConstructionCost, GameState, ZoneCategory};
+use crate::ui::theme;
 use crate::ui::theme::colors;
+use crate::zones::survey;
 use crate::PlayerAction;
 use macroquad::prelude::*;
-use macroquad_toolkit::ui::draw_ui_text;
+use macroquad_toolkit::ui::{draw_ui_text, measure_ui_text};
+
+/// What a ruin is called, and the note under it, until it has been surveyed
+pub const UNSURVEYED_NAME: &str = "Unidentified Rubble";
+pub const UNSURVEYED_HINT: &str = "Nobody remembers what stood here.";
+
+/// Laborers taken on per click of the hire button
+const HIRE_BATCH: usize = 2;
+
+/// Filter tabs per row above the zone list
+const FILTER_COLUMNS: usize = 4;
+const FILTER_ROW_H: f32 = 28.0;
 
 /// Draw the list of zones with interactive buttons
 pub fn draw_zone_list(state: &GameState, x: f32, y: f32, w: f32, h: f32) -> Option<PlayerAction> {
@@ -11,15 +24,35 @@ pub fn draw_zone_list(state: &GameState, x: f32, y: f32, w: f32, h: f32) -> Opti
     draw_rectangle_lines(x, y, w, h, 1.0, GRAY);
 
     // Header
-    draw_ui_text("Zones", x + 10.0, y + 25.0, 24.0, colors::TEXT);
+    draw_ui_text("Zones", x + 10.0, y + 25.0, 24.0, colors::text());
+    let hire_action = draw_builder_crew(state, x, y, w);
+    let filter_action = draw_filter_tabs(state, x, y + 40.0, w);
 
-    let list_y = y + 40.0;
-    let list_h = h - 50.0; // Margin at bottom
+    let filter_rows = (ZoneCategory::all().len() + 1).div_ceil(FILTER_COLUMNS);
+    let list_y = y + 44.0 + filter_rows as f32 * FILTER_ROW_H;
+    let list_h = h - (list_y - y) - 10.0; // Margin at bottom
+
+    // Zones in the chosen category, dormant ones first so restoration targets stand out
+    let mut shown: Vec<(usize, &crate::zones::Zone)> = state
+        .zones
+        .iter()
+        .enumerate()
+        .filter(|(_, zone)| {
+            // Unidentified rubble has no known category yet
+            state.zone_filter.is_none_or(|category| {
+                zone.surveyed
+                    && state
+                        .get_template(&zone.template_id)
+                        .is_some_and(|t| t.category == category)
+            })
+        })
+        .collect();
+    shown.sort_by_key(|(_, zone)| !zone.dormant);
 
     // Content metrics
     let card_height = 100.0;
     let margin = 10.0;
-    let total_content_h = (state.zones.len() as f32) * (card_height + margin);
+    let total_content_h = (shown.len() as f32) * (card_height + margin);
 
     // Mouse Interaction for Scroll
     let mouse_pos = mouse_position();
@@ -63,10 +96,10 @@ pub fn draw_zone_list(state: &GameState, x: f32, y: f32, w: f32, h: f32) -> Opti
         gl.quad_gl.scissor(Some((sx, sy, sw, sh)));
     }
 
-    let mut action_to_emit = scroll_action;
+    let mut action_to_emit = scroll_action.or(hire_action).or(filter_action);
 
-    for (i, zone) in state.zones.iter().enumerate() {
-        let card_realtive_y = i as f32 * (card_height + margin) - state.zones_scroll_offset;
+    for (row, &(i, zone)) in shown.iter().enumerate() {
+        let card_realtive_y = row as f32 * (card_height + margin) - state.zones_scroll_offset;
         let card_y = list_y + card_realtive_y;
 
         // Visibility Culling
@@ -107,6 +140,54 @@ pub fn draw_zone_list(state: &GameState, x: f32, y: f32, w: f32, h: f32) -> Opti
     action_to_emit
 }
 
+/// "All" plus one tab per zone category, in rows under the list header
+fn draw_filter_tabs(state: &GameState, x: f32, y: f32, w: f32) -> Option<PlayerAction> {
+    let tab_w = (w - 10.0) / FILTER_COLUMNS as f32;
+    let filters = std::iter::once(None).chain(ZoneCategory::all().into_iter().map(Some));
+
+    let mut action = None;
+    for (i, filter) in filters.enumerate() {
+        let tab_x = x + 5.0 + (i % FILTER_COLUMNS) as f32 * tab_w;
+        let tab_y = y + (i / FILTER_COLUMNS) as f32 * FILTER_ROW_H;
+        let name = filter.map_or("All", |category| category.label());
+        let label = if state.zone_filter == filter {
+            format!("> {}", name)
+        } else {
+            name.to_string()
+        };
+        if theme::draw_button(tab_x, tab_y, tab_w - 4.0, FILTER_ROW_H - 4.0, &label) {
+            action = Some(PlayerAction::SetZoneFilter(filter));
+        }
+    }
+    action
+}
+
+/// Builder crew summary in the list header, with a hire button while anything is being built
+fn draw_builder_crew(state: &GameState, x: f32, y: f32, w: f32) -> Option<PlayerAction> {
+    use crate::simulation::construction;
+
+    if !state.zones.iter().any(|z| z.is_under_construction()) {
+        return None;
+    }
+
+    let (builders, hired) = construction::builder_counts(state);
+    let crew = format!(
+        "Builders: {} (+{}) | {:.1}/h",
+        builders,
+        hired,
+        construction::total_work_per_hour(state)
+    );
+    draw_ui_text(&crew, x + 80.0, y + 24.0, 14.0, LIGHTGRAY);
+
+    let count = HIRE_BATCH;
+    let cost = count as f32 * construction::HIRE_COST_PER_BUILDER;
+    let label = format!("Hire {} ({:.0})", count, cost);
+    if theme::draw_button(x + w - 105.0, y + 8.0, 95.0, 24.0, &label) {
+        return Some(PlayerAction::HireBuilders(count));
+    }
+    None
+}
+
 fn draw_zone_card(
     state: &GameState,
     zone: &crate::zones::Zone,
@@ -122,17 +203,21 @@ fn draw_zone_card(
         .iter()
         .find(|t| t.id == zone.template_id)?;
 
+    if !zone.surveyed {
+        return draw_unsurveyed_card(state, index, x, y, w, h);
+    }
+
     // Background
     let bg_color = if zone.dormant {
         Color::new(0.2, 0.2, 0.2, 1.0)
     } else {
-        colors::PANEL_BG
+        colors::panel_bg()
     };
     draw_rectangle(x, y, w, h, bg_color);
     draw_rectangle_lines(x, y, w, h, 1.0, GRAY);
 
     // Name & Status
-    let name_color = if zone.dormant { GRAY } else { colors::ACCENT };
+    let name_color = if zone.dormant { GRAY } else { colors::accent() };
     draw_ui_text(&template.name, x + 10.0, y + 25.0, 24.0, name_color);
 
     // Stats (Condition, etc.)
@@ -144,7 +229,7 @@ fn draw_zone_card(
         x + 10.0,
         y + y_offset,
         18.0,
-        colors::TEXT,
+        colors::text(),
     );
     y_offset += 20.0;
 
@@ -172,9 +257,36 @@ fn draw_zone_card(
     let effects_str = effects.join(", ");
     draw_ui_text(&effects_str, x + 10.0, y + y_offset, 16.0, LIGHTGRAY);
 
+    // Construction progress replaces the button while builders are at work
+    if zone.is_under_construction() {
+        let progress = zone.construction_progress(template.construction_work);
+        draw_ui_text(
+            &format!("Building {:.0}%", progress * 100.0),
+            x + w - 120.0,
+            y + 30.0,
+            16.0,
+            YELLOW,
+        );
+        return None;
+    }
+
     // Interactive Button (Restore)
     // Only if damaged (< 100%) or dormant
     if zone.condition < 1.0 || zone.dormant {
+        // Locked until its tech is researched
+        if let Some(tech) = state.missing_tech(template) {
+            let label = format!("Requires: {}", tech);
+            let label_w = measure_ui_text(&label, None, 16, 1.0).width;
+            draw_ui_text(
+                &label,
+                x + w - label_w - 10.0,
+                y + 30.0,
+                16.0,
+                colors::warn(),
+            );
+            return None;
+        }
+
         let btn_w = 120.0;
         let btn_h = 30.0;
         let btn_x = x + w - btn_w - 10.0;
@@ -186,33 +298,86 @@ fn draw_zone_card(
 
         let is_hover = mx >= btn_x && mx <= btn_x + btn_w && my >= btn_y && my <= btn_y + btn_h;
 
-        // Cost check
-        let cost = template.construction_cost;
-        let can_afford = state.resources.materials >= cost;
+        // Cost check - every component must be in stock
+        let cost = &template.construction_cost;
+        let can_afford = state.resources.has(cost);
 
-        let btn_color = if can_afford {
-            if is_hover {
-                GREEN
-            } else {
-                DARKGREEN
-            }
+        let btn_color = if !can_afford {
+            DARKGRAY
+        } else if is_hover {
+            GREEN
         } else {
-            RED
+            DARKGREEN
         };
 
         draw_rectangle(btn_x, btn_y, btn_w, btn_h, btn_color);
-        draw_ui_text(
-            &format!("Restore ({:.1})", cost),
-            btn_x + 5.0,
-            btn_y + 20.0,
-            16.0,
-            WHITE,
-        );
+        let text_color = if can_afford { WHITE } else { GRAY };
+        draw_ui_text("Restore", btn_x + 5.0, btn_y + 20.0, 16.0, text_color);
+        draw_cost_breakdown(state, cost, x + 10.0, y + 90.0);
 
-        if is_hover && is_mouse_button_pressed(MouseButton::Left) {
+        if can_afford && is_hover && is_mouse_button_pressed(MouseButton::Left) {
             return Some(PlayerAction::RestoreZone(index));
         }
     }
 
     None
 }
+
+/// Card for a ruin nobody has identified yet, with the button to survey it
+fn draw_unsurveyed_card(
+    state: &GameState,
+    index: usize,
+    x: f32,
+    y: f32,
+    w: f32,
+    h: f32,
+) -> Option<PlayerAction> {
+    draw_rectangle(x, y, w, h, Color::new(0.2, 0.2, 0.2, 1.0));
+    draw_rectangle_lines(x, y, w, h, 1.0, GRAY);
+    draw_ui_text(UNSURVEYED_NAME, x + 10.0, y + 25.0, 24.0, GRAY);
+    draw_ui_text(UNSURVEYED_HINT, x + 10.0, y + 50.0, 16.0, LIGHTGRAY);
+
+    let btn_w = 120.0;
+    let btn_h = 30.0;
+    let btn_x = x + w - btn_w - 10.0;
+    let btn_y = y + 10.0;
+    let can_afford = state.resources.materials >= survey::SURVEY_COST;
+    let (mx, my) = mouse_position();
+    let is_hover = mx >= btn_x && mx <= btn_x + btn_w && my >= btn_y && my <= btn_y + btn_h;
+    let btn_color = if !can_afford {
+        DARKGRAY
+    } else if is_hover {
+        GREEN
+    } else {
+        DARKGREEN
+    };
+    draw_rectangle(btn_x, btn_y, btn_w, btn_h, btn_color);
+    let text_color = if can_afford { WHITE } else { GRAY };
+    draw_ui_text("Survey", btn_x + 5.0, btn_y + 20.0, 16.0, text_color);
+    draw_cost_breakdown(
+        state,
+        &ConstructionCost::Materials(survey::SURVEY_COST),
+        x + 10.0,
+        y + 90.0,
+    );
+
+    if can_afford && is_hover && is_mouse_button_pressed(MouseButton::Left) {
+        return Some(PlayerAction::SurveyZone(index));
+    }
+    None
+}
+
+/// Cost components side by side from (x, y), with anything short in the warning colour
+pub fn draw_cost_breakdown(state: &GameState, cost: &ConstructionCost, x: f32, y: f32) {
+    let mut line_x = x;
+    for (kind, amount) in cost.components() {
+        let color = if state.resources.get(kind) >= amount {
+            LIGHTGRAY
+        } else {
+            colors::warn()
+        };
+        let text = format!("{:.1} {}", amount, cost_label(kind));
+        draw_ui_text(&text, line_x, y, 14.0, color);
+        line_x += measure_ui_text(&text, None, 14, 1.0).width + 8.0;
+    }
+}
@@ -0,0 +1,218 @@
+//! Single-level undo for the last restore or upgrade
+//!
+//! An undo is only offered until the simulation has produced anything that depends on
+//! the change: repairs and upgrades of a working zone feed the very next tick, so they
+//! can be taken back only before it runs (e.g. while paused). A construction start stays
+//! undoable until builders deposit materials or begin work.
+
+use crate::data::{ConstructionCost, GameState};
+use crate::narrative::LogCategory;
+use crate::zones::{ConstructionState, ReawakeningStage, ZoneId};
+
+/// Game hours after which any undo expires
+pub const UNDO_WINDOW_HOURS: f32 = 0.5;
+
+/// What the last reversible action changed
+#[derive(Debug, Clone)]
+pub enum Change {
+    /// A damaged zone was repaired
+    Repair {
+        zone: ZoneId,
+        previous_condition: f32,
+        cost: ConstructionCost,
+    },
+    /// Construction was ordered on a dormant ruin (nothing spent yet)
+    Construction { zone: ZoneId },
+    /// A zone was upgraded to a new template
+    Upgrade {
+        zone: ZoneId,
+        previous_template: String,
+        previous_condition: f32,
+        cost: ConstructionCost,
+    },
+}
+
+impl Change {
+    /// The zone the change was made to
+    pub fn zone(&self) -> ZoneId {
+        match self {
+            Change::Repair { zone, .. }
+            | Change::Construction { zone }
+            | Change::Upgrade { zone, .. } => *zone,
+        }
+    }
+}
+
+/// The last reversible action and when it happened
+#[derive(Debug, Clone)]
+pub struct UndoEntry {
+    pub change: Change,
+    pub recorded_at: f32,
+}
+
+/// Remember a reversible action, replacing any earlier one
+pub fn record(state: &mut GameState, change: Change) {
+    state.last_undo = Some(UndoEntry {
+        change,
+        recorded_at: state.game_time_hours,
+    });
+}
+
+/// Whether the recorded action can still be reversed
+fn still_valid(state: &GameState, entry: &UndoEntry) -> bool {
+    if state.game_time_hours - entry.recorded_at > UNDO_WINDOW_HOURS {
+        return false;
+    }
+    let zone = match state.zone(entry.change.zone()) {
+        Some(zone) => zone,
+        None => return false,
+    };
+    match &entry.change {
+        // Production has run with the new condition/template once a tick passes
+        Change::Repair { .. } | Change::Upgrade { .. } => {
+            state.game_time_hours == entry.recorded_at || !zone.is_operating()
+        }
+        Change::Construction { .. } => matches!(
+            zone.construction_state,
+            ConstructionState::UnderConstruction {
+                work_done,
+                materials_deposited: false,
+            } if work_done == 0.0
+        ),
+    }
+}
+
+/// Drop the undo once it no longer applies (called after each batch of ticks)
+pub fn expire(state: &mut GameState) {
+    let valid = state
+        .last_undo
+        .as_ref()
+        .is_none_or(|entry| still_valid(state, entry));
+    if !valid {
+        state.last_undo = None;
+    }
+}
+
+/// Reverse the last restore/upgrade if it is still allowed
+pub fn undo(state: &mut GameState) {
+    let entry = match state.last_undo.take() {
+        Some(entry) if still_valid(state, &entry) => entry,
+        _ => {
+            state.log.add(
+                state.game_time_hours,
+                "Nothing to undo.".to_string(),
+                LogCategory::System,
+            );
+            return;
+        }
+    };
+
+    let index = match state.zone_index(entry.change.zone()) {
+        Some(index) => index,
+        None => return,
+    };
+    match entry.change {
+        Change::Repair {
+            previous_condition,
+            cost,
+            ..
+        } => {
+            state.zones[index].condition = previous_condition;
+            state.resources.refund(&cost);
+        }
+        Change::Construction { .. } => {
+            state.zones[index].construction_state = ConstructionState::None;
+            state.zones[index].reawakening_stage = ReawakeningStage::Ruin as u8;
+        }
+        Change::Upgrade {
+            previous_template,
+            previous_condition,
+            cost,
+            ..
+        } => {
+            let zone = &mut state.zones[index];
+            zone.template_id = previous_template;
+            zone.condition = previous_condition;
+            state.resources.refund(&cost);
+            state.stats.zones_restored = state.stats.zones_restored.saturating_sub(1);
+        }
+    }
+
+    let zone_name = state
+        .get_template(&state.zones[index].template_id)
+        .map(|t| t.name.clone())
+        .unwrap_or("zone".to_string());
+    state.log.add(
+        state.game_time_hours,
+        format!("Undid the last change to {}.", zone_name),
+        LogCategory::Zone,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::actions::apply_action;
+    use crate::PlayerAction;
+
+    /// A town with one shack (3 materials to rebuild) and materials to spare
+    fn town_with_shack() -> (GameState, ZoneId) {
+        let mut state = GameState::default();
+        state
+            .zone_templates
+            .extend(crate::assets::load_zones().unwrap());
+        let id = state.add_zone("shack").unwrap();
+        state.resources.materials = 50.0;
+        (state, id)
+    }
+
+    #[test]
+    fn test_undoing_a_repair_refunds_its_cost() {
+        let (mut state, id) = town_with_shack();
+        state.zones[0].restore(0.3);
+        apply_action(&mut state, PlayerAction::RestoreZone(0));
+        assert_eq!(state.resources.materials, 47.0);
+        assert!((state.zones[0].condition - 0.8).abs() < 1e-6);
+
+        apply_action(&mut state, PlayerAction::Undo);
+        assert_eq!(state.resources.materials, 50.0);
+        assert!((state.zone(id).unwrap().condition - 0.3).abs() < 1e-6);
+        assert!(state.last_undo.is_none());
+    }
+
+    #[test]
+    fn test_repair_undo_expires_once_a_tick_runs() {
+        let (mut state, _) = town_with_shack();
+        state.zones[0].restore(0.3);
+        apply_action(&mut state, PlayerAction::RestoreZone(0));
+
+        state.game_time_hours += 0.1;
+        expire(&mut state);
+        assert!(state.last_undo.is_none());
+
+        apply_action(&mut state, PlayerAction::Undo);
+        assert_eq!(state.resources.materials, 47.0);
+        assert!((state.zones[0].condition - 0.8).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_construction_start_is_undoable_until_work_begins() {
+        let (mut state, id) = town_with_shack();
+        apply_action(&mut state, PlayerAction::RestoreZone(0));
+        assert!(state.zones[0].is_under_construction());
+
+        apply_action(&mut state, PlayerAction::Undo);
+        let zone = state.zone(id).unwrap();
+        assert_eq!(zone.construction_state, ConstructionState::None);
+        assert_eq!(zone.reawakening(), ReawakeningStage::Ruin);
+        assert!(zone.dormant);
+        assert_eq!(state.resources.materials, 50.0);
+
+        // Once builders have the materials on site, the start can't be taken back
+        apply_action(&mut state, PlayerAction::RestoreZone(0));
+        crate::simulation::construction::advance_construction(&mut state, 1.0);
+        expire(&mut state);
+        assert!(state.last_undo.is_none());
+        assert!(state.zones[0].is_under_construction());
+    }
+}
@@ -0,0 +1,245 @@
+//! Blueprints - a saved cluster of buildings that can be laid out again elsewhere
+//!
+//! Blueprints are kept on the `Dynasty`, so a layout that worked in one town can be
+//! stamped into the next. Stamping moves the town's own buildings of the same kinds
+//! into place, at the usual relocation cost; it never conjures new ones.
+
+use super::relocate;
+use super::ZoneId;
+use crate::data::{GameState, MapRect};
+use serde::{Deserialize, Serialize};
+
+/// Blueprints the dynasty can keep at once
+pub const MAX_BLUEPRINTS: usize = 8;
+
+/// Fewest buildings worth saving as a layout (one is just a move)
+pub const MIN_BLUEPRINT_ZONES: usize = 2;
+
+/// One building in a blueprint, placed relative to the layout's top-left tile
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BlueprintZone {
+    pub template_id: String,
+    pub dx: usize,
+    pub dy: usize,
+}
+
+/// A named layout of buildings
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Blueprint {
+    pub name: String,
+    pub zones: Vec<BlueprintZone>,
+}
+
+impl Blueprint {
+    /// Record where the given zones stand relative to each other. Zones with no
+    /// site on the map are left out; None if fewer than `MIN_BLUEPRINT_ZONES` remain.
+    pub fn capture(state: &GameState, name: &str, ids: &[ZoneId]) -> Option<Self> {
+        let sites: Vec<(String, MapRect)> = ids
+            .iter()
+            .filter_map(|&id| {
+                let zone = state.zone(id)?;
+                Some((zone.template_id.clone(), state.zone_rect(zone)?))
+            })
+            .collect();
+        if sites.len() < MIN_BLUEPRINT_ZONES {
+            return None;
+        }
+
+        let left = sites.iter().map(|(_, rect)| rect.x).min().unwrap_or(0);
+        let top = sites.iter().map(|(_, rect)| rect.y).min().unwrap_or(0);
+        let zones = sites
+            .into_iter()
+            .map(|(template_id, rect)| BlueprintZone {
+                template_id,
+                dx: rect.x - left,
+                dy: rect.y - top,
+            })
+            .collect();
+        Some(Self {
+            name: name.to_string(),
+            zones,
+        })
+    }
+}
+
+/// First "Layout N" name the dynasty isn't using yet
+pub fn next_name(blueprints: &[Blueprint]) -> String {
+    (1..)
+        .map(|n| format!("Layout {}", n))
+        .find(|name| blueprints.iter().all(|b| &b.name != name))
+        .unwrap_or_default()
+}
+
+/// Where the town's buildings would go if the blueprint were stamped with its
+/// top-left at (x, y). Each entry takes the first surveyed zone of its kind not
+/// already claimed; entries the town has no zone for are left out.
+pub fn placements(
+    state: &GameState,
+    blueprint: &Blueprint,
+    x: usize,
+    y: usize,
+) -> Vec<(ZoneId, MapRect)> {
+    let mut claimed: Vec<(ZoneId, MapRect)> = Vec::new();
+    for entry in &blueprint.zones {
+        let found = state.zones.iter().find_map(|zone| {
+            if zone.template_id != entry.template_id
+                || !zone.surveyed
+                || claimed.iter().any(|(id, _)| *id == zone.id)
+            {
+                return None;
+            }
+            let rect = state.zone_rect(zone)?;
+            let site = MapRect {
+                x: x + entry.dx,
+                y: y + entry.dy,
+                ..rect
+            };
+            Some((zone.id, site))
+        });
+        if let Some(placement) = found {
+            claimed.push(placement);
+        }
+    }
+    claimed
+}
+
+/// Why the stamp can't go down, or None if it can. The buildings being moved
+/// don't block each other's new sites.
+pub fn problem(state: &GameState, placements: &[(ZoneId, MapRect)]) -> Option<&'static str> {
+    if placements.is_empty() {
+        return Some("the town has none of its buildings");
+    }
+    let moving: Vec<ZoneId> = placements.iter().map(|(id, _)| *id).collect();
+    placements
+        .iter()
+        .find_map(|&(_, site)| relocate::site_problem(state, site, &moving))
+}
+
+/// Materials it costs to stamp these placements
+pub fn cost(placements: &[(ZoneId, MapRect)]) -> f32 {
+    placements.len() as f32 * relocate::RELOCATE_COST
+}
+
+/// Move every building to its place in the stamp (check `problem` first).
+/// All of them are lifted before any is set down, so one can take over
+/// ground another is leaving.
+pub fn stamp(state: &mut GameState, placements: &[(ZoneId, MapRect)]) {
+    let lifted: Vec<_> = placements
+        .iter()
+        .map(|&(id, site)| (id, site, relocate::lift(state, id)))
+        .collect();
+    for (id, site, kind) in lifted {
+        relocate::set_down(state, id, site, kind);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::actions::apply_action;
+    use crate::PlayerAction;
+
+    fn starting_town() -> GameState {
+        let mut state = GameState::default();
+        state
+            .zone_templates
+            .extend(crate::assets::load_zones().unwrap());
+        state.setup_starting_town();
+        state
+    }
+
+    #[test]
+    fn test_stamp_keeps_the_layout_and_moves_the_town_buildings() {
+        let mut state = starting_town();
+        let ids = [state.zones[0].id, state.zones[1].id];
+        let before: Vec<MapRect> = ids
+            .iter()
+            .map(|&id| state.zone_rect(state.zone(id).unwrap()).unwrap())
+            .collect();
+        let blueprint = Blueprint::capture(&state, "Corner", &ids).unwrap();
+        assert_eq!(blueprint.zones.len(), 2);
+        assert!(blueprint.zones.iter().any(|z| z.dx == 0));
+        assert!(blueprint.zones.iter().any(|z| z.dy == 0));
+
+        let (x, y) = (0, 35);
+        let placed = placements(&state, &blueprint, x, y);
+        assert_eq!(placed.len(), 2);
+        assert_eq!(problem(&state, &placed), None);
+        stamp(&mut state, &placed);
+
+        // Same spacing as before, shifted to the new corner
+        let after: Vec<MapRect> = ids
+            .iter()
+            .map(|&id| state.zone_rect(state.zone(id).unwrap()).unwrap())
+            .collect();
+        let left = before.iter().map(|r| r.x).min().unwrap();
+        let top = before.iter().map(|r| r.y).min().unwrap();
+        for ((id, old), new) in ids.iter().zip(&before).zip(&after) {
+            assert_eq!((new.x, new.y), (x + old.x - left, y + old.y - top));
+            let tile = state.world_map.get_tile(new.x, new.y).unwrap();
+            assert_eq!(tile.zone_id, Some(*id));
+        }
+    }
+
+    #[test]
+    fn test_stamp_is_blocked_by_water_and_other_buildings() {
+        let state = starting_town();
+        let ids = [state.zones[0].id, state.zones[1].id];
+        let blueprint = Blueprint::capture(&state, "Corner", &ids).unwrap();
+
+        // Straight over the stream
+        let water = state
+            .world_map
+            .tiles
+            .iter()
+            .position(|t| t.kind == crate::simulation::map::TileType::Water)
+            .unwrap();
+        let width = state.world_map.width;
+        let wet = placements(&state, &blueprint, water % width, water / width);
+        assert!(problem(&state, &wet).is_some());
+
+        // Onto a building that isn't part of the stamp
+        let other = state.zone_rect(&state.zones[2]).unwrap();
+        let crowded = placements(&state, &blueprint, other.x, other.y);
+        assert_eq!(
+            problem(&state, &crowded),
+            Some("another building is in the way")
+        );
+
+        // A town with none of the blueprint's buildings has nothing to move
+        let empty = GameState::default();
+        assert!(placements(&empty, &blueprint, 0, 35).is_empty());
+        assert!(problem(&empty, &[]).is_some());
+    }
+
+    #[test]
+    fn test_blueprints_outlive_the_town_and_cost_a_move_per_building() {
+        let mut state = starting_town();
+        state.resources.materials = 100.0;
+        let ids = [state.zones[0].id, state.zones[1].id];
+        apply_action(&mut state, PlayerAction::DraftBlueprint(true));
+        for id in ids {
+            apply_action(&mut state, PlayerAction::ToggleBlueprintZone(id));
+        }
+        apply_action(&mut state, PlayerAction::SaveBlueprint("Corner".into()));
+        assert!(state.blueprint_draft.is_none());
+        assert_eq!(state.dynasty.blueprints.len(), 1);
+
+        state.found_new_town();
+        assert_eq!(state.dynasty.blueprints[0].name, "Corner");
+
+        state.resources.materials = 100.0;
+        apply_action(
+            &mut state,
+            PlayerAction::PlaceBlueprint("Corner".into(), 0, 35),
+        );
+        assert_eq!(
+            state.resources.materials,
+            100.0 - 2.0 * relocate::RELOCATE_COST
+        );
+        assert!(state
+            .zones
+            .iter()
+            .any(|z| z.map_rect.is_some_and(|r| (r.x, r.y) == (0, 35))));
+    }
+}
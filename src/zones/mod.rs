@@ -1,5 +1,8 @@
 //! Zones module - Zone state and behavior
 
+pub mod blueprint;
+pub mod relocate;
+pub mod survey;
 pub mod upgrades;
 mod zone;
 
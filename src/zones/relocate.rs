@@ -0,0 +1,125 @@
+//! Moving a zone to a new site without losing its progress
+
+use super::ZoneId;
+use crate::data::{GameState, MapRect};
+use crate::simulation::agents::AgentState;
+use crate::simulation::map::TileType;
+
+/// Materials it costs to move a building
+pub const RELOCATE_COST: f32 = 10.0;
+
+/// The zone's footprint moved so its top-left tile is (x, y)
+pub fn site_at(state: &GameState, id: ZoneId, x: usize, y: usize) -> Option<MapRect> {
+    let rect = state.zone(id).and_then(|zone| state.zone_rect(zone))?;
+    Some(MapRect { x, y, ..rect })
+}
+
+/// The zone's footprint centred (as near as the map edge allows) on a tile
+pub fn site_around(state: &GameState, id: ZoneId, tile_x: usize, tile_y: usize) -> Option<MapRect> {
+    let rect = state.zone(id).and_then(|zone| state.zone_rect(zone))?;
+    site_at(
+        state,
+        id,
+        tile_x.saturating_sub(rect.w / 2),
+        tile_y.saturating_sub(rect.h / 2),
+    )
+}
+
+/// Why the zone can't stand on `site`, or None if it can (its own tiles don't count)
+pub fn problem(state: &GameState, id: ZoneId, site: MapRect) -> Option<&'static str> {
+    site_problem(state, site, &[id])
+}
+
+/// Why a building can't stand on `site`, or None if it can. Tiles held by any of
+/// `moving` don't count, since those buildings are about to leave them.
+pub fn site_problem(state: &GameState, site: MapRect, moving: &[ZoneId]) -> Option<&'static str> {
+    for dy in 0..site.h {
+        for dx in 0..site.w {
+            let tile = match state.world_map.get_tile(site.x + dx, site.y + dy) {
+                Some(tile) => tile,
+                None => return Some("it would run off the map"),
+            };
+            if tile.kind == TileType::Water {
+                return Some("the ground there is water");
+            }
+            if tile.kind == TileType::Bridge {
+                return Some("a bridge is in the way");
+            }
+            if tile.zone_id.is_some_and(|other| !moving.contains(&other)) {
+                return Some("another building is in the way");
+            }
+        }
+    }
+    None
+}
+
+/// Move a zone's tiles and builders to `site`. Condition, activity and construction
+/// progress stay with the zone; the old site is left as open ground.
+pub fn relocate(state: &mut GameState, id: ZoneId, site: MapRect) {
+    let kind = lift(state, id);
+    set_down(state, id, site, kind);
+}
+
+/// Clear a zone's tiles back to open ground, returning the tile kind it stood on
+pub fn lift(state: &mut GameState, id: ZoneId) -> TileType {
+    let mut kind = TileType::Ruins;
+    for tile in &mut state.world_map.tiles {
+        if tile.zone_id == Some(id) {
+            kind = tile.kind;
+            tile.kind = TileType::Grass;
+            tile.zone_id = None;
+        }
+    }
+    kind
+}
+
+/// Put a lifted zone down on `site` and send its builders after it
+pub fn set_down(state: &mut GameState, id: ZoneId, site: MapRect, kind: TileType) {
+    state
+        .world_map
+        .set_rect(site.x, site.y, site.w, site.h, kind, Some(id));
+
+    if let Some(index) = state.zone_index(id) {
+        state.zones[index].map_rect = Some(site);
+    }
+
+    // Builders head for the new site
+    let center = site.world_center();
+    for agent in &mut state.agents {
+        if let AgentState::Building { target, zone } = &mut agent.state {
+            if *zone == id {
+                *target = center;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_move_keeps_progress_and_respects_other_buildings() {
+        let mut state = GameState::default();
+        state
+            .zone_templates
+            .extend(crate::assets::load_zones().unwrap());
+        state.setup_starting_town();
+        let id = state.zones[0].id;
+        state.zones[0].condition = 0.6;
+        let other = state.zone_rect(&state.zones[1]).unwrap();
+
+        let blocked = site_at(&state, id, other.x, other.y).unwrap();
+        assert!(problem(&state, id, blocked).is_some());
+
+        let site = site_at(&state, id, 0, 40).unwrap();
+        assert_eq!(problem(&state, id, site), None);
+        relocate(&mut state, id, site);
+
+        assert_eq!(state.zones[0].condition, 0.6);
+        assert_eq!(state.world_map.get_tile(0, 40).unwrap().zone_id, Some(id));
+        assert_eq!(state.world_map.get_tile(22, 20).unwrap().zone_id, None);
+        let moved = state.zone_rect(&state.zones[0]).unwrap();
+        assert_eq!((moved.x, moved.y), (0, 40));
+    }
+}
@@ -0,0 +1,78 @@
+//! Surveying - some ruins start as unidentified rubble until someone looks them over
+
+use crate::data::GameState;
+
+/// Materials spent surveying a ruin
+pub const SURVEY_COST: f32 = 5.0;
+
+/// Ruins whose purpose is plain from the start (homes, water and food)
+const KNOWN_AT_START: [&str; 4] = [
+    "old_homestead",
+    "village_green",
+    "old_well",
+    "community_farm",
+];
+
+/// Hide the kind and cost of every ruin a newcomer wouldn't recognise
+/// (the local biome's signature zones are plain to see too)
+pub fn cover_ruins(state: &mut GameState) {
+    let signature = state.signature_zones();
+    for zone in &mut state.zones {
+        let known = KNOWN_AT_START.contains(&zone.template_id.as_str())
+            || signature.contains(&zone.template_id.as_str());
+        if zone.dormant && !known {
+            zone.surveyed = false;
+        }
+    }
+}
+
+/// Whether the zone at this index is still unidentified rubble
+pub fn is_unsurveyed(state: &GameState, index: usize) -> bool {
+    state.zones.get(index).is_some_and(|z| !z.surveyed)
+}
+
+/// Survey a ruin, paying `SURVEY_COST`. Returns what it turned out to be, or
+/// None if it was already known or the town can't afford it.
+pub fn survey(state: &mut GameState, index: usize) -> Option<String> {
+    if !is_unsurveyed(state, index) || state.resources.materials < SURVEY_COST {
+        return None;
+    }
+    state.resources.materials -= SURVEY_COST;
+    let zone = &mut state.zones[index];
+    zone.surveyed = true;
+    let template_id = zone.template_id.clone();
+    state.get_template(&template_id).map(|t| t.name.clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_surveying_reveals_a_covered_ruin_for_a_price() {
+        let mut state = GameState::default();
+        state
+            .zone_templates
+            .extend(crate::assets::load_zones().unwrap());
+        state.setup_starting_town();
+        cover_ruins(&mut state);
+
+        let homestead = state
+            .zones
+            .iter()
+            .position(|z| z.template_id == "old_homestead")
+            .unwrap();
+        assert!(!is_unsurveyed(&state, homestead));
+        let covered = state.zones.iter().position(|z| !z.surveyed).unwrap();
+
+        state.resources.materials = SURVEY_COST - 1.0;
+        assert_eq!(survey(&mut state, covered), None);
+
+        state.resources.materials = SURVEY_COST;
+        assert!(survey(&mut state, covered).is_some());
+        assert!(!is_unsurveyed(&state, covered));
+        assert_eq!(state.resources.materials, 0.0);
+        // Nothing more to learn
+        assert_eq!(survey(&mut state, covered), None);
+    }
+}
@@ -1,6 +1,6 @@
 //! Building upgrade system
 
-use crate::data::GameState;
+use crate::data::{GameState, ZoneTemplate};
 
 /// Check if a zone can be upgraded
 pub fn can_upgrade(state: &GameState, zone_idx: usize) -> Option<&str> {
@@ -47,18 +47,12 @@ pub fn can_afford_upgrade(state: &GameState, zone_idx: usize) -> bool {
     };
 
     // Check if we have required tech
-    if let Some(ref tech_id) = target.locked_by_tech {
-        let tech_unlocked = state
-            .tech_tree
-            .iter()
-            .any(|t| &t.id == tech_id && t.unlocked);
-        if !tech_unlocked {
-            return false;
-        }
+    if state.missing_tech(target).is_some() {
+        return false;
     }
 
-    // Check if we can afford it
-    state.resources.materials >= target.construction_cost
+    // Check if we can afford every part of it
+    state.resources.has(&target.construction_cost)
 }
 
 /// Apply an upgrade to a zone (changes its template)
@@ -77,17 +71,17 @@ pub fn apply_upgrade(state: &mut GameState, zone_idx: usize) -> Option<String> {
         (
             zone.template_id.clone(),
             target_id.clone(),
-            target.construction_cost,
+            target.construction_cost.clone(),
         )
     };
 
     // Check affordability
-    if state.resources.materials < cost {
+    if !state.resources.has(&cost) {
         return None;
     }
 
     // Deduct cost
-    state.resources.materials -= cost;
+    state.resources.spend(&cost);
 
     // Update zone template
     if let Some(zone) = state.zones.get_mut(zone_idx) {
@@ -111,3 +105,100 @@ pub fn apply_upgrade(state: &mut GameState, zone_idx: usize) -> Option<String> {
 
     Some(old_id)
 }
+
+/// One stat of a zone before and after an upgrade
+#[derive(Debug, Clone, PartialEq)]
+pub struct StatChange {
+    pub label: &'static str,
+    pub current: f32,
+    pub upgraded: f32,
+    /// Upkeep and cost are better lower; everything else is better higher
+    pub higher_is_better: bool,
+}
+
+impl StatChange {
+    pub fn delta(&self) -> f32 {
+        self.upgraded - self.current
+    }
+
+    /// Whether the upgrade makes this stat better (false if it's unchanged)
+    pub fn is_improvement(&self) -> bool {
+        if self.higher_is_better {
+            self.delta() > 0.0
+        } else {
+            self.delta() < 0.0
+        }
+    }
+}
+
+/// A compared stat: its label, how to read it off a template, and whether more is better
+type StatReader = (&'static str, fn(&ZoneTemplate) -> f32, bool);
+
+/// Housing, output, upkeep and build cost of two templates side by side.
+/// Stats that are zero for both are left out.
+pub fn compare(current: &ZoneTemplate, target: &ZoneTemplate) -> Vec<StatChange> {
+    let cost = |template: &ZoneTemplate| -> f32 {
+        template
+            .construction_cost
+            .components()
+            .iter()
+            .map(|(_, amount)| amount)
+            .sum()
+    };
+    let stats: [StatReader; 8] = [
+        ("Housing", |t| t.population.capacity, true),
+        ("Materials", |t| t.output.materials, true),
+        ("Maintenance", |t| t.output.maintenance, true),
+        ("Attractiveness", |t| t.output.attractiveness, true),
+        ("Stability", |t| t.output.stability, true),
+        ("Food", |t| t.food_output, true),
+        ("Upkeep (Mat)", |t| t.upkeep.materials, false),
+        ("Upkeep (Maint)", |t| t.upkeep.maintenance, false),
+    ];
+
+    let mut changes: Vec<StatChange> = stats
+        .iter()
+        .map(|&(label, stat, higher_is_better)| StatChange {
+            label,
+            current: stat(current),
+            upgraded: stat(target),
+            higher_is_better,
+        })
+        .collect();
+    changes.push(StatChange {
+        label: "Build cost",
+        current: cost(current),
+        upgraded: cost(target),
+        higher_is_better: false,
+    });
+    changes.retain(|change| change.current != 0.0 || change.upgraded != 0.0);
+    changes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compare_marks_gains_and_costs() {
+        let templates = crate::assets::load_zones().unwrap();
+        let current = templates
+            .iter()
+            .find(|t| t.population.capacity > 0.0)
+            .unwrap()
+            .clone();
+        let mut target = current.clone();
+        target.population.capacity += 4.0;
+        target.upkeep.materials += 0.5;
+
+        let changes = compare(&current, &target);
+        let housing = changes.iter().find(|c| c.label == "Housing").unwrap();
+        assert_eq!(housing.delta(), 4.0);
+        assert!(housing.is_improvement());
+        let upkeep = changes.iter().find(|c| c.label == "Upkeep (Mat)").unwrap();
+        assert!(!upkeep.is_improvement());
+        assert!(changes
+            .iter()
+            .all(|c| c.current != 0.0 || c.upgraded != 0.0));
+    }
+}
@@ -1,7 +1,8 @@
 //! Zone runtime state
 
-use crate::data::ZoneTemplate;
+use crate::data::{MapRect, ZoneTemplate};
 use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
 
 /// Construction state for zones being built
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -25,9 +26,33 @@ impl Default for ConstructionState {
     }
 }
 
+/// How far a ruin has come back to life while being rebuilt (stored as `Zone::reawakening_stage`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ReawakeningStage {
+    /// Untouched ruins
+    Ruin = 0,
+    /// Builders have put up scaffolding
+    Scaffolding = 1,
+    /// Walls are going up
+    Partial = 2,
+    /// Finished and working again
+    Awake = 3,
+}
+
+/// Construction progress at which scaffolding gives way to the half-built building
+const PARTIAL_AT: f32 = 0.5;
+
+/// Stable zone identity, unaffected by the zone's position in `GameState::zones`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ZoneId(pub u32);
+
 /// A zone's current runtime state (separate from template data)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Zone {
+    /// Stable ID used by tiles, selection and anything else that refers to the zone
+    #[serde(default)]
+    pub id: ZoneId,
+
     /// Reference to template ID
     pub template_id: String,
 
@@ -46,21 +71,58 @@ pub struct Zone {
     /// Construction state for new zones
     #[serde(default)]
     pub construction_state: ConstructionState,
+
+    /// Mothballed by the player: intact, but produces nothing and costs no upkeep
+    #[serde(default)]
+    pub disabled: bool,
+
+    /// Where the zone stands after being moved (None = where its template puts it)
+    #[serde(default)]
+    pub map_rect: Option<MapRect>,
+
+    /// Whether the ruin has been looked over; until then its kind and restore
+    /// cost are unknown (see `zones::survey`)
+    #[serde(default = "default_surveyed")]
+    pub surveyed: bool,
+
+    /// Net materials per hour sampled once a game day, oldest first (working zones only)
+    #[serde(skip)]
+    pub output_history: VecDeque<f32>,
+}
+
+/// Zones from saves made before surveying existed are all known
+fn default_surveyed() -> bool {
+    true
 }
 
 impl Zone {
     /// Create a new zone from a template (starts dormant, needs restoration)
-    pub fn new(template_id: &str) -> Self {
+    pub fn new(id: ZoneId, template_id: &str) -> Self {
         Self {
+            id,
             template_id: template_id.to_string(),
             condition: 0.0,
             activity: 0.0,
             dormant: true,
             reawakening_stage: 0,
             construction_state: ConstructionState::None,
+            disabled: false,
+            map_rect: None,
+            surveyed: true,
+            output_history: VecDeque::new(),
         }
     }
 
+    /// Tiles the zone covers: its own site if it was moved, else the template's
+    pub fn footprint(&self, template: &ZoneTemplate) -> Option<MapRect> {
+        self.map_rect.or(template.map_rect)
+    }
+
+    /// Whether the zone currently produces and pays upkeep (not dormant or disabled)
+    pub fn is_operating(&self) -> bool {
+        !self.dormant && !self.disabled
+    }
+
     /// Check if zone is under construction
     pub fn is_under_construction(&self) -> bool {
         matches!(
@@ -84,21 +146,59 @@ impl Zone {
 
     /// Calculate effective throughput based on condition, activity, and saturation
     /// Formula: base × condition × saturation(activity, bias)
+    /// The saturation shape comes from the template's `saturation_curve`.
     /// We removed the extra 'activity' multiplier to prevent double-penalty at low pop.
     pub fn calculate_throughput(&self, template: &ZoneTemplate) -> f32 {
         if self.dormant || self.is_under_construction() {
             return 0.0;
         }
 
-        let saturation = self.activity / (self.activity + template.saturation_bias);
+        let saturation = template
+            .saturation_curve
+            .apply(self.activity, template.saturation_bias);
         template.base_throughput * self.condition * saturation
     }
 
+    /// Begin rebuilding a dormant zone from scratch (builders finish the job)
+    pub fn begin_construction(&mut self) {
+        self.construction_state = ConstructionState::UnderConstruction {
+            work_done: 0.0,
+            materials_deposited: false,
+        };
+        self.reawakening_stage = ReawakeningStage::Scaffolding as u8;
+    }
+
+    /// Current reawakening stage (zones working before stages were tracked count as awake)
+    pub fn reawakening(&self) -> ReawakeningStage {
+        if !self.dormant && !self.is_under_construction() {
+            return ReawakeningStage::Awake;
+        }
+        match self.reawakening_stage {
+            0 => ReawakeningStage::Ruin,
+            1 => ReawakeningStage::Scaffolding,
+            2 => ReawakeningStage::Partial,
+            _ => ReawakeningStage::Awake,
+        }
+    }
+
+    /// Move the reawakening stage along with construction progress (0-1); it never goes back
+    pub fn advance_reawakening(&mut self, progress: f32) {
+        let stage = if progress >= 1.0 {
+            ReawakeningStage::Awake
+        } else if progress >= PARTIAL_AT {
+            ReawakeningStage::Partial
+        } else {
+            ReawakeningStage::Scaffolding
+        };
+        self.reawakening_stage = self.reawakening_stage.max(stage as u8);
+    }
+
     /// Restore some condition (player action)
     pub fn restore(&mut self, amount: f32) {
         self.condition = (self.condition + amount).min(1.0);
         if self.dormant && self.condition > 0.1 {
             self.dormant = false;
+            self.reawakening_stage = ReawakeningStage::Awake as u8;
         }
     }
 }